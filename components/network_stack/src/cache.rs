@@ -5,7 +5,7 @@
 
 use crate::error::{NetworkError, NetworkResult};
 use crate::request::NetworkRequest;
-use crate::response::{CacheStatus, NetworkResponse};
+use crate::response::{CacheStatus, NetworkResponse, StatusCode};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -114,6 +114,15 @@ pub trait CacheStorage: Send + Sync {
     /// Get a cached response
     async fn get(&self, url: &Url) -> NetworkResult<Option<CacheEntry>>;
 
+    /// Get a cached response even if it is no longer fresh
+    ///
+    /// Used for revalidation and by cache modes that accept stale entries.
+    /// Defaults to [`CacheStorage::get`], for backends that drop stale
+    /// entries.
+    async fn get_stale(&self, url: &Url) -> NetworkResult<Option<CacheEntry>> {
+        self.get(url).await
+    }
+
     /// Store a response in the cache
     async fn put(&self, url: &Url, entry: CacheEntry) -> NetworkResult<()>;
 
@@ -195,6 +204,10 @@ impl CacheStorage for MemoryCache {
         Ok(entry)
     }
 
+    async fn get_stale(&self, url: &Url) -> NetworkResult<Option<CacheEntry>> {
+        Ok(self.entries.read().await.get(url.as_str()).cloned())
+    }
+
     async fn put(&self, url: &Url, entry: CacheEntry) -> NetworkResult<()> {
         if !entry.cacheable {
             return Ok(());
@@ -291,6 +304,23 @@ impl CacheStorage for DiskCache {
         Ok(Some(entry))
     }
 
+    async fn get_stale(&self, url: &Url) -> NetworkResult<Option<CacheEntry>> {
+        let path = self.cache_path(url);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let data = tokio::fs::read(&path)
+            .await
+            .map_err(|e| NetworkError::Internal(format!("Failed to read cache: {}", e)))?;
+
+        let entry = serde_json::from_slice(&data)
+            .map_err(|e| NetworkError::Internal(format!("Failed to deserialize cache: {}", e)))?;
+
+        Ok(Some(entry))
+    }
+
     async fn put(&self, url: &Url, entry: CacheEntry) -> NetworkResult<()> {
         if !entry.cacheable {
             return Ok(());
@@ -376,22 +406,40 @@ impl<S: CacheStorage + std::fmt::Debug + 'static> crate::interceptor::RequestInt
         use crate::interceptor::InterceptorOutcome;
         use crate::request::CacheMode;
 
-        // Check cache mode
-        match request.cache_mode {
-            CacheMode::NoStore | CacheMode::Reload => {
-                // Bypass cache
-                return Ok(InterceptorOutcome::Continue(request));
+        let mode = request.cache_mode;
+        if matches!(mode, CacheMode::NoStore | CacheMode::Reload) {
+            // Bypass cache
+            return Ok(InterceptorOutcome::Continue(request));
+        }
+
+        // Stale entries are kept for revalidation and the modes that accept them
+        let entry = self.storage.get_stale(&request.url).await?;
+
+        if matches!(mode, CacheMode::ForceCache | CacheMode::OnlyIfCached) {
+            if let Some(entry) = entry {
+                tracing::debug!(url = %request.url, ?mode, "Cache hit, staleness allowed");
+                let mut response = entry.response.clone();
+                response.cache_status = if entry.is_fresh() {
+                    CacheStatus::Hit
+                } else {
+                    CacheStatus::Stale
+                };
+                return Ok(InterceptorOutcome::ShortCircuit(Box::new(response)));
+            }
+            if mode == CacheMode::OnlyIfCached {
+                tracing::debug!(url = %request.url, "Cache miss for only-if-cached request");
+                let response =
+                    NetworkResponse::new(StatusCode::GATEWAY_TIMEOUT, request.url.clone());
+                return Ok(InterceptorOutcome::ShortCircuit(Box::new(response)));
             }
-            _ => {}
         }
 
-        // Try to get from cache
-        if let Some(entry) = self.storage.get(&request.url).await? {
-            if entry.is_fresh() {
+        if let Some(entry) = entry {
+            if entry.is_fresh() && mode != CacheMode::NoCache {
                 tracing::debug!(url = %request.url, "Cache hit");
                 let mut response = entry.response.clone();
                 response.cache_status = CacheStatus::Hit;
-                return Ok(InterceptorOutcome::ShortCircuit(response));
+                return Ok(InterceptorOutcome::ShortCircuit(Box::new(response)));
             } else if entry.can_revalidate() {
                 tracing::debug!(url = %request.url, "Cache stale, revalidating");
                 // Add conditional headers for revalidation
@@ -436,7 +484,7 @@ impl<S: CacheStorage + std::fmt::Debug + 'static> crate::interceptor::ResponseIn
         // Handle 304 Not Modified
         if response.status.as_u16() == 304 {
             // Return cached response
-            if let Some(entry) = self.storage.get(&request.url).await? {
+            if let Some(entry) = self.storage.get_stale(&request.url).await? {
                 tracing::debug!(url = %request.url, "304 Not Modified, using cached response");
                 let mut cached = entry.response;
                 cached.cache_status = CacheStatus::Revalidated;
@@ -521,4 +569,121 @@ mod tests {
         let cached = cache.get(&url).await.unwrap();
         assert!(cached.is_none());
     }
+
+    /// Serve `response` to every connection and count the requests.
+    async fn serve_counting(response: &'static str) -> (Url, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::Ordering;
+
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        let (url, _requests) = crate::test_server::serve_with(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            response
+        })
+        .await;
+
+        (url, hits)
+    }
+
+    fn caching_client(cache: &CachingInterceptor<MemoryCache>) -> crate::HttpClient {
+        crate::HttpClientBuilder::new()
+            .request_interceptor(Arc::new(cache.clone()))
+            .response_interceptor(Arc::new(cache.clone()))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_no_store_never_writes_to_cache() {
+        use crate::client::NetworkClient;
+        use crate::request::CacheMode;
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_counting(
+            "HTTP/1.1 200 OK\r\nCache-Control: max-age=3600\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+        )
+        .await;
+        let cache = CachingInterceptor::new(MemoryCache::default());
+        let client = caching_client(&cache);
+
+        for _ in 0..2 {
+            let request = NetworkRequest::get(url.clone()).cache_mode(CacheMode::NoStore);
+            let response = client.fetch(request).await.unwrap();
+            assert_eq!(response.cache_status, CacheStatus::Miss);
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert!(cache.storage().get_stale(&url).await.unwrap().is_none());
+        assert_eq!(cache.storage().size().await.unwrap(), 0);
+
+        // Nothing stored, so only-if-cached can't be satisfied
+        let request = NetworkRequest::get(url.clone()).cache_mode(CacheMode::OnlyIfCached);
+        let response = client.fetch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_force_cache_serves_stored_entry_without_network() {
+        use crate::client::NetworkClient;
+        use crate::request::CacheMode;
+        use std::sync::atomic::Ordering;
+
+        // Stale as soon as it's stored, but revalidatable
+        let (url, hits) = serve_counting(
+            "HTTP/1.1 200 OK\r\nCache-Control: max-age=0\r\nETag: \"v1\"\r\nContent-Length: 2\r\nConnection: close\r\n\r\nv1",
+        )
+        .await;
+        let cache = CachingInterceptor::new(MemoryCache::default());
+        let client = caching_client(&cache);
+
+        client
+            .fetch(NetworkRequest::get(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        for mode in [CacheMode::ForceCache, CacheMode::OnlyIfCached] {
+            let request = NetworkRequest::get(url.clone()).cache_mode(mode);
+            let response = client.fetch(request).await.unwrap();
+            assert_eq!(response.body, b"v1");
+            assert_eq!(response.cache_status, CacheStatus::Stale);
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // The default mode revalidates the stale entry
+        client
+            .fetch(NetworkRequest::get(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_cache_revalidates_fresh_entry() {
+        use crate::interceptor::{InterceptorOutcome, RequestInterceptor};
+        use crate::request::CacheMode;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let mut response = NetworkResponse::new(StatusCode::OK, url.clone());
+        response
+            .headers
+            .insert("cache-control".to_string(), "max-age=3600".to_string());
+        response
+            .headers
+            .insert("etag".to_string(), "\"v1\"".to_string());
+        let cache = CachingInterceptor::new(MemoryCache::default());
+        cache
+            .storage()
+            .put(&url, CacheEntry::from_response(&response))
+            .await
+            .unwrap();
+
+        let request = NetworkRequest::get(url.clone()).cache_mode(CacheMode::NoCache);
+        match cache.intercept_request(request).await.unwrap() {
+            InterceptorOutcome::Continue(request) => {
+                assert_eq!(request.headers.get("If-None-Match").unwrap(), "\"v1\"");
+            }
+            _ => panic!("Expected a conditional request"),
+        }
+    }
 }
@@ -3,26 +3,39 @@
 //! This module provides the main [`NetworkClient`] trait and its implementation
 //! using reqwest for HTTP requests.
 
+use crate::cookie::{cookie_header, CookieStore, MemoryCookieStore};
 use crate::error::{NetworkError, NetworkResult};
+use crate::https_upgrade_interceptor::HttpsUpgradeInterceptor;
 use crate::interceptor::{
     InterceptorOutcome, RequestInterceptor, RequestInterceptorChain, ResponseInterceptor,
     ResponseInterceptorChain,
 };
-use crate::request::NetworkRequest;
-use crate::response::{CacheStatus, NetworkResponse, StatusCode};
+use crate::pool_stats::{connection_reusable, ActiveRequest, PoolStats, PoolTracker};
+use crate::proxy::ProxyConfig;
+use crate::redirect::{self, RedirectPolicy};
+use crate::request::{NetworkRequest, RedirectMode};
+use crate::response::{read_chunk, CacheStatus, NetworkResponse, ResponseStream, StatusCode};
+use crate::retry::RetryPolicy;
+use crate::throttle::ThrottleConfig;
 use async_trait::async_trait;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 use url::Url;
 
 /// Configuration for the network client.
 #[derive(Debug, Clone)]
 pub struct NetworkClientConfig {
-    /// Default timeout for requests.
+    /// Default overall timeout for requests, from sending to the end of
+    /// the body.
     pub default_timeout: Duration,
-    /// Maximum number of redirects to follow.
-    pub max_redirects: u32,
+    /// Maximum time to establish a connection.
+    pub connect_timeout: Duration,
+    /// Maximum wait for each chunk of a response body (`None` for no limit).
+    pub read_timeout: Option<Duration>,
+    /// How redirects are followed.
+    pub redirect_policy: RedirectPolicy,
     /// Whether to accept invalid certificates (for development).
     pub accept_invalid_certs: bool,
     /// Connection pool idle timeout.
@@ -41,13 +54,24 @@ pub struct NetworkClientConfig {
     pub brotli_enabled: bool,
     /// Maximum response body size in bytes.
     pub max_response_size: usize,
+    /// Maximum concurrent requests per origin (`None` for unlimited).
+    pub max_concurrent_per_host: Option<usize>,
+    /// Automatic retry behavior (`None` to never retry).
+    pub retry_policy: Option<RetryPolicy>,
+    /// Proxy settings (`None` to use the system proxy environment
+    /// variables).
+    pub proxy: Option<ProxyConfig>,
+    /// Simulated slow connection, for testing (`None` for full speed).
+    pub throttle: Option<ThrottleConfig>,
 }
 
 impl Default for NetworkClientConfig {
     fn default() -> Self {
         Self {
-            default_timeout: Duration::from_secs(30),
-            max_redirects: 10,
+            default_timeout: NetworkRequest::DEFAULT_TIMEOUT,
+            connect_timeout: Duration::from_secs(10),
+            read_timeout: None,
+            redirect_policy: RedirectPolicy::default(),
             accept_invalid_certs: false,
             pool_idle_timeout: Duration::from_secs(90),
             pool_max_idle_per_host: 10,
@@ -57,6 +81,10 @@ impl Default for NetworkClientConfig {
             gzip_enabled: true,
             brotli_enabled: true,
             max_response_size: 100 * 1024 * 1024, // 100MB
+            max_concurrent_per_host: None,
+            retry_policy: None,
+            proxy: None,
+            throttle: None,
         }
     }
 }
@@ -67,6 +95,12 @@ pub trait NetworkClient: Send + Sync {
     /// Fetch a resource.
     async fn fetch(&self, request: NetworkRequest) -> NetworkResult<NetworkResponse>;
 
+    /// Fetch a resource, streaming the response body as it arrives.
+    ///
+    /// Request interceptors run as for [`NetworkClient::fetch`]; response
+    /// interceptors, which need the full body, are skipped.
+    async fn fetch_stream(&self, request: NetworkRequest) -> NetworkResult<ResponseStream>;
+
     /// Add a request interceptor.
     async fn add_request_interceptor(&self, interceptor: Arc<dyn RequestInterceptor>);
 
@@ -83,6 +117,10 @@ pub struct HttpClient {
     config: NetworkClientConfig,
     request_interceptors: RwLock<RequestInterceptorChain>,
     response_interceptors: RwLock<ResponseInterceptorChain>,
+    /// Per-origin concurrency limits, created on first request to each origin
+    host_semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+    cookie_store: Option<Arc<dyn CookieStore>>,
+    pool: PoolTracker,
 }
 
 impl std::fmt::Debug for HttpClient {
@@ -103,20 +141,25 @@ impl HttpClient {
     pub fn with_config(config: NetworkClientConfig) -> NetworkResult<Self> {
         let mut builder = reqwest::Client::builder()
             .timeout(config.default_timeout)
+            .connect_timeout(config.connect_timeout)
             .pool_idle_timeout(config.pool_idle_timeout)
             .pool_max_idle_per_host(config.pool_max_idle_per_host)
             .user_agent(&config.user_agent)
-            .redirect(reqwest::redirect::Policy::limited(
-                config.max_redirects as usize,
-            ))
-            .danger_accept_invalid_certs(config.accept_invalid_certs);
-
-        if config.gzip_enabled {
-            builder = builder.gzip(true);
-        }
-
-        if config.brotli_enabled {
-            builder = builder.brotli(true);
+            // Redirects are followed in `send_request`, hop by hop
+            .redirect(reqwest::redirect::Policy::none())
+            .danger_accept_invalid_certs(config.accept_invalid_certs)
+            // reqwest decodes bodies and drops the Content-Encoding and
+            // Content-Length headers, which no longer describe them
+            .gzip(config.gzip_enabled)
+            .brotli(config.brotli_enabled)
+            .deflate(config.gzip_enabled);
+
+        if let Some(proxy) = &config.proxy {
+            // Explicit settings replace the system proxy entirely
+            builder = builder.no_proxy();
+            for proxy in proxy.to_reqwest()? {
+                builder = builder.proxy(proxy);
+            }
         }
 
         let inner = builder
@@ -125,46 +168,221 @@ impl HttpClient {
 
         Ok(Self {
             inner,
+            pool: PoolTracker::new(config.pool_idle_timeout, config.pool_max_idle_per_host),
             config,
             request_interceptors: RwLock::new(RequestInterceptorChain::new()),
             response_interceptors: RwLock::new(ResponseInterceptorChain::new()),
+            host_semaphores: Mutex::new(HashMap::new()),
+            cookie_store: None,
         })
     }
 
-    /// Execute the actual HTTP request.
-    async fn execute_request(&self, request: &NetworkRequest) -> NetworkResult<NetworkResponse> {
-        let start = Instant::now();
+    /// The `Accept-Encoding` value advertising the enabled decoders.
+    fn accept_encoding(&self) -> String {
+        let mut encodings = Vec::new();
+        if self.config.gzip_enabled {
+            encodings.extend(["gzip", "deflate"]);
+        }
+        if self.config.brotli_enabled {
+            encodings.push("br");
+        }
+        if encodings.is_empty() {
+            encodings.push("identity");
+        }
+        encodings.join(", ")
+    }
+
+    /// Get a snapshot of connection pool activity.
+    ///
+    /// Active counts are requests in flight; idle counts are estimates, as
+    /// the underlying pool isn't observable directly.
+    pub fn pool_stats(&self) -> PoolStats {
+        self.pool.snapshot()
+    }
+
+    /// Get the cookie store, if one is configured.
+    pub fn cookie_store(&self) -> Option<&Arc<dyn CookieStore>> {
+        self.cookie_store.as_ref()
+    }
+
+    /// Wait for a per-origin request slot, if a per-host cap is configured.
+    async fn acquire_host_permit(&self, url: &Url) -> NetworkResult<Option<OwnedSemaphorePermit>> {
+        let Some(limit) = self.config.max_concurrent_per_host else {
+            return Ok(None);
+        };
+
+        let origin = url.origin().ascii_serialization();
+        let semaphore = {
+            let mut semaphores = self
+                .host_semaphores
+                .lock()
+                .map_err(|e| NetworkError::Internal(e.to_string()))?;
+            Arc::clone(
+                semaphores
+                    .entry(origin)
+                    .or_insert_with(|| Arc::new(Semaphore::new(limit.max(1)))),
+            )
+        };
+
+        semaphore
+            .acquire_owned()
+            .await
+            .map(Some)
+            .map_err(|e| NetworkError::Internal(format!("Failed to acquire permit: {}", e)))
+    }
+
+    /// Run the request interceptors.
+    ///
+    /// Returns the request to send, or the response an interceptor
+    /// short-circuited with.
+    async fn run_request_interceptors(
+        &self,
+        request: NetworkRequest,
+    ) -> NetworkResult<Result<NetworkRequest, NetworkResponse>> {
+        let interceptors = self.request_interceptors.read().await;
+        match interceptors.intercept(request).await? {
+            InterceptorOutcome::Continue(req) => Ok(Ok(req)),
+            InterceptorOutcome::ShortCircuit(response) => Ok(Err(*response)),
+            InterceptorOutcome::Cancel(reason) => Err(NetworkError::RequestCancelled { reason }),
+        }
+    }
+
+    /// Send the request, following redirects per the redirect policy, and
+    /// receive the final response headers.
+    ///
+    /// Also returns the URLs redirected from, in order, and the pool guard
+    /// for the final hop. Each hop counts against its own origin's pool.
+    async fn send_request(
+        &self,
+        request: &NetworkRequest,
+        compressed: bool,
+    ) -> NetworkResult<(
+        reqwest::Response,
+        crate::request::HeaderMap,
+        Vec<Url>,
+        ActiveRequest,
+    )> {
+        let mut redirect_chain = Vec::new();
+        let mut redirected: Option<NetworkRequest> = None;
+        loop {
+            let hop = redirected.as_ref().unwrap_or(request);
+            let mut active = self.pool.begin(&hop.url);
+            let (response, headers) = self.send_hop(hop, compressed).await?;
+            active.set_reusable(connection_reusable(&response));
+
+            let status = StatusCode::from(response.status());
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|value| value.to_str().ok());
+            let location = match location {
+                Some(location)
+                    if redirect::is_followable(status)
+                        && hop.redirect_mode != RedirectMode::Manual =>
+                {
+                    location
+                }
+                _ => return Ok((response, headers, redirect_chain, active)),
+            };
+
+            let next = self.config.redirect_policy.follow(
+                hop,
+                status,
+                location,
+                redirect_chain.len() as u32,
+            )?;
+            tracing::debug!(from = %hop.url, to = %next.url, status = status.as_u16(), "Following redirect");
+            redirect_chain.push(hop.url.clone());
+            redirected = Some(next);
+        }
+    }
 
+    /// Send a single request and receive the response headers.
+    ///
+    /// Unless the request sets its own `Accept-Encoding`, a compressed body
+    /// is requested when `compressed` is true and an unencoded one
+    /// otherwise. Fails if the declared body length exceeds the maximum
+    /// response size.
+    async fn send_hop(
+        &self,
+        request: &NetworkRequest,
+        compressed: bool,
+    ) -> NetworkResult<(reqwest::Response, crate::request::HeaderMap)> {
         // Build reqwest request
+        let timeout = request.timeout.unwrap_or(self.config.default_timeout);
         let mut req_builder = self
             .inner
             .request(request.method.into(), request.url.clone())
-            .timeout(request.timeout);
+            .timeout(timeout);
 
         // Add headers
         for (key, value) in &request.headers {
             req_builder = req_builder.header(key, value);
         }
 
+        let has_accept_encoding = request
+            .headers
+            .keys()
+            .any(|key| key.eq_ignore_ascii_case("accept-encoding"));
+        if !has_accept_encoding {
+            let accept_encoding = if compressed {
+                self.accept_encoding()
+            } else {
+                "identity".to_string()
+            };
+            req_builder = req_builder.header("Accept-Encoding", accept_encoding);
+        }
+
+        // Attach stored cookies unless the caller set the header explicitly
+        if let Some(store) = &self.cookie_store {
+            let has_cookie_header = request
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("cookie"));
+            if !has_cookie_header {
+                let cookies = store.cookies_for(&request.url).await;
+                if !cookies.is_empty() {
+                    req_builder = req_builder.header("Cookie", cookie_header(&cookies));
+                }
+            }
+        }
+
         // Add body
         if let Some(body) = &request.body {
             req_builder = req_builder.body(body.clone());
         }
 
-        // Note: Redirect policy is handled at the client level in reqwest.
-        // Per-request redirect policy would require creating a new client,
-        // which is expensive. For now, we use the client-level setting.
-        // If specific redirect handling is needed, the response will include
-        // redirect information that can be handled by the caller.
-        #[allow(clippy::let_underscore_untyped)]
-        let _ = &request.redirect_policy;
+        if let Some(throttle) = &self.config.throttle {
+            let body_len = request.body.as_ref().map_or(0, |body| body.len());
+            tokio::time::sleep(throttle.send_delay(body_len)).await;
+        }
 
         // Execute request
-        let response = req_builder.send().await?;
-
-        let elapsed = start.elapsed();
-        let status = StatusCode::from(response.status());
-        let final_url = response.url().clone();
+        let response = req_builder.send().await.map_err(|e| {
+            if e.is_timeout() {
+                let limit = if e.is_connect() {
+                    self.config.connect_timeout
+                } else {
+                    timeout
+                };
+                NetworkError::timeout(&request.url, limit.as_millis() as u64)
+            } else {
+                e.into()
+            }
+        })?;
+
+        if let Some(store) = &self.cookie_store {
+            let set_cookies: Vec<String> = response
+                .headers()
+                .get_all(reqwest::header::SET_COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .map(str::to_string)
+                .collect();
+            if !set_cookies.is_empty() {
+                store.store(response.url(), &set_cookies).await;
+            }
+        }
 
         // Convert headers
         let mut headers = crate::request::HeaderMap::new();
@@ -184,21 +402,115 @@ impl HttpClient {
             }
         }
 
-        // Read body
-        let body = response.bytes().await?;
+        Ok((response, headers))
+    }
+
+    /// Execute the request, retrying transient failures per the retry policy.
+    async fn execute_with_retry(&self, request: &NetworkRequest) -> NetworkResult<NetworkResponse> {
+        let policy = self
+            .config
+            .retry_policy
+            .as_ref()
+            .filter(|policy| policy.allows_method(request.method));
+
+        let mut retry = 0;
+        loop {
+            // Queue behind other requests to the same origin when a per-host
+            // cap is set; the slot is released while waiting to retry
+            let permit = self.acquire_host_permit(&request.url).await?;
+            let result = self.execute_request(request).await;
+            drop(permit);
+
+            let Some(policy) = policy.filter(|policy| retry < policy.max_retries) else {
+                return result;
+            };
+            let delay = match &result {
+                Ok(response) if policy.should_retry_status(response.status) => {
+                    policy.delay_for_response(retry, response)
+                }
+                Err(error) if policy.should_retry_error(error) => policy.jittered_backoff(retry),
+                _ => return result,
+            };
+
+            retry += 1;
+            tracing::debug!(
+                url = %request.url,
+                retry,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying request"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Execute the actual HTTP request.
+    async fn execute_request(&self, request: &NetworkRequest) -> NetworkResult<NetworkResponse> {
+        let start = Instant::now();
+        let (mut response, headers, redirect_chain, mut active) =
+            self.send_request(request, true).await?;
+
+        let elapsed = start.elapsed();
+        let status = StatusCode::from(response.status());
+        let final_url = response.url().clone();
+
+        // Declared length of a non-chunked, unencoded body (None otherwise)
+        let content_length = response.content_length();
+
+        // Read body, detecting truncation against the declared length
+        let read_timeout = request.read_timeout.or(self.config.read_timeout);
+        let mut pacer = self
+            .config
+            .throttle
+            .as_ref()
+            .and_then(ThrottleConfig::download_pacer);
+        let mut body = Vec::new();
+        loop {
+            match read_chunk(&mut response, read_timeout).await {
+                Ok(Some(chunk)) => {
+                    if let Some(pacer) = &mut pacer {
+                        pacer.pace(chunk.len()).await;
+                    }
+                    body.extend_from_slice(&chunk);
+                    if body.len() > self.config.max_response_size {
+                        return Err(NetworkError::ResponseTooLarge {
+                            size: body.len(),
+                            max_size: self.config.max_response_size,
+                        });
+                    }
+                }
+                Ok(None) => break,
+                Err(e @ NetworkError::Timeout { .. }) => return Err(e),
+                Err(e) => {
+                    return Err(match content_length {
+                        Some(expected) if (body.len() as u64) < expected => {
+                            NetworkError::IncompleteBody {
+                                expected,
+                                received: body.len() as u64,
+                            }
+                        }
+                        _ => e,
+                    });
+                }
+            }
+        }
 
-        if body.len() > self.config.max_response_size {
-            return Err(NetworkError::ResponseTooLarge {
-                size: body.len(),
-                max_size: self.config.max_response_size,
-            });
+        if let Some(expected) = content_length {
+            if (body.len() as u64) < expected {
+                return Err(NetworkError::IncompleteBody {
+                    expected,
+                    received: body.len() as u64,
+                });
+            }
         }
 
+        active.complete();
+
         Ok(NetworkResponse::new(status, final_url)
             .headers(headers)
-            .body(body.to_vec())
+            .body(body)
             .elapsed(elapsed)
-            .cache_status(CacheStatus::Miss))
+            .cache_status(CacheStatus::Miss)
+            .redirect_chain(redirect_chain))
     }
 }
 
@@ -212,24 +524,44 @@ impl Default for HttpClient {
 impl NetworkClient for HttpClient {
     async fn fetch(&self, request: NetworkRequest) -> NetworkResult<NetworkResponse> {
         // Run request interceptors
-        let interceptors = self.request_interceptors.read().await;
-        let request = match interceptors.intercept(request).await? {
-            InterceptorOutcome::Continue(req) => req,
-            InterceptorOutcome::ShortCircuit(response) => return Ok(response),
-            InterceptorOutcome::Cancel(reason) => {
-                return Err(NetworkError::RequestCancelled { reason })
-            }
+        let request = match self.run_request_interceptors(request).await? {
+            Ok(request) => request,
+            Err(response) => return Ok(response),
         };
-        drop(interceptors);
 
         // Execute the actual request
-        let response = self.execute_request(&request).await?;
+        let response = self.execute_with_retry(&request).await?;
 
         // Run response interceptors
         let interceptors = self.response_interceptors.read().await;
         interceptors.intercept(&request, response).await
     }
 
+    async fn fetch_stream(&self, request: NetworkRequest) -> NetworkResult<ResponseStream> {
+        let request = match self.run_request_interceptors(request).await? {
+            Ok(request) => request,
+            Err(response) => return Ok(ResponseStream::from_response(response)),
+        };
+
+        // The per-host slot is held until the body stream is dropped
+        let permit = self.acquire_host_permit(&request.url).await?;
+        // Ask for an unencoded body, since chunks are handed out as-is
+        let (response, headers, _, active) = self.send_request(&request, false).await?;
+
+        Ok(ResponseStream::from_reqwest(
+            response,
+            headers,
+            self.config.max_response_size,
+            request.read_timeout.or(self.config.read_timeout),
+            self.config
+                .throttle
+                .as_ref()
+                .and_then(ThrottleConfig::download_pacer),
+            permit,
+            Some(active),
+        ))
+    }
+
     async fn add_request_interceptor(&self, interceptor: Arc<dyn RequestInterceptor>) {
         let mut chain = self.request_interceptors.write().await;
         chain.add(interceptor);
@@ -251,6 +583,7 @@ pub struct HttpClientBuilder {
     config: NetworkClientConfig,
     request_interceptors: Vec<Arc<dyn RequestInterceptor>>,
     response_interceptors: Vec<Arc<dyn ResponseInterceptor>>,
+    cookie_store: Option<Arc<dyn CookieStore>>,
 }
 
 impl HttpClientBuilder {
@@ -260,14 +593,53 @@ impl HttpClientBuilder {
     }
 
     /// Set the default timeout.
+    ///
+    /// Same as [`Self::request_timeout`].
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.config.default_timeout = timeout;
         self
     }
 
+    /// Set the overall timeout for each request, from sending it to
+    /// receiving the end of the body.
+    ///
+    /// Individual requests can override it with [`NetworkRequest::timeout`].
+    pub fn request_timeout(self, timeout: Duration) -> Self {
+        self.timeout(timeout)
+    }
+
+    /// Set the maximum time to establish a connection.
+    ///
+    /// Unreachable hosts fail after this long even when the request timeout
+    /// is longer. Connections are pooled, so this can't be set per request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.config.connect_timeout = timeout;
+        self
+    }
+
+    /// Set the maximum wait for each chunk of a response body.
+    ///
+    /// Individual requests can override it with
+    /// [`NetworkRequest::read_timeout`].
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.config.read_timeout = Some(timeout);
+        self
+    }
+
     /// Set the maximum number of redirects.
+    ///
+    /// Same as setting [`RedirectPolicy::max_hops`].
     pub fn max_redirects(mut self, max: u32) -> Self {
-        self.config.max_redirects = max;
+        self.config.redirect_policy.max_hops = max;
+        self
+    }
+
+    /// Set how redirects are followed.
+    ///
+    /// Individual requests can opt out of following redirects with
+    /// [`NetworkRequest::redirect_mode`].
+    pub fn redirect_policy(mut self, policy: RedirectPolicy) -> Self {
+        self.config.redirect_policy = policy;
         self
     }
 
@@ -326,6 +698,67 @@ impl HttpClientBuilder {
         self
     }
 
+    /// Limit concurrent requests to any single origin.
+    ///
+    /// Requests beyond the cap queue until an earlier request to the same
+    /// origin completes; other origins are unaffected. When combined with a
+    /// global limit (such as [`crate::ResourceLoader`]'s), the stricter of
+    /// the two applies.
+    pub fn max_concurrent_per_host(mut self, max: usize) -> Self {
+        self.config.max_concurrent_per_host = Some(max);
+        self
+    }
+
+    /// Retry transient failures according to `policy`.
+    ///
+    /// Applies to [`NetworkClient::fetch`]; streamed requests are not retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.config.retry_policy = Some(policy);
+        self
+    }
+
+    /// Send requests through a proxy.
+    ///
+    /// Hosts on the config's no-proxy list are reached directly.
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.config.proxy = Some(proxy);
+        self
+    }
+
+    /// Simulate a slow connection with `throttle`.
+    ///
+    /// For testing loading behavior; see [`ThrottleConfig`].
+    pub fn throttle(mut self, throttle: ThrottleConfig) -> Self {
+        self.config.throttle = Some(throttle);
+        self
+    }
+
+    /// Send and record cookies using `store`.
+    ///
+    /// Cookies are attached to requests that don't already carry a `Cookie`
+    /// header, including each hop of a redirect chain, and every response in
+    /// the chain updates the store.
+    pub fn cookie_store(mut self, store: Arc<dyn CookieStore>) -> Self {
+        self.cookie_store = Some(store);
+        self
+    }
+
+    /// Keep cookies in a fresh in-memory store that is discarded with the
+    /// client, as private tabs require.
+    pub fn private_browsing(self) -> Self {
+        self.cookie_store(Arc::new(MemoryCookieStore::new()))
+    }
+
+    /// Upgrade insecure requests to HTTPS with `interceptor`.
+    ///
+    /// The interceptor also learns HSTS hosts from responses.
+    pub fn https_upgrade(mut self, interceptor: HttpsUpgradeInterceptor) -> Self {
+        let interceptor = Arc::new(interceptor);
+        self.request_interceptors.push(interceptor.clone());
+        self.response_interceptors.push(interceptor);
+        self
+    }
+
     /// Add a request interceptor.
     pub fn request_interceptor(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
         self.request_interceptors.push(interceptor);
@@ -340,7 +773,8 @@ impl HttpClientBuilder {
 
     /// Build the HTTP client.
     pub fn build(self) -> NetworkResult<HttpClient> {
-        let client = HttpClient::with_config(self.config)?;
+        let mut client = HttpClient::with_config(self.config)?;
+        client.cookie_store = self.cookie_store;
 
         // Add interceptors synchronously during build
         let mut req_chain = RequestInterceptorChain::new();
@@ -362,126 +796,20 @@ impl HttpClientBuilder {
     }
 }
 
-/// Cookie management interface.
-#[async_trait]
-pub trait CookieStore: Send + Sync {
-    /// Get cookies for a URL.
-    async fn get_cookies(&self, url: &Url) -> Vec<Cookie>;
-
-    /// Set a cookie for a URL.
-    async fn set_cookie(&self, cookie: Cookie, url: &Url);
-
-    /// Remove a cookie.
-    async fn remove_cookie(&self, name: &str, url: &Url);
-
-    /// Clear all cookies.
-    async fn clear(&self);
-}
-
-/// A cookie representation.
-#[derive(Debug, Clone)]
-pub struct Cookie {
-    /// Cookie name.
-    pub name: String,
-    /// Cookie value.
-    pub value: String,
-    /// Domain the cookie applies to.
-    pub domain: Option<String>,
-    /// Path the cookie applies to.
-    pub path: Option<String>,
-    /// Expiration time (Unix timestamp).
-    pub expires: Option<i64>,
-    /// Whether the cookie is secure-only.
-    pub secure: bool,
-    /// Whether the cookie is HTTP-only.
-    pub http_only: bool,
-    /// SameSite attribute.
-    pub same_site: SameSite,
-}
-
-/// SameSite cookie attribute.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum SameSite {
-    /// Cookie is sent in all contexts.
-    #[default]
-    None,
-    /// Cookie is sent for same-site and top-level navigation.
-    Lax,
-    /// Cookie is only sent for same-site requests.
-    Strict,
-}
-
-impl Cookie {
-    /// Create a new session cookie.
-    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            value: value.into(),
-            domain: None,
-            path: None,
-            expires: None,
-            secure: false,
-            http_only: false,
-            same_site: SameSite::default(),
-        }
-    }
-
-    /// Set the domain.
-    pub fn domain(mut self, domain: impl Into<String>) -> Self {
-        self.domain = Some(domain.into());
-        self
-    }
-
-    /// Set the path.
-    pub fn path(mut self, path: impl Into<String>) -> Self {
-        self.path = Some(path.into());
-        self
-    }
-
-    /// Set the expiration time.
-    pub fn expires(mut self, timestamp: i64) -> Self {
-        self.expires = Some(timestamp);
-        self
-    }
-
-    /// Mark as secure-only.
-    pub fn secure(mut self) -> Self {
-        self.secure = true;
-        self
-    }
-
-    /// Mark as HTTP-only.
-    pub fn http_only(mut self) -> Self {
-        self.http_only = true;
-        self
-    }
-
-    /// Set the SameSite attribute.
-    pub fn same_site(mut self, same_site: SameSite) -> Self {
-        self.same_site = same_site;
-        self
-    }
-
-    /// Check if the cookie is expired.
-    pub fn is_expired(&self) -> bool {
-        if let Some(expires) = self.expires {
-            let now = chrono::Utc::now().timestamp();
-            expires < now
-        } else {
-            false // Session cookies don't expire
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::NetworkErrorKind;
+    use crate::request::Method;
+    use crate::test_server::{request_path, serve_recording, serve_with, RecordedRequests};
 
     #[test]
     fn test_client_config_default() {
         let config = NetworkClientConfig::default();
         assert_eq!(config.default_timeout, Duration::from_secs(30));
-        assert_eq!(config.max_redirects, 10);
+        assert_eq!(config.connect_timeout, Duration::from_secs(10));
+        assert_eq!(config.read_timeout, None);
+        assert_eq!(config.redirect_policy.max_hops, 10);
         assert!(!config.accept_invalid_certs);
         assert!(config.http2_enabled);
         assert!(!config.http3_enabled); // HTTP/3 disabled by default
@@ -500,7 +828,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(client.config().default_timeout, Duration::from_secs(60));
-        assert_eq!(client.config().max_redirects, 5);
+        assert_eq!(client.config().redirect_policy.max_hops, 5);
         assert_eq!(client.config().user_agent, "TestAgent/1.0");
         assert!(!client.config().gzip_enabled);
     }
@@ -526,38 +854,958 @@ mod tests {
         assert!(!client.config().http3_enabled);
     }
 
-    #[test]
-    fn test_cookie_builder() {
-        let cookie = Cookie::new("session", "abc123")
-            .domain("example.com")
-            .path("/app")
-            .secure()
-            .http_only()
-            .same_site(SameSite::Strict);
-
-        assert_eq!(cookie.name, "session");
-        assert_eq!(cookie.value, "abc123");
-        assert_eq!(cookie.domain, Some("example.com".to_string()));
-        assert_eq!(cookie.path, Some("/app".to_string()));
-        assert!(cookie.secure);
-        assert!(cookie.http_only);
-        assert_eq!(cookie.same_site, SameSite::Strict);
+    /// Serve a single raw HTTP response, closing the connection afterwards.
+    async fn serve_raw_response(response: Vec<u8>) -> Url {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            // Read the request headers before responding
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            socket.write_all(&response).await.unwrap();
+            socket.shutdown().await.unwrap();
+        });
+
+        Url::parse(&format!("http://{}/file", addr)).unwrap()
+    }
+
+    fn raw_response(content_length: usize, body_len: usize) -> Vec<u8> {
+        let mut response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            content_length
+        )
+        .into_bytes();
+        response.resize(response.len() + body_len, b'x');
+        response
+    }
+
+    #[tokio::test]
+    async fn test_fetch_detects_truncated_body() {
+        let url = serve_raw_response(raw_response(1000, 800)).await;
+        let client = HttpClient::new().unwrap();
+
+        let result = client.fetch(NetworkRequest::get(url)).await;
+
+        match result {
+            Err(NetworkError::IncompleteBody { expected, received }) => {
+                assert_eq!(expected, 1000);
+                assert_eq!(received, 800);
+            }
+            other => panic!("expected IncompleteBody, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_complete_body_succeeds() {
+        let url = serve_raw_response(raw_response(1000, 1000)).await;
+        let client = HttpClient::new().unwrap();
+
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+
+        assert_eq!(response.body.len(), 1000);
+    }
+
+    #[tokio::test]
+    async fn test_throttle_paces_download() {
+        // 20 KB at 100 KB/s takes about 200ms, plus 50ms latency
+        let url = serve_raw_response(raw_response(20_000, 20_000)).await;
+        let client = HttpClientBuilder::new()
+            .throttle(ThrottleConfig::new(100_000, 0, 50))
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(response.body.len(), 20_000);
+        assert!(elapsed >= Duration::from_millis(250), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(1000), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_throttle_paces_stream_and_upload() {
+        use futures_util::StreamExt;
+
+        let url = serve_raw_response(raw_response(10_000, 10_000)).await;
+        let client = HttpClientBuilder::new()
+            .throttle(ThrottleConfig::new(50_000, 500, 0))
+            .build()
+            .unwrap();
+
+        // 100 bytes up at 500 B/s takes 200ms, then 10 KB down at 50 KB/s
+        // another 200ms
+        let request = NetworkRequest::new(Method::Post, url).body(vec![b'u'; 100]);
+        let start = Instant::now();
+        let mut stream = client.fetch_stream(request).await.unwrap();
+        let mut received = 0;
+        while let Some(chunk) = stream.next().await {
+            received += chunk.unwrap().len();
+        }
+        let elapsed = start.elapsed();
+
+        assert_eq!(received, 10_000);
+        assert!(elapsed >= Duration::from_millis(400), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(1200), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_chunked_body_is_exempt() {
+        let response =
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n5\r\nhello\r\n0\r\n\r\n"
+                .to_vec();
+        let url = serve_raw_response(response).await;
+        let client = HttpClient::new().unwrap();
+
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+
+        assert_eq!(response.body, b"hello");
+    }
+
+    /// Serve a chunked response, flushing each chunk after `delay`.
+    async fn serve_chunks(chunks: Vec<&'static str>, delay: Duration) -> Url {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+            for chunk in chunks {
+                tokio::time::sleep(delay).await;
+                let frame = format!("{:x}\r\n{}\r\n", chunk.len(), chunk);
+                socket.write_all(frame.as_bytes()).await.unwrap();
+                socket.flush().await.unwrap();
+            }
+            socket.write_all(b"0\r\n\r\n").await.unwrap();
+            socket.shutdown().await.unwrap();
+        });
+
+        Url::parse(&format!("http://{}/stream", addr)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_stream_yields_chunks_in_order() {
+        use futures_util::StreamExt;
+
+        let url = serve_chunks(
+            vec!["first,", "second,", "third"],
+            Duration::from_millis(50),
+        )
+        .await;
+        let client = HttpClient::new().unwrap();
+
+        let mut stream = client.fetch_stream(NetworkRequest::get(url)).await.unwrap();
+        assert!(stream.status.is_success());
+
+        let mut chunks = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            chunks.push(chunk.unwrap());
+        }
+
+        assert_eq!(chunks, vec!["first,", "second,", "third"]);
+        assert_eq!(stream.bytes_received(), 18);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_stream_enforces_max_response_size() {
+        use futures_util::StreamExt;
+
+        let url = serve_chunks(vec!["0123456789", "0123456789"], Duration::from_millis(10)).await;
+        let client = HttpClientBuilder::new()
+            .max_response_size(15)
+            .build()
+            .unwrap();
+
+        let mut stream = client.fetch_stream(NetworkRequest::get(url)).await.unwrap();
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), "0123456789");
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(NetworkError::ResponseTooLarge {
+                size: 20,
+                max_size: 15
+            }))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_stream_detects_truncated_body() {
+        use futures_util::StreamExt;
+
+        let url = serve_raw_response(raw_response(1000, 800)).await;
+        let client = HttpClient::new().unwrap();
+
+        let mut stream = client.fetch_stream(NetworkRequest::get(url)).await.unwrap();
+        let mut result = Ok(());
+        while let Some(chunk) = stream.next().await {
+            if let Err(e) = chunk {
+                result = Err(e);
+            }
+        }
+
+        assert!(matches!(
+            result,
+            Err(NetworkError::IncompleteBody {
+                expected: 1000,
+                received: 800
+            })
+        ));
+    }
+
+    /// Serve `responses` in order, one per connection, repeating the last.
+    ///
+    /// Returns the URL and a count of requests received.
+    async fn serve_sequence(
+        responses: Vec<&'static str>,
+    ) -> (Url, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+
+                let hit = counter.fetch_add(1, Ordering::SeqCst);
+                let response = responses[hit.min(responses.len() - 1)];
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (Url::parse(&format!("http://{}/", addr)).unwrap(), hits)
+    }
+
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok";
+
+    fn fast_retries(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::new(max_retries).delays(Duration::from_millis(10), Duration::from_millis(50))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_retries_until_success() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_sequence(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let client = HttpClientBuilder::new()
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.body, b"ok");
+        // One initial attempt plus two retries
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_gives_up_after_max_retries() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_sequence(vec![UNAVAILABLE]).await;
+        let client = HttpClientBuilder::new()
+            .retry_policy(fast_retries(2))
+            .build()
+            .unwrap();
+
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+
+        assert_eq!(response.status.as_u16(), 503);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_does_not_retry_post_by_default() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_sequence(vec![UNAVAILABLE, OK]).await;
+        let client = HttpClientBuilder::new()
+            .retry_policy(fast_retries(3))
+            .build()
+            .unwrap();
+
+        let response = client
+            .fetch(NetworkRequest::post(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(response.status.as_u16(), 503);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // Opting in retries POST as well
+        let (url, hits) = serve_sequence(vec![UNAVAILABLE, OK]).await;
+        let client = HttpClientBuilder::new()
+            .retry_policy(fast_retries(3).retry_non_idempotent(true))
+            .build()
+            .unwrap();
+        let response = client.fetch(NetworkRequest::post(url)).await.unwrap();
+        assert!(response.is_success());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_honors_retry_after() {
+        let (url, _) = serve_sequence(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            OK,
+        ])
+        .await;
+        let client = HttpClientBuilder::new()
+            .retry_policy(fast_retries(1).delays(Duration::from_millis(10), Duration::from_secs(5)))
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+
+        assert!(response.is_success());
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    /// Tracks in-flight requests across one or more counting servers.
+    #[derive(Default)]
+    struct InFlightCounter {
+        current: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    /// Serve delayed responses, recording concurrency in `counter`.
+    async fn serve_counting(counter: Arc<InFlightCounter>, delay: Duration) -> Url {
+        use std::sync::atomic::Ordering;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let counter = Arc::clone(&counter);
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    let mut request = Vec::new();
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        let n = socket.read(&mut buf).await.unwrap();
+                        if n == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                    }
+
+                    let now = counter.current.fetch_add(1, Ordering::SeqCst) + 1;
+                    counter.peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(delay).await;
+                    counter.current.fetch_sub(1, Ordering::SeqCst);
+
+                    let _ = socket
+                        .write_all(
+                            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                        )
+                        .await;
+                });
+            }
+        });
+
+        Url::parse(&format!("http://{}/", addr)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_per_host_cap_serializes_same_origin() {
+        use std::sync::atomic::Ordering;
+
+        let counter = Arc::new(InFlightCounter::default());
+        let url = serve_counting(Arc::clone(&counter), Duration::from_millis(100)).await;
+        let client = HttpClientBuilder::new()
+            .max_concurrent_per_host(1)
+            .build()
+            .unwrap();
+
+        let (a, b) = tokio::join!(
+            client.fetch(NetworkRequest::get(url.clone())),
+            client.fetch(NetworkRequest::get(url.join("other").unwrap())),
+        );
+
+        assert!(a.is_ok() && b.is_ok());
+        assert_eq!(counter.peak.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_per_host_cap_allows_cross_origin_concurrency() {
+        use std::sync::atomic::Ordering;
+
+        let counter = Arc::new(InFlightCounter::default());
+        let origin_a = serve_counting(Arc::clone(&counter), Duration::from_millis(200)).await;
+        let origin_b = serve_counting(Arc::clone(&counter), Duration::from_millis(200)).await;
+        let client = HttpClientBuilder::new()
+            .max_concurrent_per_host(1)
+            .build()
+            .unwrap();
+
+        let (a, b) = tokio::join!(
+            client.fetch(NetworkRequest::get(origin_a)),
+            client.fetch(NetworkRequest::get(origin_b)),
+        );
+
+        assert!(a.is_ok() && b.is_ok());
+        assert_eq!(counter.peak.load(Ordering::SeqCst), 2);
+    }
+
+    const SET_SESSION: &str = "HTTP/1.1 200 OK\r\nSet-Cookie: session=abc; Path=/\r\n\
+        Set-Cookie: theme=dark; Max-Age=3600\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok";
+
+    #[tokio::test]
+    async fn test_cookies_sent_on_same_domain_requests() {
+        let (url, requests) = serve_recording(SET_SESSION).await;
+        let client = HttpClientBuilder::new()
+            .cookie_store(Arc::new(MemoryCookieStore::new()))
+            .build()
+            .unwrap();
+
+        client
+            .fetch(NetworkRequest::get(url.join("login").unwrap()))
+            .await
+            .unwrap();
+        client
+            .fetch(NetworkRequest::get(url.join("page").unwrap()))
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(!requests[0].contains("cookie:"));
+        assert!(requests[1].contains("cookie: session=abc; theme=dark\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_cookies_not_sent_cross_domain() {
+        let (url, requests) = serve_recording(SET_SESSION).await;
+        let client = HttpClientBuilder::new().private_browsing().build().unwrap();
+
+        client
+            .fetch(NetworkRequest::get(url.clone()))
+            .await
+            .unwrap();
+
+        // Same server, different host name
+        let mut other = url.clone();
+        other.set_host(Some("localhost")).unwrap();
+        client.fetch(NetworkRequest::get(other)).await.unwrap();
+
+        {
+            let requests = requests.lock().unwrap();
+            assert_eq!(requests.len(), 2);
+            assert!(!requests[1].contains("cookie:"));
+        }
+
+        // Private stores are never shared between clients
+        let store = client.cookie_store().unwrap();
+        assert_eq!(store.cookies_for(&url).await.len(), 2);
+        let fresh = HttpClientBuilder::new().private_browsing().build().unwrap();
+        assert!(fresh
+            .cookie_store()
+            .unwrap()
+            .cookies_for(&url)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gzip_body_decoded_and_encoding_headers_dropped() {
+        use std::io::Write;
+
+        let resource = "hello compression ".repeat(100);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(resource.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let mut raw = format!(
+            "HTTP/1.1 200 OK\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n",
+            gzipped.len()
+        )
+        .into_bytes();
+        raw.extend_from_slice(&gzipped);
+        let (url, requests) = serve_recording(raw).await;
+
+        let client = HttpClient::new().unwrap();
+        let response = client.fetch(NetworkRequest::get(url)).await.unwrap();
+
+        assert_eq!(response.text().unwrap(), resource);
+        // Neither header describes the decoded body
+        assert!(response.header("content-encoding").is_none());
+        assert!(response.header("content-length").is_none());
+        assert!(requests.lock().unwrap()[0].contains("accept-encoding: gzip, deflate, br\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_head_with_content_encoding_is_not_decoded() {
+        let url = serve_raw_response(
+            b"HTTP/1.1 200 OK\r\nContent-Encoding: gzip\r\nContent-Length: 512\r\n\
+              Connection: close\r\n\r\n"
+                .to_vec(),
+        )
+        .await;
+
+        let client = HttpClient::new().unwrap();
+        let response = client
+            .fetch(NetworkRequest::new(Method::Head, url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status.as_u16(), 200);
+        assert!(response.body.is_empty());
+        assert!(response.header("content-encoding").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_connect_timeout_fails_fast() {
+        // A listener with a full accept backlog never completes new
+        // handshakes, like an unreachable host
+        let socket = tokio::net::TcpSocket::new_v4().unwrap();
+        socket.bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let listener = socket.listen(0).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let _queued = std::net::TcpStream::connect(addr).unwrap();
+
+        let client = HttpClientBuilder::new()
+            .connect_timeout(Duration::from_millis(200))
+            .request_timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+        let url = Url::parse(&format!("http://{}/", addr)).unwrap();
+
+        let start = Instant::now();
+        let result = client.fetch(NetworkRequest::get(url)).await;
+
+        assert!(matches!(
+            result,
+            Err(NetworkError::Timeout {
+                timeout_ms: 200,
+                ..
+            })
+        ));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_read_timeout_on_slow_body() {
+        let client = HttpClientBuilder::new()
+            .read_timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let url = serve_chunks(vec!["slow", "body"], Duration::from_millis(500)).await;
+        let result = client.fetch(NetworkRequest::get(url)).await;
+        assert!(matches!(
+            result,
+            Err(NetworkError::Timeout {
+                timeout_ms: 100,
+                ..
+            })
+        ));
+
+        // A per-request override allows the slow body to finish
+        let url = serve_chunks(vec!["slow", "body"], Duration::from_millis(200)).await;
+        let request = NetworkRequest::get(url).read_timeout(Duration::from_secs(5));
+        let response = client.fetch(request).await.unwrap();
+        assert_eq!(response.text().unwrap(), "slowbody");
+    }
+
+    #[tokio::test]
+    async fn test_error_kind_for_timeout() {
+        let client = HttpClientBuilder::new()
+            .read_timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let url = serve_chunks(vec!["slow"], Duration::from_millis(500)).await;
+
+        let err = client.fetch(NetworkRequest::get(url)).await.unwrap_err();
+        assert_eq!(err.kind(), NetworkErrorKind::Timeout);
+    }
+
+    #[tokio::test]
+    async fn test_error_kind_for_bad_tls_endpoint() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // A plain-text server on an https:// URL fails the TLS handshake
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")
+                .await;
+        });
+
+        let client = HttpClient::new().unwrap();
+        let url = Url::parse(&format!("https://{}/", addr)).unwrap();
+        let err = client.fetch(NetworkRequest::get(url)).await.unwrap_err();
+        assert_eq!(err.kind(), NetworkErrorKind::Tls, "{}", err);
+        assert!(!err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_error_kind_for_refused_connection_ignores_url() {
+        // Nothing listens on the port once the listener is dropped
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let client = HttpClient::new().unwrap();
+        let url = Url::parse(&format!("http://{}/ssl/certificate-check", addr)).unwrap();
+        let err = client.fetch(NetworkRequest::get(url)).await.unwrap_err();
+        assert_eq!(err.kind(), NetworkErrorKind::Connect, "{}", err);
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_error_kind_for_unresolvable_host() {
+        let client = HttpClient::new().unwrap();
+        // The .invalid TLD never resolves
+        let url = Url::parse("http://nowhere.invalid/").unwrap();
+        let err = client.fetch(NetworkRequest::get(url)).await.unwrap_err();
+        assert_eq!(err.kind(), NetworkErrorKind::Dns, "{}", err);
+        assert!(matches!(err, NetworkError::DnsError { ref host } if host == "nowhere.invalid"));
     }
 
     #[test]
-    fn test_cookie_expiration() {
-        // Not expired (1 year from now)
-        let future_cookie = Cookie::new("test", "value")
-            .expires(chrono::Utc::now().timestamp() + 31536000);
-        assert!(!future_cookie.is_expired());
-
-        // Expired (1 year ago)
-        let past_cookie = Cookie::new("test", "value")
-            .expires(chrono::Utc::now().timestamp() - 31536000);
-        assert!(past_cookie.is_expired());
-
-        // Session cookie (never expires)
-        let session_cookie = Cookie::new("test", "value");
-        assert!(!session_cookie.is_expired());
+    fn test_error_kind_classification() {
+        let url = Url::parse("https://example.com/").unwrap();
+        let cases = [
+            (
+                NetworkError::DnsError {
+                    host: "example.com".to_string(),
+                },
+                NetworkErrorKind::Dns,
+            ),
+            (
+                NetworkError::connection_failed(&url, "refused"),
+                NetworkErrorKind::Connect,
+            ),
+            (
+                NetworkError::HttpError {
+                    url: url.to_string(),
+                    status_code: 503,
+                },
+                NetworkErrorKind::Http(StatusCode::new(503)),
+            ),
+            (
+                NetworkError::IncompleteBody {
+                    expected: 10,
+                    received: 4,
+                },
+                NetworkErrorKind::Body,
+            ),
+            (
+                NetworkError::RequestCancelled {
+                    reason: "blocked".to_string(),
+                },
+                NetworkErrorKind::Interceptor,
+            ),
+            (
+                NetworkError::Internal("oops".to_string()),
+                NetworkErrorKind::Other,
+            ),
+        ];
+        for (err, kind) in cases {
+            assert_eq!(err.kind(), kind);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_timeout_applies_to_streams() {
+        use futures_util::StreamExt;
+
+        let client = HttpClientBuilder::new()
+            .read_timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let url = serve_chunks(vec!["slow"], Duration::from_millis(500)).await;
+
+        let mut stream = client.fetch_stream(NetworkRequest::get(url)).await.unwrap();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(NetworkError::Timeout { .. }))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pool_stats_observe_in_flight_peak() {
+        let counter = Arc::new(InFlightCounter::default());
+        let url = serve_counting(Arc::clone(&counter), Duration::from_millis(300)).await;
+        let client = HttpClient::new().unwrap();
+
+        let requests = (0..4).map(|i| {
+            client.fetch(NetworkRequest::get(
+                url.join(&format!("item/{}", i)).unwrap(),
+            ))
+        });
+        let (results, during) = tokio::join!(futures_util::future::join_all(requests), async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            client.pool_stats()
+        });
+        assert!(results.iter().all(|result| result.is_ok()));
+
+        assert_eq!(during.active_connections, 4);
+        assert_eq!(during.host(&url).unwrap().active, 4);
+
+        // The server closes each connection, so none are left idle
+        let after = client.pool_stats();
+        let host = after.host(&url).unwrap();
+        assert_eq!(host.active, 0);
+        assert_eq!(host.peak_active, 4);
+        assert_eq!(host.total_requests, 4);
+        assert_eq!(after.idle_connections, 0);
+    }
+
+    #[tokio::test]
+    async fn test_pool_stats_count_reusable_connections_as_idle() {
+        use futures_util::StreamExt;
+
+        let (url, _requests) =
+            serve_recording("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok").await;
+        let client = HttpClient::new().unwrap();
+
+        client
+            .fetch(NetworkRequest::get(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(client.pool_stats().host(&url).unwrap().idle, 1);
+
+        let mut stream = client
+            .fetch_stream(NetworkRequest::get(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(client.pool_stats().host(&url).unwrap().active, 1);
+        while stream.next().await.is_some() {}
+        drop(stream);
+
+        let host = *client.pool_stats().host(&url).unwrap();
+        assert_eq!((host.active, host.idle, host.total_requests), (0, 1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_pool_stats_count_each_redirect_hop_against_its_origin() {
+        let (target, _requests) = serve_recording(OK).await;
+        let (start, _requests) = serve_recording(format!(
+            "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\n\r\n",
+            target
+        ))
+        .await;
+        let client = HttpClient::new().unwrap();
+
+        client
+            .fetch(NetworkRequest::get(start.clone()))
+            .await
+            .unwrap();
+
+        let stats = client.pool_stats();
+        assert_eq!(stats.active_connections, 0);
+        assert_eq!(stats.host(&start).unwrap().total_requests, 1);
+        assert_eq!(stats.host(&target).unwrap().total_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_https_upgrade_skips_local_hosts() {
+        let (url, _requests) = serve_recording(OK).await;
+        let upgrade = HttpsUpgradeInterceptor::new();
+        let client = HttpClientBuilder::new()
+            .https_upgrade(upgrade.clone())
+            .build()
+            .unwrap();
+
+        // Local hosts stay on plain HTTP
+        let response = client
+            .fetch(NetworkRequest::get(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(response.url, url);
+
+        // Once upgraded, the plain-HTTP test server can't complete the
+        // TLS handshake
+        upgrade.set_skip_local_hosts(false).await;
+        let request = NetworkRequest::get(url).timeout(Duration::from_millis(500));
+        assert!(client.fetch(request).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_requests_sent_through_proxy() {
+        let (proxy, requests) =
+            serve_recording("HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                .await;
+        let client = HttpClientBuilder::new()
+            .proxy(ProxyConfig::new(proxy).basic_auth("user", "pass"))
+            .build()
+            .unwrap();
+
+        let response = client
+            .fetch(NetworkRequest::get(
+                Url::parse("http://site.test/page").unwrap(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.body, b"ok");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("get http://site.test/page http/1.1"));
+        assert!(requests[0].contains("proxy-authorization: basic dxnlcjpwyxnz"));
+    }
+
+    #[tokio::test]
+    async fn test_no_proxy_hosts_bypass_proxy() {
+        let (url, requests) =
+            serve_recording("HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                .await;
+        // Nothing listens on the discard port, so proxied requests fail
+        let proxy =
+            ProxyConfig::new(Url::parse("http://127.0.0.1:9").unwrap()).no_proxy(["127.0.0.1"]);
+        let client = HttpClientBuilder::new().proxy(proxy).build().unwrap();
+
+        let response = client
+            .fetch(NetworkRequest::get(url.join("direct").unwrap()))
+            .await
+            .unwrap();
+        assert_eq!(response.body, b"ok");
+        assert!(requests.lock().unwrap()[0].starts_with("get /direct http/1.1"));
+
+        let proxied = client
+            .fetch(NetworkRequest::get(
+                Url::parse("http://site.test/").unwrap(),
+            ))
+            .await;
+        assert!(proxied.is_err());
+    }
+
+    /// Serve a response per request path (404 otherwise), recording each
+    /// request's head.
+    async fn serve_routes(routes: Vec<(&'static str, String)>) -> (Url, RecordedRequests) {
+        let routes: HashMap<&str, String> = routes.into_iter().collect();
+        serve_with(move |head| {
+            routes.get(request_path(head)).cloned().unwrap_or_else(|| {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string()
+            })
+        })
+        .await
+    }
+
+    fn redirect_to(status: u16, location: &str) -> String {
+        format!(
+            "HTTP/1.1 {} Redirect\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            status, location
+        )
+    }
+
+    #[tokio::test]
+    async fn test_redirect_chain_strips_auth_across_origins() {
+        let (other, other_requests) = serve_routes(vec![(
+            "/final",
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok".to_string(),
+        )])
+        .await;
+        let final_url = other.join("final").unwrap();
+        let (start, requests) = serve_routes(vec![
+            ("/start", redirect_to(302, "/same")),
+            ("/same", redirect_to(307, final_url.as_str())),
+        ])
+        .await;
+        let request = NetworkRequest::get(start.join("start").unwrap())
+            .header("Authorization", "Bearer secret");
+
+        let client = HttpClient::new().unwrap();
+        let response = client.fetch(request.clone()).await.unwrap();
+        assert_eq!(response.body, b"ok");
+        assert_eq!(response.url, final_url);
+        assert_eq!(
+            response.redirect_chain,
+            vec![start.join("start").unwrap(), start.join("same").unwrap()]
+        );
+        // Same-origin hops keep the header; the cross-origin hop drops it
+        assert!(requests.lock().unwrap()[1].contains("authorization: bearer secret"));
+        assert!(!other_requests.lock().unwrap()[0].contains("authorization"));
+
+        let client = HttpClientBuilder::new()
+            .redirect_policy(RedirectPolicy::default().strip_auth_on_cross_origin(false))
+            .build()
+            .unwrap();
+        client.fetch(request).await.unwrap();
+        assert!(other_requests.lock().unwrap()[1].contains("authorization: bearer secret"));
+    }
+
+    #[tokio::test]
+    async fn test_redirect_hop_limit_and_modes() {
+        let (url, requests) = serve_routes(vec![("/loop", redirect_to(302, "/loop"))]).await;
+        let url = url.join("loop").unwrap();
+
+        let client = HttpClientBuilder::new()
+            .redirect_policy(RedirectPolicy::new(3))
+            .build()
+            .unwrap();
+        let result = client.fetch(NetworkRequest::get(url.clone())).await;
+        assert!(matches!(
+            result,
+            Err(NetworkError::TooManyRedirects { count: 4 })
+        ));
+        // The original request plus three followed hops
+        assert_eq!(requests.lock().unwrap().len(), 4);
+
+        let manual = NetworkRequest::get(url.clone()).redirect_mode(RedirectMode::Manual);
+        let response = client.fetch(manual).await.unwrap();
+        assert_eq!(response.status, StatusCode::FOUND);
+        assert!(response.redirect_chain.is_empty());
+
+        let error = NetworkRequest::get(url).redirect_mode(RedirectMode::Error);
+        let result = client.fetch(error).await;
+        assert!(matches!(result, Err(NetworkError::RedirectBlocked { .. })));
     }
 }
@@ -0,0 +1,227 @@
+//! Compression metrics for the network stack.
+//!
+//! [`CompressionInterceptor`] compares each response's transferred size (its
+//! `Content-Length`) with the size of the decoded body, grouped by
+//! `Content-Encoding`. [`crate::HttpClient`] lets reqwest decode bodies,
+//! which drops both headers, so responses it decoded are recorded as
+//! `identity`; encoded sizes are measured for responses that still carry
+//! their original headers.
+
+use crate::error::NetworkResult;
+use crate::interceptor::ResponseInterceptor;
+use crate::request::NetworkRequest;
+use crate::response::NetworkResponse;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Transfer totals for a set of responses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EncodingStats {
+    /// Number of responses.
+    pub responses: u64,
+    /// Bytes received over the network.
+    pub transferred_bytes: u64,
+    /// Bytes after decoding.
+    pub resource_bytes: u64,
+}
+
+impl EncodingStats {
+    /// Transferred size as a fraction of the decoded size (1.0 when nothing
+    /// was recorded).
+    pub fn ratio(&self) -> f64 {
+        if self.resource_bytes == 0 {
+            1.0
+        } else {
+            self.transferred_bytes as f64 / self.resource_bytes as f64
+        }
+    }
+
+    /// Bytes saved by compression.
+    pub fn saved_bytes(&self) -> u64 {
+        self.resource_bytes.saturating_sub(self.transferred_bytes)
+    }
+
+    fn record(&mut self, transferred_bytes: u64, resource_bytes: u64) {
+        self.responses += 1;
+        self.transferred_bytes += transferred_bytes;
+        self.resource_bytes += resource_bytes;
+    }
+}
+
+/// Cumulative statistics recorded by a [`CompressionInterceptor`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompressionStats {
+    /// Totals across all measured responses.
+    pub total: EncodingStats,
+    /// Totals per `Content-Encoding` (`"identity"` for unencoded responses).
+    pub by_encoding: HashMap<String, EncodingStats>,
+    /// Encoded responses skipped because their transferred size was unknown
+    /// (no `Content-Length`).
+    pub unmeasured: u64,
+}
+
+impl CompressionStats {
+    /// Overall transferred size as a fraction of the decoded size.
+    pub fn ratio(&self) -> f64 {
+        self.total.ratio()
+    }
+
+    /// Get the totals for one encoding.
+    pub fn encoding(&self, encoding: &str) -> Option<&EncodingStats> {
+        self.by_encoding.get(encoding)
+    }
+}
+
+/// Response interceptor that records transferred versus decoded sizes.
+///
+/// Responses served from the cache are not counted, since nothing was
+/// transferred.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionInterceptor {
+    stats: Arc<Mutex<CompressionStats>>,
+}
+
+impl CompressionInterceptor {
+    /// Create an interceptor with empty statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the statistics recorded so far.
+    pub fn stats(&self) -> CompressionStats {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Reset the statistics.
+    pub fn reset(&self) {
+        *self.stats.lock().unwrap_or_else(|e| e.into_inner()) = CompressionStats::default();
+    }
+
+    /// Get a response's content encoding and transferred size, if known.
+    pub fn transfer_size(response: &NetworkResponse) -> (String, Option<u64>) {
+        let encoding = response
+            .header("content-encoding")
+            .map(|value| value.trim().to_ascii_lowercase())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "identity".to_string());
+
+        let transferred = response
+            .header("content-length")
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .or_else(|| (encoding == "identity").then_some(response.body.len() as u64));
+
+        (encoding, transferred)
+    }
+}
+
+#[async_trait]
+impl ResponseInterceptor for CompressionInterceptor {
+    async fn intercept_response(
+        &self,
+        _request: &NetworkRequest,
+        response: NetworkResponse,
+    ) -> NetworkResult<NetworkResponse> {
+        if response.cache_status.is_hit() {
+            return Ok(response);
+        }
+
+        let (encoding, transferred) = Self::transfer_size(&response);
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        match transferred {
+            Some(transferred) => {
+                let resource = response.body.len() as u64;
+                stats.total.record(transferred, resource);
+                stats
+                    .by_encoding
+                    .entry(encoding)
+                    .or_default()
+                    .record(transferred, resource);
+            }
+            None => stats.unmeasured += 1,
+        }
+        drop(stats);
+
+        Ok(response)
+    }
+
+    fn name(&self) -> &str {
+        "CompressionInterceptor"
+    }
+
+    fn priority(&self) -> i32 {
+        100 // Run early, before other interceptors rewrite the response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::HeaderMap;
+    use crate::response::{CacheStatus, StatusCode};
+    use url::Url;
+
+    fn response(headers: &[(&str, &str)], body_len: usize) -> NetworkResponse {
+        let mut map = HeaderMap::new();
+        for (key, value) in headers {
+            map.insert(key.to_string(), value.to_string());
+        }
+        NetworkResponse::new(StatusCode::OK, Url::parse("https://example.com/").unwrap())
+            .headers(map)
+            .body(vec![b'a'; body_len])
+    }
+
+    async fn record(interceptor: &CompressionInterceptor, response: NetworkResponse) {
+        let request = NetworkRequest::get(response.url.clone());
+        interceptor
+            .intercept_response(&request, response)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_records_gzip_ratio() {
+        let interceptor = CompressionInterceptor::new();
+
+        record(
+            &interceptor,
+            response(
+                &[("Content-Encoding", "gzip"), ("Content-Length", "250")],
+                1000,
+            ),
+        )
+        .await;
+        record(&interceptor, response(&[], 500)).await;
+
+        let stats = interceptor.stats();
+        let gzip = stats.encoding("gzip").unwrap();
+        assert_eq!(gzip.responses, 1);
+        assert_eq!(gzip.ratio(), 0.25);
+        assert_eq!(gzip.saved_bytes(), 750);
+        assert_eq!(stats.encoding("identity").unwrap().transferred_bytes, 500);
+        assert_eq!(stats.total.transferred_bytes, 750);
+        assert_eq!(stats.total.resource_bytes, 1500);
+        assert_eq!(stats.ratio(), 0.5);
+
+        interceptor.reset();
+        assert_eq!(interceptor.stats(), CompressionStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_skips_unknown_sizes_and_cache_hits() {
+        let interceptor = CompressionInterceptor::new();
+
+        // Chunked brotli: transferred size unknown
+        record(&interceptor, response(&[("Content-Encoding", "br")], 100)).await;
+        record(
+            &interceptor,
+            response(&[("Content-Length", "100")], 100).cache_status(CacheStatus::Hit),
+        )
+        .await;
+
+        let stats = interceptor.stats();
+        assert_eq!(stats.unmeasured, 1);
+        assert_eq!(stats.total, EncodingStats::default());
+        assert_eq!(stats.ratio(), 1.0);
+    }
+}
@@ -0,0 +1,718 @@
+//! Cookie storage for the network client.
+//!
+//! A [`CookieStore`] configured on [`crate::HttpClientBuilder`] supplies the
+//! `Cookie` header for outgoing requests and records cookies from
+//! `Set-Cookie` response headers, following RFC 6265 domain, path, `Secure`
+//! and expiry rules. [`MemoryCookieStore`] keeps cookies for the lifetime of
+//! the client (suitable for private tabs); [`FileCookieStore`] also persists
+//! them to a JSON file.
+
+use crate::error::{NetworkError, NetworkResult};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+use url::{Host, Url};
+
+/// Multi-label public suffixes that may not be used as a cookie domain.
+///
+/// Every single-label domain (a TLD such as `com`) is treated as a public
+/// suffix too. This covers the common registry-controlled second levels,
+/// not the full Public Suffix List.
+const PUBLIC_SUFFIXES: &[&str] = &[
+    "ac.jp", "ac.uk", "co.in", "co.jp", "co.kr", "co.nz", "co.uk", "co.za", "com.au", "com.br",
+    "com.cn", "com.mx", "com.sg", "com.tr", "com.tw", "edu.au", "gov.au", "gov.uk", "ltd.uk",
+    "me.uk", "ne.jp", "net.au", "net.uk", "or.jp", "org.au", "org.nz", "org.uk", "plc.uk",
+];
+
+/// Cookie management interface.
+#[async_trait]
+pub trait CookieStore: Send + Sync + std::fmt::Debug {
+    /// Store the cookies from a response's `Set-Cookie` headers.
+    ///
+    /// Headers that fail to parse or that `url` may not set are ignored.
+    async fn store(&self, url: &Url, set_cookie_headers: &[String]) {
+        for header in set_cookie_headers {
+            if let Some(cookie) = Cookie::parse(header) {
+                self.set_cookie(cookie, url).await;
+            }
+        }
+    }
+
+    /// Get the cookies to send with a request to a URL, most specific path
+    /// first.
+    async fn cookies_for(&self, url: &Url) -> Vec<Cookie>;
+
+    /// Get the cookies visible to scripts on a URL (excludes HTTP-only
+    /// cookies).
+    async fn script_cookies_for(&self, url: &Url) -> Vec<Cookie> {
+        let mut cookies = self.cookies_for(url).await;
+        cookies.retain(|cookie| !cookie.http_only);
+        cookies
+    }
+
+    /// Set a cookie for a URL.
+    ///
+    /// A cookie without a domain applies only to the URL's host, and one
+    /// without a path to the URL's directory. Setting an expired cookie
+    /// removes any matching stored cookie.
+    async fn set_cookie(&self, cookie: Cookie, url: &Url);
+
+    /// Remove a cookie.
+    async fn remove_cookie(&self, name: &str, url: &Url);
+
+    /// Clear all cookies.
+    async fn clear(&self);
+}
+
+/// A cookie representation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cookie {
+    /// Cookie name.
+    pub name: String,
+    /// Cookie value.
+    pub value: String,
+    /// Domain the cookie applies to.
+    pub domain: Option<String>,
+    /// Whether the cookie applies only to `domain` itself, not subdomains.
+    #[serde(default)]
+    pub host_only: bool,
+    /// Path the cookie applies to.
+    pub path: Option<String>,
+    /// Expiration time (Unix timestamp).
+    pub expires: Option<i64>,
+    /// Whether the cookie is secure-only.
+    pub secure: bool,
+    /// Whether the cookie is HTTP-only.
+    pub http_only: bool,
+    /// SameSite attribute.
+    pub same_site: SameSite,
+}
+
+/// SameSite cookie attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SameSite {
+    /// Cookie is sent in all contexts.
+    #[default]
+    None,
+    /// Cookie is sent for same-site and top-level navigation.
+    Lax,
+    /// Cookie is only sent for same-site requests.
+    Strict,
+}
+
+impl Cookie {
+    /// Create a new session cookie.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            domain: None,
+            host_only: false,
+            path: None,
+            expires: None,
+            secure: false,
+            http_only: false,
+            same_site: SameSite::default(),
+        }
+    }
+
+    /// Parse a `Set-Cookie` header value.
+    ///
+    /// Returns `None` if the header has no `name=value` pair. `Max-Age`
+    /// takes precedence over `Expires`; the domain and path are left unset
+    /// when the header doesn't specify them.
+    pub fn parse(header: &str) -> Option<Self> {
+        let mut attributes = header.split(';');
+        let (name, value) = attributes.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = Self::new(name, value.trim().trim_matches('"'));
+        let mut max_age = None;
+        for attribute in attributes {
+            let (key, value) = attribute
+                .split_once('=')
+                .map_or((attribute.trim(), ""), |(k, v)| (k.trim(), v.trim()));
+
+            match key.to_ascii_lowercase().as_str() {
+                "domain" if !value.is_empty() => {
+                    cookie.domain = Some(value.trim_start_matches('.').to_ascii_lowercase());
+                }
+                "path" if value.starts_with('/') => cookie.path = Some(value.to_string()),
+                "expires" => {
+                    if let Ok(date) = chrono::DateTime::parse_from_rfc2822(value) {
+                        cookie.expires = Some(date.timestamp());
+                    }
+                }
+                "max-age" => max_age = value.parse::<i64>().ok(),
+                "secure" => cookie.secure = true,
+                "httponly" => cookie.http_only = true,
+                "samesite" => {
+                    cookie.same_site = match value.to_ascii_lowercase().as_str() {
+                        "strict" => SameSite::Strict,
+                        "lax" => SameSite::Lax,
+                        _ => SameSite::None,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(seconds) = max_age {
+            // A zero or negative Max-Age expires the cookie immediately
+            cookie.expires = Some(if seconds <= 0 {
+                0
+            } else {
+                chrono::Utc::now().timestamp().saturating_add(seconds)
+            });
+        }
+
+        Some(cookie)
+    }
+
+    /// Set the domain.
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Set the path.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the expiration time.
+    pub fn expires(mut self, timestamp: i64) -> Self {
+        self.expires = Some(timestamp);
+        self
+    }
+
+    /// Mark as secure-only.
+    pub fn secure(mut self) -> Self {
+        self.secure = true;
+        self
+    }
+
+    /// Mark as HTTP-only.
+    pub fn http_only(mut self) -> Self {
+        self.http_only = true;
+        self
+    }
+
+    /// Set the SameSite attribute.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = same_site;
+        self
+    }
+
+    /// Check if the cookie is expired.
+    pub fn is_expired(&self) -> bool {
+        if let Some(expires) = self.expires {
+            let now = chrono::Utc::now().timestamp();
+            expires < now
+        } else {
+            false // Session cookies don't expire
+        }
+    }
+
+    /// Check whether the cookie should be sent with a request to `url`.
+    ///
+    /// Only cookies scoped to a domain (as stored cookies are) can match.
+    pub fn matches_url(&self, url: &Url) -> bool {
+        let Some(domain) = &self.domain else {
+            return false;
+        };
+        let host_matches = if self.host_only {
+            host_of(url).is_some_and(|host| host == *domain)
+        } else {
+            domain_matches(url, domain)
+        };
+
+        host_matches
+            && path_matches(self.path.as_deref().unwrap_or("/"), url.path())
+            && (!self.secure || is_secure(url))
+            && !self.is_expired()
+    }
+
+    /// Resolve the cookie's scope against the URL setting it.
+    ///
+    /// Returns `None` if `url` may not set the cookie: its domain doesn't
+    /// cover the URL's host, is a public suffix other than the host itself,
+    /// or it is secure-only and the URL isn't.
+    fn scoped_to(mut self, url: &Url) -> Option<Self> {
+        let host = host_of(url)?;
+        match &self.domain {
+            // A public suffix may only name the host itself, and then the
+            // cookie is host-only (RFC 6265 section 5.3, step 5)
+            Some(domain) if is_public_suffix(domain) => {
+                if *domain != host {
+                    return None;
+                }
+                self.host_only = true;
+            }
+            Some(domain) if domain_matches(url, domain) => self.host_only = false,
+            Some(_) => return None,
+            None => {
+                self.domain = Some(host);
+                self.host_only = true;
+            }
+        }
+        if self.path.is_none() {
+            self.path = Some(default_path(url));
+        }
+        if self.secure && !is_secure(url) {
+            return None;
+        }
+        Some(self)
+    }
+
+    /// Check whether two cookies have the same name and scope.
+    fn same_identity(&self, other: &Self) -> bool {
+        self.name == other.name && self.domain == other.domain && self.path == other.path
+    }
+}
+
+/// Format cookies as a `Cookie` request header value.
+pub(crate) fn cookie_header(cookies: &[Cookie]) -> String {
+    cookies
+        .iter()
+        .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// The URL's host, lowercased.
+fn host_of(url: &Url) -> Option<String> {
+    url.host_str().map(str::to_ascii_lowercase)
+}
+
+/// Check whether a domain is a public suffix, under which anyone can register.
+fn is_public_suffix(domain: &str) -> bool {
+    !domain.contains('.') || PUBLIC_SUFFIXES.contains(&domain)
+}
+
+/// RFC 6265 domain matching; IP addresses only match exactly.
+fn domain_matches(url: &Url, domain: &str) -> bool {
+    let Some(host) = host_of(url) else {
+        return false;
+    };
+    host == domain
+        || (matches!(url.host(), Some(Host::Domain(_)))
+            && host.ends_with(domain)
+            && host[..host.len() - domain.len()].ends_with('.'))
+}
+
+/// RFC 6265 path matching.
+fn path_matches(cookie_path: &str, request_path: &str) -> bool {
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')))
+}
+
+/// The default cookie path: the request path up to its last `/`.
+fn default_path(url: &Url) -> String {
+    match url.path().rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(index) => url.path()[..index].to_string(),
+    }
+}
+
+fn is_secure(url: &Url) -> bool {
+    matches!(url.scheme(), "https" | "wss")
+}
+
+/// In-memory cookie store; cookies are lost when it is dropped.
+#[derive(Debug, Default)]
+pub struct MemoryCookieStore {
+    cookies: RwLock<Vec<Cookie>>,
+}
+
+impl MemoryCookieStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a store holding `cookies`, dropping any that have expired.
+    pub fn with_cookies(mut cookies: Vec<Cookie>) -> Self {
+        cookies.retain(|cookie| !cookie.is_expired());
+        Self {
+            cookies: RwLock::new(cookies),
+        }
+    }
+
+    /// Get all unexpired cookies.
+    pub async fn cookies(&self) -> Vec<Cookie> {
+        let mut cookies = self.cookies.write().await;
+        cookies.retain(|cookie| !cookie.is_expired());
+        cookies.clone()
+    }
+}
+
+#[async_trait]
+impl CookieStore for MemoryCookieStore {
+    async fn cookies_for(&self, url: &Url) -> Vec<Cookie> {
+        let mut cookies = self.cookies.write().await;
+        cookies.retain(|cookie| !cookie.is_expired());
+
+        let mut matching: Vec<Cookie> = cookies
+            .iter()
+            .filter(|cookie| cookie.matches_url(url))
+            .cloned()
+            .collect();
+        matching
+            .sort_by_key(|cookie| std::cmp::Reverse(cookie.path.as_ref().map_or(0, String::len)));
+        matching
+    }
+
+    async fn set_cookie(&self, cookie: Cookie, url: &Url) {
+        let name = cookie.name.clone();
+        let Some(cookie) = cookie.scoped_to(url) else {
+            tracing::debug!(url = %url, name = %name, "Rejected cookie outside its scope");
+            return;
+        };
+
+        let mut cookies = self.cookies.write().await;
+        cookies.retain(|existing| !existing.same_identity(&cookie));
+        if !cookie.is_expired() {
+            cookies.push(cookie);
+        }
+    }
+
+    async fn remove_cookie(&self, name: &str, url: &Url) {
+        self.cookies
+            .write()
+            .await
+            .retain(|cookie| cookie.name != name || !cookie.matches_url(url));
+    }
+
+    async fn clear(&self) {
+        self.cookies.write().await.clear();
+    }
+}
+
+/// Cookie store that persists cookies to a JSON file.
+///
+/// Session cookies (those without an expiry) are kept in memory only. The
+/// file is rewritten after every change.
+#[derive(Debug)]
+pub struct FileCookieStore {
+    path: PathBuf,
+    memory: MemoryCookieStore,
+}
+
+impl FileCookieStore {
+    /// Open a store backed by `path`, loading any cookies saved there.
+    pub fn open(path: impl Into<PathBuf>) -> NetworkResult<Self> {
+        let path = path.into();
+        let cookies = if path.exists() {
+            let data = std::fs::read(&path)
+                .map_err(|e| NetworkError::Internal(format!("Failed to read cookies: {}", e)))?;
+            serde_json::from_slice(&data).map_err(|e| {
+                NetworkError::Internal(format!("Failed to deserialize cookies: {}", e))
+            })?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            path,
+            memory: MemoryCookieStore::with_cookies(cookies),
+        })
+    }
+
+    /// Get all unexpired cookies.
+    pub async fn cookies(&self) -> Vec<Cookie> {
+        self.memory.cookies().await
+    }
+
+    /// Write the persistent cookies to the file.
+    pub async fn save(&self) -> NetworkResult<()> {
+        let mut cookies = self.memory.cookies().await;
+        cookies.retain(|cookie| cookie.expires.is_some());
+
+        let data = serde_json::to_vec_pretty(&cookies)
+            .map_err(|e| NetworkError::Internal(format!("Failed to serialize cookies: {}", e)))?;
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                NetworkError::Internal(format!("Failed to create cookie directory: {}", e))
+            })?;
+        }
+        tokio::fs::write(&self.path, data)
+            .await
+            .map_err(|e| NetworkError::Internal(format!("Failed to write cookies: {}", e)))
+    }
+
+    async fn save_logged(&self) {
+        if let Err(e) = self.save().await {
+            tracing::warn!(path = %self.path.display(), error = %e, "Failed to save cookies");
+        }
+    }
+}
+
+#[async_trait]
+impl CookieStore for FileCookieStore {
+    async fn store(&self, url: &Url, set_cookie_headers: &[String]) {
+        self.memory.store(url, set_cookie_headers).await;
+        self.save_logged().await;
+    }
+
+    async fn cookies_for(&self, url: &Url) -> Vec<Cookie> {
+        self.memory.cookies_for(url).await
+    }
+
+    async fn set_cookie(&self, cookie: Cookie, url: &Url) {
+        self.memory.set_cookie(cookie, url).await;
+        self.save_logged().await;
+    }
+
+    async fn remove_cookie(&self, name: &str, url: &Url) {
+        self.memory.remove_cookie(name, url).await;
+        self.save_logged().await;
+    }
+
+    async fn clear(&self) {
+        self.memory.clear().await;
+        self.save_logged().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    async fn names_for(store: &impl CookieStore, target: &str) -> Vec<String> {
+        store
+            .cookies_for(&url(target))
+            .await
+            .into_iter()
+            .map(|cookie| cookie.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_cookie_builder() {
+        let cookie = Cookie::new("session", "abc123")
+            .domain("example.com")
+            .path("/app")
+            .secure()
+            .http_only()
+            .same_site(SameSite::Strict);
+
+        assert_eq!(cookie.name, "session");
+        assert_eq!(cookie.value, "abc123");
+        assert_eq!(cookie.domain, Some("example.com".to_string()));
+        assert_eq!(cookie.path, Some("/app".to_string()));
+        assert!(cookie.secure);
+        assert!(cookie.http_only);
+        assert_eq!(cookie.same_site, SameSite::Strict);
+    }
+
+    #[test]
+    fn test_cookie_expiration() {
+        // Not expired (1 year from now)
+        let future_cookie =
+            Cookie::new("test", "value").expires(chrono::Utc::now().timestamp() + 31536000);
+        assert!(!future_cookie.is_expired());
+
+        // Expired (1 year ago)
+        let past_cookie =
+            Cookie::new("test", "value").expires(chrono::Utc::now().timestamp() - 31536000);
+        assert!(past_cookie.is_expired());
+
+        // Session cookie (never expires)
+        let session_cookie = Cookie::new("test", "value");
+        assert!(!session_cookie.is_expired());
+    }
+
+    #[test]
+    fn test_parse_set_cookie() {
+        let cookie = Cookie::parse(
+            "id=a3fWa; Domain=.Example.com; Path=/docs; Secure; HttpOnly; SameSite=Lax; \
+             Expires=Wed, 21 Oct 2099 07:28:00 GMT",
+        )
+        .unwrap();
+
+        assert_eq!(cookie.name, "id");
+        assert_eq!(cookie.value, "a3fWa");
+        assert_eq!(cookie.domain.as_deref(), Some("example.com"));
+        assert_eq!(cookie.path.as_deref(), Some("/docs"));
+        assert!(cookie.secure);
+        assert!(cookie.http_only);
+        assert_eq!(cookie.same_site, SameSite::Lax);
+        assert_eq!(cookie.expires, Some(4096250880));
+
+        // Max-Age wins over Expires
+        let cookie =
+            Cookie::parse("id=1; Max-Age=0; Expires=Wed, 21 Oct 2099 07:28:00 GMT").unwrap();
+        assert!(cookie.is_expired());
+
+        assert!(Cookie::parse("no-equals-sign").is_none());
+        assert!(Cookie::parse("=value").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_domain_and_path_scoping() {
+        let store = MemoryCookieStore::new();
+        let origin = url("https://www.example.com/app/login");
+        store
+            .store(
+                &origin,
+                &[
+                    "host=1".to_string(),
+                    "shared=2; Domain=example.com; Path=/".to_string(),
+                    "foreign=3; Domain=other.com".to_string(),
+                ],
+            )
+            .await;
+
+        // Host-only cookies default to the request's directory
+        assert_eq!(
+            names_for(&store, "https://www.example.com/app/page").await,
+            vec!["host", "shared"]
+        );
+        assert_eq!(
+            names_for(&store, "https://www.example.com/other").await,
+            vec!["shared"]
+        );
+        assert_eq!(
+            names_for(&store, "https://api.example.com/app/x").await,
+            vec!["shared"]
+        );
+        assert_eq!(
+            names_for(&store, "https://www.example.com/application").await,
+            vec!["shared"]
+        );
+        assert!(names_for(&store, "https://notexample.com/")
+            .await
+            .is_empty());
+        assert!(names_for(&store, "https://other.com/").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_public_suffix_domains_rejected() {
+        let store = MemoryCookieStore::new();
+        store
+            .store(
+                &url("https://www.example.com/"),
+                &["tld=1; Domain=com".to_string()],
+            )
+            .await;
+        store
+            .store(
+                &url("https://shop.example.co.uk/"),
+                &[
+                    "suffix=2; Domain=co.uk".to_string(),
+                    "site=3; Domain=example.co.uk".to_string(),
+                ],
+            )
+            .await;
+
+        assert!(names_for(&store, "https://other.com/").await.is_empty());
+        assert!(names_for(&store, "https://www.example.com/")
+            .await
+            .is_empty());
+        assert!(names_for(&store, "https://evil.co.uk/").await.is_empty());
+        assert_eq!(
+            names_for(&store, "https://www.example.co.uk/").await,
+            vec!["site"]
+        );
+
+        // A host that is itself a public suffix gets a host-only cookie
+        let store = MemoryCookieStore::new();
+        store
+            .store(
+                &url("http://localhost/"),
+                &["dev=1; Domain=localhost".to_string()],
+            )
+            .await;
+        assert_eq!(names_for(&store, "http://localhost/").await, vec!["dev"]);
+        let cookie = &store.cookies_for(&url("http://localhost/")).await[0];
+        assert!(cookie.host_only);
+    }
+
+    #[tokio::test]
+    async fn test_secure_http_only_and_expiry() {
+        let store = MemoryCookieStore::new();
+        let secure_origin = url("https://example.com/");
+        store
+            .store(
+                &secure_origin,
+                &["token=1; Secure".to_string(), "sid=2; HttpOnly".to_string()],
+            )
+            .await;
+
+        assert_eq!(
+            names_for(&store, "https://example.com/").await,
+            vec!["token", "sid"]
+        );
+        assert_eq!(names_for(&store, "http://example.com/").await, vec!["sid"]);
+        let script: Vec<_> = store
+            .script_cookies_for(&secure_origin)
+            .await
+            .into_iter()
+            .map(|cookie| cookie.name)
+            .collect();
+        assert_eq!(script, vec!["token"]);
+
+        // Insecure origins can't set secure cookies
+        store
+            .store(&url("http://example.com/"), &["evil=1; Secure".to_string()])
+            .await;
+        assert!(!names_for(&store, "https://example.com/")
+            .await
+            .contains(&"evil".to_string()));
+
+        // An expired cookie deletes the stored one
+        store
+            .store(&secure_origin, &["sid=gone; Max-Age=0".to_string()])
+            .await;
+        assert_eq!(
+            names_for(&store, "https://example.com/").await,
+            vec!["token"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_store_persists_across_reopen() {
+        let path = std::env::temp_dir()
+            .join(format!("cookies-{}", uuid::Uuid::new_v4()))
+            .join("cookies.json");
+        let origin = url("https://example.com/");
+
+        let store = FileCookieStore::open(&path).unwrap();
+        store
+            .store(
+                &origin,
+                &[
+                    "persistent=1; Max-Age=3600".to_string(),
+                    "session=2".to_string(),
+                ],
+            )
+            .await;
+        assert_eq!(names_for(&store, "https://example.com/").await.len(), 2);
+
+        // Only cookies with an expiry survive a restart
+        let reopened = FileCookieStore::open(&path).unwrap();
+        assert_eq!(
+            names_for(&reopened, "https://example.com/").await,
+            vec!["persistent"]
+        );
+
+        reopened.clear().await;
+        assert!(FileCookieStore::open(&path)
+            .unwrap()
+            .cookies()
+            .await
+            .is_empty());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -1,5 +1,7 @@
 //! Network error types for the network stack.
 
+use crate::response::StatusCode;
+use std::error::Error as _;
 use thiserror::Error;
 use url::Url;
 
@@ -69,6 +71,15 @@ pub enum NetworkError {
         max_size: usize,
     },
 
+    /// Response body ended before the declared `Content-Length`.
+    #[error("Incomplete response body: received {received} of {expected} bytes")]
+    IncompleteBody {
+        /// Declared content length in bytes.
+        expected: u64,
+        /// Bytes actually received.
+        received: u64,
+    },
+
     /// Redirect limit exceeded.
     #[error("Redirect limit exceeded ({count} redirects)")]
     TooManyRedirects {
@@ -76,6 +87,43 @@ pub enum NetworkError {
         count: u32,
     },
 
+    /// A redirect was refused by the redirect policy or request.
+    #[error("Redirect from {from} to {to} blocked: {reason}")]
+    RedirectBlocked {
+        /// The URL that was redirected.
+        from: String,
+        /// The redirect target.
+        to: String,
+        /// Why the redirect was not followed.
+        reason: String,
+    },
+
+    /// The response has an unexpected content type.
+    #[error("Expected {expected} response but got content type {actual}")]
+    UnexpectedContentType {
+        /// Kind of content that was expected.
+        expected: String,
+        /// The response's Content-Type.
+        actual: String,
+    },
+
+    /// The response body is not valid JSON for the requested type.
+    #[error("Invalid JSON at line {line}, column {column}: {message} (near `{snippet}`)")]
+    InvalidJson {
+        /// The parser's error message.
+        message: String,
+        /// Line of the error (1-based).
+        line: usize,
+        /// Column of the error (1-based).
+        column: usize,
+        /// Body text around the error.
+        snippet: String,
+    },
+
+    /// The response body could not be decoded.
+    #[error("Content decoding error: {0}")]
+    DecodingError(String),
+
     /// Cookie error.
     #[error("Cookie error: {0}")]
     CookieError(String),
@@ -93,7 +141,49 @@ pub enum NetworkError {
     Internal(String),
 }
 
+/// Broad category of a [`NetworkError`], for retry decisions and for
+/// showing the user a message that fits the failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetworkErrorKind {
+    /// The host name could not be resolved.
+    Dns,
+    /// The server could not be reached.
+    Connect,
+    /// The TLS handshake failed, e.g. because of an invalid certificate.
+    Tls,
+    /// The request or a read timed out.
+    Timeout,
+    /// The server answered with an error status.
+    Http(StatusCode),
+    /// The response body was too large, truncated, or could not be decoded.
+    Body,
+    /// An interceptor cancelled the request.
+    Interceptor,
+    /// Anything else.
+    Other,
+}
+
 impl NetworkError {
+    /// Classify this error.
+    pub fn kind(&self) -> NetworkErrorKind {
+        match self {
+            NetworkError::DnsError { .. } => NetworkErrorKind::Dns,
+            NetworkError::ConnectionFailed { .. } => NetworkErrorKind::Connect,
+            NetworkError::TlsError { .. } => NetworkErrorKind::Tls,
+            NetworkError::Timeout { .. } => NetworkErrorKind::Timeout,
+            NetworkError::HttpError { status_code, .. } => {
+                NetworkErrorKind::Http(StatusCode::new(*status_code))
+            }
+            NetworkError::ResponseTooLarge { .. }
+            | NetworkError::IncompleteBody { .. }
+            | NetworkError::UnexpectedContentType { .. }
+            | NetworkError::InvalidJson { .. }
+            | NetworkError::DecodingError(_) => NetworkErrorKind::Body,
+            NetworkError::RequestCancelled { .. } => NetworkErrorKind::Interceptor,
+            _ => NetworkErrorKind::Other,
+        }
+    }
+
     /// Create a timeout error.
     pub fn timeout(url: &Url, timeout_ms: u64) -> Self {
         Self::Timeout {
@@ -131,10 +221,32 @@ impl From<reqwest::Error> for NetworkError {
         }
 
         if err.is_connect() {
-            return NetworkError::ConnectionFailed {
-                url: err.url().map(|u| u.to_string()).unwrap_or_default(),
-                reason: err.to_string(),
+            let url = err.url().map(|u| u.to_string()).unwrap_or_default();
+            // Classify on the source errors only: reqwest's own message
+            // includes the URL, which may itself contain e.g. "ssl"
+            let causes = source_chain(&err);
+            let lower = causes.to_ascii_lowercase();
+            let reason = if causes.is_empty() {
+                err.to_string()
+            } else {
+                causes
             };
+            if lower.contains("dns error") || lower.contains("failed to lookup address") {
+                return NetworkError::DnsError {
+                    host: err
+                        .url()
+                        .and_then(|u| u.host_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                };
+            }
+            if ["tls", "ssl", "certificate", "handshake"]
+                .iter()
+                .any(|needle| lower.contains(needle))
+            {
+                return NetworkError::TlsError { url, reason };
+            }
+            return NetworkError::ConnectionFailed { url, reason };
         }
 
         if err.is_status() {
@@ -154,5 +266,19 @@ impl From<reqwest::Error> for NetworkError {
     }
 }
 
+/// Join the messages of an error's sources.
+///
+/// reqwest only says "error sending request"; the resolver and TLS backend
+/// errors that say what actually went wrong are further down the chain.
+fn source_chain(err: &reqwest::Error) -> String {
+    let mut messages = Vec::new();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        messages.push(cause.to_string());
+        source = cause.source();
+    }
+    messages.join(": ")
+}
+
 /// Result type for network operations.
 pub type NetworkResult<T> = Result<T, NetworkError>;
@@ -0,0 +1,334 @@
+//! HTTPS upgrade interceptor for the network stack.
+//!
+//! Rewrites `http://` (and `ws://`) requests to their secure scheme when the
+//! host is in the HSTS set or "upgrade all" mode is on. The HSTS set can be
+//! filled by hand or learned from `Strict-Transport-Security` headers on
+//! HTTPS responses.
+
+use crate::error::NetworkResult;
+use crate::interceptor::{InterceptorOutcome, RequestInterceptor, ResponseInterceptor};
+use crate::request::NetworkRequest;
+use crate::response::NetworkResponse;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use url::{Host, Url};
+
+/// Request metadata key holding the original URL of an upgraded request.
+pub const UPGRADED_FROM_METADATA: &str = "upgraded_from";
+
+/// Configuration for the HTTPS upgrade interceptor.
+#[derive(Debug, Clone)]
+pub struct HttpsUpgradeConfig {
+    /// Upgrade every eligible request, not just HSTS hosts.
+    pub upgrade_all: bool,
+    /// Leave `localhost` and IP address hosts alone.
+    pub skip_local_hosts: bool,
+}
+
+impl Default for HttpsUpgradeConfig {
+    fn default() -> Self {
+        Self {
+            upgrade_all: true,
+            skip_local_hosts: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HstsEntry {
+    include_subdomains: bool,
+    /// `None` for entries added by hand, which never expire.
+    expires: Option<Instant>,
+}
+
+/// Interceptor that upgrades insecure requests to HTTPS.
+#[derive(Debug, Clone, Default)]
+pub struct HttpsUpgradeInterceptor {
+    config: Arc<RwLock<HttpsUpgradeConfig>>,
+    hsts: Arc<RwLock<HashMap<String, HstsEntry>>>,
+}
+
+impl HttpsUpgradeInterceptor {
+    /// Create an interceptor that upgrades all non-local requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an interceptor that only upgrades hosts in the HSTS set.
+    pub fn hsts_only() -> Self {
+        Self::with_config(HttpsUpgradeConfig {
+            upgrade_all: false,
+            ..HttpsUpgradeConfig::default()
+        })
+    }
+
+    /// Create an interceptor with custom configuration.
+    pub fn with_config(config: HttpsUpgradeConfig) -> Self {
+        Self {
+            config: Arc::new(RwLock::new(config)),
+            hsts: Arc::default(),
+        }
+    }
+
+    /// Enable or disable upgrading all requests.
+    pub async fn set_upgrade_all(&self, enabled: bool) {
+        self.config.write().await.upgrade_all = enabled;
+    }
+
+    /// Enable or disable skipping `localhost` and IP address hosts.
+    pub async fn set_skip_local_hosts(&self, enabled: bool) {
+        self.config.write().await.skip_local_hosts = enabled;
+    }
+
+    /// Get the current configuration.
+    pub async fn get_config(&self) -> HttpsUpgradeConfig {
+        self.config.read().await.clone()
+    }
+
+    /// Add a host to the HSTS set.
+    pub async fn add_hsts_host(&self, host: &str, include_subdomains: bool) {
+        self.hsts.write().await.insert(
+            host.to_ascii_lowercase(),
+            HstsEntry {
+                include_subdomains,
+                expires: None,
+            },
+        );
+    }
+
+    /// Remove a host from the HSTS set.
+    pub async fn remove_hsts_host(&self, host: &str) {
+        self.hsts.write().await.remove(&host.to_ascii_lowercase());
+    }
+
+    /// Check whether a host is covered by the HSTS set.
+    pub async fn is_hsts_host(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        let now = Instant::now();
+        let hsts = self.hsts.read().await;
+
+        let live = |entry: &HstsEntry| entry.expires.is_none_or(|expires| expires > now);
+        if hsts.get(&host).is_some_and(live) {
+            return true;
+        }
+        // Check parent domains with includeSubDomains
+        host.match_indices('.').any(|(index, _)| {
+            hsts.get(&host[index + 1..])
+                .is_some_and(|entry| entry.include_subdomains && live(entry))
+        })
+    }
+
+    /// Record a `Strict-Transport-Security` header received from `host`.
+    ///
+    /// `max-age=0` removes the host from the HSTS set.
+    pub async fn record_sts_header(&self, host: &str, value: &str) {
+        let mut max_age = None;
+        let mut include_subdomains = false;
+        for directive in value.split(';').map(str::trim) {
+            let (name, arg) = directive.split_once('=').unwrap_or((directive, ""));
+            match name.trim().to_ascii_lowercase().as_str() {
+                "max-age" => max_age = arg.trim().trim_matches('"').parse::<u64>().ok(),
+                "includesubdomains" => include_subdomains = true,
+                _ => {}
+            }
+        }
+
+        let Some(max_age) = max_age else {
+            return;
+        };
+        let host = host.to_ascii_lowercase();
+        let mut hsts = self.hsts.write().await;
+        if max_age == 0 {
+            hsts.remove(&host);
+        } else {
+            hsts.insert(
+                host,
+                HstsEntry {
+                    include_subdomains,
+                    expires: Some(Instant::now() + Duration::from_secs(max_age)),
+                },
+            );
+        }
+    }
+
+    /// Get the upgraded URL for a request, if it should be upgraded.
+    pub async fn upgraded_url(&self, url: &Url) -> Option<Url> {
+        let secure_scheme = match url.scheme() {
+            "http" => "https",
+            "ws" => "wss",
+            _ => return None,
+        };
+        let host = url.host()?;
+
+        let config = self.config.read().await.clone();
+        if config.skip_local_hosts && is_local_host(&host) {
+            return None;
+        }
+        if !config.upgrade_all && !self.is_hsts_host(&host.to_string()).await {
+            return None;
+        }
+
+        let mut upgraded = url.clone();
+        upgraded.set_scheme(secure_scheme).ok()?;
+        Some(upgraded)
+    }
+}
+
+/// Check for `localhost` (including subdomains) and IP address hosts.
+fn is_local_host(host: &Host<&str>) -> bool {
+    match host {
+        Host::Domain(domain) => {
+            let domain = domain.to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        Host::Ipv4(_) | Host::Ipv6(_) => true,
+    }
+}
+
+#[async_trait]
+impl RequestInterceptor for HttpsUpgradeInterceptor {
+    async fn intercept_request(
+        &self,
+        mut request: NetworkRequest,
+    ) -> NetworkResult<InterceptorOutcome<NetworkRequest>> {
+        if let Some(upgraded) = self.upgraded_url(&request.url).await {
+            tracing::debug!(from = %request.url, to = %upgraded, "Upgrading request to HTTPS");
+            request
+                .metadata
+                .insert(UPGRADED_FROM_METADATA.to_string(), request.url.to_string());
+            request.url = upgraded;
+        }
+        Ok(InterceptorOutcome::Continue(request))
+    }
+
+    fn name(&self) -> &str {
+        "HttpsUpgradeInterceptor"
+    }
+
+    fn priority(&self) -> i32 {
+        250 // Run before the cache so lookups use the upgraded URL
+    }
+}
+
+#[async_trait]
+impl ResponseInterceptor for HttpsUpgradeInterceptor {
+    async fn intercept_response(
+        &self,
+        _request: &NetworkRequest,
+        response: NetworkResponse,
+    ) -> NetworkResult<NetworkResponse> {
+        // Browsers ignore the header on insecure responses
+        if response.url.scheme() == "https" {
+            if let (Some(host), Some(value)) = (
+                response.url.host_str(),
+                response.header("strict-transport-security"),
+            ) {
+                self.record_sts_header(host, value).await;
+            }
+        }
+        Ok(response)
+    }
+
+    fn name(&self) -> &str {
+        "HttpsUpgradeInterceptor"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::StatusCode;
+
+    async fn intercept(interceptor: &HttpsUpgradeInterceptor, url: &str) -> NetworkRequest {
+        let request = NetworkRequest::get(Url::parse(url).unwrap());
+        match interceptor.intercept_request(request).await.unwrap() {
+            InterceptorOutcome::Continue(request) => request,
+            _ => panic!("Expected Continue"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_mode_upgrades_except_local_hosts() {
+        let interceptor = HttpsUpgradeInterceptor::new();
+
+        let request = intercept(&interceptor, "http://example.com/path?q=1").await;
+        assert_eq!(request.url.as_str(), "https://example.com/path?q=1");
+        assert_eq!(
+            request.metadata.get(UPGRADED_FROM_METADATA).unwrap(),
+            "http://example.com/path?q=1"
+        );
+
+        let request = intercept(&interceptor, "http://example.com:80/").await;
+        assert_eq!(request.url.as_str(), "https://example.com/");
+        let request = intercept(&interceptor, "ws://example.com/socket").await;
+        assert_eq!(request.url.as_str(), "wss://example.com/socket");
+
+        for url in [
+            "http://localhost:3000/",
+            "http://app.localhost/",
+            "http://127.0.0.1:8080/",
+            "http://[::1]/",
+        ] {
+            let request = intercept(&interceptor, url).await;
+            assert_eq!(request.url.as_str(), url);
+            assert!(request.metadata.is_empty());
+        }
+
+        interceptor.set_skip_local_hosts(false).await;
+        let request = intercept(&interceptor, "http://localhost:3000/").await;
+        assert_eq!(request.url.as_str(), "https://localhost:3000/");
+    }
+
+    #[tokio::test]
+    async fn test_hsts_only_mode() {
+        let interceptor = HttpsUpgradeInterceptor::hsts_only();
+        interceptor.add_hsts_host("secure.example", true).await;
+        interceptor.add_hsts_host("exact.example", false).await;
+
+        for (url, expected) in [
+            ("http://secure.example/", "https://secure.example/"),
+            ("http://api.secure.example/", "https://api.secure.example/"),
+            ("http://exact.example/", "https://exact.example/"),
+            ("http://sub.exact.example/", "http://sub.exact.example/"),
+            ("http://other.example/", "http://other.example/"),
+        ] {
+            assert_eq!(intercept(&interceptor, url).await.url.as_str(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_learns_hsts_from_secure_responses() {
+        let interceptor = HttpsUpgradeInterceptor::hsts_only();
+        let respond = |url: &str, sts: &str| {
+            let mut headers = crate::request::HeaderMap::new();
+            headers.insert("Strict-Transport-Security".to_string(), sts.to_string());
+            NetworkResponse::new(StatusCode::OK, Url::parse(url).unwrap()).headers(headers)
+        };
+        let request = NetworkRequest::get(Url::parse("https://example.com/").unwrap());
+
+        // Ignored over plain HTTP
+        interceptor
+            .intercept_response(&request, respond("http://example.com/", "max-age=3600"))
+            .await
+            .unwrap();
+        assert!(!interceptor.is_hsts_host("example.com").await);
+
+        interceptor
+            .intercept_response(
+                &request,
+                respond("https://example.com/", "max-age=3600; includeSubDomains"),
+            )
+            .await
+            .unwrap();
+        assert!(interceptor.is_hsts_host("www.example.com").await);
+
+        interceptor
+            .intercept_response(&request, respond("https://example.com/", "max-age=0"))
+            .await
+            .unwrap();
+        assert!(!interceptor.is_hsts_host("example.com").await);
+    }
+}
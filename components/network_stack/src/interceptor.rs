@@ -21,7 +21,7 @@ pub enum InterceptorOutcome<T> {
     /// Continue processing with the (possibly modified) value.
     Continue(T),
     /// Stop processing and return early with a response.
-    ShortCircuit(NetworkResponse),
+    ShortCircuit(Box<NetworkResponse>),
     /// Cancel the request entirely.
     Cancel(String),
 }
@@ -467,9 +467,9 @@ impl ResponseInterceptor for RetryInterceptor {
         _request: &NetworkRequest,
         response: NetworkResponse,
     ) -> NetworkResult<NetworkResponse> {
-        // Note: Actual retry logic would need to be in the client,
-        // as interceptors can't re-execute requests.
-        // This interceptor just adds retry metadata for the client to use.
+        // Interceptors can't re-execute requests; configure a
+        // `RetryPolicy` on the client for actual retries.
+        // This interceptor only logs responses that may need one.
         if response.status.is_server_error() {
             tracing::warn!(
                 status = %response.status,
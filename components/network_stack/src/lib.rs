@@ -11,6 +11,7 @@
 //! - **Cache control**: Header-based cache semantics with configurable modes
 //! - **Connection pooling**: Efficient connection reuse with configurable pool settings
 //! - **Timeout configuration**: Per-request and client-level timeout settings
+//! - **Bandwidth throttling**: Simulated slow links for testing loading behavior
 //!
 //! # Architecture
 //!
@@ -117,32 +118,54 @@
 
 mod cache;
 mod client;
+mod compression_interceptor;
+mod cookie;
 mod error;
+mod https_upgrade_interceptor;
 mod interceptor;
+mod mock_interceptor;
+mod pool_stats;
 mod privacy_interceptor;
+mod proxy;
+mod rate_limit_interceptor;
+mod redirect;
 pub mod protocol;
 mod request;
 mod resource_loader;
 mod response;
+mod retry;
+#[cfg(test)]
+mod test_server;
+mod throttle;
 
 // Re-export public types
 pub use cache::{CacheEntry, CacheStorage, CachingInterceptor, DiskCache, MemoryCache};
-pub use client::{
-    Cookie, CookieStore, HttpClient, HttpClientBuilder, NetworkClient, NetworkClientConfig,
-    SameSite,
+pub use client::{HttpClient, HttpClientBuilder, NetworkClient, NetworkClientConfig};
+pub use compression_interceptor::{CompressionInterceptor, CompressionStats, EncodingStats};
+pub use cookie::{Cookie, CookieStore, FileCookieStore, MemoryCookieStore, SameSite};
+pub use error::{NetworkError, NetworkErrorKind, NetworkResult};
+pub use https_upgrade_interceptor::{
+    HttpsUpgradeConfig, HttpsUpgradeInterceptor, UPGRADED_FROM_METADATA,
 };
-pub use error::{NetworkError, NetworkResult};
 pub use interceptor::{
     AuthInterceptor, AuthType, InterceptorOutcome, LoggingInterceptor, RequestInterceptor,
     RequestInterceptorChain, ResponseInterceptor, ResponseInterceptorChain, RetryInterceptor,
     UserAgentInterceptor,
 };
+pub use mock_interceptor::{MockInterceptor, MockResponse};
+pub use pool_stats::{HostPoolStats, PoolStats};
 pub use privacy_interceptor::{PrivacyInterceptor, PrivacyInterceptorConfig};
+pub use proxy::{ProxyConfig, ProxyCredentials, ProxyKind};
+pub use rate_limit_interceptor::RateLimitInterceptor;
+pub use redirect::RedirectPolicy;
 pub use request::{
-    CacheMode, CredentialsMode, HeaderMap, Method, NetworkRequest, RedirectPolicy, ResourceType,
+    CacheMode, CredentialsMode, HeaderMap, Method, MultipartBuilder, NetworkRequest,
+    RedirectMode, ResourceType,
 };
 pub use resource_loader::{ResourceLoadResult, ResourceLoader, ResourceLoaderBuilder};
-pub use response::{CacheStatus, NetworkResponse, StatusCode};
+pub use response::{CacheStatus, NetworkResponse, ResponseStream, StatusCode};
+pub use retry::{RetryOn, RetryPolicy};
+pub use throttle::ThrottleConfig;
 
 /// Re-export url crate for convenience.
 pub use url::Url;
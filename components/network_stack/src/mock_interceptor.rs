@@ -0,0 +1,326 @@
+//! Response mocking for tests.
+//!
+//! [`MockInterceptor`] answers matching requests with canned responses, so
+//! components built on a [`crate::NetworkClient`] can be tested without a
+//! live server. Requests are matched on method and a URL pattern where `*`
+//! matches any run of characters.
+
+use crate::error::NetworkResult;
+use crate::interceptor::{InterceptorOutcome, RequestInterceptor};
+use crate::request::{HeaderMap, Method, NetworkRequest};
+use crate::response::{NetworkResponse, StatusCode};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// Canned response returned by a [`MockInterceptor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    /// Status code.
+    pub status: StatusCode,
+    /// Response headers.
+    pub headers: HeaderMap,
+    /// Response body.
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// Create an empty response with the given status.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Create a `200 OK` response with the given body.
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self::new(StatusCode::OK).body(body)
+    }
+
+    /// Add a response header.
+    ///
+    /// Names are lowercased, as they are on responses from the network.
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .insert(key.into().to_ascii_lowercase(), value.into());
+        self
+    }
+
+    /// Set the response body.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Build the response for a request to `url`.
+    fn to_response(&self, url: Url) -> NetworkResponse {
+        NetworkResponse::new(self.status, url)
+            .headers(self.headers.clone())
+            .body(self.body.clone())
+    }
+}
+
+#[derive(Debug)]
+struct MockRoute {
+    /// `None` matches any method.
+    method: Option<Method>,
+    pattern: String,
+    responses: Vec<MockResponse>,
+    calls: usize,
+}
+
+impl MockRoute {
+    fn matches(&self, request: &NetworkRequest) -> bool {
+        self.method.is_none_or(|method| method == request.method)
+            && glob_match(&self.pattern, request.url.as_str())
+    }
+
+    /// Get the response for the next call; the last one repeats once the
+    /// sequence runs out.
+    fn next_response(&mut self) -> &MockResponse {
+        let index = self.calls.min(self.responses.len() - 1);
+        self.calls += 1;
+        &self.responses[index]
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<NetworkRequest>,
+    deny_unmatched: bool,
+}
+
+/// Request interceptor that short-circuits matching requests with canned
+/// responses.
+///
+/// Registering several responses for the same method and pattern returns
+/// them in order, repeating the last. The first registered route that
+/// matches a request answers it. Unmatched requests go to the network
+/// unless [`MockInterceptor::deny_unmatched`] is set.
+#[derive(Debug, Clone, Default)]
+pub struct MockInterceptor {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockInterceptor {
+    /// Create an interceptor with no mocks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `method` requests to URLs matching `pattern` with `response`.
+    ///
+    /// Calling this again with the same method and pattern queues another
+    /// response for the following call.
+    pub fn mock(&self, method: Method, pattern: impl Into<String>, response: MockResponse) {
+        self.add(Some(method), pattern.into(), response);
+    }
+
+    /// Answer requests with any method to URLs matching `pattern`.
+    pub fn mock_any(&self, pattern: impl Into<String>, response: MockResponse) {
+        self.add(None, pattern.into(), response);
+    }
+
+    fn add(&self, method: Option<Method>, pattern: String, response: MockResponse) {
+        let mut state = self.lock();
+        match state
+            .routes
+            .iter_mut()
+            .find(|route| route.method == method && route.pattern == pattern)
+        {
+            Some(route) => route.responses.push(response),
+            None => state.routes.push(MockRoute {
+                method,
+                pattern,
+                responses: vec![response],
+                calls: 0,
+            }),
+        }
+    }
+
+    /// Set whether unmatched requests are cancelled instead of sent.
+    pub fn deny_unmatched(&self, deny: bool) {
+        self.lock().deny_unmatched = deny;
+    }
+
+    /// Get the requests answered with a mock, in order.
+    pub fn requests(&self) -> Vec<NetworkRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Get how many requests a route has answered.
+    pub fn call_count(&self, method: Method, pattern: &str) -> usize {
+        self.lock()
+            .routes
+            .iter()
+            .find(|route| route.method == Some(method) && route.pattern == pattern)
+            .map_or(0, |route| route.calls)
+    }
+
+    /// Remove all mocks and recorded requests.
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.routes.clear();
+        state.requests.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl RequestInterceptor for MockInterceptor {
+    async fn intercept_request(
+        &self,
+        request: NetworkRequest,
+    ) -> NetworkResult<InterceptorOutcome<NetworkRequest>> {
+        let mut state = self.lock();
+        let Some(route) = state
+            .routes
+            .iter_mut()
+            .find(|route| route.matches(&request))
+        else {
+            if state.deny_unmatched {
+                return Ok(InterceptorOutcome::Cancel(format!(
+                    "No mock for {:?} {}",
+                    request.method, request.url
+                )));
+            }
+            return Ok(InterceptorOutcome::Continue(request));
+        };
+
+        let response = route.next_response().to_response(request.url.clone());
+        tracing::debug!(url = %request.url, status = response.status.as_u16(), "Serving mock response");
+        state.requests.push(request);
+        Ok(InterceptorOutcome::ShortCircuit(Box::new(response)))
+    }
+
+    fn name(&self) -> &str {
+        "MockInterceptor"
+    }
+
+    fn priority(&self) -> i32 {
+        -1000 // Run last so other interceptors still see the request
+    }
+}
+
+/// Match `text` against a glob `pattern` where `*` matches any run of
+/// characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let Some((first, rest)) = pattern.split_once('*') else {
+        return pattern == text;
+    };
+    let Some(mut remaining) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts: Vec<&str> = rest.split('*').collect();
+    let last = parts.pop().unwrap_or_default();
+    for part in parts {
+        match remaining.find(part) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.len() >= last.len() && remaining.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{HttpClient, NetworkClient};
+    use crate::error::NetworkError;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(
+            "https://api.test/users",
+            "https://api.test/users"
+        ));
+        assert!(!glob_match(
+            "https://api.test/users",
+            "https://api.test/users/1"
+        ));
+        assert!(glob_match(
+            "https://api.test/users/*",
+            "https://api.test/users/1"
+        ));
+        assert!(glob_match(
+            "*://api.test/*/avatar",
+            "http://api.test/users/1/avatar"
+        ));
+        assert!(!glob_match(
+            "*://api.test/*/avatar",
+            "http://api.test/users/1"
+        ));
+        assert!(glob_match("*", "https://anything.test/"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_returns_canned_response() {
+        let mock = MockInterceptor::new();
+        mock.mock(
+            Method::Get,
+            "https://api.mock.invalid/users/*",
+            MockResponse::ok(r#"{"name":"Ada"}"#).header("Content-Type", "application/json"),
+        );
+        let client = HttpClient::new().unwrap();
+        client.add_request_interceptor(Arc::new(mock.clone())).await;
+
+        // The .invalid TLD never resolves, so this can only succeed via the mock
+        let response = client
+            .fetch(NetworkRequest::get(url("https://api.mock.invalid/users/1")))
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.text().unwrap(), r#"{"name":"Ada"}"#);
+        assert_eq!(response.content_type.as_deref(), Some("application/json"));
+        assert_eq!(response.url.as_str(), "https://api.mock.invalid/users/1");
+        assert_eq!(mock.requests().len(), 1);
+
+        // A different method isn't matched
+        mock.deny_unmatched(true);
+        let result = client
+            .fetch(NetworkRequest::new(
+                Method::Delete,
+                url("https://api.mock.invalid/users/1"),
+            ))
+            .await;
+        assert!(matches!(result, Err(NetworkError::RequestCancelled { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_sequential_responses() {
+        let mock = MockInterceptor::new();
+        let pattern = "https://api.mock.invalid/job";
+        mock.mock(
+            Method::Post,
+            pattern,
+            MockResponse::new(StatusCode::new(202)),
+        );
+        mock.mock(Method::Post, pattern, MockResponse::ok("done"));
+        let client = HttpClient::new().unwrap();
+        client.add_request_interceptor(Arc::new(mock.clone())).await;
+
+        let mut statuses = Vec::new();
+        for _ in 0..3 {
+            let request = NetworkRequest::new(Method::Post, url(pattern));
+            statuses.push(client.fetch(request).await.unwrap().status.as_u16());
+        }
+        // The last response repeats
+        assert_eq!(statuses, vec![202, 200, 200]);
+        assert_eq!(mock.call_count(Method::Post, pattern), 3);
+
+        mock.reset();
+        assert!(mock.requests().is_empty());
+        assert_eq!(mock.call_count(Method::Post, pattern), 0);
+    }
+}
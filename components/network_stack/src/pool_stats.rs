@@ -0,0 +1,241 @@
+//! Connection pool observability.
+//!
+//! reqwest doesn't expose its connection pool, so [`crate::HttpClient`]
+//! tracks requests per origin itself. Active counts are exact; idle counts
+//! are estimated from requests that ended on a reusable connection, capped
+//! by the pool's per-host idle limit and expired after its idle timeout.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// Connection counts for one origin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HostPoolStats {
+    /// Requests currently in flight.
+    pub active: usize,
+    /// Estimated idle connections kept for reuse.
+    pub idle: usize,
+    /// Most requests ever in flight at once.
+    pub peak_active: usize,
+    /// Requests sent so far.
+    pub total_requests: u64,
+}
+
+/// Snapshot of a client's connection pool.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Requests currently in flight across all origins.
+    pub active_connections: usize,
+    /// Estimated idle connections across all origins.
+    pub idle_connections: usize,
+    /// Counts keyed by origin (e.g. `https://example.com`).
+    pub hosts: HashMap<String, HostPoolStats>,
+}
+
+impl PoolStats {
+    /// Get the counts for the origin of `url`.
+    pub fn host(&self, url: &Url) -> Option<&HostPoolStats> {
+        self.hosts.get(&url.origin().ascii_serialization())
+    }
+}
+
+#[derive(Debug)]
+struct OriginActivity {
+    stats: HostPoolStats,
+    last_active: Instant,
+}
+
+/// Per-origin request tracking behind [`PoolStats`].
+#[derive(Debug, Clone)]
+pub(crate) struct PoolTracker {
+    origins: Arc<Mutex<HashMap<String, OriginActivity>>>,
+    idle_timeout: Duration,
+    max_idle_per_host: usize,
+}
+
+impl PoolTracker {
+    pub(crate) fn new(idle_timeout: Duration, max_idle_per_host: usize) -> Self {
+        Self {
+            origins: Arc::new(Mutex::new(HashMap::new())),
+            idle_timeout,
+            max_idle_per_host,
+        }
+    }
+
+    /// Record the start of a request; it ends when the guard is dropped.
+    pub(crate) fn begin(&self, url: &Url) -> ActiveRequest {
+        let origin = url.origin().ascii_serialization();
+        let now = Instant::now();
+        let mut origins = self.origins.lock().unwrap_or_else(|e| e.into_inner());
+        let activity = origins
+            .entry(origin.clone())
+            .or_insert_with(|| OriginActivity {
+                stats: HostPoolStats::default(),
+                last_active: now,
+            });
+        self.expire_idle(activity, now);
+
+        let stats = &mut activity.stats;
+        // An idle connection, if any, is taken for this request
+        stats.idle = stats.idle.saturating_sub(1);
+        stats.active += 1;
+        stats.peak_active = stats.peak_active.max(stats.active);
+        stats.total_requests += 1;
+        activity.last_active = now;
+
+        ActiveRequest {
+            tracker: self.clone(),
+            origin,
+            reusable: false,
+            completed: false,
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> PoolStats {
+        let now = Instant::now();
+        let mut origins = self.origins.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stats = PoolStats::default();
+        for (origin, activity) in origins.iter_mut() {
+            self.expire_idle(activity, now);
+            stats.active_connections += activity.stats.active;
+            stats.idle_connections += activity.stats.idle;
+            stats.hosts.insert(origin.clone(), activity.stats);
+        }
+        stats
+    }
+
+    /// Drop idle connections the pool would have closed by now.
+    fn expire_idle(&self, activity: &mut OriginActivity, now: Instant) {
+        if activity.stats.active == 0
+            && now.duration_since(activity.last_active) >= self.idle_timeout
+        {
+            activity.stats.idle = 0;
+        }
+    }
+}
+
+/// Guard for an in-flight request.
+///
+/// On drop the request stops counting as active, and its connection counts
+/// as idle if the request completed on a reusable connection.
+#[derive(Debug)]
+pub(crate) struct ActiveRequest {
+    tracker: PoolTracker,
+    origin: String,
+    reusable: bool,
+    completed: bool,
+}
+
+impl ActiveRequest {
+    /// Record whether the server allows reusing the connection.
+    pub(crate) fn set_reusable(&mut self, reusable: bool) {
+        self.reusable = reusable;
+    }
+
+    /// Record that the response body was read to the end.
+    pub(crate) fn complete(&mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for ActiveRequest {
+    fn drop(&mut self) {
+        let mut origins = self
+            .tracker
+            .origins
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(activity) = origins.get_mut(&self.origin) {
+            let stats = &mut activity.stats;
+            stats.active = stats.active.saturating_sub(1);
+            if self.reusable && self.completed {
+                stats.idle = (stats.idle + 1).min(self.tracker.max_idle_per_host);
+            }
+            activity.last_active = Instant::now();
+        }
+    }
+}
+
+/// Check whether a response leaves its connection open for reuse.
+pub(crate) fn connection_reusable(response: &reqwest::Response) -> bool {
+    let closes = response
+        .headers()
+        .get_all(reqwest::header::CONNECTION)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.to_ascii_lowercase().contains("close"));
+    response.version() >= reqwest::Version::HTTP_11 && !closes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_tracks_active_and_idle_per_origin() {
+        let tracker = PoolTracker::new(Duration::from_secs(90), 2);
+        let a = url("https://a.example/page");
+
+        let mut first = tracker.begin(&a);
+        let mut second = tracker.begin(&a);
+        let third = tracker.begin(&a);
+        let _other = tracker.begin(&url("https://b.example/"));
+
+        let stats = tracker.snapshot();
+        assert_eq!(stats.active_connections, 4);
+        assert_eq!(stats.host(&a).unwrap().active, 3);
+
+        first.set_reusable(true);
+        first.complete();
+        second.set_reusable(true);
+        second.complete();
+        drop((first, second, third));
+
+        // The request that never completed doesn't leave an idle connection
+        let host = *tracker.snapshot().host(&a).unwrap();
+        assert_eq!(
+            host,
+            HostPoolStats {
+                active: 0,
+                idle: 2,
+                peak_active: 3,
+                total_requests: 3,
+            }
+        );
+
+        // New requests reuse idle connections
+        let _reused = tracker.begin(&a);
+        assert_eq!(tracker.snapshot().host(&a).unwrap().idle, 1);
+    }
+
+    #[test]
+    fn test_idle_capped_and_expired() {
+        let a = url("https://a.example/");
+        let run_three = |tracker: &PoolTracker| {
+            let guards: Vec<_> = (0..3)
+                .map(|_| {
+                    let mut guard = tracker.begin(&a);
+                    guard.set_reusable(true);
+                    guard.complete();
+                    guard
+                })
+                .collect();
+            drop(guards);
+            tracker.snapshot()
+        };
+
+        let stats = run_three(&PoolTracker::new(Duration::from_secs(90), 1));
+        assert_eq!(stats.idle_connections, 1);
+        assert_eq!(stats.host(&a).unwrap().peak_active, 3);
+
+        // Past the pool's idle timeout nothing is idle
+        let stats = run_three(&PoolTracker::new(Duration::ZERO, 1));
+        assert_eq!(stats.idle_connections, 0);
+    }
+}
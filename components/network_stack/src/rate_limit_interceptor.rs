@@ -0,0 +1,226 @@
+//! Per-host rate limiting for the network stack.
+//!
+//! [`RateLimitInterceptor`] keeps a sliding window of recent requests for
+//! each host. A host may receive up to `burst` requests within any window of
+//! `burst / requests_per_second` seconds, so short bursts go out immediately
+//! while the sustained rate never exceeds `requests_per_second`. Requests
+//! over the limit are delayed until a slot frees up, or rejected outright
+//! when the interceptor is configured with [`RateLimitInterceptor::reject_excess`].
+
+use crate::error::NetworkResult;
+use crate::interceptor::{InterceptorOutcome, RequestInterceptor};
+use crate::request::NetworkRequest;
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Interceptor that limits how quickly requests are sent to each host.
+#[derive(Debug, Clone)]
+pub struct RateLimitInterceptor {
+    requests_per_second: u32,
+    burst: u32,
+    window: Duration,
+    reject: bool,
+    /// Send times (past or reserved) of recent requests, keyed by host.
+    hosts: Arc<Mutex<HashMap<String, VecDeque<Instant>>>>,
+}
+
+impl RateLimitInterceptor {
+    /// Create an interceptor allowing `requests_per_second` per host on
+    /// average, with bursts of up to `burst` requests.
+    ///
+    /// Both values are clamped to at least 1.
+    pub fn new(requests_per_second: u32, burst: u32) -> Self {
+        let requests_per_second = requests_per_second.max(1);
+        let burst = burst.max(1);
+        Self {
+            requests_per_second,
+            burst,
+            window: Duration::from_secs_f64(f64::from(burst) / f64::from(requests_per_second)),
+            reject: false,
+            hosts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Cancel requests over the limit instead of delaying them.
+    pub fn reject_excess(mut self) -> Self {
+        self.reject = true;
+        self
+    }
+
+    /// Get the configured average rate per host.
+    pub fn requests_per_second(&self) -> u32 {
+        self.requests_per_second
+    }
+
+    /// Get the configured burst size.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Reserve a send slot for `host`.
+    ///
+    /// Returns the time the request may be sent, or `None` if the host is
+    /// over its limit and excess requests are rejected.
+    fn reserve(&self, host: &str, now: Instant) -> Option<Instant> {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let sent = hosts.entry(host.to_string()).or_default();
+
+        while sent
+            .front()
+            .is_some_and(|&t| now.saturating_duration_since(t) >= self.window)
+        {
+            sent.pop_front();
+        }
+
+        let burst = self.burst as usize;
+        let slot = if sent.len() < burst {
+            now
+        } else if self.reject {
+            return None;
+        } else {
+            // The request `burst` places back must leave the window first
+            (sent[sent.len() - burst] + self.window).max(now)
+        };
+        sent.push_back(slot);
+        Some(slot)
+    }
+}
+
+#[async_trait]
+impl RequestInterceptor for RateLimitInterceptor {
+    async fn intercept_request(
+        &self,
+        request: NetworkRequest,
+    ) -> NetworkResult<InterceptorOutcome<NetworkRequest>> {
+        let host = request.url.host_str().unwrap_or_default().to_string();
+
+        match self.reserve(&host, Instant::now()) {
+            Some(slot) => {
+                tokio::time::sleep_until(slot).await;
+                Ok(InterceptorOutcome::Continue(request))
+            }
+            None => Ok(InterceptorOutcome::Cancel(format!(
+                "Rate limit exceeded for {}",
+                host
+            ))),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "RateLimitInterceptor"
+    }
+
+    fn priority(&self) -> i32 {
+        -90 // Run late so cached responses are not throttled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{HttpClientBuilder, NetworkClient};
+    use crate::error::NetworkError;
+    use url::Url;
+
+    fn get(url: &str) -> NetworkRequest {
+        NetworkRequest::get(Url::parse(url).unwrap())
+    }
+
+    /// Send `count` requests back to back and return when each was released,
+    /// relative to the first.
+    async fn fire(interceptor: &RateLimitInterceptor, url: &str, count: usize) -> Vec<Duration> {
+        let start = Instant::now();
+        let mut released = Vec::new();
+        for _ in 0..count {
+            let outcome = interceptor.intercept_request(get(url)).await.unwrap();
+            assert!(matches!(outcome, InterceptorOutcome::Continue(_)));
+            released.push(start.elapsed());
+        }
+        released
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_then_throttled_to_rate() {
+        let interceptor = RateLimitInterceptor::new(2, 2);
+
+        let released = fire(&interceptor, "https://example.com/a", 6).await;
+
+        let secs: Vec<u64> = released.iter().map(|d| d.as_secs()).collect();
+        assert_eq!(secs, vec![0, 0, 1, 1, 2, 2]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_burst_spread_over_window() {
+        let interceptor = RateLimitInterceptor::new(10, 5);
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..20)
+            .map(|_| {
+                let interceptor = interceptor.clone();
+                tokio::spawn(async move {
+                    interceptor
+                        .intercept_request(get("https://example.com/"))
+                        .await
+                        .unwrap();
+                    start.elapsed()
+                })
+            })
+            .collect();
+
+        let mut released = Vec::new();
+        for handle in handles {
+            released.push(handle.await.unwrap());
+        }
+        released.sort();
+
+        // 5 immediately, then 5 more every half second: 10 requests/second
+        assert_eq!(released[4], Duration::ZERO);
+        assert_eq!(released[5], Duration::from_millis(500));
+        assert_eq!(released[19], Duration::from_millis(1500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_hosts_limited_independently() {
+        let interceptor = RateLimitInterceptor::new(1, 1);
+
+        fire(&interceptor, "https://a.example/", 1).await;
+        let released = fire(&interceptor, "https://b.example/", 1).await;
+        assert_eq!(released[0], Duration::ZERO);
+
+        let released = fire(&interceptor, "https://a.example/", 1).await;
+        assert_eq!(released[0], Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reject_excess() {
+        let interceptor = RateLimitInterceptor::new(1, 2).reject_excess();
+
+        fire(&interceptor, "https://example.com/", 2).await;
+        let outcome = interceptor
+            .intercept_request(get("https://example.com/"))
+            .await
+            .unwrap();
+        assert!(matches!(outcome, InterceptorOutcome::Cancel(_)));
+
+        // The window slides forward and frees up capacity
+        tokio::time::advance(Duration::from_secs(2)).await;
+        fire(&interceptor, "https://example.com/", 2).await;
+    }
+
+    #[tokio::test]
+    async fn test_client_rejects_over_limit() {
+        let client = HttpClientBuilder::new()
+            .request_interceptor(Arc::new(RateLimitInterceptor::new(1, 1).reject_excess()))
+            .build()
+            .unwrap();
+
+        // The first request uses the host's only slot; whether it connects
+        // doesn't matter.
+        let _ = client.fetch(get("http://127.0.0.1:9/")).await;
+        let result = client.fetch(get("http://127.0.0.1:9/")).await;
+        assert!(matches!(result, Err(NetworkError::RequestCancelled { .. })));
+    }
+}
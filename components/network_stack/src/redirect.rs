@@ -0,0 +1,195 @@
+//! Redirect handling for the network stack.
+//!
+//! [`crate::HttpClient`] follows redirects itself instead of leaving them to
+//! reqwest, so a [`RedirectPolicy`] can be applied to every hop and the
+//! chain recorded on the response.
+
+use crate::error::{NetworkError, NetworkResult};
+use crate::request::{Method, NetworkRequest, RedirectMode};
+use crate::response::StatusCode;
+
+/// Redirect behavior for a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedirectPolicy {
+    /// Maximum number of redirects to follow for one request.
+    pub max_hops: u32,
+    /// Remove credential headers (`Authorization`, `Proxy-Authorization`
+    /// and `Cookie`) when a redirect leads to another origin.
+    pub strip_auth_on_cross_origin: bool,
+    /// Follow redirects from HTTPS to plain HTTP.
+    pub allow_downgrade: bool,
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            max_hops: 10,
+            strip_auth_on_cross_origin: true,
+            allow_downgrade: false,
+        }
+    }
+}
+
+impl RedirectPolicy {
+    /// Create a policy following up to `max_hops` redirects, with the
+    /// default safeguards.
+    pub fn new(max_hops: u32) -> Self {
+        Self {
+            max_hops,
+            ..Self::default()
+        }
+    }
+
+    /// Set whether credential headers are removed on cross-origin
+    /// redirects.
+    pub fn strip_auth_on_cross_origin(mut self, strip: bool) -> Self {
+        self.strip_auth_on_cross_origin = strip;
+        self
+    }
+
+    /// Set whether HTTPS to HTTP redirects are followed.
+    pub fn allow_downgrade(mut self, allow: bool) -> Self {
+        self.allow_downgrade = allow;
+        self
+    }
+
+    /// Build the request for the next hop after `request` was redirected
+    /// to `location`, having already followed `hops` redirects.
+    pub(crate) fn follow(
+        &self,
+        request: &NetworkRequest,
+        status: StatusCode,
+        location: &str,
+        hops: u32,
+    ) -> NetworkResult<NetworkRequest> {
+        let from = &request.url;
+        let to = from
+            .join(location)
+            .map_err(|e| NetworkError::InvalidUrl(format!("{}: {}", location, e)))?;
+        let blocked = |reason: &str| NetworkError::RedirectBlocked {
+            from: from.to_string(),
+            to: to.to_string(),
+            reason: reason.to_string(),
+        };
+
+        if request.redirect_mode == RedirectMode::Error {
+            return Err(blocked("the request does not allow redirects"));
+        }
+        if hops >= self.max_hops {
+            return Err(NetworkError::TooManyRedirects { count: hops + 1 });
+        }
+        if !matches!(to.scheme(), "http" | "https") {
+            return Err(blocked("unsupported scheme"));
+        }
+        if from.scheme() == "https" && to.scheme() == "http" && !self.allow_downgrade {
+            return Err(blocked("HTTPS to HTTP downgrade"));
+        }
+
+        let mut next = request.clone();
+        // 303 always switches to GET; 301 and 302 do for POST, as browsers do
+        let to_get = match status.as_u16() {
+            303 => request.method != Method::Head,
+            301 | 302 => request.method == Method::Post,
+            _ => false,
+        };
+        if to_get {
+            next.method = Method::Get;
+            next.body = None;
+            next.headers.retain(|key, _| {
+                !key.eq_ignore_ascii_case("content-type")
+                    && !key.eq_ignore_ascii_case("content-length")
+            });
+        }
+        if self.strip_auth_on_cross_origin && to.origin() != from.origin() {
+            next.headers.retain(|key, _| {
+                !CREDENTIAL_HEADERS
+                    .iter()
+                    .any(|name| key.eq_ignore_ascii_case(name))
+            });
+        }
+        next.url = to;
+
+        Ok(next)
+    }
+}
+
+/// Headers carrying credentials, removed on cross-origin redirects.
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie"];
+
+/// Check whether a status is a redirect with a `Location` to follow.
+pub(crate) fn is_followable(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 301 | 302 | 303 | 307 | 308)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use url::Url;
+
+    fn request(method: Method, url: &str) -> NetworkRequest {
+        NetworkRequest::new(method, Url::parse(url).unwrap())
+            .header("Authorization", "Bearer secret")
+            .header("Proxy-Authorization", "Basic cHJveHk=")
+            .header("Cookie", "session=abc")
+            .header("Content-Type", "text/plain")
+            .body(b"data".to_vec())
+    }
+
+    #[test]
+    fn test_follow_rewrites_method_and_strips_auth() {
+        let policy = RedirectPolicy::default();
+        let post = request(Method::Post, "https://a.example/form");
+
+        let next = policy
+            .follow(&post, StatusCode::new(303), "/done", 0)
+            .unwrap();
+        assert_eq!(next.url.as_str(), "https://a.example/done");
+        assert_eq!(next.method, Method::Get);
+        assert!(next.body.is_none());
+        assert!(!next.headers.contains_key("Content-Type"));
+        for name in ["Authorization", "Proxy-Authorization", "Cookie"] {
+            assert!(next.headers.contains_key(name));
+        }
+
+        // 307 keeps the method and body
+        let next = policy
+            .follow(&post, StatusCode::new(307), "https://b.example/form", 0)
+            .unwrap();
+        assert_eq!(next.method, Method::Post);
+        assert_eq!(next.body.as_deref(), Some(&b"data"[..]));
+        for name in ["Authorization", "Proxy-Authorization", "Cookie"] {
+            assert!(!next.headers.contains_key(name), "{} kept", name);
+        }
+        assert!(next.headers.contains_key("Content-Type"));
+
+        let keep = policy.strip_auth_on_cross_origin(false);
+        let next = keep
+            .follow(&post, StatusCode::new(307), "https://b.example/form", 0)
+            .unwrap();
+        for name in ["Authorization", "Proxy-Authorization", "Cookie"] {
+            assert!(next.headers.contains_key(name));
+        }
+    }
+
+    #[test]
+    fn test_follow_blocks_downgrade_and_limits_hops() {
+        let policy = RedirectPolicy::new(2);
+        let get = request(Method::Get, "https://a.example/");
+        let found = StatusCode::FOUND;
+
+        let result = policy.follow(&get, found, "http://a.example/", 0);
+        assert!(matches!(result, Err(NetworkError::RedirectBlocked { .. })));
+        let allowed = policy.allow_downgrade(true);
+        assert!(allowed.follow(&get, found, "http://a.example/", 0).is_ok());
+
+        assert!(policy.follow(&get, found, "/next", 1).is_ok());
+        let result = policy.follow(&get, found, "/next", 2);
+        assert!(matches!(
+            result,
+            Err(NetworkError::TooManyRedirects { count: 3 })
+        ));
+
+        let result = policy.follow(&get, found, "file:///etc/passwd", 0);
+        assert!(matches!(result, Err(NetworkError::RedirectBlocked { .. })));
+    }
+}
@@ -35,6 +35,21 @@ impl Default for Method {
     }
 }
 
+impl Method {
+    /// Check if repeating the request has the same effect as sending it once.
+    pub fn is_idempotent(&self) -> bool {
+        matches!(
+            self,
+            Method::Get
+                | Method::Head
+                | Method::Put
+                | Method::Delete
+                | Method::Options
+                | Method::Trace
+        )
+    }
+}
+
 impl From<Method> for reqwest::Method {
     fn from(method: Method) -> Self {
         match method {
@@ -99,20 +114,25 @@ impl Default for ResourceType {
     }
 }
 
-/// Cache mode for requests.
+/// Cache mode for requests, mirroring the Fetch spec's `RequestCache`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum CacheMode {
-    /// Use standard HTTP cache semantics.
+    /// Use standard HTTP cache semantics: serve fresh entries, revalidate
+    /// stale ones.
     #[default]
     Default,
-    /// Bypass cache, always fetch from network.
+    /// Bypass the cache entirely: neither read nor update it.
     NoStore,
-    /// Only use cached response, fail if not cached.
+    /// Serve any cached entry, even a stale one; without an entry, respond
+    /// with a synthetic 504 instead of going to the network.
     OnlyIfCached,
-    /// Revalidate cached response with server.
+    /// Fetch from the network without reading the cache, then update it.
     Reload,
-    /// Force cache, only revalidate if no cache entry.
+    /// Serve any cached entry, even a stale one; fetch from the network
+    /// only on a miss.
     ForceCache,
+    /// Revalidate any cached entry with the server, even a fresh one.
+    NoCache,
 }
 
 /// Credentials mode for cross-origin requests.
@@ -127,26 +147,22 @@ pub enum CredentialsMode {
     Include,
 }
 
-/// Redirect policy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum RedirectPolicy {
-    /// Follow redirects automatically (with limit).
-    Follow {
-        /// Maximum number of redirects to follow.
-        max_redirects: u32,
-    },
-    /// Error on redirect.
+/// How a request handles redirects, mirroring the Fetch spec's
+/// `RequestRedirect`.
+///
+/// Followed redirects are subject to the client's
+/// [`crate::RedirectPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum RedirectMode {
+    /// Follow redirects automatically.
+    #[default]
+    Follow,
+    /// Fail on redirect.
     Error,
-    /// Return redirect response without following.
+    /// Return the redirect response without following it.
     Manual,
 }
 
-impl Default for RedirectPolicy {
-    fn default() -> Self {
-        Self::Follow { max_redirects: 10 }
-    }
-}
-
 /// A network request.
 #[derive(Debug, Clone)]
 pub struct NetworkRequest {
@@ -158,16 +174,19 @@ pub struct NetworkRequest {
     pub headers: HeaderMap,
     /// Request body (if any).
     pub body: Option<Vec<u8>>,
-    /// Request timeout.
-    pub timeout: Duration,
+    /// Overall request timeout, overriding the client's request timeout.
+    pub timeout: Option<Duration>,
+    /// Maximum wait for each chunk of the response body, overriding the
+    /// client's read timeout.
+    pub read_timeout: Option<Duration>,
     /// Resource type hint.
     pub resource_type: ResourceType,
     /// Cache mode.
     pub cache_mode: CacheMode,
     /// Credentials mode.
     pub credentials_mode: CredentialsMode,
-    /// Redirect policy.
-    pub redirect_policy: RedirectPolicy,
+    /// Redirect mode.
+    pub redirect_mode: RedirectMode,
     /// Priority (0-255, higher = more important).
     pub priority: u8,
     /// Custom metadata attached to the request.
@@ -175,7 +194,7 @@ pub struct NetworkRequest {
 }
 
 impl NetworkRequest {
-    /// Default timeout duration.
+    /// Default overall request timeout for clients.
     pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
     /// Create a new GET request.
@@ -188,6 +207,11 @@ impl NetworkRequest {
         Self::new(Method::Post, url)
     }
 
+    /// Start building a POST request with a `multipart/form-data` body.
+    pub fn multipart(url: Url) -> MultipartBuilder {
+        MultipartBuilder::new(url)
+    }
+
     /// Create a new request with the given method and URL.
     pub fn new(method: Method, url: Url) -> Self {
         Self {
@@ -195,11 +219,12 @@ impl NetworkRequest {
             method,
             headers: HeaderMap::new(),
             body: None,
-            timeout: Self::DEFAULT_TIMEOUT,
+            timeout: None,
+            read_timeout: None,
             resource_type: ResourceType::Other,
             cache_mode: CacheMode::Default,
             credentials_mode: CredentialsMode::SameOrigin,
-            redirect_policy: RedirectPolicy::default(),
+            redirect_mode: RedirectMode::default(),
             priority: 128,
             metadata: HashMap::new(),
         }
@@ -232,9 +257,15 @@ impl NetworkRequest {
         Ok(self)
     }
 
-    /// Set the timeout.
+    /// Set the overall timeout for this request.
     pub fn timeout(mut self, timeout: Duration) -> Self {
-        self.timeout = timeout;
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the read timeout for this request.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
         self
     }
 
@@ -256,9 +287,9 @@ impl NetworkRequest {
         self
     }
 
-    /// Set the redirect policy.
-    pub fn redirect_policy(mut self, redirect_policy: RedirectPolicy) -> Self {
-        self.redirect_policy = redirect_policy;
+    /// Set the redirect mode.
+    pub fn redirect_mode(mut self, redirect_mode: RedirectMode) -> Self {
+        self.redirect_mode = redirect_mode;
         self
     }
 
@@ -285,6 +316,95 @@ impl NetworkRequest {
     }
 }
 
+/// Builder for a `multipart/form-data` request, such as a file upload.
+///
+/// Created with [`NetworkRequest::multipart`]. Parts are serialized in the
+/// order they are added.
+#[derive(Debug, Clone)]
+pub struct MultipartBuilder {
+    request: NetworkRequest,
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl MultipartBuilder {
+    fn new(url: Url) -> Self {
+        Self {
+            request: NetworkRequest::post(url),
+            boundary: format!("----CortenFormBoundary{}", uuid::Uuid::new_v4().simple()),
+            body: Vec::new(),
+        }
+    }
+
+    /// Get the boundary separating the parts.
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Add a text field.
+    pub fn text_field(mut self, name: &str, value: impl AsRef<str>) -> Self {
+        self.part_headers(name, None, None);
+        self.body.extend_from_slice(value.as_ref().as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Add a file field with the given file name and MIME type.
+    ///
+    /// A MIME type containing control characters such as CR or LF, which
+    /// could inject part headers, is replaced by `application/octet-stream`.
+    pub fn file_field(mut self, name: &str, filename: &str, mime: &str, bytes: &[u8]) -> Self {
+        self.part_headers(name, Some(filename), Some(mime));
+        self.body.extend_from_slice(bytes);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Finish the body and return the request, with its `Content-Type` set.
+    pub fn build(mut self) -> NetworkRequest {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.request.headers.insert(
+            "Content-Type".to_string(),
+            format!("multipart/form-data; boundary={}", self.boundary),
+        );
+        self.request.body = Some(self.body);
+        self.request
+    }
+
+    /// Write the boundary line and headers that start a part.
+    fn part_headers(&mut self, name: &str, filename: Option<&str>, mime: Option<&str>) {
+        let mut headers = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            escape_field(name)
+        );
+        if let Some(filename) = filename {
+            headers.push_str(&format!("; filename=\"{}\"", escape_field(filename)));
+        }
+        headers.push_str("\r\n");
+        if let Some(mime) = mime {
+            let mime = if mime.chars().any(char::is_control) {
+                "application/octet-stream"
+            } else {
+                mime
+            };
+            headers.push_str(&format!("Content-Type: {}\r\n", mime));
+        }
+        headers.push_str("\r\n");
+        self.body.extend_from_slice(headers.as_bytes());
+    }
+}
+
+/// Percent-encode the characters that would break a quoted
+/// `Content-Disposition` parameter, as browsers do.
+fn escape_field(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,6 +441,7 @@ mod tests {
         let request = NetworkRequest::get(url)
             .header("Accept", "application/json")
             .timeout(Duration::from_secs(10))
+            .read_timeout(Duration::from_secs(2))
             .resource_type(ResourceType::Xhr)
             .cache_mode(CacheMode::NoStore)
             .priority(255);
@@ -329,7 +450,8 @@ mod tests {
             request.headers.get("Accept"),
             Some(&"application/json".to_string())
         );
-        assert_eq!(request.timeout, Duration::from_secs(10));
+        assert_eq!(request.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(request.read_timeout, Some(Duration::from_secs(2)));
         assert_eq!(request.resource_type, ResourceType::Xhr);
         assert_eq!(request.cache_mode, CacheMode::NoStore);
         assert_eq!(request.priority, 255);
@@ -343,4 +465,54 @@ mod tests {
         assert!(NetworkRequest::get(https_url).is_secure());
         assert!(!NetworkRequest::get(http_url).is_secure());
     }
+
+    #[test]
+    fn test_multipart_two_part_body() {
+        let url = Url::parse("https://example.com/upload").unwrap();
+        let builder = NetworkRequest::multipart(url)
+            .text_field("title", "Holiday")
+            .file_field("photo", "beach.png", "image/png", &[0x89, b'P', b'N', b'G']);
+        let boundary = builder.boundary().to_string();
+        let request = builder.build();
+
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.headers.get("Content-Type"),
+            Some(&format!("multipart/form-data; boundary={}", boundary))
+        );
+
+        let mut expected = format!(
+            "--{b}\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nHoliday\r\n\
+             --{b}\r\nContent-Disposition: form-data; name=\"photo\"; filename=\"beach.png\"\r\n\
+             Content-Type: image/png\r\n\r\n",
+            b = boundary
+        )
+        .into_bytes();
+        expected.extend_from_slice(&[0x89, b'P', b'N', b'G']);
+        expected.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+        assert_eq!(request.body.unwrap(), expected);
+    }
+
+    #[test]
+    fn test_multipart_escapes_field_names() {
+        let url = Url::parse("https://example.com/upload").unwrap();
+        let request = NetworkRequest::multipart(url)
+            .file_field("doc", "my \"report\"\r\n.txt", "text/plain", b"hi")
+            .build();
+        let body = String::from_utf8(request.body.unwrap()).unwrap();
+
+        assert!(body.contains("filename=\"my %22report%22%0D%0A.txt\""));
+    }
+
+    #[test]
+    fn test_multipart_rejects_header_injection_in_mime_type() {
+        let url = Url::parse("https://example.com/upload").unwrap();
+        let request = NetworkRequest::multipart(url)
+            .file_field("doc", "a.txt", "text/plain\r\nX-Injected: yes", b"hi")
+            .build();
+        let body = String::from_utf8(request.body.unwrap()).unwrap();
+
+        assert!(body.contains("Content-Type: application/octet-stream\r\n\r\nhi"));
+        assert!(!body.contains("X-Injected"));
+    }
 }
@@ -73,11 +73,12 @@ impl<C: NetworkClient + 'static> ResourceLoader<C> {
         self.validate_response(&response, resource_type)?;
 
         let from_cache = response.cache_status.is_hit();
+        let redirect_chain = response.redirect_chain.clone();
 
         Ok(ResourceLoadResult {
             response,
             resource_type,
-            redirect_chain: Vec::new(), // TODO: Track redirects
+            redirect_chain,
             from_cache,
         })
     }
@@ -1,8 +1,16 @@
 //! Network response types.
 
+use crate::error::{NetworkError, NetworkResult};
+use crate::pool_stats::ActiveRequest;
 use crate::request::HeaderMap;
+use crate::throttle::Pacer;
+use bytes::Bytes;
+use futures_util::stream::{BoxStream, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::{Context, Poll};
 use std::time::Duration;
+use tokio::sync::OwnedSemaphorePermit;
 use url::Url;
 
 /// HTTP status code wrapper.
@@ -64,6 +72,7 @@ impl StatusCode {
     pub const INTERNAL_SERVER_ERROR: StatusCode = StatusCode(500);
     pub const BAD_GATEWAY: StatusCode = StatusCode(502);
     pub const SERVICE_UNAVAILABLE: StatusCode = StatusCode(503);
+    pub const GATEWAY_TIMEOUT: StatusCode = StatusCode(504);
 }
 
 impl From<u16> for StatusCode {
@@ -126,6 +135,9 @@ pub struct NetworkResponse {
     pub content_type: Option<String>,
     /// Content length from headers (convenience).
     pub content_length: Option<usize>,
+    /// URLs redirected from, in order, before reaching [`Self::url`].
+    #[serde(default, with = "url_list_serde")]
+    pub redirect_chain: Vec<Url>,
 }
 
 // Helper modules for serializing Duration and Url
@@ -169,6 +181,29 @@ mod url_serde {
     }
 }
 
+mod url_list_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use url::Url;
+
+    pub fn serialize<S>(urls: &[Url], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let urls: Vec<&str> = urls.iter().map(Url::as_str).collect();
+        urls.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Url>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| Url::parse(s).map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
+
 impl NetworkResponse {
     /// Create a new response.
     pub fn new(status: StatusCode, url: Url) -> Self {
@@ -181,6 +216,7 @@ impl NetworkResponse {
             cache_status: CacheStatus::Miss,
             content_type: None,
             content_length: None,
+            redirect_chain: Vec::new(),
         }
     }
 
@@ -213,6 +249,12 @@ impl NetworkResponse {
         self
     }
 
+    /// Set the URLs redirected from.
+    pub fn redirect_chain(mut self, redirect_chain: Vec<Url>) -> Self {
+        self.redirect_chain = redirect_chain;
+        self
+    }
+
     /// Check if the response indicates success.
     pub fn is_success(&self) -> bool {
         self.status.is_success()
@@ -229,8 +271,35 @@ impl NetworkResponse {
     }
 
     /// Parse the body as JSON.
-    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
-        serde_json::from_slice(&self.body)
+    ///
+    /// Fails with [`NetworkError::UnexpectedContentType`] if the response
+    /// declares a non-JSON `Content-Type`; use [`Self::json_lenient`] to
+    /// skip that check. Parse errors include the text around the problem.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> NetworkResult<T> {
+        if let Some(content_type) = &self.content_type {
+            if !is_json_content_type(content_type) {
+                return Err(NetworkError::UnexpectedContentType {
+                    expected: "JSON".to_string(),
+                    actual: content_type.clone(),
+                });
+            }
+        }
+        self.json_lenient()
+    }
+
+    /// Parse the body as JSON regardless of the `Content-Type`.
+    pub fn json_lenient<T: serde::de::DeserializeOwned>(&self) -> NetworkResult<T> {
+        serde_json::from_slice(&self.body).map_err(|e| NetworkError::InvalidJson {
+            message: e
+                .to_string()
+                .split(" at line ")
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            line: e.line(),
+            column: e.column(),
+            snippet: json_error_snippet(&self.body, e.line(), e.column()),
+        })
     }
 
     /// Get a header value.
@@ -284,6 +353,210 @@ impl NetworkResponse {
     }
 }
 
+/// Check for `application/json`, `text/json` or a `+json` media type.
+fn is_json_content_type(content_type: &str) -> bool {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    essence == "application/json" || essence == "text/json" || essence.ends_with("+json")
+}
+
+/// Up to 20 bytes of body text on either side of a parse error position.
+fn json_error_snippet(body: &[u8], line: usize, column: usize) -> String {
+    const CONTEXT: usize = 20;
+
+    let line_start: usize = body
+        .split_inclusive(|&b| b == b'\n')
+        .take(line.saturating_sub(1))
+        .map(<[u8]>::len)
+        .sum();
+    let offset = (line_start + column.saturating_sub(1)).min(body.len());
+    let start = offset.saturating_sub(CONTEXT);
+    let end = (offset + CONTEXT).min(body.len());
+
+    String::from_utf8_lossy(&body[start..end])
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A network response whose body is read incrementally.
+///
+/// Yields body chunks as they arrive. The stream ends with an error if the
+/// body exceeds the client's maximum response size or ends before the
+/// declared `Content-Length`.
+pub struct ResponseStream {
+    /// HTTP status code.
+    pub status: StatusCode,
+    /// Response headers.
+    pub headers: HeaderMap,
+    /// Final URL (after redirects).
+    pub url: Url,
+    /// Declared length of a non-chunked, undecoded body.
+    pub content_length: Option<u64>,
+    inner: BoxStream<'static, NetworkResult<Bytes>>,
+    received: u64,
+    max_size: usize,
+    finished: bool,
+    /// Per-host concurrency slot, held until the body is dropped
+    _permit: Option<OwnedSemaphorePermit>,
+    /// Pool tracking for the request, ended when the body is dropped
+    active: Option<ActiveRequest>,
+}
+
+impl std::fmt::Debug for ResponseStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseStream")
+            .field("status", &self.status)
+            .field("url", &self.url)
+            .field("content_length", &self.content_length)
+            .field("received", &self.received)
+            .finish()
+    }
+}
+
+impl ResponseStream {
+    /// Stream the body of a reqwest response.
+    pub(crate) fn from_reqwest(
+        response: reqwest::Response,
+        headers: HeaderMap,
+        max_size: usize,
+        read_timeout: Option<Duration>,
+        pacer: Option<Pacer>,
+        permit: Option<OwnedSemaphorePermit>,
+        active: Option<ActiveRequest>,
+    ) -> Self {
+        let status = StatusCode::from(response.status());
+        let url = response.url().clone();
+        let content_length = response.content_length();
+        let inner =
+            futures_util::stream::unfold(Some((response, pacer)), move |state| async move {
+                let (mut response, mut pacer) = state?;
+                match read_chunk(&mut response, read_timeout).await {
+                    Ok(Some(chunk)) => {
+                        if let Some(pacer) = &mut pacer {
+                            pacer.pace(chunk.len()).await;
+                        }
+                        Some((Ok(chunk), Some((response, pacer))))
+                    }
+                    Ok(None) => None,
+                    Err(e) => Some((Err(e), None)),
+                }
+            });
+
+        Self {
+            status,
+            url,
+            content_length,
+            headers,
+            inner: inner.boxed(),
+            received: 0,
+            max_size,
+            finished: false,
+            _permit: permit,
+            active,
+        }
+    }
+
+    /// Stream an already-buffered response as a single chunk.
+    pub(crate) fn from_response(response: NetworkResponse) -> Self {
+        let body = Bytes::from(response.body);
+        Self {
+            status: response.status,
+            url: response.url,
+            content_length: Some(body.len() as u64),
+            headers: response.headers,
+            max_size: body.len(),
+            inner: futures_util::stream::iter([Ok(body)]).boxed(),
+            received: 0,
+            finished: false,
+            _permit: None,
+            active: None,
+        }
+    }
+
+    /// Number of body bytes received so far.
+    pub fn bytes_received(&self) -> u64 {
+        self.received
+    }
+
+    /// Error for a body that ended short of its declared length, if any.
+    fn incomplete_body(&self) -> Option<NetworkError> {
+        match self.content_length {
+            Some(expected) if self.received < expected => Some(NetworkError::IncompleteBody {
+                expected,
+                received: self.received,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Read the next body chunk, failing if none arrives within `read_timeout`.
+pub(crate) async fn read_chunk(
+    response: &mut reqwest::Response,
+    read_timeout: Option<Duration>,
+) -> NetworkResult<Option<Bytes>> {
+    let Some(limit) = read_timeout else {
+        return Ok(response.chunk().await?);
+    };
+    match tokio::time::timeout(limit, response.chunk()).await {
+        Ok(chunk) => Ok(chunk?),
+        Err(_) => Err(NetworkError::timeout(
+            response.url(),
+            limit.as_millis() as u64,
+        )),
+    }
+}
+
+impl Stream for ResponseStream {
+    type Item = NetworkResult<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        let item = match self.inner.poll_next_unpin(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(Some(Ok(chunk))) => {
+                self.received += chunk.len() as u64;
+                if self.received > self.max_size as u64 {
+                    self.finished = true;
+                    Some(Err(NetworkError::ResponseTooLarge {
+                        size: self.received as usize,
+                        max_size: self.max_size,
+                    }))
+                } else {
+                    Some(Ok(chunk))
+                }
+            }
+            Poll::Ready(Some(Err(e))) => {
+                self.finished = true;
+                if matches!(e, NetworkError::Timeout { .. }) {
+                    Some(Err(e))
+                } else {
+                    Some(Err(self.incomplete_body().unwrap_or(e)))
+                }
+            }
+            Poll::Ready(None) => {
+                self.finished = true;
+                let incomplete = self.incomplete_body();
+                if incomplete.is_none() {
+                    if let Some(active) = &mut self.active {
+                        active.complete();
+                    }
+                }
+                incomplete.map(Err)
+            }
+        };
+        Poll::Ready(item)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,4 +628,78 @@ mod tests {
         let parsed: serde_json::Value = response.json().unwrap();
         assert_eq!(parsed["key"], "value");
     }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Account {
+        name: String,
+        age: u32,
+    }
+
+    fn json_response(content_type: &str, body: &str) -> NetworkResponse {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type".to_string(), content_type.to_string());
+        NetworkResponse::new(StatusCode::OK, Url::parse("https://example.com").unwrap())
+            .headers(headers)
+            .body(body.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_json_into_struct() {
+        let response = json_response(
+            "application/json; charset=utf-8",
+            r#"{"name": "Ada", "age": 36}"#,
+        );
+        let account: Account = response.json().unwrap();
+        assert_eq!(
+            account,
+            Account {
+                name: "Ada".to_string(),
+                age: 36
+            }
+        );
+
+        let response = json_response("application/problem+json", r#"{"name": "Bo", "age": 1}"#);
+        assert!(response.json::<Account>().is_ok());
+    }
+
+    #[test]
+    fn test_json_checks_content_type_unless_lenient() {
+        let response = json_response("text/html", r#"{"name": "Ada", "age": 36}"#);
+
+        assert!(matches!(
+            response.json::<Account>(),
+            Err(NetworkError::UnexpectedContentType { actual, .. }) if actual == "text/html"
+        ));
+        assert!(response.json_lenient::<Account>().is_ok());
+    }
+
+    #[test]
+    fn test_json_malformed_error_has_snippet() {
+        let response = json_response(
+            "application/json",
+            "{\n  \"name\": \"Ada\"\n  \"age\": 36\n}",
+        );
+
+        let err = response.json::<Account>().unwrap_err();
+        let NetworkError::InvalidJson {
+            message,
+            line,
+            column,
+            snippet,
+        } = &err
+        else {
+            panic!("Expected InvalidJson, got {:?}", err);
+        };
+        assert_eq!((*line, *column), (3, 3));
+        assert_eq!(message, "expected `,` or `}`");
+        assert!(snippet.contains("\"Ada\" \"age\""), "snippet: {}", snippet);
+        assert!(err.to_string().contains("line 3, column 3"));
+
+        // Type mismatches are reported the same way
+        let response = json_response("application/json", r#"{"name": "Ada", "age": "old"}"#);
+        assert!(matches!(
+            response.json::<Account>(),
+            Err(NetworkError::InvalidJson { message, .. }) if message.contains("invalid type")
+        ));
+    }
 }
@@ -0,0 +1,246 @@
+//! Automatic request retries with exponential backoff.
+//!
+//! A [`RetryPolicy`] configured on [`crate::HttpClientBuilder`] makes the
+//! client re-send requests that fail with a transient error or a retryable
+//! status. Only idempotent methods are retried unless the policy opts in to
+//! retrying everything.
+
+use crate::error::NetworkError;
+use crate::request::Method;
+use crate::response::{NetworkResponse, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// A condition under which a request is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryOn {
+    /// The response has this status code.
+    Status(StatusCode),
+    /// The request timed out.
+    Timeout,
+    /// The connection could not be established.
+    ConnectionFailed,
+    /// The host name could not be resolved.
+    DnsError,
+}
+
+/// Retry behavior for a client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each later retry.
+    pub base_delay: Duration,
+    /// Upper bound on any single delay, including `Retry-After` waits.
+    pub max_delay: Duration,
+    /// Conditions that trigger a retry.
+    pub retry_on: Vec<RetryOn>,
+    /// Retry non-idempotent methods (such as POST) too.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            retry_on: vec![
+                RetryOn::Status(StatusCode::new(429)),
+                RetryOn::Status(StatusCode::new(502)),
+                RetryOn::Status(StatusCode::new(503)),
+                RetryOn::Status(StatusCode::new(504)),
+                RetryOn::Timeout,
+                RetryOn::ConnectionFailed,
+                RetryOn::DnsError,
+            ],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with default conditions and delays.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Set the base and maximum delays.
+    pub fn delays(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Replace the conditions that trigger a retry.
+    pub fn retry_on(mut self, retry_on: Vec<RetryOn>) -> Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    /// Allow retrying non-idempotent methods.
+    pub fn retry_non_idempotent(mut self, enabled: bool) -> Self {
+        self.retry_non_idempotent = enabled;
+        self
+    }
+
+    /// Check whether requests with this method may be retried.
+    pub fn allows_method(&self, method: Method) -> bool {
+        self.retry_non_idempotent || method.is_idempotent()
+    }
+
+    /// Check whether a response status should be retried.
+    pub fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retry_on.contains(&RetryOn::Status(status))
+    }
+
+    /// Check whether an error should be retried.
+    pub fn should_retry_error(&self, error: &NetworkError) -> bool {
+        let condition = match error {
+            NetworkError::Timeout { .. } => RetryOn::Timeout,
+            NetworkError::ConnectionFailed { .. } => RetryOn::ConnectionFailed,
+            NetworkError::DnsError { .. } => RetryOn::DnsError,
+            _ => return false,
+        };
+        self.retry_on.contains(&condition)
+    }
+
+    /// Exponential backoff before retry number `retry` (0-based), capped at
+    /// `max_delay`, without jitter.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry);
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Delay before retry number `retry` after receiving `response`.
+    ///
+    /// A `Retry-After` header on a 429 or 503 response is honored (up to
+    /// `max_delay`); otherwise the backoff is jittered to between half and
+    /// all of its value so clients don't retry in lockstep.
+    pub fn delay_for_response(&self, retry: u32, response: &NetworkResponse) -> Duration {
+        let status = response.status.as_u16();
+        if status == 429 || status == 503 {
+            if let Some(delay) = response
+                .header("retry-after")
+                .and_then(|v| parse_retry_after(v))
+            {
+                return delay.min(self.max_delay);
+            }
+        }
+        self.jittered_backoff(retry)
+    }
+
+    /// Backoff before retry number `retry` with jitter applied.
+    pub fn jittered_backoff(&self, retry: u32) -> Duration {
+        self.backoff(retry).mul_f64(0.5 + jitter_fraction() / 2.0)
+    }
+}
+
+/// Parse a `Retry-After` value given in seconds or as an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// A pseudo-random fraction in `[0, 1)`.
+fn jitter_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use url::Url;
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let policy =
+            RetryPolicy::new(5).delays(Duration::from_millis(100), Duration::from_millis(500));
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+
+        for retry in 0..4 {
+            let delay = policy.jittered_backoff(retry);
+            assert!(delay >= policy.backoff(retry) / 2);
+            assert!(delay <= policy.backoff(retry));
+        }
+    }
+
+    #[test]
+    fn test_only_idempotent_methods_by_default() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows_method(Method::Get));
+        assert!(policy.allows_method(Method::Head));
+        assert!(policy.allows_method(Method::Put));
+        assert!(policy.allows_method(Method::Delete));
+        assert!(!policy.allows_method(Method::Post));
+        assert!(!policy.allows_method(Method::Patch));
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.allows_method(Method::Post));
+    }
+
+    #[test]
+    fn test_retry_conditions() {
+        let policy = RetryPolicy::default().retry_on(vec![
+            RetryOn::Status(StatusCode::new(503)),
+            RetryOn::Timeout,
+        ]);
+
+        assert!(policy.should_retry_status(StatusCode::new(503)));
+        assert!(!policy.should_retry_status(StatusCode::new(500)));
+        assert!(policy.should_retry_error(&NetworkError::Timeout {
+            url: String::new(),
+            timeout_ms: 0
+        }));
+        assert!(!policy.should_retry_error(&NetworkError::DnsError {
+            host: "example.com".to_string()
+        }));
+        assert!(!policy.should_retry_error(&NetworkError::InvalidUrl(String::new())));
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let policy = RetryPolicy::default();
+        let url = Url::parse("https://example.com").unwrap();
+        let with_header = |status: u16, value: &str| {
+            let mut headers = crate::request::HeaderMap::new();
+            headers.insert("Retry-After".to_string(), value.to_string());
+            NetworkResponse::new(StatusCode::new(status), url.clone()).headers(headers)
+        };
+
+        assert_eq!(
+            policy.delay_for_response(0, &with_header(429, "2")),
+            Duration::from_secs(2)
+        );
+        // Capped at max_delay
+        assert_eq!(
+            policy.delay_for_response(0, &with_header(503, "3600")),
+            policy.max_delay
+        );
+        // A date in the past means retry immediately
+        assert_eq!(
+            policy.delay_for_response(0, &with_header(503, "Wed, 21 Oct 2015 07:28:00 GMT")),
+            Duration::ZERO
+        );
+        // Only honored for 429 and 503
+        assert!(policy.delay_for_response(0, &with_header(502, "60")) <= policy.backoff(0));
+    }
+}
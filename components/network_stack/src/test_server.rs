@@ -0,0 +1,62 @@
+//! Minimal HTTP/1.1 server for tests.
+//!
+//! Accepts connections on a local port, reads each request head, and answers
+//! with whatever the test's routing closure returns before closing the
+//! connection.
+
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use url::Url;
+
+/// Request heads received by a test server, lowercased, in arrival order.
+pub(crate) type RecordedRequests = Arc<Mutex<Vec<String>>>;
+
+/// Serve every connection with `route(head)`, recording each request's head.
+///
+/// `head` is the lowercased request line and headers. Returns the server's
+/// base URL.
+pub(crate) async fn serve_with<F, R>(route: F) -> (Url, RecordedRequests)
+where
+    F: Fn(&str) -> R + Send + 'static,
+    R: AsRef<[u8]> + Send,
+{
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let head = String::from_utf8_lossy(&request).to_ascii_lowercase();
+            let response = route(&head);
+            recorded.lock().unwrap().push(head);
+            let _ = socket.write_all(response.as_ref()).await;
+            let _ = socket.shutdown().await;
+        }
+    });
+
+    (Url::parse(&format!("http://{}/", addr)).unwrap(), requests)
+}
+
+/// Serve `response` to every connection, recording each request's head.
+pub(crate) async fn serve_recording(
+    response: impl AsRef<[u8]> + Send + 'static,
+) -> (Url, RecordedRequests) {
+    serve_with(move |_| response.as_ref().to_vec()).await
+}
+
+/// Get the path from a request head.
+pub(crate) fn request_path(head: &str) -> &str {
+    head.split(' ').nth(1).unwrap_or("/")
+}
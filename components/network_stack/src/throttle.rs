@@ -0,0 +1,105 @@
+//! Bandwidth throttling for testing slow connections.
+//!
+//! A [`ThrottleConfig`] on [`crate::HttpClientBuilder::throttle`] makes the
+//! client behave like a slow link: each request waits for the configured
+//! latency plus the time its body would take to upload, and response bodies
+//! are delivered no faster than the download rate. Meant for development and
+//! tests, not for production traffic shaping.
+
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Simulated link speed and latency.
+///
+/// A rate of 0 means that direction is unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThrottleConfig {
+    /// Download rate in bytes per second.
+    pub down_bytes_per_sec: u64,
+    /// Upload rate in bytes per second.
+    pub up_bytes_per_sec: u64,
+    /// Delay added before each request is sent, in milliseconds.
+    pub latency_ms: u64,
+}
+
+impl ThrottleConfig {
+    /// Create a throttle with the given rates and latency.
+    pub fn new(down_bytes_per_sec: u64, up_bytes_per_sec: u64, latency_ms: u64) -> Self {
+        Self {
+            down_bytes_per_sec,
+            up_bytes_per_sec,
+            latency_ms,
+        }
+    }
+
+    /// Get the delay before sending a request with a body of `body_len`
+    /// bytes: the latency plus the simulated upload time.
+    pub fn send_delay(&self, body_len: usize) -> Duration {
+        Duration::from_millis(self.latency_ms)
+            + transfer_time(body_len as u64, self.up_bytes_per_sec)
+    }
+
+    /// Create a pacer for a response body, if downloads are limited.
+    pub(crate) fn download_pacer(&self) -> Option<Pacer> {
+        (self.down_bytes_per_sec > 0).then(|| Pacer::new(self.down_bytes_per_sec))
+    }
+}
+
+/// Time to transfer `bytes` at `rate` bytes per second (zero if unlimited).
+fn transfer_time(bytes: u64, rate: u64) -> Duration {
+    if rate == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(bytes as f64 / rate as f64)
+}
+
+/// Holds back body chunks so the body arrives no faster than a given rate.
+#[derive(Debug)]
+pub(crate) struct Pacer {
+    rate: u64,
+    start: Instant,
+    bytes: u64,
+}
+
+impl Pacer {
+    fn new(rate: u64) -> Self {
+        Self {
+            rate,
+            start: Instant::now(),
+            bytes: 0,
+        }
+    }
+
+    /// Record `len` more bytes and wait until the rate allows them.
+    pub(crate) async fn pace(&mut self, len: usize) {
+        self.bytes += len as u64;
+        tokio::time::sleep_until(self.start + transfer_time(self.bytes, self.rate)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_delay() {
+        let throttle = ThrottleConfig::new(0, 1000, 50);
+        assert_eq!(throttle.send_delay(0), Duration::from_millis(50));
+        assert_eq!(throttle.send_delay(500), Duration::from_millis(550));
+
+        let unlimited = ThrottleConfig::default();
+        assert_eq!(unlimited.send_delay(1_000_000), Duration::ZERO);
+        assert!(unlimited.download_pacer().is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pacer_holds_to_rate() {
+        let mut pacer = ThrottleConfig::new(1000, 0, 0).download_pacer().unwrap();
+        let start = Instant::now();
+
+        pacer.pace(250).await;
+        assert_eq!(start.elapsed(), Duration::from_millis(250));
+        pacer.pace(750).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+}
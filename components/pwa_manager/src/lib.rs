@@ -5,9 +5,9 @@
 //!
 //! - Web App Manifest parsing (manifest.json)
 //! - PWA installation and uninstallation
-//! - Installed PWA tracking and persistence
+//! - Installed PWA tracking and persistence (JSON registry on disk)
 //! - Standalone app window management
-//! - Service worker registration interface (stub)
+//! - Service worker registration lifecycle (script execution is stubbed)
 //! - Install prompt handling
 //!
 //! # Example
@@ -26,15 +26,21 @@
 //! ```
 
 use chrono::{DateTime, Utc};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Reverse;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::RwLock;
 use url::Url;
 use uuid::Uuid;
 
+/// File name of the installed-PWA registry within the install directory
+pub const REGISTRY_FILE: &str = "registry.json";
+
 /// Unique identifier for an installed PWA
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PwaId(Uuid);
@@ -63,6 +69,29 @@ impl std::fmt::Display for PwaId {
     }
 }
 
+/// Unique identifier for a service worker registration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ServiceWorkerId(Uuid);
+
+impl ServiceWorkerId {
+    /// Create a new random service worker ID
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for ServiceWorkerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for ServiceWorkerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// PWA-related errors
 #[derive(Debug, Error)]
 pub enum PwaError {
@@ -142,6 +171,34 @@ impl PwaDisplayMode {
     pub fn is_standalone_window(&self) -> bool {
         matches!(self, Self::Standalone | Self::Fullscreen | Self::MinimalUi)
     }
+
+    /// The mode to try when this one is unsupported, per the manifest spec's
+    /// fallback chain (fullscreen → standalone → minimal-ui → browser)
+    pub fn fallback(&self) -> Option<Self> {
+        match self {
+            Self::Fullscreen => Some(Self::Standalone),
+            Self::Standalone => Some(Self::MinimalUi),
+            Self::MinimalUi => Some(Self::Browser),
+            Self::Browser => None,
+        }
+    }
+
+    /// Resolve the mode to use given the modes the platform supports
+    ///
+    /// Walks the fallback chain from this mode and returns the first
+    /// supported one. `Browser` is always available as the last resort.
+    pub fn effective_mode(&self, supported: &[PwaDisplayMode]) -> PwaDisplayMode {
+        let mut mode = *self;
+        loop {
+            if supported.contains(&mode) {
+                return mode;
+            }
+            match mode.fallback() {
+                Some(next) => mode = next,
+                None => return Self::Browser,
+            }
+        }
+    }
 }
 
 /// Icon definition in a PWA manifest
@@ -215,6 +272,15 @@ impl PwaIcon {
     pub fn is_maskable(&self) -> bool {
         self.purpose.to_lowercase().contains("maskable")
     }
+
+    /// Check if icon can be shown as-is (purpose `any`, the default)
+    pub fn is_any_purpose(&self) -> bool {
+        self.purpose.trim().is_empty()
+            || self
+                .purpose
+                .split_whitespace()
+                .any(|purpose| purpose.eq_ignore_ascii_case("any"))
+    }
 }
 
 /// Shortcut definition for PWA
@@ -252,6 +318,207 @@ impl PwaShortcut {
     }
 }
 
+/// Schemes a PWA may handle without the `web+` prefix
+const SAFELISTED_PROTOCOLS: &[&str] = &[
+    "bitcoin",
+    "ftp",
+    "ftps",
+    "geo",
+    "im",
+    "irc",
+    "ircs",
+    "magnet",
+    "mailto",
+    "matrix",
+    "mms",
+    "news",
+    "nntp",
+    "openpgp4fpr",
+    "sftp",
+    "sip",
+    "sms",
+    "smsto",
+    "ssh",
+    "tel",
+    "urn",
+    "webcal",
+    "wtai",
+    "xmpp",
+];
+
+/// The URL standard's component percent-encode set, used to substitute
+/// links into protocol handler templates
+const COMPONENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'$')
+    .add(b'%')
+    .add(b'&')
+    .add(b'+')
+    .add(b',')
+    .add(b'/')
+    .add(b':')
+    .add(b';')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'?')
+    .add(b'@')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Protocol handler declared in the manifest
+///
+/// Routes links with a custom scheme (such as `web+coffee:`) to a URL in
+/// the app, with `%s` in the template replaced by the encoded link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolHandler {
+    /// Scheme to handle, without the trailing colon
+    pub protocol: String,
+
+    /// URL template containing a `%s` placeholder
+    pub url: String,
+}
+
+impl ProtocolHandler {
+    /// Create a new protocol handler
+    pub fn new(protocol: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            protocol: protocol.into(),
+            url: url.into(),
+        }
+    }
+
+    /// Check whether the scheme may be handled and the template has a
+    /// placeholder
+    ///
+    /// Custom schemes need a `web+` prefix followed by lowercase ASCII
+    /// letters; otherwise the scheme must be on the safelist.
+    pub fn is_valid(&self) -> bool {
+        let protocol = self.protocol.to_ascii_lowercase();
+        let scheme_ok = match protocol.strip_prefix("web+") {
+            Some(name) => !name.is_empty() && name.bytes().all(|b| b.is_ascii_lowercase()),
+            None => SAFELISTED_PROTOCOLS.contains(&protocol.as_str()),
+        };
+        scheme_ok && self.url.contains("%s")
+    }
+
+    /// Check whether this handler handles `scheme`, with or without a
+    /// trailing colon
+    pub fn handles(&self, scheme: &str) -> bool {
+        let scheme = scheme.strip_suffix(':').unwrap_or(scheme);
+        self.protocol.eq_ignore_ascii_case(scheme)
+    }
+
+    /// Expand the template with `target`, percent-encoded as a URL
+    /// component (spaces become `%20`)
+    pub fn expand(&self, target: &str) -> String {
+        let encoded = utf8_percent_encode(target, COMPONENT).to_string();
+        self.url.replacen("%s", &encoded, 1)
+    }
+}
+
+/// HTTP method a share target is invoked with
+///
+/// Serialized in uppercase; parsed case-insensitively, as manifests commonly
+/// use `"get"` and `"post"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ShareMethod {
+    /// Shared data is sent in the query string
+    #[default]
+    Get,
+    /// Shared data is sent as a form-encoded body
+    Post,
+}
+
+impl<'de> Deserialize<'de> for ShareMethod {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let method = String::deserialize(deserializer)?;
+        if method.eq_ignore_ascii_case("get") {
+            Ok(ShareMethod::Get)
+        } else if method.eq_ignore_ascii_case("post") {
+            Ok(ShareMethod::Post)
+        } else {
+            Err(serde::de::Error::unknown_variant(&method, &["GET", "POST"]))
+        }
+    }
+}
+
+/// Query or form field names the shared data is sent under
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareTargetParams {
+    /// Field for the shared title
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Field for the shared text
+    #[serde(default)]
+    pub text: Option<String>,
+
+    /// Field for the shared URL
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// Web Share Target declared in the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareTarget {
+    /// URL to navigate to with the shared data
+    pub action: String,
+
+    /// How the shared data is sent
+    #[serde(default)]
+    pub method: ShareMethod,
+
+    /// Field names for the shared data
+    #[serde(default)]
+    pub params: ShareTargetParams,
+}
+
+impl ShareTarget {
+    /// Create a GET share target with no params
+    pub fn new(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            method: ShareMethod::default(),
+            params: ShareTargetParams::default(),
+        }
+    }
+
+    /// Form-encode the shared data under the declared field names
+    ///
+    /// Values that are missing, or have no field name, are left out.
+    pub fn encode_params(
+        &self,
+        title: Option<&str>,
+        text: Option<&str>,
+        url: Option<&str>,
+    ) -> String {
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        let fields = [
+            (&self.params.title, title),
+            (&self.params.text, text),
+            (&self.params.url, url),
+        ];
+        for (name, value) in fields {
+            if let (Some(name), Some(value)) = (name, value) {
+                serializer.append_pair(name, value);
+            }
+        }
+        serializer.finish()
+    }
+}
+
 /// Screenshot definition for PWA
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PwaScreenshot {
@@ -350,6 +617,14 @@ pub struct WebAppManifest {
     /// Unique identifier for the app
     #[serde(default)]
     pub id: String,
+
+    /// Custom schemes the app handles
+    #[serde(default)]
+    pub protocol_handlers: Vec<ProtocolHandler>,
+
+    /// Where shared data is sent when the app is a share target
+    #[serde(default)]
+    pub share_target: Option<ShareTarget>,
 }
 
 /// Related application reference
@@ -389,6 +664,8 @@ impl WebAppManifest {
             related_applications: Vec::new(),
             prefer_related_applications: false,
             id: String::new(),
+            protocol_handlers: Vec::new(),
+            share_target: None,
         }
     }
 
@@ -413,6 +690,41 @@ impl WebAppManifest {
         Ok(())
     }
 
+    /// Check that `start_url` and `scope` stay on the install origin
+    ///
+    /// Both are resolved against `origin` and must share its scheme, host,
+    /// and port, so a manifest cannot install an app that launches elsewhere.
+    pub fn validate_against_origin(&self, origin: &Url) -> Result<()> {
+        for (field, value) in [("start_url", &self.start_url), ("scope", &self.scope)] {
+            let resolved = self.resolve_url(origin, value)?;
+            let same_origin = resolved.scheme() == origin.scheme()
+                && resolved.host_str() == origin.host_str()
+                && resolved.port_or_known_default() == origin.port_or_known_default();
+            if !same_origin {
+                return Err(PwaError::InvalidManifest(format!(
+                    "{} {} is not on the install origin {}",
+                    field, resolved, origin
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// SHA-256 (hex) over the manifest's canonical JSON form
+    ///
+    /// Object keys are sorted, so two manifests with the same content hash
+    /// identically regardless of how the source JSON was laid out.
+    pub fn content_hash(&self) -> String {
+        // A Value's object keys are sorted, which makes its JSON canonical
+        let canonical = serde_json::to_value(self)
+            .map(|value| value.to_string())
+            .expect("manifest always serializes to JSON");
+
+        let mut hasher = Sha256::new();
+        hasher.update(canonical.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
     /// Get the display name (prefer short_name if available)
     pub fn display_name(&self) -> &str {
         if self.short_name.is_empty() {
@@ -424,8 +736,37 @@ impl WebAppManifest {
 
     /// Get the best icon for a given size
     pub fn best_icon_for_size(&self, target_size: u32) -> Option<&PwaIcon> {
-        self.icons
-            .iter()
+        Self::closest_icon(self.icons.iter(), target_size)
+    }
+
+    /// Get the best icon for a given size, honoring icon purpose
+    ///
+    /// Icons matching the preference (`maskable`, or `any` otherwise) are
+    /// considered first; if there are none, every icon is considered.
+    pub fn best_icon_for(&self, target_size: u32, prefer_maskable: bool) -> Option<&PwaIcon> {
+        let preferred = self.icons.iter().filter(|icon| {
+            if prefer_maskable {
+                icon.is_maskable()
+            } else {
+                icon.is_any_purpose()
+            }
+        });
+        Self::closest_icon(preferred, target_size).or_else(|| self.best_icon_for_size(target_size))
+    }
+
+    /// Get the best `any`-purpose icon for a CSS pixel size on a display
+    /// with the given device pixel ratio
+    pub fn best_icon_for_dpr(&self, css_size: u32, dpr: f32) -> Option<&PwaIcon> {
+        let device_size = (css_size as f32 * dpr.max(1.0)).round() as u32;
+        self.best_icon_for(device_size, false)
+    }
+
+    /// Pick the icon with a size closest to `target_size`
+    fn closest_icon<'a>(
+        icons: impl Iterator<Item = &'a PwaIcon>,
+        target_size: u32,
+    ) -> Option<&'a PwaIcon> {
+        icons
             .filter_map(|icon| {
                 let sizes = icon.parse_sizes();
                 let best_match = sizes
@@ -485,6 +826,18 @@ pub struct InstalledPwa {
     /// Custom user notes
     #[serde(default)]
     pub user_notes: String,
+
+    /// Content hash of `manifest`, used to detect manifest updates
+    #[serde(default)]
+    pub manifest_hash: String,
+
+    /// Number of times the manifest has been updated, starting at 1
+    #[serde(default = "default_pwa_version")]
+    pub version: u32,
+}
+
+fn default_pwa_version() -> u32 {
+    1
 }
 
 impl InstalledPwa {
@@ -493,6 +846,8 @@ impl InstalledPwa {
         let now = Utc::now();
         Self {
             id: PwaId::new(),
+            manifest_hash: manifest.content_hash(),
+            version: default_pwa_version(),
             manifest,
             origin: origin.into(),
             install_date: now,
@@ -515,13 +870,118 @@ impl InstalledPwa {
         self.manifest.display_name()
     }
 
+    /// Replace the manifest, bumping the version if its content changed
+    ///
+    /// Returns whether the manifest changed.
+    pub fn update_manifest(&mut self, manifest: WebAppManifest) -> bool {
+        let hash = manifest.content_hash();
+        if hash == self.manifest_hash {
+            return false;
+        }
+
+        self.manifest = manifest;
+        self.manifest_hash = hash;
+        self.version += 1;
+        true
+    }
+
     /// Get the start URL resolved against the origin
     pub fn resolved_start_url(&self) -> Result<Url> {
+        self.resolve(&self.manifest.start_url)
+    }
+
+    /// Get the manifest scope resolved against the origin
+    pub fn resolved_scope(&self) -> Result<Url> {
+        self.resolve(&self.manifest.scope)
+    }
+
+    /// Check whether a URL falls within the app's scope
+    pub fn is_url_in_scope(&self, url: &Url) -> Result<bool> {
+        let scope = self.resolved_scope()?;
+        Ok(url.origin() == scope.origin() && url.path().starts_with(scope.path()))
+    }
+
+    /// Get the manifest shortcuts as `(name, url)` launch targets
+    ///
+    /// Shortcut URLs are resolved against the origin; shortcuts that point
+    /// outside the app's scope are skipped.
+    pub fn resolved_shortcuts(&self) -> Result<Vec<(String, Url)>> {
+        let mut shortcuts = Vec::new();
+        for shortcut in &self.manifest.shortcuts {
+            let url = self.resolve(&shortcut.url)?;
+            if self.is_url_in_scope(&url)? {
+                shortcuts.push((shortcut.name.clone(), url));
+            }
+        }
+        Ok(shortcuts)
+    }
+
+    /// Get the URL that handles `target`, a link with the given scheme
+    ///
+    /// Returns `None` if no valid handler matches the scheme or the expanded
+    /// template falls outside the app's scope.
+    pub fn resolve_protocol_handler(&self, scheme: &str, target: &str) -> Option<Url> {
+        let handler = self
+            .manifest
+            .protocol_handlers
+            .iter()
+            .find(|handler| handler.handles(scheme) && handler.is_valid())?;
+        let url = self.resolve(&handler.expand(target)).ok()?;
+        self.is_url_in_scope(&url).ok()?.then_some(url)
+    }
+
+    /// Build the URL to navigate to when data is shared to this app
+    ///
+    /// For a GET share target the shared data replaces the action's query.
+    /// For POST the action URL is returned as is, and the request body is
+    /// [`ShareTarget::encode_params`] sent as
+    /// `application/x-www-form-urlencoded`.
+    pub fn build_share_url(
+        &self,
+        title: Option<&str>,
+        text: Option<&str>,
+        url: Option<&str>,
+    ) -> Result<Url> {
+        let target =
+            self.manifest.share_target.as_ref().ok_or_else(|| {
+                PwaError::InvalidManifest("Manifest has no share_target".to_string())
+            })?;
+
+        let mut action = self.resolve(&target.action)?;
+        if !self.is_url_in_scope(&action)? {
+            return Err(PwaError::InvalidUrl(format!(
+                "Share target {} is outside the app scope",
+                action
+            )));
+        }
+
+        if target.method == ShareMethod::Get {
+            let query = target.encode_params(title, text, url);
+            action.set_query((!query.is_empty()).then_some(query.as_str()));
+        }
+        Ok(action)
+    }
+
+    /// Resolve a manifest URL against the origin
+    fn resolve(&self, relative: &str) -> Result<Url> {
         let base = Url::parse(&self.origin).map_err(|e| PwaError::InvalidUrl(e.to_string()))?;
-        self.manifest.resolve_url(&base, &self.manifest.start_url)
+        self.manifest.resolve_url(&base, relative)
     }
 }
 
+/// Ordering for [`PwaManager::list_installed_sorted`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// Most launched first
+    LaunchCount,
+    /// Most recently used first
+    LastUsed,
+    /// Most recently installed first
+    InstallDate,
+    /// Alphabetical by display name (case-insensitive)
+    Name,
+}
+
 /// PWA installation prompt configuration
 #[derive(Debug, Clone)]
 pub struct PwaInstallPrompt {
@@ -705,24 +1165,42 @@ impl PwaWindow {
     }
 }
 
-/// Service worker registration state
+/// Service worker lifecycle state
+///
+/// A registration moves `Installing` → `Installed` → `Activating` →
+/// `Activated`, and becomes `Redundant` when it is replaced or unregistered.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ServiceWorkerState {
-    /// Not registered
-    Unregistered,
-    /// Registration in progress
+    /// Script is being fetched and its install event run
     Installing,
-    /// Installed and waiting
-    Waiting,
-    /// Active and controlling
-    Active,
-    /// Failed to register
-    Failed,
+    /// Installed and waiting to activate
+    Installed,
+    /// Activate event is running
+    Activating,
+    /// Active and controlling pages in scope
+    Activated,
+    /// Replaced by a newer registration or unregistered
+    Redundant,
 }
 
-/// Service worker registration (stub interface)
+impl ServiceWorkerState {
+    /// The state that follows this one in the normal lifecycle
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            Self::Installing => Some(Self::Installed),
+            Self::Installed => Some(Self::Activating),
+            Self::Activating => Some(Self::Activated),
+            Self::Activated | Self::Redundant => None,
+        }
+    }
+}
+
+/// Service worker registration for an installed PWA
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceWorkerRegistration {
+    /// Registration identifier
+    pub id: ServiceWorkerId,
+
     /// Script URL of the service worker
     pub script_url: String,
 
@@ -732,40 +1210,49 @@ pub struct ServiceWorkerRegistration {
     /// Current state
     pub state: ServiceWorkerState,
 
-    /// Registration timestamp
-    pub registered_at: Option<DateTime<Utc>>,
+    /// When the worker was activated
+    #[serde(alias = "registered_at")]
+    pub activated_at: Option<DateTime<Utc>>,
 }
 
 impl ServiceWorkerRegistration {
-    /// Create a new unregistered service worker
+    /// Create a new registration in the `Installing` state
     pub fn new(script_url: impl Into<String>, scope: impl Into<String>) -> Self {
         Self {
+            id: ServiceWorkerId::new(),
             script_url: script_url.into(),
             scope: scope.into(),
-            state: ServiceWorkerState::Unregistered,
-            registered_at: None,
+            state: ServiceWorkerState::Installing,
+            activated_at: None,
         }
     }
 
-    /// Mark as installing
-    pub fn mark_installing(&mut self) {
-        self.state = ServiceWorkerState::Installing;
-    }
-
-    /// Mark as active
-    pub fn mark_active(&mut self) {
-        self.state = ServiceWorkerState::Active;
-        self.registered_at = Some(Utc::now());
+    /// Move to the next lifecycle state
+    ///
+    /// Fails once the worker is `Activated` or `Redundant`.
+    pub fn advance(&mut self) -> Result<ServiceWorkerState> {
+        let next = self.state.next().ok_or_else(|| {
+            PwaError::ServiceWorkerError(format!(
+                "Cannot advance service worker in state {:?}",
+                self.state
+            ))
+        })?;
+
+        self.state = next;
+        if next == ServiceWorkerState::Activated {
+            self.activated_at = Some(Utc::now());
+        }
+        Ok(next)
     }
 
-    /// Mark as failed
-    pub fn mark_failed(&mut self) {
-        self.state = ServiceWorkerState::Failed;
+    /// Mark as redundant (replaced or unregistered)
+    pub fn mark_redundant(&mut self) {
+        self.state = ServiceWorkerState::Redundant;
     }
 
     /// Check if active
     pub fn is_active(&self) -> bool {
-        self.state == ServiceWorkerState::Active
+        self.state == ServiceWorkerState::Activated
     }
 }
 
@@ -776,14 +1263,17 @@ pub struct PwaManager {
     /// Installed PWAs indexed by ID
     installed: Arc<RwLock<HashMap<PwaId, InstalledPwa>>>,
 
-    /// Service worker registrations by origin
-    service_workers: Arc<RwLock<HashMap<String, ServiceWorkerRegistration>>>,
+    /// Service worker registrations by PWA
+    service_workers: Arc<RwLock<HashMap<PwaId, ServiceWorkerRegistration>>>,
 
     /// Active PWA windows
     windows: Arc<RwLock<HashMap<Uuid, PwaWindow>>>,
 
     /// Installation directory
     install_dir: PathBuf,
+
+    /// Where the installed-PWA registry is persisted, if anywhere
+    registry_path: Option<PathBuf>,
 }
 
 impl PwaManager {
@@ -798,6 +1288,7 @@ impl PwaManager {
             service_workers: Arc::new(RwLock::new(HashMap::new())),
             windows: Arc::new(RwLock::new(HashMap::new())),
             install_dir,
+            registry_path: None,
         }
     }
 
@@ -808,27 +1299,118 @@ impl PwaManager {
             service_workers: Arc::new(RwLock::new(HashMap::new())),
             windows: Arc::new(RwLock::new(HashMap::new())),
             install_dir,
+            registry_path: None,
         }
     }
 
+    /// Load a PWA manager from a registry file written by [`persist`]
+    ///
+    /// The registry's directory becomes the install directory, and later
+    /// installs and uninstalls are persisted back to `path`. A missing or
+    /// corrupt registry starts empty rather than failing. Entries written
+    /// before manifest hashes were stored get their hash computed here.
+    ///
+    /// [`persist`]: PwaManager::persist
+    pub async fn load_from_disk(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let install_dir = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let installed: HashMap<PwaId, InstalledPwa> = match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice::<Vec<InstalledPwa>>(&bytes)
+                .map(|pwas| {
+                    pwas.into_iter()
+                        .map(|mut pwa| {
+                            if pwa.manifest_hash.is_empty() {
+                                pwa.manifest_hash = pwa.manifest.content_hash();
+                            }
+                            (pwa.id, pwa)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut manager = Self::with_install_dir(install_dir);
+        manager.installed = Arc::new(RwLock::new(installed));
+        manager.registry_path = Some(path);
+        Ok(manager)
+    }
+
     /// Get the installation directory
     pub fn install_dir(&self) -> &PathBuf {
         &self.install_dir
     }
 
+    /// Get the registry file path, if this manager persists its installs
+    pub fn registry_path(&self) -> Option<&Path> {
+        self.registry_path.as_deref()
+    }
+
+    /// Write all installed PWAs to the registry file as JSON
+    ///
+    /// Does nothing for managers without a registry path.
+    pub async fn persist(&self) -> Result<()> {
+        let Some(path) = &self.registry_path else {
+            return Ok(());
+        };
+
+        let pwas = self.list_installed().await;
+        let json = serde_json::to_vec_pretty(&pwas)
+            .map_err(|e| PwaError::SerializationError(e.to_string()))?;
+
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        tokio::fs::write(path, json).await?;
+        Ok(())
+    }
+
     /// Install a PWA from a manifest
+    ///
+    /// Only one PWA may be installed per origin: if an enabled PWA already
+    /// exists for `origin` this fails with [`PwaError::AlreadyInstalled`]. A
+    /// disabled one is replaced. Use [`reinstall`](Self::reinstall) to
+    /// deliberately overwrite an enabled install.
     pub async fn install(&self, manifest: WebAppManifest, origin: &str) -> Result<InstalledPwa> {
+        self.install_for_origin(manifest, origin, false).await
+    }
+
+    /// Install a PWA, replacing any existing install from the same origin
+    pub async fn reinstall(&self, origin: &str, manifest: WebAppManifest) -> Result<InstalledPwa> {
+        self.install_for_origin(manifest, origin, true).await
+    }
+
+    /// Validate and store a new install, replacing the origin's existing one
+    /// if it is disabled or `replace_enabled` is set
+    async fn install_for_origin(
+        &self,
+        manifest: WebAppManifest,
+        origin: &str,
+        replace_enabled: bool,
+    ) -> Result<InstalledPwa> {
         // Validate manifest
         manifest.validate()?;
+        let origin_url = Url::parse(origin).map_err(|e| PwaError::InvalidUrl(e.to_string()))?;
+        manifest.validate_against_origin(&origin_url)?;
 
-        // Check if already installed from this origin
-        let installed = self.installed.read().await;
-        for pwa in installed.values() {
-            if pwa.origin == origin {
+        // Check and insert under one lock so concurrent installs can't race
+        let mut installed = self.installed.write().await;
+        let existing = installed.values().find(|pwa| pwa.origin == origin);
+        let replaced = match existing {
+            Some(pwa) if pwa.enabled && !replace_enabled => {
                 return Err(PwaError::AlreadyInstalled(origin.to_string()));
             }
+            Some(pwa) => Some(pwa.id),
+            None => None,
+        };
+        if let Some(old_id) = replaced {
+            installed.remove(&old_id);
         }
-        drop(installed);
 
         // Create installation location
         let pwa_id = PwaId::new();
@@ -839,9 +1421,14 @@ impl PwaManager {
         let id = pwa.id;
 
         // Store the installation
-        let mut installed = self.installed.write().await;
         installed.insert(id, pwa.clone());
+        drop(installed);
+
+        if let Some(old_id) = replaced {
+            self.release_resources(old_id).await;
+        }
 
+        self.persist().await?;
         Ok(pwa)
     }
 
@@ -849,12 +1436,50 @@ impl PwaManager {
     pub async fn uninstall(&self, id: PwaId) -> Result<()> {
         let mut installed = self.installed.write().await;
         installed.remove(&id).ok_or(PwaError::NotFound(id))?;
+        drop(installed);
+
+        self.release_resources(id).await;
+        self.persist().await
+    }
+
+    /// Drop the service worker and close the windows of a removed PWA
+    async fn release_resources(&self, id: PwaId) {
+        self.service_workers.write().await.remove(&id);
 
-        // Close any windows for this PWA
         let mut windows = self.windows.write().await;
         windows.retain(|_, window| window.pwa_id != id);
+    }
 
-        Ok(())
+    /// Check whether `new_manifest` differs from the installed one
+    pub async fn check_for_update(&self, id: PwaId, new_manifest: &WebAppManifest) -> Result<bool> {
+        let installed = self.installed.read().await;
+        let pwa = installed.get(&id).ok_or(PwaError::NotFound(id))?;
+        Ok(pwa.manifest_hash != new_manifest.content_hash())
+    }
+
+    /// Update an installed PWA to a new manifest
+    ///
+    /// The manifest is validated as for `install`. Install date, launch
+    /// count, and other usage data are kept; `version` is bumped when the
+    /// manifest actually changed.
+    pub async fn apply_update(
+        &self,
+        id: PwaId,
+        new_manifest: WebAppManifest,
+    ) -> Result<InstalledPwa> {
+        new_manifest.validate()?;
+
+        let mut installed = self.installed.write().await;
+        let pwa = installed.get_mut(&id).ok_or(PwaError::NotFound(id))?;
+        let origin = Url::parse(&pwa.origin).map_err(|e| PwaError::InvalidUrl(e.to_string()))?;
+        new_manifest.validate_against_origin(&origin)?;
+
+        pwa.update_manifest(new_manifest);
+        let updated = pwa.clone();
+        drop(installed);
+
+        self.persist().await?;
+        Ok(updated)
     }
 
     /// Get an installed PWA by ID
@@ -869,10 +1494,42 @@ impl PwaManager {
         installed.values().cloned().collect()
     }
 
-    /// Find a PWA by origin
-    pub async fn find_by_origin(&self, origin: &str) -> Option<InstalledPwa> {
+    /// Get all installed PWAs in the given order
+    pub async fn list_installed_sorted(&self, by: SortKey) -> Vec<InstalledPwa> {
+        let mut pwas = self.list_installed().await;
+        match by {
+            SortKey::LaunchCount => pwas.sort_by_key(|pwa| Reverse(pwa.launch_count)),
+            SortKey::LastUsed => pwas.sort_by_key(|pwa| Reverse(pwa.last_used)),
+            SortKey::InstallDate => pwas.sort_by_key(|pwa| Reverse(pwa.install_date)),
+            SortKey::Name => pwas.sort_by_key(|pwa| pwa.display_name().to_lowercase()),
+        }
+        pwas
+    }
+
+    /// Find the PWA installed from an origin
+    pub async fn find_by_origin(&self, origin: &str) -> Option<PwaId> {
         let installed = self.installed.read().await;
-        installed.values().find(|pwa| pwa.origin == origin).cloned()
+        installed
+            .values()
+            .find(|pwa| pwa.origin == origin)
+            .map(|pwa| pwa.id)
+    }
+
+    /// Find an enabled PWA that handles `scheme` and get the URL to open
+    /// for `target`
+    ///
+    /// If several apps handle the scheme, the most recently used one wins.
+    pub async fn resolve_protocol(&self, scheme: &str, target: &str) -> Option<(PwaId, Url)> {
+        let installed = self.installed.read().await;
+        installed
+            .values()
+            .filter(|pwa| pwa.enabled)
+            .filter_map(|pwa| {
+                pwa.resolve_protocol_handler(scheme, target)
+                    .map(|url| (pwa.last_used, pwa.id, url))
+            })
+            .max_by_key(|(last_used, _, _)| *last_used)
+            .map(|(_, id, url)| (id, url))
     }
 
     /// Check if a PWA is installed from an origin
@@ -880,17 +1537,26 @@ impl PwaManager {
         self.find_by_origin(origin).await.is_some()
     }
 
+    /// Record a launch of a PWA and persist the updated usage data
+    pub async fn record_launch_by_id(&self, id: PwaId) -> Result<()> {
+        let mut installed = self.installed.write().await;
+        installed
+            .get_mut(&id)
+            .ok_or(PwaError::NotFound(id))?
+            .record_launch();
+        drop(installed);
+
+        self.persist().await
+    }
+
     /// Launch a PWA and create a window
     pub async fn launch(&self, id: PwaId) -> Result<Uuid> {
         // Get and update the PWA
-        let mut installed = self.installed.write().await;
-        let pwa = installed.get_mut(&id).ok_or(PwaError::NotFound(id))?;
-        pwa.record_launch();
-        let pwa_clone = pwa.clone();
-        drop(installed);
+        self.record_launch_by_id(id).await?;
+        let pwa = self.get(id).await.ok_or(PwaError::NotFound(id))?;
 
         // Create the window
-        let window = PwaWindow::new(&pwa_clone)?;
+        let window = PwaWindow::new(&pwa)?;
         let window_id = window.id;
 
         let mut windows = self.windows.write().await;
@@ -899,6 +1565,28 @@ impl PwaManager {
         Ok(window_id)
     }
 
+    /// Launch one of a PWA's manifest shortcuts
+    ///
+    /// `shortcut_index` indexes the manifest's `shortcuts`. Records a launch
+    /// and returns the URL to open.
+    pub async fn launch_shortcut(&self, id: PwaId, shortcut_index: usize) -> Result<Url> {
+        let pwa = self.get(id).await.ok_or(PwaError::NotFound(id))?;
+        let shortcut = pwa.manifest.shortcuts.get(shortcut_index).ok_or_else(|| {
+            PwaError::InvalidManifest(format!("No shortcut at index {}", shortcut_index))
+        })?;
+
+        let url = pwa.resolve(&shortcut.url)?;
+        if !pwa.is_url_in_scope(&url)? {
+            return Err(PwaError::InvalidUrl(format!(
+                "Shortcut {} is outside the app scope",
+                url
+            )));
+        }
+
+        self.record_launch_by_id(id).await?;
+        Ok(url)
+    }
+
     /// Close a PWA window
     pub async fn close_window(&self, window_id: Uuid) -> Result<()> {
         let mut windows = self.windows.write().await;
@@ -924,39 +1612,72 @@ impl PwaManager {
             .collect()
     }
 
-    /// Register a service worker
+    /// Register a service worker for an installed PWA
+    ///
+    /// `script_url` is resolved against the PWA's origin and must fall within
+    /// the manifest scope. The new registration starts `Installing` and
+    /// replaces any previous registration for the PWA.
     pub async fn register_service_worker(
         &self,
-        origin: &str,
+        pwa_id: PwaId,
         script_url: &str,
-        scope: &str,
-    ) -> Result<()> {
-        let mut sw = ServiceWorkerRegistration::new(script_url, scope);
-        sw.mark_installing();
+    ) -> Result<ServiceWorkerId> {
+        let pwa = self.get(pwa_id).await.ok_or(PwaError::NotFound(pwa_id))?;
+
+        let scope = pwa.resolved_scope()?;
+        let script = pwa.resolve(script_url)?;
+        if !pwa.is_url_in_scope(&script)? {
+            return Err(PwaError::ServiceWorkerError(format!(
+                "Script {} is outside the scope {}",
+                script, scope
+            )));
+        }
 
-        // In a real implementation, this would actually register the service worker
-        // For now, we just store the registration
-        sw.mark_active();
+        let registration = ServiceWorkerRegistration::new(script.as_str(), scope.as_str());
+        let id = registration.id;
 
+        self.service_workers
+            .write()
+            .await
+            .insert(pwa_id, registration);
+
+        Ok(id)
+    }
+
+    /// Move a PWA's service worker to its next lifecycle state
+    ///
+    /// Called as the (stubbed) install and activate steps complete.
+    pub async fn advance_service_worker(&self, pwa_id: PwaId) -> Result<ServiceWorkerState> {
         let mut service_workers = self.service_workers.write().await;
-        service_workers.insert(origin.to_string(), sw);
+        service_workers
+            .get_mut(&pwa_id)
+            .ok_or_else(|| PwaError::ServiceWorkerError("Not registered".to_string()))?
+            .advance()
+    }
 
-        Ok(())
+    /// Get the state of a PWA's service worker
+    pub async fn service_worker_state(&self, pwa_id: PwaId) -> Option<ServiceWorkerState> {
+        let service_workers = self.service_workers.read().await;
+        service_workers.get(&pwa_id).map(|sw| sw.state)
     }
 
-    /// Get service worker registration for an origin
-    pub async fn get_service_worker(&self, origin: &str) -> Option<ServiceWorkerRegistration> {
+    /// Get the service worker registration for a PWA
+    pub async fn get_service_worker(&self, pwa_id: PwaId) -> Option<ServiceWorkerRegistration> {
         let service_workers = self.service_workers.read().await;
-        service_workers.get(origin).cloned()
+        service_workers.get(&pwa_id).cloned()
     }
 
-    /// Unregister a service worker
-    pub async fn unregister_service_worker(&self, origin: &str) -> Result<()> {
+    /// Unregister a PWA's service worker, returning it in the `Redundant` state
+    pub async fn unregister_service_worker(
+        &self,
+        pwa_id: PwaId,
+    ) -> Result<ServiceWorkerRegistration> {
         let mut service_workers = self.service_workers.write().await;
-        service_workers
-            .remove(origin)
+        let mut registration = service_workers
+            .remove(&pwa_id)
             .ok_or_else(|| PwaError::ServiceWorkerError("Not registered".to_string()))?;
-        Ok(())
+        registration.mark_redundant();
+        Ok(registration)
     }
 
     /// Update the last used timestamp for a PWA
@@ -1084,6 +1805,46 @@ mod tests {
         assert!(manifest.validate().is_err());
     }
 
+    #[test]
+    fn test_manifest_validate_against_origin_same_origin() {
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.start_url = "https://example.com/app?source=pwa".to_string();
+        manifest.scope = "/app/".to_string();
+
+        let origin = Url::parse("https://example.com").unwrap();
+        assert!(manifest.validate_against_origin(&origin).is_ok());
+    }
+
+    #[test]
+    fn test_manifest_validate_against_origin_rejects_other_host() {
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.start_url = "https://evil.example.net/".to_string();
+
+        let origin = Url::parse("https://example.com").unwrap();
+        assert!(matches!(
+            manifest.validate_against_origin(&origin),
+            Err(PwaError::InvalidManifest(_))
+        ));
+    }
+
+    #[test]
+    fn test_manifest_validate_against_origin_rejects_other_port_or_scheme() {
+        let origin = Url::parse("https://example.com").unwrap();
+
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.scope = "https://example.com:8443/".to_string();
+        assert!(manifest.validate_against_origin(&origin).is_err());
+
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.start_url = "http://example.com/".to_string();
+        assert!(manifest.validate_against_origin(&origin).is_err());
+
+        // An explicit default port is still the same origin
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.start_url = "https://example.com:443/".to_string();
+        assert!(manifest.validate_against_origin(&origin).is_ok());
+    }
+
     #[test]
     fn test_manifest_display_name_prefers_short_name() {
         let mut manifest = WebAppManifest::new("Full Name");
@@ -1118,6 +1879,50 @@ mod tests {
         assert_eq!(icon.unwrap().src, "/icon-192.png");
     }
 
+    #[test]
+    fn test_manifest_best_icon_for_purpose() {
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.icons = vec![
+            PwaIcon::new("/maskable-192.png", "192x192").with_purpose("maskable"),
+            PwaIcon::new("/icon-192.png", "192x192"),
+            PwaIcon::new("/icon-512.png", "512x512").with_purpose("any"),
+        ];
+
+        assert_eq!(
+            manifest.best_icon_for(192, true).unwrap().src,
+            "/maskable-192.png"
+        );
+        assert_eq!(
+            manifest.best_icon_for(192, false).unwrap().src,
+            "/icon-192.png"
+        );
+        // Falls back to any icon when no maskable icon exists
+        manifest.icons.remove(0);
+        assert_eq!(
+            manifest.best_icon_for(512, true).unwrap().src,
+            "/icon-512.png"
+        );
+    }
+
+    #[test]
+    fn test_manifest_best_icon_for_dpr() {
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.icons = vec![
+            PwaIcon::new("/icon-48.png", "48x48"),
+            PwaIcon::new("/icon-96.png", "96x96"),
+            PwaIcon::new("/icon-96-maskable.png", "96x96").with_purpose("maskable"),
+        ];
+
+        assert_eq!(
+            manifest.best_icon_for_dpr(48, 1.0).unwrap().src,
+            "/icon-48.png"
+        );
+        assert_eq!(
+            manifest.best_icon_for_dpr(48, 2.0).unwrap().src,
+            "/icon-96.png"
+        );
+    }
+
     #[test]
     fn test_manifest_largest_icon() {
         let mut manifest = WebAppManifest::new("Test");
@@ -1160,6 +1965,30 @@ mod tests {
         assert!(!PwaDisplayMode::Browser.is_standalone_window());
     }
 
+    #[test]
+    fn test_display_mode_effective_mode() {
+        use PwaDisplayMode::*;
+
+        assert_eq!(
+            Fullscreen.effective_mode(&[Fullscreen, Standalone]),
+            Fullscreen
+        );
+        assert_eq!(
+            Fullscreen.effective_mode(&[Standalone, Browser]),
+            Standalone
+        );
+        assert_eq!(Fullscreen.effective_mode(&[MinimalUi, Browser]), MinimalUi);
+        assert_eq!(Fullscreen.effective_mode(&[Browser]), Browser);
+        assert_eq!(Fullscreen.effective_mode(&[]), Browser);
+
+        // The chain only moves towards less immersive modes
+        assert_eq!(
+            Standalone.effective_mode(&[Fullscreen, MinimalUi]),
+            MinimalUi
+        );
+        assert_eq!(MinimalUi.effective_mode(&[Fullscreen]), Browser);
+    }
+
     // =====================
     // PwaIcon Tests
     // =====================
@@ -1268,6 +2097,208 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_manager_install_rejects_cross_origin_manifest() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let mut manifest = WebAppManifest::new("Test App");
+        manifest.start_url = "https://other.com/".to_string();
+
+        let result = manager.install(manifest, "https://example.com").await;
+        assert!(matches!(result, Err(PwaError::InvalidManifest(_))));
+        assert_eq!(manager.installed_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_manager_check_for_update() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let manifest = WebAppManifest::new("Test App");
+        let pwa = manager
+            .install(manifest.clone(), "https://example.com")
+            .await
+            .unwrap();
+
+        assert!(!manager.check_for_update(pwa.id, &manifest).await.unwrap());
+
+        let mut changed = manifest.clone();
+        changed.theme_color = "#ff0000".to_string();
+        assert!(manager.check_for_update(pwa.id, &changed).await.unwrap());
+
+        assert!(matches!(
+            manager.check_for_update(PwaId::new(), &manifest).await,
+            Err(PwaError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_manager_apply_update_preserves_usage() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let pwa = manager
+            .install(WebAppManifest::new("Test App"), "https://example.com")
+            .await
+            .unwrap();
+        assert_eq!(pwa.version, 1);
+        manager.launch(pwa.id).await.unwrap();
+
+        let mut changed = WebAppManifest::new("Test App 2");
+        changed.start_url = "/v2".to_string();
+        let updated = manager.apply_update(pwa.id, changed.clone()).await.unwrap();
+
+        assert_eq!(updated.manifest, changed);
+        assert_eq!(updated.manifest_hash, changed.content_hash());
+        assert_eq!(updated.version, 2);
+        assert_eq!(updated.install_date, pwa.install_date);
+        assert_eq!(updated.launch_count, 1);
+        assert!(!manager.check_for_update(pwa.id, &changed).await.unwrap());
+
+        // Re-applying the same manifest is not a new version
+        let same = manager.apply_update(pwa.id, changed).await.unwrap();
+        assert_eq!(same.version, 2);
+    }
+
+    #[test]
+    fn test_manifest_content_hash_ignores_json_layout() {
+        let a = WebAppManifest::from_json(r#"{"name": "App", "short_name": "A"}"#).unwrap();
+        let b = WebAppManifest::from_json(r#"{ "short_name":"A","name":"App" }"#).unwrap();
+        assert_eq!(a.content_hash(), b.content_hash());
+        assert_ne!(a.content_hash(), WebAppManifest::new("App").content_hash());
+    }
+
+    #[tokio::test]
+    async fn test_manager_persist_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let registry = temp_dir.path().join(REGISTRY_FILE);
+
+        let manager = PwaManager::load_from_disk(&registry).await.unwrap();
+        assert_eq!(manager.installed_count().await, 0);
+        assert_eq!(manager.install_dir(), temp_dir.path());
+
+        let app1 = manager
+            .install(WebAppManifest::new("App 1"), "https://app1.com")
+            .await
+            .unwrap();
+        let app2 = manager
+            .install(WebAppManifest::new("App 2"), "https://app2.com")
+            .await
+            .unwrap();
+        assert!(registry.exists());
+
+        let reloaded = PwaManager::load_from_disk(&registry).await.unwrap();
+        let mut ids: Vec<String> = reloaded
+            .list_installed()
+            .await
+            .iter()
+            .map(|pwa| pwa.id.to_string())
+            .collect();
+        ids.sort();
+        let mut expected = vec![app1.id.to_string(), app2.id.to_string()];
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(reloaded.get(app1.id).await.unwrap().manifest.name, "App 1");
+
+        // Uninstalling persists too
+        reloaded.uninstall(app1.id).await.unwrap();
+        let reloaded = PwaManager::load_from_disk(&registry).await.unwrap();
+        assert_eq!(reloaded.installed_count().await, 1);
+        assert!(reloaded.get(app2.id).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_manager_load_legacy_registry_computes_manifest_hash() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let registry = temp_dir.path().join(REGISTRY_FILE);
+        let manifest = WebAppManifest::new("Legacy");
+        let pwa = InstalledPwa::new(manifest.clone(), "https://legacy.com", PathBuf::new());
+
+        // Registries from before manifest hashing have no hash field
+        let mut entry = serde_json::to_value(&pwa).unwrap();
+        entry.as_object_mut().unwrap().remove("manifest_hash");
+        std::fs::write(&registry, serde_json::to_vec(&[entry]).unwrap()).unwrap();
+
+        let manager = PwaManager::load_from_disk(&registry).await.unwrap();
+        let loaded = manager.get(pwa.id).await.unwrap();
+        assert_eq!(loaded.manifest_hash, manifest.content_hash());
+        assert!(!manager.check_for_update(pwa.id, &manifest).await.unwrap());
+        assert!(manager
+            .check_for_update(pwa.id, &WebAppManifest::new("Legacy 2"))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_manager_load_corrupt_registry_starts_empty() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let registry = temp_dir.path().join(REGISTRY_FILE);
+        std::fs::write(&registry, b"{not json").unwrap();
+
+        let manager = PwaManager::load_from_disk(&registry).await.unwrap();
+        assert_eq!(manager.installed_count().await, 0);
+        assert_eq!(manager.registry_path(), Some(registry.as_path()));
+    }
+
+    #[tokio::test]
+    async fn test_manager_install_one_per_origin() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        manager
+            .install(WebAppManifest::new("App"), "https://example.com")
+            .await
+            .unwrap();
+
+        let result = manager
+            .install(WebAppManifest::new("App 2"), "https://example.com")
+            .await;
+        assert!(matches!(
+            result,
+            Err(PwaError::AlreadyInstalled(origin)) if origin == "https://example.com"
+        ));
+
+        assert!(manager
+            .install(WebAppManifest::new("Other"), "https://other.com")
+            .await
+            .is_ok());
+        assert_eq!(manager.installed_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_manager_install_replaces_disabled_pwa() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let old = manager
+            .install(WebAppManifest::new("Old"), "https://example.com")
+            .await
+            .unwrap();
+        manager.disable(old.id).await.unwrap();
+
+        let new = manager
+            .install(WebAppManifest::new("New"), "https://example.com")
+            .await
+            .unwrap();
+        assert_eq!(manager.installed_count().await, 1);
+        assert!(manager.get(old.id).await.is_none());
+        assert_eq!(
+            manager.find_by_origin("https://example.com").await,
+            Some(new.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_manager_reinstall_overwrites() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let old = manager
+            .install(WebAppManifest::new("Old"), "https://example.com")
+            .await
+            .unwrap();
+        let window_id = manager.launch(old.id).await.unwrap();
+
+        let new = manager
+            .reinstall("https://example.com", WebAppManifest::new("New"))
+            .await
+            .unwrap();
+
+        assert_ne!(new.id, old.id);
+        assert_eq!(manager.installed_count().await, 1);
+        assert_eq!(manager.get(new.id).await.unwrap().manifest.name, "New");
+        assert!(manager.get_window(window_id).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_manager_uninstall() {
         let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
@@ -1298,13 +2329,60 @@ mod tests {
         assert_eq!(installed.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_manager_list_installed_sorted() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+
+        let mail = manager
+            .install(WebAppManifest::new("mail"), "https://mail.com")
+            .await
+            .unwrap();
+        let chat = manager
+            .install(WebAppManifest::new("Chat"), "https://chat.com")
+            .await
+            .unwrap();
+        let docs = manager
+            .install(WebAppManifest::new("Docs"), "https://docs.com")
+            .await
+            .unwrap();
+
+        for _ in 0..3 {
+            manager.record_launch_by_id(chat.id).await.unwrap();
+        }
+        manager.record_launch_by_id(mail.id).await.unwrap();
+        manager.launch(docs.id).await.unwrap();
+        manager.launch(docs.id).await.unwrap();
+
+        let ids = |pwas: Vec<InstalledPwa>| pwas.iter().map(|pwa| pwa.id).collect::<Vec<_>>();
+
+        assert_eq!(
+            ids(manager.list_installed_sorted(SortKey::LaunchCount).await),
+            vec![chat.id, docs.id, mail.id]
+        );
+        assert_eq!(
+            ids(manager.list_installed_sorted(SortKey::Name).await),
+            vec![chat.id, docs.id, mail.id]
+        );
+        assert_eq!(
+            manager.list_installed_sorted(SortKey::LastUsed).await[0].id,
+            docs.id
+        );
+        assert!(matches!(
+            manager.record_launch_by_id(PwaId::new()).await,
+            Err(PwaError::NotFound(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_manager_find_by_origin() {
         let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
-        manager.install(WebAppManifest::new("Test"), "https://example.com").await.unwrap();
+        let pwa = manager
+            .install(WebAppManifest::new("Test"), "https://example.com")
+            .await
+            .unwrap();
 
         let found = manager.find_by_origin("https://example.com").await;
-        assert!(found.is_some());
+        assert_eq!(found, Some(pwa.id));
 
         let not_found = manager.find_by_origin("https://other.com").await;
         assert!(not_found.is_none());
@@ -1371,30 +2449,117 @@ mod tests {
     #[tokio::test]
     async fn test_service_worker_registration() {
         let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let pwa = manager
+            .install(WebAppManifest::new("Test"), "https://example.com")
+            .await
+            .unwrap();
 
-        manager.register_service_worker(
-            "https://example.com",
-            "/sw.js",
-            "/"
-        ).await.unwrap();
+        let id = manager
+            .register_service_worker(pwa.id, "/sw.js")
+            .await
+            .unwrap();
 
-        let sw = manager.get_service_worker("https://example.com").await;
-        assert!(sw.is_some());
-        assert!(sw.unwrap().is_active());
+        let sw = manager.get_service_worker(pwa.id).await.unwrap();
+        assert_eq!(sw.id, id);
+        assert_eq!(sw.script_url, "https://example.com/sw.js");
+        assert_eq!(sw.state, ServiceWorkerState::Installing);
+        assert!(!sw.is_active());
     }
 
     #[tokio::test]
-    async fn test_service_worker_unregister() {
+    async fn test_service_worker_lifecycle() {
         let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let pwa = manager
+            .install(WebAppManifest::new("Test"), "https://example.com")
+            .await
+            .unwrap();
+        manager
+            .register_service_worker(pwa.id, "/sw.js")
+            .await
+            .unwrap();
+
+        for expected in [
+            ServiceWorkerState::Installed,
+            ServiceWorkerState::Activating,
+            ServiceWorkerState::Activated,
+        ] {
+            assert_eq!(
+                manager.advance_service_worker(pwa.id).await.unwrap(),
+                expected
+            );
+            assert_eq!(manager.service_worker_state(pwa.id).await, Some(expected));
+        }
 
-        manager.register_service_worker(
-            "https://example.com",
-            "/sw.js",
-            "/"
-        ).await.unwrap();
+        let sw = manager.get_service_worker(pwa.id).await.unwrap();
+        assert!(sw.is_active());
+        assert!(sw.activated_at.is_some());
+
+        // Activated is terminal
+        assert!(manager.advance_service_worker(pwa.id).await.is_err());
 
-        manager.unregister_service_worker("https://example.com").await.unwrap();
-        assert!(manager.get_service_worker("https://example.com").await.is_none());
+        // Registering again replaces the activated worker
+        let id = manager
+            .register_service_worker(pwa.id, "/sw.js")
+            .await
+            .unwrap();
+        let sw = manager.get_service_worker(pwa.id).await.unwrap();
+        assert_eq!(sw.id, id);
+        assert_eq!(sw.state, ServiceWorkerState::Installing);
+        assert!(sw.activated_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_service_worker_rejects_script_outside_scope() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.scope = "/app/".to_string();
+        let pwa = manager
+            .install(manifest, "https://example.com")
+            .await
+            .unwrap();
+
+        assert!(manager
+            .register_service_worker(pwa.id, "/app/sw.js")
+            .await
+            .is_ok());
+        assert!(matches!(
+            manager.register_service_worker(pwa.id, "/sw.js").await,
+            Err(PwaError::ServiceWorkerError(_))
+        ));
+        assert!(matches!(
+            manager
+                .register_service_worker(pwa.id, "https://evil.com/app/sw.js")
+                .await,
+            Err(PwaError::ServiceWorkerError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_service_worker_register_unknown_pwa() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let result = manager
+            .register_service_worker(PwaId::new(), "/sw.js")
+            .await;
+        assert!(matches!(result, Err(PwaError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_service_worker_unregister() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let pwa = manager
+            .install(WebAppManifest::new("Test"), "https://example.com")
+            .await
+            .unwrap();
+        manager
+            .register_service_worker(pwa.id, "/sw.js")
+            .await
+            .unwrap();
+
+        let removed = manager.unregister_service_worker(pwa.id).await.unwrap();
+        assert_eq!(removed.state, ServiceWorkerState::Redundant);
+        assert!(manager.get_service_worker(pwa.id).await.is_none());
+        assert_eq!(manager.service_worker_state(pwa.id).await, None);
+        assert!(manager.unregister_service_worker(pwa.id).await.is_err());
     }
 
     // =====================
@@ -1473,6 +2638,240 @@ mod tests {
         assert_eq!(url.as_str(), "https://example.com/app");
     }
 
+    #[test]
+    fn test_installed_pwa_resolved_shortcuts() {
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.scope = "/app/".to_string();
+        manifest.shortcuts = vec![
+            PwaShortcut::new("Compose", "/app/compose"),
+            PwaShortcut::new("Outside", "/admin"),
+            PwaShortcut::new("Inbox", "https://example.com/app/inbox?unread=1"),
+            PwaShortcut::new("Elsewhere", "https://other.com/app/"),
+        ];
+        let pwa = InstalledPwa::new(manifest, "https://example.com", PathBuf::from("/tmp"));
+
+        let shortcuts = pwa.resolved_shortcuts().unwrap();
+        assert_eq!(
+            shortcuts,
+            vec![
+                (
+                    "Compose".to_string(),
+                    Url::parse("https://example.com/app/compose").unwrap()
+                ),
+                (
+                    "Inbox".to_string(),
+                    Url::parse("https://example.com/app/inbox?unread=1").unwrap()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_manager_launch_shortcut() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let mut manifest = WebAppManifest::new("Test");
+        manifest.scope = "/app/".to_string();
+        manifest.start_url = "/app/".to_string();
+        manifest.shortcuts = vec![
+            PwaShortcut::new("Compose", "compose"),
+            PwaShortcut::new("Outside", "/admin"),
+        ];
+        let pwa = manager
+            .install(manifest, "https://example.com/app/")
+            .await
+            .unwrap();
+
+        let url = manager.launch_shortcut(pwa.id, 0).await.unwrap();
+        assert_eq!(url.as_str(), "https://example.com/app/compose");
+        assert_eq!(manager.get(pwa.id).await.unwrap().launch_count, 1);
+
+        assert!(matches!(
+            manager.launch_shortcut(pwa.id, 1).await,
+            Err(PwaError::InvalidUrl(_))
+        ));
+        assert!(manager.launch_shortcut(pwa.id, 5).await.is_err());
+        assert_eq!(manager.get(pwa.id).await.unwrap().launch_count, 1);
+    }
+
+    #[test]
+    fn test_protocol_handler_validity() {
+        assert!(ProtocolHandler::new("web+coffee", "/order?u=%s").is_valid());
+        assert!(ProtocolHandler::new("mailto", "/compose?to=%s").is_valid());
+        assert!(!ProtocolHandler::new("web+", "/x?u=%s").is_valid());
+        assert!(!ProtocolHandler::new("web+c0ffee", "/x?u=%s").is_valid());
+        assert!(!ProtocolHandler::new("https", "/x?u=%s").is_valid());
+        assert!(!ProtocolHandler::new("web+coffee", "/order").is_valid());
+    }
+
+    #[test]
+    fn test_protocol_handler_expand_encodes_component() {
+        let handler = ProtocolHandler::new("web+coffee", "/order?u=%s");
+        assert_eq!(
+            handler.expand("web+coffee:flat white&milk=oat"),
+            "/order?u=web%2Bcoffee%3Aflat%20white%26milk%3Doat"
+        );
+        assert_eq!(handler.expand("café"), "/order?u=caf%C3%A9");
+    }
+
+    #[tokio::test]
+    async fn test_manager_resolve_protocol() {
+        let manager = PwaManager::with_install_dir(PathBuf::from("/tmp/pwa_test"));
+        let mut manifest = WebAppManifest::new("Coffee");
+        manifest.scope = "/app/".to_string();
+        manifest.start_url = "/app/".to_string();
+        manifest.protocol_handlers = vec![
+            ProtocolHandler::new("web+coffee", "/app/order?type=%s"),
+            ProtocolHandler::new("web+tea", "/admin?type=%s"),
+        ];
+        let pwa = manager
+            .install(manifest, "https://example.com/app/")
+            .await
+            .unwrap();
+
+        let (id, url) = manager
+            .resolve_protocol("web+coffee:", "web+coffee:latte&milk")
+            .await
+            .unwrap();
+        assert_eq!(id, pwa.id);
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/app/order?type=web%2Bcoffee%3Alatte%26milk"
+        );
+
+        // Out-of-scope templates and unknown schemes don't resolve
+        assert!(manager
+            .resolve_protocol("web+tea", "web+tea:green")
+            .await
+            .is_none());
+        assert!(manager
+            .resolve_protocol("web+cocoa", "web+cocoa:x")
+            .await
+            .is_none());
+
+        manager.disable(pwa.id).await.unwrap();
+        assert!(manager
+            .resolve_protocol("web+coffee", "web+coffee:x")
+            .await
+            .is_none());
+    }
+
+    #[test]
+    fn test_manifest_protocol_handlers_default() {
+        let manifest = WebAppManifest::from_json(r#"{"name": "Test"}"#).unwrap();
+        assert!(manifest.protocol_handlers.is_empty());
+
+        let manifest = WebAppManifest::from_json(
+            r#"{"name": "Test", "protocol_handlers": [{"protocol": "web+coffee", "url": "/?c=%s"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.protocol_handlers,
+            vec![ProtocolHandler::new("web+coffee", "/?c=%s")]
+        );
+    }
+
+    fn share_pwa(method: ShareMethod, action: &str) -> InstalledPwa {
+        let mut manifest = WebAppManifest::new("Share");
+        manifest.scope = "/app/".to_string();
+        manifest.share_target = Some(ShareTarget {
+            action: action.to_string(),
+            method,
+            params: ShareTargetParams {
+                title: Some("name".to_string()),
+                text: Some("description".to_string()),
+                url: Some("link".to_string()),
+            },
+        });
+        InstalledPwa::new(manifest, "https://example.com", PathBuf::from("/tmp"))
+    }
+
+    #[test]
+    fn test_build_share_url_get() {
+        let pwa = share_pwa(ShareMethod::Get, "/app/share?stale=1");
+
+        let url = pwa
+            .build_share_url(Some("Tea & cake"), None, Some("https://example.org/a?b=c"))
+            .unwrap();
+        assert_eq!(url.path(), "/app/share");
+        assert_eq!(
+            url.query(),
+            Some("name=Tea+%26+cake&link=https%3A%2F%2Fexample.org%2Fa%3Fb%3Dc")
+        );
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("name".to_string(), "Tea & cake".to_string()),
+                ("link".to_string(), "https://example.org/a?b=c".to_string()),
+            ]
+        );
+
+        let url = pwa.build_share_url(None, None, None).unwrap();
+        assert_eq!(url.as_str(), "https://example.com/app/share");
+    }
+
+    #[test]
+    fn test_build_share_url_post_and_errors() {
+        let pwa = share_pwa(ShareMethod::Post, "/app/share");
+        let url = pwa
+            .build_share_url(Some("Hi"), Some("there"), None)
+            .unwrap();
+        assert_eq!(url.as_str(), "https://example.com/app/share");
+        let target = pwa.manifest.share_target.as_ref().unwrap();
+        assert_eq!(
+            target.encode_params(Some("Hi"), Some("there"), None),
+            "name=Hi&description=there"
+        );
+
+        let outside = share_pwa(ShareMethod::Get, "/admin/share");
+        assert!(matches!(
+            outside.build_share_url(Some("Hi"), None, None),
+            Err(PwaError::InvalidUrl(_))
+        ));
+
+        let none = InstalledPwa::new(
+            WebAppManifest::new("Plain"),
+            "https://example.com",
+            PathBuf::from("/tmp"),
+        );
+        assert!(none.build_share_url(None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_manifest_share_target_from_json() {
+        let manifest = WebAppManifest::from_json(
+            r#"{"name": "Test", "share_target": {"action": "/share", "method": "POST", "params": {"text": "t"}}}"#,
+        )
+        .unwrap();
+        let target = manifest.share_target.unwrap();
+        assert_eq!(target.method, ShareMethod::Post);
+        assert_eq!(target.params.text.as_deref(), Some("t"));
+        assert_eq!(target.params.title, None);
+    }
+
+    #[test]
+    fn test_manifest_share_target_method_case_insensitive() {
+        for (method, expected) in [
+            ("post", ShareMethod::Post),
+            ("Post", ShareMethod::Post),
+            ("get", ShareMethod::Get),
+        ] {
+            let json = format!(
+                r#"{{"name": "Test", "share_target": {{"action": "/share", "method": "{}"}}}}"#,
+                method
+            );
+            let manifest = WebAppManifest::from_json(&json).unwrap();
+            assert_eq!(manifest.share_target.unwrap().method, expected);
+        }
+
+        let invalid = r#"{"name": "Test", "share_target": {"action": "/share", "method": "put"}}"#;
+        assert!(WebAppManifest::from_json(invalid).is_err());
+        assert_eq!(
+            serde_json::to_string(&ShareMethod::Post).unwrap(),
+            r#""POST""#
+        );
+    }
+
     // =====================
     // PwaShortcut Tests
     // =====================
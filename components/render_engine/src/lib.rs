@@ -6,8 +6,13 @@
 //!
 //! - **RenderEngine trait**: Abstraction for render engine implementations
 //! - **Viewport management**: Dimensions, scroll position, zoom level, and device pixel ratio
+//! - **Smooth scrolling**: `ScrollAnimator` eases the viewport toward a scroll target
 //! - **Frame scheduling**: Support for 60fps rendering with animation frame callbacks
+//! - **Frame timing stats**: `FrameScheduler::stats` reports frame-time percentiles for profiling
 //! - **Dirty region tracking**: Efficient partial repaints through invalidation regions
+//! - **Zoom scaling**: `scale_frame` resamples frames bilinearly for zoom levels other than 1.0
+//! - **Double buffering**: `DoubleBuffer` swaps a back frame in for tear-free presentation
+//! - **Tiled rendering**: `TiledRenderEngine` re-renders only the tiles touched by invalidations
 //! - **Compositor integration**: Layer-based compositing for smooth scrolling and animations
 //! - **DOM integration stubs**: Placeholder types for future HTML/CSS/DOM engine integration
 //!
@@ -78,7 +83,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use thiserror::Error;
 
@@ -97,6 +102,10 @@ pub enum RenderError {
     AllocationFailed,
     #[error("Compositor error: {0}")]
     CompositorError(String),
+    #[error("Pixel format mismatch: expected {0:?}, got {1:?}")]
+    FormatMismatch(PixelFormat, PixelFormat),
+    #[error("Image encoding failed: {0}")]
+    EncodingFailed(String),
 }
 
 /// Result type for render operations
@@ -213,6 +222,27 @@ pub struct Viewport {
     pub zoom: f32,
     /// Device pixel ratio for HiDPI displays
     pub device_pixel_ratio: f32,
+    /// Maximum elastic overscroll past the scroll bounds, in pixels
+    #[serde(default)]
+    pub overscroll: f32,
+    /// Document content size (width, height), if known
+    #[serde(default)]
+    pub content_size: Option<(u32, u32)>,
+}
+
+/// Smallest supported zoom level
+pub const MIN_ZOOM: f32 = 0.1;
+
+/// Largest supported zoom level
+pub const MAX_ZOOM: f32 = 10.0;
+
+/// Check that `zoom` is finite and within [`MIN_ZOOM`]..=[`MAX_ZOOM`]
+fn check_zoom(zoom: f32) -> RenderResult<()> {
+    if (MIN_ZOOM..=MAX_ZOOM).contains(&zoom) {
+        Ok(())
+    } else {
+        Err(RenderError::InvalidZoom(zoom))
+    }
 }
 
 impl Viewport {
@@ -225,6 +255,8 @@ impl Viewport {
             scroll_y: 0,
             zoom: 1.0,
             device_pixel_ratio: 1.0,
+            overscroll: 0.0,
+            content_size: None,
         }
     }
 
@@ -237,20 +269,90 @@ impl Viewport {
             scroll_y: 0,
             zoom: 1.0,
             device_pixel_ratio,
+            overscroll: 0.0,
+            content_size: None,
         }
     }
 
     /// Set scroll position
+    ///
+    /// Clamped to the scrollable range once a content size is known.
     pub fn set_scroll(&mut self, x: i32, y: i32) {
         self.scroll_x = x;
         self.scroll_y = y;
+        if self.content_size.is_some() {
+            self.clamp_scroll();
+        }
+    }
+
+    /// Set the document content size and clamp the scroll offset to it
+    pub fn set_content_size(&mut self, width: u32, height: u32) {
+        self.content_size = Some((width, height));
+        self.clamp_scroll();
+    }
+
+    /// Clamp the scroll offset to `[0, content - visible]` on each axis
+    pub fn clamp_scroll(&mut self) {
+        let ((min_x, max_x), (min_y, max_y)) = self.scroll_bounds();
+        self.scroll_x = self.scroll_x.clamp(min_x, max_x);
+        self.scroll_y = self.scroll_y.clamp(min_y, max_y);
+    }
+
+    /// Set the elastic overscroll allowance in pixels
+    pub fn set_overscroll(&mut self, overscroll: f32) {
+        self.overscroll = overscroll.max(0.0);
+    }
+
+    /// Set scroll position, permitting temporary overscroll
+    ///
+    /// Offsets past the scroll bounds are allowed up to `overscroll` pixels;
+    /// call [`Viewport::settle_overscroll`] to animate back into bounds.
+    pub fn set_scroll_elastic(&mut self, x: i32, y: i32) {
+        let slack = self.overscroll as i32;
+        let ((min_x, max_x), (min_y, max_y)) = self.scroll_bounds();
+        self.scroll_x = x.clamp(min_x.saturating_sub(slack), max_x.saturating_add(slack));
+        self.scroll_y = y.clamp(min_y.saturating_sub(slack), max_y.saturating_add(slack));
+    }
+
+    /// Step the scroll offset back toward the scroll bounds
+    ///
+    /// Each call removes a fraction of the remaining overscroll. Returns
+    /// `true` while the viewport is still outside its bounds, so the caller
+    /// knows to keep animating.
+    pub fn settle_overscroll(&mut self) -> bool {
+        let ((min_x, max_x), (min_y, max_y)) = self.scroll_bounds();
+        self.scroll_x = settle_axis(self.scroll_x, min_x, max_x);
+        self.scroll_y = settle_axis(self.scroll_y, min_y, max_y);
+        self.is_overscrolled()
+    }
+
+    /// Whether the scroll offset is currently outside the scroll bounds
+    pub fn is_overscrolled(&self) -> bool {
+        let ((min_x, max_x), (min_y, max_y)) = self.scroll_bounds();
+        !(min_x..=max_x).contains(&self.scroll_x) || !(min_y..=max_y).contains(&self.scroll_y)
+    }
+
+    /// Valid scroll ranges as `((min_x, max_x), (min_y, max_y))`
+    ///
+    /// Without a content size only the lower bound applies.
+    fn scroll_bounds(&self) -> ((i32, i32), (i32, i32)) {
+        match self.content_size {
+            Some((content_width, content_height)) => {
+                let visible = self.visible_bounds();
+                let max_x = content_width.saturating_sub(visible.width);
+                let max_y = content_height.saturating_sub(visible.height);
+                (
+                    (0, max_x.min(i32::MAX as u32) as i32),
+                    (0, max_y.min(i32::MAX as u32) as i32),
+                )
+            }
+            None => ((0, i32::MAX), (0, i32::MAX)),
+        }
     }
 
     /// Set zoom level
     pub fn set_zoom(&mut self, zoom: f32) -> RenderResult<()> {
-        if zoom < 0.1 || zoom > 10.0 {
-            return Err(RenderError::InvalidZoom(zoom));
-        }
+        check_zoom(zoom)?;
         self.zoom = zoom;
         Ok(())
     }
@@ -288,12 +390,129 @@ impl Viewport {
     }
 }
 
+/// Move one scroll axis a step back toward `[min, max]`
+fn settle_axis(value: i32, min: i32, max: i32) -> i32 {
+    /// Fraction of the remaining overscroll removed per settle step
+    const SETTLE_FACTOR: f32 = 0.3;
+
+    let target = value.clamp(min, max);
+    let distance = target - value;
+    if distance == 0 {
+        return value;
+    }
+    let step = (distance as f32 * SETTLE_FACTOR) as i32;
+    if step == 0 {
+        // Always make progress so settling terminates
+        value + distance.signum()
+    } else {
+        value + step
+    }
+}
+
 impl Default for Viewport {
     fn default() -> Self {
         Self::new(800, 600)
     }
 }
 
+/// Scroll animation in progress
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnimation {
+    from: (i32, i32),
+    to: (i32, i32),
+    start: Instant,
+    duration: Duration,
+}
+
+/// Smooth-scroll interpolation for a [`Viewport`]
+///
+/// Call [`ScrollAnimator::start`] with a target, then [`ScrollAnimator::tick`]
+/// (or [`ScrollAnimator::apply`]) once per frame. Positions follow an
+/// ease-out cubic curve: fast at first, slowing into the target.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollAnimator {
+    animation: Option<ScrollAnimation>,
+}
+
+impl ScrollAnimator {
+    /// Create an idle animator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start animating from `from` to `to` over `duration`, beginning at `now`
+    ///
+    /// Replaces any animation in progress.
+    pub fn start(&mut self, from: (i32, i32), to: (i32, i32), duration: Duration, now: Instant) {
+        self.animation = Some(ScrollAnimation {
+            from,
+            to,
+            start: now,
+            duration,
+        });
+    }
+
+    /// Whether an animation is in progress
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    /// Stop the animation where it is
+    pub fn cancel(&mut self) {
+        self.animation = None;
+    }
+
+    /// Get the target of the animation in progress
+    pub fn target(&self) -> Option<(i32, i32)> {
+        self.animation.map(|animation| animation.to)
+    }
+
+    /// Get the interpolated position at `now` without advancing
+    pub fn position_at(&self, now: Instant) -> Option<(i32, i32)> {
+        let animation = self.animation?;
+        let t = if animation.duration.is_zero() {
+            1.0
+        } else {
+            let elapsed = now.saturating_duration_since(animation.start);
+            (elapsed.as_secs_f64() / animation.duration.as_secs_f64()).min(1.0)
+        };
+        let eased = ease_out_cubic(t);
+        let lerp = |from: i32, to: i32| from + ((to - from) as f64 * eased).round() as i32;
+        Some((
+            lerp(animation.from.0, animation.to.0),
+            lerp(animation.from.1, animation.to.1),
+        ))
+    }
+
+    /// Advance to `now`, returning the position to scroll to
+    ///
+    /// Returns the target once the duration has passed, after which the
+    /// animator is idle and returns `None`.
+    pub fn tick(&mut self, now: Instant) -> Option<(i32, i32)> {
+        let position = self.position_at(now)?;
+        let animation = self.animation?;
+        if now.saturating_duration_since(animation.start) >= animation.duration {
+            self.animation = None;
+        }
+        Some(position)
+    }
+
+    /// Advance to `now` and scroll `viewport` to the new position
+    ///
+    /// Returns whether the animation is still running.
+    pub fn apply(&mut self, viewport: &mut Viewport, now: Instant) -> bool {
+        if let Some((x, y)) = self.tick(now) {
+            viewport.set_scroll(x, y);
+        }
+        self.is_animating()
+    }
+}
+
+/// Ease-out cubic curve over `t` in `[0, 1]`
+fn ease_out_cubic(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
+
 /// Pixel format for frame buffers
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PixelFormat {
@@ -324,6 +543,76 @@ impl Default for PixelFormat {
     }
 }
 
+impl PixelFormat {
+    /// Decode a single pixel in this format into RGBA components
+    fn decode_rgba(&self, pixel: &[u8]) -> [u8; 4] {
+        match self {
+            PixelFormat::Rgba8 => [pixel[0], pixel[1], pixel[2], pixel[3]],
+            PixelFormat::Bgra8 => [pixel[2], pixel[1], pixel[0], pixel[3]],
+            PixelFormat::Rgb8 => [pixel[0], pixel[1], pixel[2], 255],
+            PixelFormat::Rgb565 => {
+                let value = u16::from_le_bytes([pixel[0], pixel[1]]);
+                let r = ((value >> 11) & 0x1f) as u8;
+                let g = ((value >> 5) & 0x3f) as u8;
+                let b = (value & 0x1f) as u8;
+                [
+                    (r << 3) | (r >> 2),
+                    (g << 2) | (g >> 4),
+                    (b << 3) | (b >> 2),
+                    255,
+                ]
+            }
+        }
+    }
+
+    /// Encode RGBA components into a single pixel in this format
+    fn encode_rgba(&self, rgba: [u8; 4], out: &mut [u8]) {
+        match self {
+            PixelFormat::Rgba8 => out.copy_from_slice(&rgba),
+            PixelFormat::Bgra8 => out.copy_from_slice(&[rgba[2], rgba[1], rgba[0], rgba[3]]),
+            PixelFormat::Rgb8 => out.copy_from_slice(&rgba[..3]),
+            PixelFormat::Rgb565 => {
+                out.copy_from_slice(&quantize_rgb565(rgba[0], rgba[1], rgba[2]).to_le_bytes())
+            }
+        }
+    }
+}
+
+/// Dithering applied when reducing color depth during frame conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DitherMode {
+    /// Plain truncation (may show banding)
+    #[default]
+    None,
+    /// Ordered dithering using a 4x4 Bayer matrix
+    Ordered,
+}
+
+/// 4x4 Bayer threshold matrix (values 0..16)
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Pack 8-bit RGB components into a 5-6-5 value
+fn quantize_rgb565(r: u8, g: u8, b: u8) -> u16 {
+    ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3)
+}
+
+/// Apply the Bayer threshold for pixel (x, y) before 5-6-5 quantization
+///
+/// Each channel is biased by a fraction of its quantization step (8 for
+/// the 5-bit channels, 4 for the 6-bit green channel), so neighbouring
+/// pixels round in different directions instead of banding.
+fn dither_rgb565(rgba: [u8; 4], x: u32, y: u32) -> [u8; 4] {
+    let threshold = BAYER_4X4[(y % 4) as usize][(x % 4) as usize];
+    let bias5 = threshold / 2;
+    let bias6 = threshold / 4;
+    [
+        rgba[0].saturating_add(bias5),
+        rgba[1].saturating_add(bias6),
+        rgba[2].saturating_add(bias5),
+        rgba[3],
+    ]
+}
+
 /// Rendered frame containing pixel data
 #[derive(Debug, Clone)]
 pub struct Frame {
@@ -333,8 +622,8 @@ pub struct Frame {
     pub height: u32,
     /// Pixel format
     pub format: PixelFormat,
-    /// Raw pixel data
-    pub data: Vec<u8>,
+    /// Raw pixel data, shared between clones until one of them is written
+    pub data: Arc<Vec<u8>>,
     /// Timestamp when frame was rendered
     pub timestamp: Duration,
     /// Dirty regions that were rendered in this frame
@@ -351,7 +640,7 @@ impl Frame {
         }
 
         let size = width as usize * height as usize * format.bytes_per_pixel();
-        let data = vec![0u8; size];
+        let data = Arc::new(vec![0u8; size]);
 
         Ok(Self {
             width,
@@ -384,13 +673,20 @@ impl Frame {
             width,
             height,
             format,
-            data,
+            data: Arc::new(data),
             timestamp: Duration::ZERO,
             dirty_regions: Vec::new(),
             sequence: 0,
         })
     }
 
+    /// Get the pixel data for writing
+    ///
+    /// Copies the data first if another clone of this frame still shares it.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        Arc::make_mut(&mut self.data).as_mut_slice()
+    }
+
     /// Get pixel at coordinates (returns None if out of bounds)
     pub fn get_pixel(&self, x: u32, y: u32) -> Option<&[u8]> {
         if x >= self.width || y >= self.height {
@@ -411,7 +707,7 @@ impl Frame {
             return false;
         }
         let offset = (y as usize * self.width as usize + x as usize) * bpp;
-        self.data[offset..offset + bpp].copy_from_slice(pixel);
+        self.data_mut()[offset..offset + bpp].copy_from_slice(pixel);
         true
     }
 
@@ -429,6 +725,250 @@ impl Frame {
     pub fn is_empty(&self) -> bool {
         self.width == 0 || self.height == 0
     }
+
+    /// Encode the frame as PNG bytes
+    ///
+    /// RGBA and RGB frames are encoded directly; BGRA and RGB565 frames are
+    /// converted to RGBA first.
+    pub fn encode_png(&self) -> RenderResult<Vec<u8>> {
+        use image::ImageEncoder;
+
+        let converted;
+        let (data, color_type) = match self.format {
+            PixelFormat::Rgba8 => (&self.data, image::ExtendedColorType::Rgba8),
+            PixelFormat::Rgb8 => (&self.data, image::ExtendedColorType::Rgb8),
+            PixelFormat::Bgra8 | PixelFormat::Rgb565 => {
+                converted = self.convert(PixelFormat::Rgba8)?;
+                (&converted.data, image::ExtendedColorType::Rgba8)
+            }
+        };
+
+        let mut png = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png)
+            .write_image(data, self.width, self.height, color_type)
+            .map_err(|e| RenderError::EncodingFailed(e.to_string()))?;
+        Ok(png)
+    }
+
+    /// Copy pixels from `src_rect` in `src` into this frame at `(dst_x, dst_y)`
+    ///
+    /// The copy is clipped against both frames' bounds; anything falling
+    /// outside either frame is skipped. Both frames must share a pixel format.
+    pub fn blit_from(
+        &mut self,
+        src: &Frame,
+        src_rect: Rect,
+        dst_x: i32,
+        dst_y: i32,
+    ) -> RenderResult<()> {
+        if src.format != self.format {
+            return Err(RenderError::FormatMismatch(self.format, src.format));
+        }
+
+        // Clip against the source frame
+        let Some(clipped) = src_rect.intersection(&Rect::from_size(src.width, src.height)) else {
+            return Ok(());
+        };
+
+        // Map to the destination and clip against this frame
+        let target = Rect {
+            x: dst_x + (clipped.x - src_rect.x),
+            y: dst_y + (clipped.y - src_rect.y),
+            width: clipped.width,
+            height: clipped.height,
+        };
+        let Some(dst) = target.intersection(&Rect::from_size(self.width, self.height)) else {
+            return Ok(());
+        };
+
+        let src_x = (clipped.x + (dst.x - target.x)) as usize;
+        let src_y = (clipped.y + (dst.y - target.y)) as usize;
+        let bpp = self.format.bytes_per_pixel();
+        let row_bytes = dst.width as usize * bpp;
+        let src_stride = src.stride();
+        let dst_stride = self.stride();
+        let data = self.data_mut();
+
+        for row in 0..dst.height as usize {
+            let src_offset = (src_y + row) * src_stride + src_x * bpp;
+            let dst_offset = (dst.y as usize + row) * dst_stride + dst.x as usize * bpp;
+            data[dst_offset..dst_offset + row_bytes]
+                .copy_from_slice(&src.data[src_offset..src_offset + row_bytes]);
+        }
+
+        Ok(())
+    }
+
+    /// Convert this frame to another pixel format
+    pub fn convert(&self, target: PixelFormat) -> RenderResult<Frame> {
+        self.convert_dithered(target, DitherMode::None)
+    }
+
+    /// Convert this frame to another pixel format with optional dithering
+    ///
+    /// Dithering only applies when the target is `Rgb565`; other targets
+    /// have at least 8 bits per channel and are converted exactly.
+    pub fn convert_dithered(&self, target: PixelFormat, dither: DitherMode) -> RenderResult<Frame> {
+        let mut converted = Frame::new(self.width, self.height, target)?;
+        converted.timestamp = self.timestamp;
+        converted.dirty_regions = self.dirty_regions.clone();
+        converted.sequence = self.sequence;
+
+        let apply_dither = target == PixelFormat::Rgb565 && dither == DitherMode::Ordered;
+        let src_bpp = self.format.bytes_per_pixel();
+        let dst_bpp = target.bytes_per_pixel();
+        let data = converted.data_mut();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let index = y as usize * self.width as usize + x as usize;
+                let src = &self.data[index * src_bpp..(index + 1) * src_bpp];
+                let mut rgba = self.format.decode_rgba(src);
+                if apply_dither {
+                    rgba = dither_rgb565(rgba, x, y);
+                }
+                target.encode_rgba(rgba, &mut data[index * dst_bpp..(index + 1) * dst_bpp]);
+            }
+        }
+
+        Ok(converted)
+    }
+}
+
+/// Scale a frame by `zoom` using bilinear resampling
+///
+/// The result is `zoom` times the frame's size in each dimension (at least
+/// one pixel) and keeps its pixel format and metadata. Samples are taken at
+/// pixel centers and clamped at the edges, so edge pixels keep the source
+/// edge colors. Fails with [`RenderError::InvalidZoom`] unless `zoom` is
+/// finite and within [`MIN_ZOOM`]..=[`MAX_ZOOM`].
+pub fn scale_frame(frame: &Frame, zoom: f32) -> RenderResult<Frame> {
+    check_zoom(zoom)?;
+
+    let width = ((frame.width as f32 * zoom).round() as u32).max(1);
+    let height = ((frame.height as f32 * zoom).round() as u32).max(1);
+    let mut scaled = Frame::new(width, height, frame.format)?;
+    scaled.timestamp = frame.timestamp;
+    scaled.dirty_regions = frame.dirty_regions.clone();
+    scaled.sequence = frame.sequence;
+
+    let format = frame.format;
+    let bpp = format.bytes_per_pixel();
+    let max_x = (frame.width - 1) as f32;
+    let max_y = (frame.height - 1) as f32;
+    let sample = |x: u32, y: u32| {
+        let index = (y as usize * frame.width as usize + x as usize) * bpp;
+        format.decode_rgba(&frame.data[index..index + bpp])
+    };
+    let data = scaled.data_mut();
+
+    for y in 0..height {
+        let src_y = ((y as f32 + 0.5) / zoom - 0.5).clamp(0.0, max_y);
+        let (y0, fy) = (src_y.floor() as u32, src_y.fract());
+        let y1 = (y0 + 1).min(frame.height - 1);
+        for x in 0..width {
+            let src_x = ((x as f32 + 0.5) / zoom - 0.5).clamp(0.0, max_x);
+            let (x0, fx) = (src_x.floor() as u32, src_x.fract());
+            let x1 = (x0 + 1).min(frame.width - 1);
+
+            let (p00, p10, p01, p11) = (
+                sample(x0, y0),
+                sample(x1, y0),
+                sample(x0, y1),
+                sample(x1, y1),
+            );
+            let mut rgba = [0u8; 4];
+            for channel in 0..4 {
+                let top = p00[channel] as f32 * (1.0 - fx) + p10[channel] as f32 * fx;
+                let bottom = p01[channel] as f32 * (1.0 - fx) + p11[channel] as f32 * fx;
+                rgba[channel] = (top * (1.0 - fy) + bottom * fy).round() as u8;
+            }
+
+            let index = (y as usize * width as usize + x as usize) * bpp;
+            format.encode_rgba(rgba, &mut data[index..index + bpp]);
+        }
+    }
+
+    Ok(scaled)
+}
+
+/// Pair of frames for tear-free presentation
+///
+/// The renderer draws into the back frame while the presenter reads the
+/// front one; [`DoubleBuffer::swap`] exchanges them in constant time.
+#[derive(Debug, Clone)]
+pub struct DoubleBuffer {
+    frames: [Frame; 2],
+    /// Index of the front frame in `frames`
+    front: usize,
+}
+
+impl DoubleBuffer {
+    /// Allocate two `width`x`height` frames in `format`
+    pub fn new(width: u32, height: u32, format: PixelFormat) -> RenderResult<Self> {
+        Ok(Self {
+            frames: [
+                Frame::new(width, height, format)?,
+                Frame::new(width, height, format)?,
+            ],
+            front: 0,
+        })
+    }
+
+    /// Get the frame being presented
+    pub fn front(&self) -> &Frame {
+        &self.frames[self.front]
+    }
+
+    /// Get the frame being rendered into
+    pub fn back_mut(&mut self) -> &mut Frame {
+        &mut self.frames[1 - self.front]
+    }
+
+    /// Present the back frame, making the old front the new back
+    pub fn swap(&mut self) {
+        self.front = 1 - self.front;
+    }
+
+    /// Get the buffer dimensions
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.front().width, self.front().height)
+    }
+
+    /// Get the buffer pixel format
+    pub fn format(&self) -> PixelFormat {
+        self.front().format
+    }
+
+    /// Reallocate both frames at a new size, cleared
+    ///
+    /// Does nothing if the size is unchanged.
+    pub fn resize(&mut self, width: u32, height: u32) -> RenderResult<()> {
+        if self.dimensions() != (width, height) {
+            *self = Self::new(width, height, self.format())?;
+        }
+        Ok(())
+    }
+
+    /// Render a frame with `engine` into the back buffer and present it
+    ///
+    /// If the frame's size or format changed, the old front buffer is
+    /// replaced with a cleared frame to match, since it becomes the next back
+    /// buffer.
+    pub fn render<E: RenderEngine + ?Sized>(
+        &mut self,
+        engine: &mut E,
+        viewport: &Viewport,
+    ) -> RenderResult<&Frame> {
+        let frame = engine.render_frame(viewport)?;
+        let front = self.front();
+        if (front.width, front.height, front.format) != (frame.width, frame.height, frame.format) {
+            self.frames[self.front] = Frame::new(frame.width, frame.height, frame.format)?;
+        }
+        *self.back_mut() = frame;
+        self.swap();
+        Ok(self.front())
+    }
 }
 
 /// Type alias for animation frame callback
@@ -449,6 +989,8 @@ pub struct CompositorLayer {
     pub z_index: i32,
     /// Transform matrix (simplified as scale and translate)
     pub transform: LayerTransform,
+    /// Solid fill color (RGBA) used when compositing
+    pub color: [u8; 4],
 }
 
 /// Layer transform (simplified 2D transform)
@@ -500,6 +1042,44 @@ impl LayerTransform {
         }
     }
 
+    /// Create rotation transform (radians, around the origin)
+    pub fn rotate(radians: f32) -> Self {
+        Self {
+            rotation: radians,
+            ..Self::identity()
+        }
+    }
+
+    /// Apply the transform to a point
+    ///
+    /// Scale is applied first, then rotation around the origin, then
+    /// translation.
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        let (sx, sy) = (x * self.scale_x, y * self.scale_y);
+        let (sin, cos) = self.rotation.sin_cos();
+        (
+            sx * cos - sy * sin + self.translate_x,
+            sx * sin + sy * cos + self.translate_y,
+        )
+    }
+
+    /// Combine two transforms: `self` is applied first, then `other`
+    ///
+    /// The result is exact whenever `other` scales uniformly or `self` has
+    /// no rotation; otherwise the combination would introduce skew, which
+    /// this simplified transform cannot represent, and scale is
+    /// approximated per axis.
+    pub fn compose(&self, other: &LayerTransform) -> LayerTransform {
+        let (translate_x, translate_y) = other.apply(self.translate_x, self.translate_y);
+        LayerTransform {
+            translate_x,
+            translate_y,
+            scale_x: self.scale_x * other.scale_x,
+            scale_y: self.scale_y * other.scale_y,
+            rotation: self.rotation + other.rotation,
+        }
+    }
+
     /// Check if this is an identity transform
     pub fn is_identity(&self) -> bool {
         (self.translate_x - 0.0).abs() < f32::EPSILON
@@ -520,9 +1100,16 @@ impl CompositorLayer {
             visible: true,
             z_index: 0,
             transform: LayerTransform::identity(),
+            color: [255, 255, 255, 255],
         }
     }
 
+    /// Set layer fill color (RGBA)
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
     /// Set layer opacity
     pub fn with_opacity(mut self, opacity: f32) -> Self {
         self.opacity = opacity.clamp(0.0, 1.0);
@@ -542,6 +1129,34 @@ impl CompositorLayer {
     }
 }
 
+/// Optional features supported by a render engine implementation
+///
+/// Returned by [`RenderEngine::capabilities`] so callers can feature-detect
+/// instead of assuming support for optional trait behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineCapabilities {
+    /// Compositor layers are supported
+    pub supports_layers: bool,
+    /// Idle-time callbacks are supported
+    pub supports_idle_callbacks: bool,
+    /// `render_dirty` repaints only dirty regions rather than the full frame
+    pub supports_partial_repaint: bool,
+    /// Largest supported frame/texture dimension in pixels
+    pub max_texture_size: u32,
+}
+
+impl Default for EngineCapabilities {
+    /// Conservative defaults: no optional features and a 4096px texture limit
+    fn default() -> Self {
+        Self {
+            supports_layers: false,
+            supports_idle_callbacks: false,
+            supports_partial_repaint: false,
+            max_texture_size: 4096,
+        }
+    }
+}
+
 /// Render engine trait - abstraction for render engine implementations
 ///
 /// This trait defines the interface that all render engine implementations
@@ -556,6 +1171,19 @@ pub trait RenderEngine: Send + Sync {
     /// A rendered frame containing pixel data
     fn render_frame(&mut self, viewport: &Viewport) -> RenderResult<Frame>;
 
+    /// Render only the pending dirty regions for the given viewport
+    ///
+    /// Implementations that keep a persistent frame buffer should repaint
+    /// only pixels inside the dirty regions and report exactly those
+    /// regions in `Frame::dirty_regions`. The default implementation
+    /// falls back to a full repaint.
+    ///
+    /// # Arguments
+    /// * `viewport` - The viewport configuration for rendering
+    fn render_dirty(&mut self, viewport: &Viewport) -> RenderResult<Frame> {
+        self.render_frame(viewport)
+    }
+
     /// Invalidate a region, marking it for repaint
     ///
     /// # Arguments
@@ -592,6 +1220,13 @@ pub trait RenderEngine: Send + Sync {
 
     /// Check if a repaint is needed
     fn needs_repaint(&self) -> bool;
+
+    /// Report which optional features this engine supports
+    ///
+    /// The default implementation reports conservative defaults.
+    fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities::default()
+    }
 }
 
 /// Mock render engine for testing and placeholder implementation
@@ -607,8 +1242,15 @@ pub struct MockRenderEngine {
     frame_count: Mutex<u64>,
     layers: Mutex<Vec<CompositorLayer>>,
     next_layer_id: Mutex<u64>,
+    output_format: PixelFormat,
+    frame_buffer: Mutex<Option<Frame>>,
+    pixels_painted: AtomicU64,
+    max_dirty_regions: usize,
 }
 
+/// Default number of dirty regions tracked before collapsing to their bounds
+pub const DEFAULT_MAX_DIRTY_REGIONS: usize = 32;
+
 impl MockRenderEngine {
     /// Create a new mock render engine
     pub fn new(width: u32, height: u32) -> Self {
@@ -625,7 +1267,73 @@ impl MockRenderEngine {
             frame_count: Mutex::new(0),
             layers: Mutex::new(Vec::new()),
             next_layer_id: Mutex::new(1),
+            output_format: PixelFormat::Rgba8,
+            frame_buffer: Mutex::new(None),
+            pixels_painted: AtomicU64::new(0),
+            max_dirty_regions: DEFAULT_MAX_DIRTY_REGIONS,
+        }
+    }
+
+    /// Set the dirty region threshold
+    ///
+    /// Once more than `max` regions are pending, they collapse into a single
+    /// bounding rectangle. Values below 1 are treated as 1.
+    pub fn set_max_dirty_regions(&mut self, max: usize) {
+        self.max_dirty_regions = max.max(1);
+        let mut dirty_regions = self.dirty_regions.lock().unwrap();
+        Self::collapse_if_over(&mut dirty_regions, self.max_dirty_regions);
+    }
+
+    /// Get the dirty region threshold
+    pub fn max_dirty_regions(&self) -> usize {
+        self.max_dirty_regions
+    }
+
+    /// Collapse all regions into their bounding union if over `max`
+    fn collapse_if_over(regions: &mut Vec<Rect>, max: usize) {
+        if regions.len() > max {
+            let bounds = regions[1..]
+                .iter()
+                .fold(regions[0], |acc, rect| acc.union(rect));
+            regions.clear();
+            regions.push(bounds);
+        }
+    }
+
+    /// Number of pixels painted by the most recent render
+    pub fn pixels_painted(&self) -> u64 {
+        self.pixels_painted.load(Ordering::SeqCst)
+    }
+
+    /// Fill a region of the frame with the mock gradient pattern
+    fn fill_gradient(frame: &mut Frame, region: &Rect) -> u64 {
+        let format = frame.format;
+        let bpp = format.bytes_per_pixel();
+        let (width, height) = (frame.width, frame.height);
+        let mut pixel = [0u8; 4];
+        let x_end = region.x.max(0) as u32 + region.width;
+        let y_end = region.y.max(0) as u32 + region.height;
+        for y in region.y.max(0) as u32..y_end.min(height) {
+            for x in region.x.max(0) as u32..x_end.min(width) {
+                let r = (x as f32 / width as f32 * 255.0) as u8;
+                let g = (y as f32 / height as f32 * 255.0) as u8;
+                let b = 128u8;
+                let a = 255u8;
+                format.encode_rgba([r, g, b, a], &mut pixel[..bpp]);
+                frame.set_pixel(x, y, &pixel[..bpp]);
+            }
         }
+        region.area()
+    }
+
+    /// Set the pixel format used for rendered frames
+    pub fn set_output_format(&mut self, format: PixelFormat) {
+        self.output_format = format;
+    }
+
+    /// Get the pixel format used for rendered frames
+    pub fn output_format(&self) -> PixelFormat {
+        self.output_format
     }
 
     /// Process pending animation frame callbacks
@@ -696,6 +1404,52 @@ impl MockRenderEngine {
         sorted
     }
 
+    /// Composite all visible layers into `base` in z-order
+    ///
+    /// Each layer is alpha-blended as a solid fill of its `color` over its
+    /// `bounds`, offset by the layer's translation and weighted by its
+    /// opacity. Pixels outside the frame are clipped.
+    pub fn composite_layers(&self, base: &mut Frame) {
+        let frame_rect = Rect::from_size(base.width, base.height);
+        let format = base.format;
+        let bpp = format.bytes_per_pixel();
+        let mut pixel = [0u8; 4];
+
+        for layer in self.get_layers_sorted() {
+            if !layer.visible || layer.opacity <= 0.0 {
+                continue;
+            }
+
+            let target = Rect {
+                x: layer.bounds.x + layer.transform.translate_x.round() as i32,
+                y: layer.bounds.y + layer.transform.translate_y.round() as i32,
+                ..layer.bounds
+            };
+            let Some(clipped) = target.intersection(&frame_rect) else {
+                continue;
+            };
+
+            let alpha = layer.opacity * (layer.color[3] as f32 / 255.0);
+            for y in clipped.y as u32..clipped.y as u32 + clipped.height {
+                for x in clipped.x as u32..clipped.x as u32 + clipped.width {
+                    let dst = match base.get_pixel(x, y) {
+                        Some(p) => format.decode_rgba(p),
+                        None => continue,
+                    };
+                    let mut blended = [0u8; 4];
+                    for c in 0..3 {
+                        let value = layer.color[c] as f32 * alpha + dst[c] as f32 * (1.0 - alpha);
+                        blended[c] = value.round().clamp(0.0, 255.0) as u8;
+                    }
+                    let out_alpha = alpha * 255.0 + dst[3] as f32 * (1.0 - alpha);
+                    blended[3] = out_alpha.round().clamp(0.0, 255.0) as u8;
+                    format.encode_rgba(blended, &mut pixel[..bpp]);
+                    base.set_pixel(x, y, &pixel[..bpp]);
+                }
+            }
+        }
+    }
+
     /// Get current dimensions
     pub fn dimensions(&self) -> (u32, u32) {
         (self.width, self.height)
@@ -712,26 +1466,44 @@ impl RenderEngine for MockRenderEngine {
         if viewport.width == 0 || viewport.height == 0 {
             return Err(RenderError::InvalidViewport(viewport.width, viewport.height));
         }
+        check_zoom(viewport.zoom)?;
 
         let sequence = self.frame_sequence.fetch_add(1, Ordering::SeqCst);
         let timestamp = self.start_time.elapsed();
 
         // Create a mock frame with a simple pattern
-        let mut frame = Frame::new(viewport.width, viewport.height, PixelFormat::Rgba8)?;
+        let format = self.output_format;
+        let (mut frame, painted) = if viewport.zoom == 1.0 {
+            let mut frame = Frame::new(viewport.width, viewport.height, format)?;
+            let painted = Self::fill_gradient(
+                &mut frame,
+                &Rect::from_size(viewport.width, viewport.height),
+            );
+            (frame, painted)
+        } else {
+            // Paint the visible document area, then scale it to the viewport
+            let content_width = (viewport.width as f32 / viewport.zoom).ceil().max(1.0) as u32;
+            let content_height = (viewport.height as f32 / viewport.zoom).ceil().max(1.0) as u32;
+            let mut content = Frame::new(content_width, content_height, format)?;
+            let painted = Self::fill_gradient(
+                &mut content,
+                &Rect::from_size(content_width, content_height),
+            );
+            let scaled = scale_frame(&content, viewport.zoom)?;
+
+            let mut frame = Frame::new(viewport.width, viewport.height, format)?;
+            frame.blit_from(
+                &scaled,
+                Rect::from_size(viewport.width, viewport.height),
+                0,
+                0,
+            )?;
+            (frame, painted)
+        };
         frame.timestamp = timestamp;
         frame.sequence = sequence;
         frame.dirty_regions = self.dirty_regions.lock().unwrap().clone();
-
-        // Fill with a simple gradient pattern (for testing)
-        for y in 0..viewport.height {
-            for x in 0..viewport.width {
-                let r = (x as f32 / viewport.width as f32 * 255.0) as u8;
-                let g = (y as f32 / viewport.height as f32 * 255.0) as u8;
-                let b = 128u8;
-                let a = 255u8;
-                frame.set_pixel(x, y, &[r, g, b, a]);
-            }
-        }
+        self.pixels_painted.store(painted, Ordering::SeqCst);
 
         // Clear dirty regions after rendering
         self.dirty_regions.lock().unwrap().clear();
@@ -741,22 +1513,75 @@ impl RenderEngine for MockRenderEngine {
         Ok(frame)
     }
 
+    fn render_dirty(&mut self, viewport: &Viewport) -> RenderResult<Frame> {
+        if viewport.width == 0 || viewport.height == 0 {
+            return Err(RenderError::InvalidViewport(
+                viewport.width,
+                viewport.height,
+            ));
+        }
+        check_zoom(viewport.zoom)?;
+
+        // Partial repaints aren't scaled; repaint zoomed frames in full
+        if viewport.zoom != 1.0 {
+            *self.frame_buffer.lock().unwrap() = None;
+            return self.render_frame(viewport);
+        }
+
+        let full = Rect::from_size(viewport.width, viewport.height);
+        let format = self.output_format;
+        let mut buffer = self.frame_buffer.lock().unwrap();
+
+        // Reallocate (and repaint fully) when the buffer can't be reused
+        let reusable = buffer.as_ref().is_some_and(|b| {
+            b.width == viewport.width && b.height == viewport.height && b.format == format
+        });
+        let regions: Vec<Rect> = if reusable {
+            self.dirty_regions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(|r| r.intersection(&full))
+                .collect()
+        } else {
+            *buffer = Some(Frame::new(viewport.width, viewport.height, format)?);
+            vec![full]
+        };
+
+        let frame = buffer.as_mut().ok_or(RenderError::AllocationFailed)?;
+        let painted = regions
+            .iter()
+            .map(|region| Self::fill_gradient(frame, region))
+            .sum();
+
+        frame.sequence = self.frame_sequence.fetch_add(1, Ordering::SeqCst);
+        frame.timestamp = self.start_time.elapsed();
+        frame.dirty_regions = regions;
+        // Shares the pixel data; the next repaint copies it only if the
+        // caller still holds this frame
+        let result = frame.clone();
+        drop(buffer);
+
+        self.pixels_painted.store(painted, Ordering::SeqCst);
+        self.dirty_regions.lock().unwrap().clear();
+        *self.frame_count.lock().unwrap() += 1;
+        *self.last_frame_time.lock().unwrap() = Instant::now();
+
+        Ok(result)
+    }
+
     fn invalidate(&mut self, region: Option<Rect>) {
         let mut dirty_regions = self.dirty_regions.lock().unwrap();
         match region {
             Some(rect) => {
-                // Merge with existing dirty regions if overlapping
-                let mut merged = false;
-                for existing in dirty_regions.iter_mut() {
-                    if existing.intersects(&rect) {
-                        *existing = existing.union(&rect);
-                        merged = true;
-                        break;
-                    }
-                }
-                if !merged {
-                    dirty_regions.push(rect);
+                // Absorb every overlapping region, re-checking the grown
+                // rect against the rest so merging is transitive
+                let mut merged = rect;
+                while let Some(index) = dirty_regions.iter().position(|r| r.intersects(&merged)) {
+                    merged = merged.union(&dirty_regions.remove(index));
                 }
+                dirty_regions.push(merged);
+                Self::collapse_if_over(&mut dirty_regions, self.max_dirty_regions);
             }
             None => {
                 // Invalidate entire viewport
@@ -776,7 +1601,10 @@ impl RenderEngine for MockRenderEngine {
     }
 
     fn set_zoom(&mut self, scale: f32) {
-        self.zoom = scale.clamp(0.1, 10.0);
+        if scale.is_nan() {
+            return;
+        }
+        self.zoom = scale.clamp(MIN_ZOOM, MAX_ZOOM);
         // Invalidate when zoom changes
         let mut dirty_regions = self.dirty_regions.lock().unwrap();
         dirty_regions.clear();
@@ -808,32 +1636,322 @@ impl RenderEngine for MockRenderEngine {
         !self.dirty_regions.lock().unwrap().is_empty()
             || !self.animation_callbacks.lock().unwrap().is_empty()
     }
+
+    fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities {
+            supports_layers: true,
+            supports_idle_callbacks: false,
+            supports_partial_repaint: true,
+            max_texture_size: MOCK_MAX_TEXTURE_SIZE,
+        }
+    }
 }
 
+/// Largest frame dimension the mock engine advertises
+const MOCK_MAX_TEXTURE_SIZE: u32 = 16384;
+
 impl Default for MockRenderEngine {
     fn default() -> Self {
         Self::new(800, 600)
     }
 }
 
-/// Frame scheduler for managing render timing
+/// Default tile edge length for [`TiledRenderEngine`], in pixels
+pub const DEFAULT_TILE_SIZE: u32 = 256;
+
+/// Render engine wrapper that renders the viewport in fixed-size tiles
 ///
-/// Helps achieve smooth 60fps rendering by tracking frame timing
-/// and providing vsync-like scheduling hints.
-#[derive(Debug)]
-pub struct FrameScheduler {
-    /// Target frame rate (default: 60fps)
-    target_fps: f32,
-    /// Target frame duration
-    target_frame_duration: Duration,
+/// Invalidations mark the tiles they overlap as dirty, and `render_dirty`
+/// re-renders only those tiles through the wrapped engine, stitching them
+/// into a persistent frame. The dirty tiles are invalidated on the wrapped
+/// engine and repainted in a single `render_dirty` pass over the whole
+/// viewport, so scroll offsets and the wrapped engine's own dirty state
+/// stay consistent across tiles.
+pub struct TiledRenderEngine<E: RenderEngine> {
+    inner: E,
+    width: u32,
+    height: u32,
+    tile_size: u32,
+    /// Dirty flag per tile, row-major
+    dirty_tiles: Vec<bool>,
+    frame_buffer: Option<Frame>,
+    frame_sequence: u64,
+    start_time: Instant,
+    frame_count: u64,
+}
+
+impl<E: RenderEngine> TiledRenderEngine<E> {
+    /// Wrap `inner`, rendering a `width`x`height` surface in
+    /// [`DEFAULT_TILE_SIZE`] tiles
+    pub fn new(inner: E, width: u32, height: u32) -> Self {
+        let mut engine = Self {
+            inner,
+            width,
+            height,
+            tile_size: DEFAULT_TILE_SIZE,
+            dirty_tiles: Vec::new(),
+            frame_buffer: None,
+            frame_sequence: 0,
+            start_time: Instant::now(),
+            frame_count: 0,
+        };
+        engine.reset_tiles();
+        engine
+    }
+
+    /// Use tiles of `tile_size` pixels per edge (at least 1)
+    pub fn with_tile_size(mut self, tile_size: u32) -> Self {
+        self.set_tile_size(tile_size);
+        self
+    }
+
+    /// Change the tile size, marking every tile dirty
+    pub fn set_tile_size(&mut self, tile_size: u32) {
+        self.tile_size = tile_size.max(1);
+        self.reset_tiles();
+    }
+
+    /// Get the tile edge length in pixels
+    pub fn tile_size(&self) -> u32 {
+        self.tile_size
+    }
+
+    /// Get the number of tile columns and rows covering the surface
+    pub fn tile_grid(&self) -> (u32, u32) {
+        (
+            self.width.div_ceil(self.tile_size),
+            self.height.div_ceil(self.tile_size),
+        )
+    }
+
+    /// Number of tiles waiting to be re-rendered
+    pub fn dirty_tile_count(&self) -> usize {
+        self.dirty_tiles.iter().filter(|dirty| **dirty).count()
+    }
+
+    /// Get the bounds of every dirty tile, clipped to the surface
+    pub fn dirty_tiles(&self) -> Vec<Rect> {
+        let (columns, _) = self.tile_grid();
+        self.dirty_tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, dirty)| **dirty)
+            .map(|(index, _)| self.tile_rect(index as u32 % columns, index as u32 / columns))
+            .collect()
+    }
+
+    /// Get the wrapped engine
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Get the wrapped engine mutably
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.inner
+    }
+
+    /// Bounds of the tile at `column`, `row`, clipped to the surface
+    fn tile_rect(&self, column: u32, row: u32) -> Rect {
+        let x = column * self.tile_size;
+        let y = row * self.tile_size;
+        Rect::new(
+            x as i32,
+            y as i32,
+            self.tile_size.min(self.width - x),
+            self.tile_size.min(self.height - y),
+        )
+    }
+
+    /// Render `tiles` through the wrapped engine into the frame buffer
+    ///
+    /// The wrapped engine repaints the tiles in one pass over the full
+    /// viewport, and each tile is copied out of the result. The buffer is
+    /// (re)allocated from the wrapped engine's output when missing or in a
+    /// different format.
+    fn render_tiles(&mut self, viewport: &Viewport, tiles: &[Rect]) -> RenderResult<Frame> {
+        if tiles.is_empty() {
+            if let Some(buffer) = self.frame_buffer.take() {
+                return Ok(buffer);
+            }
+        }
+        for tile in tiles {
+            self.inner.invalidate(Some(*tile));
+        }
+        let rendered = self.inner.render_dirty(viewport)?;
+        let mut buffer = match self.frame_buffer.take() {
+            Some(buffer) if buffer.format == rendered.format => buffer,
+            _ => return Ok(rendered),
+        };
+        for tile in tiles {
+            buffer.blit_from(&rendered, *tile, tile.x, tile.y)?;
+        }
+        Ok(buffer)
+    }
+
+    /// Rebuild the tile grid for the current size, with every tile dirty
+    fn reset_tiles(&mut self) {
+        let (columns, rows) = self.tile_grid();
+        self.dirty_tiles = vec![true; columns as usize * rows as usize];
+        self.frame_buffer = None;
+    }
+
+    /// Mark the tiles overlapping `region` dirty
+    fn mark_dirty(&mut self, region: &Rect) {
+        let Some(region) = region.intersection(&Rect::from_size(self.width, self.height)) else {
+            return;
+        };
+        let (columns, _) = self.tile_grid();
+        let first_column = region.x as u32 / self.tile_size;
+        let last_column = (region.x as u32 + region.width - 1) / self.tile_size;
+        let first_row = region.y as u32 / self.tile_size;
+        let last_row = (region.y as u32 + region.height - 1) / self.tile_size;
+        for row in first_row..=last_row {
+            for column in first_column..=last_column {
+                self.dirty_tiles[(row * columns + column) as usize] = true;
+            }
+        }
+    }
+}
+
+impl<E: RenderEngine> RenderEngine for TiledRenderEngine<E> {
+    fn render_frame(&mut self, viewport: &Viewport) -> RenderResult<Frame> {
+        self.dirty_tiles.fill(true);
+        self.render_dirty(viewport)
+    }
+
+    fn render_dirty(&mut self, viewport: &Viewport) -> RenderResult<Frame> {
+        if viewport.width == 0 || viewport.height == 0 {
+            return Err(RenderError::InvalidViewport(
+                viewport.width,
+                viewport.height,
+            ));
+        }
+        if (viewport.width, viewport.height) != (self.width, self.height) {
+            self.width = viewport.width;
+            self.height = viewport.height;
+            self.reset_tiles();
+        }
+
+        let tiles = self.dirty_tiles();
+        let mut buffer = match self.render_tiles(viewport, &tiles) {
+            Ok(buffer) => buffer,
+            Err(e) => {
+                // The buffer may have been dropped mid-copy
+                self.reset_tiles();
+                return Err(e);
+            }
+        };
+        self.dirty_tiles.fill(false);
+
+        buffer.sequence = self.frame_sequence;
+        buffer.timestamp = self.start_time.elapsed();
+        buffer.dirty_regions = tiles;
+        self.frame_sequence += 1;
+        self.frame_count += 1;
+
+        // Shares the pixel data with the buffer, see `Frame::data`
+        let frame = buffer.clone();
+        self.frame_buffer = Some(buffer);
+        Ok(frame)
+    }
+
+    fn invalidate(&mut self, region: Option<Rect>) {
+        match region {
+            Some(rect) => self.mark_dirty(&rect),
+            None => self.dirty_tiles.fill(true),
+        }
+        self.inner.invalidate(region);
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        self.reset_tiles();
+        self.inner.resize(width, height);
+    }
+
+    fn set_zoom(&mut self, scale: f32) {
+        self.dirty_tiles.fill(true);
+        self.inner.set_zoom(scale);
+    }
+
+    fn request_animation_frame(&mut self, callback: FrameCallback) {
+        self.inner.request_animation_frame(callback);
+    }
+
+    fn get_frame_rate(&self) -> f32 {
+        let elapsed = self.start_time.elapsed().as_secs_f32();
+        if elapsed > 0.0 {
+            self.frame_count as f32 / elapsed
+        } else {
+            0.0
+        }
+    }
+
+    fn get_dirty_regions(&self) -> Vec<Rect> {
+        self.dirty_tiles()
+    }
+
+    fn clear_dirty_regions(&mut self) {
+        self.dirty_tiles.fill(false);
+        self.inner.clear_dirty_regions();
+    }
+
+    fn needs_repaint(&self) -> bool {
+        self.dirty_tile_count() > 0 || self.inner.needs_repaint()
+    }
+
+    fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities {
+            supports_partial_repaint: true,
+            ..self.inner.capabilities()
+        }
+    }
+}
+
+/// Frame scheduler for managing render timing
+///
+/// Helps achieve smooth 60fps rendering by tracking frame timing
+/// and providing vsync-like scheduling hints.
+#[derive(Debug)]
+pub struct FrameScheduler {
+    /// Target frame rate (default: 60fps)
+    target_fps: f32,
+    /// Target frame duration
+    target_frame_duration: Duration,
     /// Last frame timestamp
     last_frame: Instant,
     /// Frame time history for averaging
     frame_times: VecDeque<Duration>,
     /// Maximum history size
     max_history: usize,
+    /// Frames begun while the scheduler was overrun
+    dropped_frames: u64,
 }
 
+/// Frame time distribution over a [`FrameScheduler`]'s history window
+///
+/// Percentiles use the nearest-rank method. All durations are zero when no
+/// frames have been recorded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Median frame time
+    pub p50: Duration,
+    /// 95th percentile frame time
+    pub p95: Duration,
+    /// 99th percentile frame time
+    pub p99: Duration,
+    /// Shortest frame time
+    pub min: Duration,
+    /// Longest frame time
+    pub max: Duration,
+    /// Number of frames in the window
+    pub count: usize,
+}
+
+/// Average frame time, as a multiple of the target, above which frames are skipped
+const FRAME_SKIP_THRESHOLD: f32 = 1.5;
+
 impl FrameScheduler {
     /// Create a new frame scheduler targeting 60fps
     pub fn new() -> Self {
@@ -848,6 +1966,7 @@ impl FrameScheduler {
             last_frame: Instant::now(),
             frame_times: VecDeque::with_capacity(60),
             max_history: 60,
+            dropped_frames: 0,
         }
     }
 
@@ -857,13 +1976,36 @@ impl FrameScheduler {
         let delta = now - self.last_frame;
         self.last_frame = now;
 
-        // Track frame time
-        self.frame_times.push_back(delta);
+        if self.should_skip_frame() {
+            self.dropped_frames += 1;
+        }
+        self.record_frame_time(delta);
+
+        delta
+    }
+
+    /// Record a frame duration in the timing history
+    ///
+    /// `begin_frame` calls this with the measured delta; it can also be used
+    /// to feed externally measured frame times.
+    pub fn record_frame_time(&mut self, duration: Duration) {
+        self.frame_times.push_back(duration);
         if self.frame_times.len() > self.max_history {
             self.frame_times.pop_front();
         }
+    }
 
-        delta
+    /// Check if the caller should drop a frame to catch up
+    ///
+    /// Returns true when the average frame time exceeds 1.5x the target
+    /// frame duration.
+    pub fn should_skip_frame(&self) -> bool {
+        self.average_frame_time() > self.target_frame_duration.mul_f32(FRAME_SKIP_THRESHOLD)
+    }
+
+    /// Number of frames begun while the scheduler was overrun
+    pub fn dropped_frame_count(&self) -> u64 {
+        self.dropped_frames
     }
 
     /// Get time remaining until next frame should start
@@ -876,6 +2018,17 @@ impl FrameScheduler {
         }
     }
 
+    /// Sleep until the next frame is due
+    ///
+    /// Returns immediately if a frame is already due. Use `should_render`
+    /// instead for polling-style loops.
+    pub async fn wait_for_next_frame(&self) {
+        let remaining = self.time_until_next_frame();
+        if !remaining.is_zero() {
+            tokio::time::sleep(remaining).await;
+        }
+    }
+
     /// Check if it's time for the next frame
     pub fn should_render(&self) -> bool {
         self.last_frame.elapsed() >= self.target_frame_duration
@@ -890,6 +2043,29 @@ impl FrameScheduler {
         total / self.frame_times.len() as u32
     }
 
+    /// Get percentile frame-time stats over recent history
+    pub fn stats(&self) -> FrameStats {
+        // Sort a copy so the history keeps its arrival order
+        let mut sorted: Vec<Duration> = self.frame_times.iter().copied().collect();
+        sorted.sort_unstable();
+
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return FrameStats::default();
+        };
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+        FrameStats {
+            p50: percentile(50.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+            min,
+            max,
+            count: sorted.len(),
+        }
+    }
+
     /// Get current FPS based on recent history
     pub fn current_fps(&self) -> f32 {
         let avg = self.average_frame_time();
@@ -926,6 +2102,7 @@ impl Default for FrameScheduler {
 /// when integrating with an actual DOM engine (e.g., Servo, WebKit).
 pub mod dom {
     use serde::{Deserialize, Serialize};
+    use std::collections::{HashMap, HashSet};
 
     /// Unique identifier for a DOM node
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -1105,10 +2282,12 @@ pub mod dom {
         /// Get computed style for a node
         fn get_computed_style(&self, id: NodeId) -> Option<ComputedStyle>;
 
-        /// Query nodes by CSS selector (stub - returns empty vec)
+        /// Query the first node matching a CSS selector, in document order
+        ///
+        /// Returns at most one node; empty if nothing matches.
         fn query_selector(&self, selector: &str) -> Vec<NodeId>;
 
-        /// Query all matching nodes by CSS selector (stub - returns empty vec)
+        /// Query all nodes matching a CSS selector, in document order
         fn query_selector_all(&self, selector: &str) -> Vec<NodeId>;
 
         /// Get element by ID
@@ -1123,77 +2302,214 @@ pub mod dom {
 
     /// Stub DOM integration implementation for testing
     ///
-    /// This implementation returns empty/default values for all operations.
-    /// It serves as a placeholder until a real DOM engine is integrated.
+    /// Backed by an in-memory node tree with parent/child links, supporting
+    /// ID, class, and tag lookups plus minimal `query_selector` matching
+    /// (tag, `#id`, `.class`, and compounds such as `div.card#main`). It
+    /// serves as a placeholder until a real DOM engine is integrated.
     #[derive(Debug, Default)]
     pub struct StubDomIntegration {
-        /// Mock document root node
-        root: Option<DomNode>,
+        /// All nodes, keyed by ID
+        nodes: HashMap<NodeId, DomNode>,
+        /// Document root node
+        root: Option<NodeId>,
     }
 
     impl StubDomIntegration {
         /// Create a new stub DOM integration
         pub fn new() -> Self {
-            Self { root: None }
+            Self::default()
         }
 
         /// Create a stub integration with a mock document
         pub fn with_mock_document() -> Self {
-            let root = DomNode::element(
+            Self::from_nodes(vec![DomNode::element(
                 NodeId::new(1),
                 ElementData::new("html"),
-            );
-            Self { root: Some(root) }
+            )])
+        }
+
+        /// Build a tree from a list of nodes
+        ///
+        /// Links are taken from both `children` and `parent`, and made
+        /// consistent in both directions. The root is the first node
+        /// without a parent.
+        pub fn from_nodes(nodes: Vec<DomNode>) -> Self {
+            let root = nodes.iter().find(|n| n.parent.is_none()).map(|n| n.id);
+            let mut links = Vec::new();
+            for node in &nodes {
+                links.extend(node.children.iter().map(|&child| (node.id, child)));
+                if let Some(parent) = node.parent {
+                    links.push((parent, node.id));
+                }
+            }
+
+            let mut map: HashMap<NodeId, DomNode> = nodes.into_iter().map(|n| (n.id, n)).collect();
+            for (parent, child) in links {
+                if let Some(node) = map.get_mut(&child) {
+                    node.parent = Some(parent);
+                }
+                if let Some(node) = map.get_mut(&parent) {
+                    if !node.children.contains(&child) {
+                        node.children.push(child);
+                    }
+                }
+            }
+
+            Self { nodes: map, root }
+        }
+
+        /// Element nodes in document (depth-first) order
+        ///
+        /// Each node is visited once, so malformed links (a node reachable
+        /// through several parents, or a cycle) can't loop forever.
+        fn elements_in_order(&self) -> Vec<&DomNode> {
+            let mut result = Vec::new();
+            let mut visited = HashSet::new();
+            let mut stack: Vec<NodeId> = self.root.into_iter().collect();
+            while let Some(id) = stack.pop() {
+                if !visited.insert(id) {
+                    continue;
+                }
+                if let Some(node) = self.nodes.get(&id) {
+                    if node.is_element() {
+                        result.push(node);
+                    }
+                    stack.extend(node.children.iter().rev());
+                }
+            }
+            result
+        }
+
+        /// IDs of elements matching a predicate, in document order
+        fn find_elements(&self, predicate: impl Fn(&DomNode) -> bool) -> Vec<NodeId> {
+            self.elements_in_order()
+                .into_iter()
+                .filter(|n| predicate(n))
+                .map(|n| n.id)
+                .collect()
         }
     }
 
-    impl DomIntegration for StubDomIntegration {
-        fn get_node(&self, id: NodeId) -> Option<DomNode> {
-            if let Some(ref root) = self.root {
-                if root.id == id {
-                    return Some(root.clone());
+    /// A parsed compound selector such as `div.card#main`
+    #[derive(Debug, Default)]
+    struct SimpleSelector<'a> {
+        tag: Option<&'a str>,
+        id: Option<&'a str>,
+        classes: Vec<&'a str>,
+    }
+
+    impl<'a> SimpleSelector<'a> {
+        /// Parse a selector, returning `None` for unsupported syntax
+        fn parse(selector: &'a str) -> Option<Self> {
+            let selector = selector.trim();
+            if selector.is_empty() {
+                return None;
+            }
+
+            let mut parsed = SimpleSelector::default();
+            let tag_end = selector.find(['#', '.']).unwrap_or(selector.len());
+            let tag = &selector[..tag_end];
+            if !tag.is_empty() && tag != "*" {
+                parsed.tag = Some(tag);
+            }
+
+            let mut rest = &selector[tag_end..];
+            while let Some(prefix) = rest.chars().next() {
+                let end = rest[1..].find(['#', '.']).map_or(rest.len(), |i| i + 1);
+                let name = &rest[1..end];
+                if name.is_empty() {
+                    return None;
                 }
+                match prefix {
+                    '#' => parsed.id = Some(name),
+                    _ => parsed.classes.push(name),
+                }
+                rest = &rest[end..];
             }
-            None
+
+            let valid = |s: &str| {
+                s.chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+            };
+            let names = parsed
+                .tag
+                .iter()
+                .chain(parsed.id.iter())
+                .chain(parsed.classes.iter());
+            names.copied().all(valid).then_some(parsed)
+        }
+
+        /// Check whether an element node matches
+        fn matches(&self, node: &DomNode) -> bool {
+            let Some(data) = node.element_data.as_ref() else {
+                return false;
+            };
+            self.tag
+                .is_none_or(|t| data.tag_name.eq_ignore_ascii_case(t))
+                && self
+                    .id
+                    .is_none_or(|id| data.get_attribute("id") == Some(id))
+                && self.classes.iter().all(|c| has_class(data, c))
+        }
+    }
+
+    /// Check whether an element's `class` attribute contains `class_name`
+    fn has_class(data: &ElementData, class_name: &str) -> bool {
+        data.get_attribute("class")
+            .is_some_and(|classes| classes.split_whitespace().any(|c| c == class_name))
+    }
+
+    impl DomIntegration for StubDomIntegration {
+        fn get_node(&self, id: NodeId) -> Option<DomNode> {
+            self.nodes.get(&id).cloned()
         }
 
         fn get_document_root(&self) -> Option<NodeId> {
-            self.root.as_ref().map(|n| n.id)
+            self.root
         }
 
-        fn get_computed_style(&self, _id: NodeId) -> Option<ComputedStyle> {
-            // Return default computed style for any node
-            Some(ComputedStyle::new())
+        fn get_computed_style(&self, id: NodeId) -> Option<ComputedStyle> {
+            // Default computed style for any known node
+            self.nodes.contains_key(&id).then(ComputedStyle::new)
         }
 
-        fn query_selector(&self, _selector: &str) -> Vec<NodeId> {
-            // Stub: CSS selector queries not implemented
-            Vec::new()
+        fn query_selector(&self, selector: &str) -> Vec<NodeId> {
+            self.query_selector_all(selector)
+                .into_iter()
+                .take(1)
+                .collect()
         }
 
-        fn query_selector_all(&self, _selector: &str) -> Vec<NodeId> {
-            // Stub: CSS selector queries not implemented
-            Vec::new()
+        fn query_selector_all(&self, selector: &str) -> Vec<NodeId> {
+            match SimpleSelector::parse(selector) {
+                Some(parsed) => self.find_elements(|n| parsed.matches(n)),
+                None => Vec::new(),
+            }
         }
 
-        fn get_element_by_id(&self, _id: &str) -> Option<NodeId> {
-            // Stub: ID queries not implemented
-            None
+        fn get_element_by_id(&self, id: &str) -> Option<NodeId> {
+            self.find_elements(|n| {
+                n.element_data
+                    .as_ref()
+                    .is_some_and(|d| d.get_attribute("id") == Some(id))
+            })
+            .first()
+            .copied()
         }
 
-        fn get_elements_by_class_name(&self, _class_name: &str) -> Vec<NodeId> {
-            // Stub: class name queries not implemented
-            Vec::new()
+        fn get_elements_by_class_name(&self, class_name: &str) -> Vec<NodeId> {
+            self.find_elements(|n| {
+                n.element_data
+                    .as_ref()
+                    .is_some_and(|d| has_class(d, class_name))
+            })
         }
 
         fn get_elements_by_tag_name(&self, tag_name: &str) -> Vec<NodeId> {
-            // Return root if it matches the tag name
-            if let Some(ref root) = self.root {
-                if root.tag_name() == Some(tag_name) {
-                    return vec![root.id];
-                }
-            }
-            Vec::new()
+            self.find_elements(|n| {
+                n.tag_name()
+                    .is_some_and(|t| tag_name == "*" || t.eq_ignore_ascii_case(tag_name))
+            })
         }
     }
 }
@@ -1322,6 +2638,124 @@ mod tests {
         assert_eq!(vp.scroll_y, 200);
     }
 
+    #[test]
+    fn test_viewport_elastic_overscroll() {
+        let mut vp = Viewport::new(800, 600);
+        vp.set_overscroll(100.0);
+
+        // Within the allowance past the top-left edge
+        vp.set_scroll_elastic(-60, -120);
+        assert_eq!(vp.scroll_x, -60);
+        assert_eq!(vp.scroll_y, -100);
+        assert!(vp.is_overscrolled());
+
+        // Settling moves back toward bounds and eventually stops
+        assert!(vp.settle_overscroll());
+        assert!(vp.scroll_x > -60 && vp.scroll_x <= 0);
+        assert!(vp.scroll_y > -100 && vp.scroll_y <= 0);
+
+        let mut steps = 0;
+        while vp.settle_overscroll() {
+            steps += 1;
+            assert!(steps < 100, "settling did not converge");
+        }
+        assert_eq!((vp.scroll_x, vp.scroll_y), (0, 0));
+        assert!(!vp.settle_overscroll());
+    }
+
+    #[test]
+    fn test_viewport_clamp_scroll_to_content() {
+        let mut vp = Viewport::new(800, 600);
+        vp.set_content_size(2000, 3000);
+
+        vp.set_scroll(5000, 5000);
+        assert_eq!(vp.scroll_x, 1200);
+        assert_eq!(vp.scroll_y, 2400);
+
+        vp.set_scroll(-50, -10);
+        assert_eq!((vp.scroll_x, vp.scroll_y), (0, 0));
+
+        // Zooming in shows less content, so more can be scrolled
+        vp.set_zoom(2.0).unwrap();
+        vp.set_scroll(5000, 0);
+        assert_eq!(vp.scroll_x, 1600);
+    }
+
+    #[test]
+    fn test_viewport_content_smaller_than_viewport() {
+        let mut vp = Viewport::new(800, 600);
+        vp.scroll_x = 40;
+        vp.set_content_size(500, 400);
+        assert_eq!((vp.scroll_x, vp.scroll_y), (0, 0));
+    }
+
+    #[test]
+    fn test_viewport_elastic_overscroll_past_content_end() {
+        let mut vp = Viewport::new(800, 600);
+        vp.set_content_size(2000, 600);
+        vp.set_overscroll(50.0);
+
+        vp.set_scroll_elastic(1230, 0);
+        assert_eq!(vp.scroll_x, 1230);
+        assert!(vp.is_overscrolled());
+
+        while vp.settle_overscroll() {}
+        assert_eq!(vp.scroll_x, 1200);
+    }
+
+    #[test]
+    fn test_viewport_elastic_without_overscroll_clamps() {
+        let mut vp = Viewport::new(800, 600);
+        vp.set_scroll_elastic(-50, 30);
+        assert_eq!(vp.scroll_x, 0);
+        assert_eq!(vp.scroll_y, 30);
+        assert!(!vp.is_overscrolled());
+    }
+
+    #[test]
+    fn test_scroll_animator_interpolates_with_easing() {
+        let mut animator = ScrollAnimator::new();
+        assert!(!animator.is_animating());
+        let start = Instant::now();
+        let duration = Duration::from_millis(200);
+        animator.start((0, 0), (100, 400), duration, start);
+
+        assert_eq!(animator.position_at(start), Some((0, 0)));
+        // Ease-out: past the linear midpoint halfway through
+        let (x, y) = animator.tick(start + duration / 2).unwrap();
+        assert!(x > 50 && y > 200, "{:?}", (x, y));
+        assert!(x < 100 && y < 400);
+        assert!(animator.is_animating());
+
+        assert_eq!(animator.tick(start + duration), Some((100, 400)));
+        assert!(!animator.is_animating());
+        assert_eq!(animator.tick(start + duration * 2), None);
+    }
+
+    #[test]
+    fn test_scroll_animator_applies_to_viewport_and_cancels() {
+        let mut viewport = Viewport::new(800, 600);
+        viewport.set_scroll(10, 20);
+        let mut animator = ScrollAnimator::new();
+        let start = Instant::now();
+        let duration = Duration::from_millis(100);
+        animator.start((10, 20), (10, 520), duration, start);
+        assert_eq!(animator.target(), Some((10, 520)));
+
+        assert!(animator.apply(&mut viewport, start + duration / 4));
+        let partway = viewport.scroll_y;
+        assert!(partway > 20 && partway < 520);
+
+        animator.cancel();
+        assert!(!animator.apply(&mut viewport, start + duration));
+        assert_eq!(viewport.scroll_y, partway);
+
+        // A zero duration jumps straight to the target
+        animator.start((10, partway), (0, 0), Duration::ZERO, start);
+        assert!(!animator.apply(&mut viewport, start));
+        assert_eq!((viewport.scroll_x, viewport.scroll_y), (0, 0));
+    }
+
     #[test]
     fn test_viewport_set_zoom() {
         let mut vp = Viewport::new(800, 600);
@@ -1438,6 +2872,322 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_scale_frame_bilinear_upscale() {
+        let data = [
+            [0, 0, 0, 255],
+            [200, 0, 0, 255],
+            [0, 100, 0, 255],
+            [200, 100, 40, 255],
+        ]
+        .concat();
+        let frame = Frame::from_data(2, 2, PixelFormat::Rgba8, data).unwrap();
+
+        let scaled = scale_frame(&frame, 2.0).unwrap();
+        assert_eq!((scaled.width, scaled.height), (4, 4));
+
+        // Corners keep the source colors
+        assert_eq!(scaled.get_pixel(0, 0).unwrap(), &[0, 0, 0, 255]);
+        assert_eq!(scaled.get_pixel(3, 0).unwrap(), &[200, 0, 0, 255]);
+        assert_eq!(scaled.get_pixel(0, 3).unwrap(), &[0, 100, 0, 255]);
+        assert_eq!(scaled.get_pixel(3, 3).unwrap(), &[200, 100, 40, 255]);
+
+        // Edge pixels between corners are interpolated along the edge
+        assert_eq!(scaled.get_pixel(1, 0).unwrap(), &[50, 0, 0, 255]);
+        assert_eq!(scaled.get_pixel(2, 0).unwrap(), &[150, 0, 0, 255]);
+        assert_eq!(scaled.get_pixel(0, 1).unwrap(), &[0, 25, 0, 255]);
+        assert_eq!(scaled.get_pixel(3, 2).unwrap(), &[200, 75, 30, 255]);
+    }
+
+    #[test]
+    fn test_scale_frame_formats_and_errors() {
+        for format in [
+            PixelFormat::Rgba8,
+            PixelFormat::Bgra8,
+            PixelFormat::Rgb8,
+            PixelFormat::Rgb565,
+        ] {
+            let mut pixel = vec![0u8; format.bytes_per_pixel()];
+            format.encode_rgba([248, 252, 0, 255], &mut pixel);
+            let frame = Frame::from_data(4, 2, format, pixel.repeat(8)).unwrap();
+
+            let scaled = scale_frame(&frame, 0.5).unwrap();
+            assert_eq!((scaled.width, scaled.height, scaled.format), (2, 1, format));
+            assert_eq!(scaled.get_pixel(1, 0).unwrap(), pixel.as_slice());
+        }
+
+        let frame = Frame::new(2, 2, PixelFormat::Rgba8).unwrap();
+        for zoom in [0.0, -1.0, 0.01, 100.0, f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                scale_frame(&frame, zoom),
+                Err(RenderError::InvalidZoom(_))
+            ));
+        }
+        assert_eq!(scale_frame(&frame, MIN_ZOOM).unwrap().width, 1);
+    }
+
+    #[test]
+    fn test_mock_engine_rejects_invalid_viewport_zoom() {
+        let mut engine = MockRenderEngine::new(80, 60);
+        for zoom in [0.0, f32::NAN, f32::INFINITY, 1000.0] {
+            let viewport = Viewport {
+                zoom,
+                ..Viewport::new(80, 60)
+            };
+            assert!(matches!(
+                engine.render_frame(&viewport),
+                Err(RenderError::InvalidZoom(_))
+            ));
+            assert!(matches!(
+                engine.render_dirty(&viewport),
+                Err(RenderError::InvalidZoom(_))
+            ));
+        }
+
+        let mut viewport = Viewport::new(80, 60);
+        assert!(viewport.set_zoom(0.0).is_err());
+        assert!(viewport.set_zoom(f32::NAN).is_err());
+        assert_eq!(viewport.zoom, 1.0);
+
+        engine.set_zoom(f32::NAN);
+        assert_eq!(engine.current_zoom(), 1.0);
+        engine.set_zoom(0.0);
+        assert_eq!(engine.current_zoom(), MIN_ZOOM);
+    }
+
+    #[test]
+    fn test_mock_engine_applies_viewport_zoom() {
+        let mut engine = MockRenderEngine::new(80, 60);
+        let mut viewport = Viewport::new(80, 60);
+        viewport.set_zoom(2.0).unwrap();
+
+        let zoomed = engine.render_frame(&viewport).unwrap();
+        assert_eq!((zoomed.width, zoomed.height), (80, 60));
+        // Only the visible half of the document is painted
+        assert_eq!(engine.pixels_painted(), 40 * 30);
+
+        let half = engine.render_frame(&Viewport::new(40, 30)).unwrap();
+        assert_eq!(zoomed.data, scale_frame(&half, 2.0).unwrap().data);
+        assert_eq!(engine.render_dirty(&viewport).unwrap().data, zoomed.data);
+    }
+
+    #[test]
+    fn test_frame_convert_round_trip() {
+        let mut frame = Frame::new(2, 1, PixelFormat::Rgba8).unwrap();
+        frame.set_pixel(0, 0, &[10, 20, 30, 255]);
+        frame.set_pixel(1, 0, &[200, 100, 50, 128]);
+
+        let bgra = frame.convert(PixelFormat::Bgra8).unwrap();
+        assert_eq!(bgra.get_pixel(0, 0).unwrap(), &[30, 20, 10, 255]);
+
+        let back = bgra.convert(PixelFormat::Rgba8).unwrap();
+        assert_eq!(back.data, frame.data);
+
+        let rgb = frame.convert(PixelFormat::Rgb8).unwrap();
+        assert_eq!(rgb.get_pixel(1, 0).unwrap(), &[200, 100, 50]);
+    }
+
+    #[test]
+    fn test_frame_convert_rgb565_quantization() {
+        let mut frame = Frame::new(1, 1, PixelFormat::Rgba8).unwrap();
+        frame.set_pixel(0, 0, &[255, 255, 255, 255]);
+        let converted = frame.convert(PixelFormat::Rgb565).unwrap();
+        assert_eq!(converted.get_pixel(0, 0).unwrap(), &0xffffu16.to_le_bytes());
+
+        frame.set_pixel(0, 0, &[0b1000_0111, 0b0100_0011, 0b0000_1111, 255]);
+        let converted = frame.convert(PixelFormat::Rgb565).unwrap();
+        let value = u16::from_le_bytes([converted.data[0], converted.data[1]]);
+        assert_eq!(value >> 11, 0b10000);
+        assert_eq!((value >> 5) & 0x3f, 0b010000);
+        assert_eq!(value & 0x1f, 0b00001);
+    }
+
+    #[test]
+    fn test_frame_convert_dithered_gradient() {
+        let (width, height) = (256, 8);
+        let mut frame = Frame::new(width, height, PixelFormat::Rgba8).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                let v = x as u8;
+                frame.set_pixel(x, y, &[v, v, v, 255]);
+            }
+        }
+
+        let plain = frame
+            .convert_dithered(PixelFormat::Rgb565, DitherMode::None)
+            .unwrap();
+        let dithered = frame
+            .convert_dithered(PixelFormat::Rgb565, DitherMode::Ordered)
+            .unwrap();
+
+        assert_eq!((dithered.width, dithered.height), (width, height));
+        assert_eq!(dithered.format, PixelFormat::Rgb565);
+        assert_eq!(dithered.data.len(), (width * height * 2) as usize);
+
+        let distinct = |f: &Frame| {
+            f.data
+                .chunks(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        };
+        assert!(distinct(&dithered) > distinct(&plain));
+    }
+
+    /// Build an RGBA frame where each pixel encodes its own coordinates
+    fn coordinate_frame(width: u32, height: u32) -> Frame {
+        let mut frame = Frame::new(width, height, PixelFormat::Rgba8).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                frame.set_pixel(x, y, &[x as u8, y as u8, 0, 255]);
+            }
+        }
+        frame
+    }
+
+    #[test]
+    fn test_frame_encode_png_round_trip() {
+        let mut engine = MockRenderEngine::new(64, 32);
+        let frame = engine.render_frame(&Viewport::new(64, 32)).unwrap();
+
+        let png = frame.encode_png().unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+            .unwrap()
+            .to_rgba8();
+        assert_eq!(decoded.dimensions(), (64, 32));
+        assert_eq!(
+            decoded.get_pixel(40, 20).0,
+            <[u8; 4]>::try_from(frame.get_pixel(40, 20).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_frame_encode_png_converts_bgra_and_rgb565() {
+        let mut rgba = Frame::new(4, 4, PixelFormat::Rgba8).unwrap();
+        rgba.set_pixel(1, 2, &[255, 0, 0, 255]);
+
+        for format in [PixelFormat::Bgra8, PixelFormat::Rgb565] {
+            let frame = rgba.convert(format).unwrap();
+            let png = frame.encode_png().unwrap();
+            let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+
+            assert_eq!(decoded.dimensions(), (4, 4));
+            assert_eq!(decoded.get_pixel(1, 2).0, [255, 0, 0, 255]);
+        }
+    }
+
+    #[test]
+    fn test_frame_blit_full_copy() {
+        let src = coordinate_frame(10, 10);
+        let mut dst = Frame::new(10, 10, PixelFormat::Rgba8).unwrap();
+
+        dst.blit_from(&src, Rect::from_size(10, 10), 0, 0).unwrap();
+
+        assert_eq!(dst.data, src.data);
+    }
+
+    #[test]
+    fn test_frame_blit_region_offset() {
+        let src = coordinate_frame(10, 10);
+        let mut dst = Frame::new(20, 20, PixelFormat::Rgba8).unwrap();
+
+        dst.blit_from(&src, Rect::new(2, 3, 4, 4), 10, 12).unwrap();
+
+        assert_eq!(dst.get_pixel(10, 12).unwrap(), &[2, 3, 0, 255]);
+        assert_eq!(dst.get_pixel(13, 15).unwrap(), &[5, 6, 0, 255]);
+        assert_eq!(dst.get_pixel(14, 12).unwrap(), &[0, 0, 0, 0]);
+        assert_eq!(dst.get_pixel(9, 12).unwrap(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_frame_blit_clipped_at_edges() {
+        let src = coordinate_frame(10, 10);
+        let mut dst = Frame::new(10, 10, PixelFormat::Rgba8).unwrap();
+
+        // Past the right/bottom edge of the destination
+        dst.blit_from(&src, Rect::from_size(10, 10), 7, 8).unwrap();
+        assert_eq!(dst.get_pixel(7, 8).unwrap(), &[0, 0, 0, 255]);
+        assert_eq!(dst.get_pixel(9, 9).unwrap(), &[2, 1, 0, 255]);
+        assert_eq!(dst.get_pixel(6, 8).unwrap(), &[0, 0, 0, 0]);
+
+        // Negative destination and a source rect hanging off the source
+        let mut dst = Frame::new(10, 10, PixelFormat::Rgba8).unwrap();
+        dst.blit_from(&src, Rect::new(-2, 5, 6, 10), -1, 0).unwrap();
+        assert_eq!(dst.get_pixel(1, 0).unwrap(), &[0, 5, 0, 255]);
+        assert_eq!(dst.get_pixel(4, 4).unwrap(), &[3, 9, 0, 255]);
+        assert_eq!(dst.get_pixel(0, 0).unwrap(), &[0, 0, 0, 0]);
+        assert_eq!(dst.get_pixel(1, 5).unwrap(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_frame_blit_format_mismatch() {
+        let src = Frame::new(4, 4, PixelFormat::Bgra8).unwrap();
+        let mut dst = Frame::new(4, 4, PixelFormat::Rgba8).unwrap();
+
+        let result = dst.blit_from(&src, Rect::from_size(4, 4), 0, 0);
+
+        assert!(matches!(
+            result,
+            Err(RenderError::FormatMismatch(
+                PixelFormat::Rgba8,
+                PixelFormat::Bgra8
+            ))
+        ));
+    }
+
+    // ==================== DoubleBuffer Tests ====================
+
+    fn fill(frame: &mut Frame, rgba: [u8; 4]) {
+        for pixel in frame.data_mut().chunks_mut(4) {
+            pixel.copy_from_slice(&rgba);
+        }
+    }
+
+    #[test]
+    fn test_double_buffer_swap() {
+        let mut buffer = DoubleBuffer::new(4, 4, PixelFormat::Rgba8).unwrap();
+        let red = [255, 0, 0, 255];
+        let blue = [0, 0, 255, 255];
+
+        fill(buffer.back_mut(), red);
+        // Writes to the back buffer are invisible until the swap
+        assert_eq!(buffer.front().get_pixel(0, 0), Some(&[0, 0, 0, 0][..]));
+        buffer.swap();
+        assert_eq!(buffer.front().get_pixel(3, 3), Some(&red[..]));
+
+        fill(buffer.back_mut(), blue);
+        assert_eq!(buffer.front().get_pixel(3, 3), Some(&red[..]));
+        buffer.swap();
+        assert_eq!(buffer.front().get_pixel(3, 3), Some(&blue[..]));
+        // The previous front is now the back buffer
+        assert_eq!(buffer.back_mut().get_pixel(0, 0), Some(&red[..]));
+    }
+
+    #[test]
+    fn test_double_buffer_resize_and_render() {
+        let mut buffer = DoubleBuffer::new(4, 4, PixelFormat::Rgba8).unwrap();
+        buffer.resize(8, 2).unwrap();
+        assert_eq!(buffer.dimensions(), (8, 2));
+        assert_eq!(buffer.back_mut().size(), 8 * 2 * 4);
+        assert!(buffer.resize(0, 2).is_err());
+
+        let mut engine = MockRenderEngine::new(16, 16);
+        let first = buffer
+            .render(&mut engine, &Viewport::new(16, 16))
+            .unwrap()
+            .sequence;
+        let front = buffer.render(&mut engine, &Viewport::new(16, 16)).unwrap();
+        assert_eq!((front.width, front.height), (16, 16));
+        assert_eq!(front.sequence, first + 1);
+
+        engine.set_output_format(PixelFormat::Rgb565);
+        buffer.render(&mut engine, &Viewport::new(16, 16)).unwrap();
+        assert_eq!(buffer.format(), PixelFormat::Rgb565);
+        assert_eq!(buffer.back_mut().format, PixelFormat::Rgb565);
+    }
+
     // ==================== LayerTransform Tests ====================
 
     #[test]
@@ -1462,6 +3212,46 @@ mod tests {
         assert_eq!(transform.scale_y, 2.0);
     }
 
+    #[test]
+    fn test_layer_transform_apply() {
+        let (x, y) = LayerTransform::identity().apply(3.0, 4.0);
+        assert_eq!((x, y), (3.0, 4.0));
+
+        let (x, y) = LayerTransform::translate(10.0, -5.0).apply(3.0, 4.0);
+        assert_eq!((x, y), (13.0, -1.0));
+
+        let (x, y) = LayerTransform::rotate(std::f32::consts::FRAC_PI_2).apply(1.0, 0.0);
+        assert!(x.abs() < 1e-6);
+        assert!((y - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_layer_transform_compose() {
+        let t = LayerTransform {
+            translate_x: 5.0,
+            translate_y: 7.0,
+            scale_x: 2.0,
+            scale_y: 3.0,
+            rotation: 0.0,
+        };
+        assert_eq!(t.compose(&LayerTransform::identity()), t);
+        assert_eq!(LayerTransform::identity().compose(&t), t);
+
+        let moved =
+            LayerTransform::translate(1.0, 2.0).compose(&LayerTransform::translate(3.0, 4.0));
+        assert_eq!(moved, LayerTransform::translate(4.0, 6.0));
+
+        // Composed result matches applying each transform in turn
+        let first = LayerTransform::scale(2.0, 2.0).compose(&LayerTransform::translate(1.0, 0.0));
+        let second = LayerTransform::rotate(std::f32::consts::FRAC_PI_2);
+        let combined = first.compose(&second);
+        let (px, py) = first.apply(1.0, 1.0);
+        let (ex, ey) = second.apply(px, py);
+        let (x, y) = combined.apply(1.0, 1.0);
+        assert!((x - ex).abs() < 1e-5);
+        assert!((y - ey).abs() < 1e-5);
+    }
+
     // ==================== CompositorLayer Tests ====================
 
     #[test]
@@ -1506,6 +3296,119 @@ mod tests {
         assert_eq!(frame.sequence, 0);
     }
 
+    #[test]
+    fn test_mock_engine_output_format() {
+        let mut engine = MockRenderEngine::new(4, 4);
+        let viewport = Viewport::new(4, 4);
+        assert_eq!(engine.output_format(), PixelFormat::Rgba8);
+
+        // x = 2 of 4 -> r = 127, y = 1 of 4 -> g = 63, b = 128
+        let rgba = engine.render_frame(&viewport).unwrap();
+        assert_eq!(rgba.format, PixelFormat::Rgba8);
+        assert_eq!(rgba.get_pixel(2, 1).unwrap(), &[127, 63, 128, 255]);
+
+        engine.set_output_format(PixelFormat::Bgra8);
+        let bgra = engine.render_frame(&viewport).unwrap();
+        assert_eq!(bgra.format, PixelFormat::Bgra8);
+        assert_eq!(bgra.data.len(), 4 * 4 * 4);
+        assert_eq!(bgra.get_pixel(2, 1).unwrap(), &[128, 63, 127, 255]);
+
+        engine.set_output_format(PixelFormat::Rgb8);
+        let rgb = engine.render_frame(&viewport).unwrap();
+        assert_eq!(rgb.format, PixelFormat::Rgb8);
+        assert_eq!(rgb.data.len(), 4 * 4 * 3);
+        assert_eq!(rgb.get_pixel(2, 1).unwrap(), &[127, 63, 128]);
+
+        engine.set_output_format(PixelFormat::Rgb565);
+        let rgb565 = engine.render_frame(&viewport).unwrap();
+        assert_eq!(rgb565.format, PixelFormat::Rgb565);
+        assert_eq!(rgb565.data.len(), 4 * 4 * 2);
+        let expected: u16 = (15 << 11) | (15 << 5) | 16;
+        assert_eq!(rgb565.get_pixel(2, 1).unwrap(), &expected.to_le_bytes());
+    }
+
+    #[test]
+    fn test_mock_engine_render_dirty_limits_repaint() {
+        let mut engine = MockRenderEngine::new(4000, 4000);
+        let viewport = Viewport::new(4000, 4000);
+
+        // First render has no buffer to reuse, so it repaints everything
+        let first = engine.render_dirty(&viewport).unwrap();
+        let full_pixels = engine.pixels_painted();
+        assert_eq!(full_pixels, 4000 * 4000);
+        assert_eq!(first.dirty_regions, vec![Rect::from_size(4000, 4000)]);
+
+        // Scribble outside the dirty region to prove it is left untouched
+        {
+            let mut buffer = engine.frame_buffer.lock().unwrap();
+            buffer.as_mut().unwrap().set_pixel(0, 0, &[1, 2, 3, 4]);
+        }
+
+        engine.invalidate(Some(Rect::new(100, 100, 10, 10)));
+        let frame = engine.render_dirty(&viewport).unwrap();
+        let dirty_pixels = engine.pixels_painted();
+
+        assert_eq!(dirty_pixels, 100);
+        assert!(dirty_pixels * 1000 < full_pixels);
+        assert_eq!(frame.dirty_regions, vec![Rect::new(100, 100, 10, 10)]);
+        assert_eq!(frame.get_pixel(0, 0).unwrap(), &[1, 2, 3, 4]);
+        assert_eq!(
+            frame.get_pixel(105, 105).unwrap(),
+            first.get_pixel(105, 105).unwrap()
+        );
+        assert!(engine.get_dirty_regions().is_empty());
+    }
+
+    #[test]
+    fn test_mock_engine_render_dirty_clips_to_viewport() {
+        let mut engine = MockRenderEngine::new(100, 100);
+        let viewport = Viewport::new(100, 100);
+        engine.render_dirty(&viewport).unwrap();
+
+        engine.invalidate(Some(Rect::new(90, 90, 50, 50)));
+        let frame = engine.render_dirty(&viewport).unwrap();
+        assert_eq!(frame.dirty_regions, vec![Rect::new(90, 90, 10, 10)]);
+        assert_eq!(engine.pixels_painted(), 100);
+
+        // Nothing dirty means nothing repainted
+        let frame = engine.render_dirty(&viewport).unwrap();
+        assert!(frame.dirty_regions.is_empty());
+        assert_eq!(engine.pixels_painted(), 0);
+    }
+
+    #[test]
+    fn test_mock_engine_render_dirty_shares_buffer() {
+        let mut engine = MockRenderEngine::new(100, 100);
+        let viewport = Viewport::new(100, 100);
+
+        let frame = engine.render_dirty(&viewport).unwrap();
+        let pixels = Arc::as_ptr(&frame.data);
+        assert!(Arc::ptr_eq(
+            &frame.data,
+            &engine.frame_buffer.lock().unwrap().as_ref().unwrap().data
+        ));
+
+        // A dropped frame lets the next repaint reuse the buffer in place
+        drop(frame);
+        engine.invalidate(Some(Rect::new(0, 0, 10, 10)));
+        let frame = engine.render_dirty(&viewport).unwrap();
+        assert_eq!(Arc::as_ptr(&frame.data), pixels);
+
+        // A held frame keeps its pixels while the buffer moves on
+        let held = frame.get_pixel(5, 5).unwrap().to_vec();
+        engine
+            .frame_buffer
+            .lock()
+            .unwrap()
+            .as_mut()
+            .unwrap()
+            .set_pixel(5, 5, &[9, 9, 9, 9]);
+        engine.invalidate(Some(Rect::new(50, 50, 10, 10)));
+        let next = engine.render_dirty(&viewport).unwrap();
+        assert_eq!(frame.get_pixel(5, 5).unwrap(), held.as_slice());
+        assert_eq!(next.get_pixel(5, 5).unwrap(), &[9, 9, 9, 9]);
+    }
+
     #[test]
     fn test_mock_engine_invalidate_region() {
         let mut engine = MockRenderEngine::new(800, 600);
@@ -1520,6 +3423,40 @@ mod tests {
         assert_eq!(engine.get_dirty_regions().len(), 2);
     }
 
+    #[test]
+    fn test_mock_engine_invalidate_merges_transitively() {
+        let mut engine = MockRenderEngine::new(800, 600);
+
+        engine.invalidate(Some(Rect::new(0, 0, 10, 10)));
+        engine.invalidate(Some(Rect::new(20, 0, 10, 10)));
+        assert_eq!(engine.get_dirty_regions().len(), 2);
+
+        // Bridges both existing regions
+        engine.invalidate(Some(Rect::new(5, 0, 20, 5)));
+
+        assert_eq!(engine.get_dirty_regions(), vec![Rect::new(0, 0, 30, 10)]);
+    }
+
+    #[test]
+    fn test_mock_engine_dirty_regions_bounded() {
+        let mut engine = MockRenderEngine::new(1000, 1000);
+        engine.set_max_dirty_regions(16);
+
+        for i in 0..100 {
+            let x = (i % 10) * 100;
+            let y = (i / 10) * 100;
+            engine.invalidate(Some(Rect::new(x, y, 10, 10)));
+            assert!(engine.get_dirty_regions().len() <= 16);
+        }
+
+        // Everything stays covered by the collapsed bounds
+        let regions = engine.get_dirty_regions();
+        for i in 0..100 {
+            let point = Rect::new((i % 10) * 100, (i / 10) * 100, 1, 1);
+            assert!(regions.iter().any(|r| r.intersects(&point)));
+        }
+    }
+
     #[test]
     fn test_mock_engine_invalidate_all() {
         let mut engine = MockRenderEngine::new(800, 600);
@@ -1613,6 +3550,86 @@ mod tests {
         assert!(!engine.remove_layer(layer1)); // Already removed
     }
 
+    #[test]
+    fn test_mock_engine_composite_overlapping_layers() {
+        let engine = MockRenderEngine::new(100, 100);
+        let mut base = Frame::new(100, 100, PixelFormat::Rgba8).unwrap();
+        for y in 0..100 {
+            for x in 0..100 {
+                base.set_pixel(x, y, &[0, 0, 0, 255]);
+            }
+        }
+
+        let red = engine.add_layer(Rect::new(0, 0, 60, 60));
+        let blue = engine.add_layer(Rect::new(40, 40, 60, 60));
+        engine.update_layer(red, |l| {
+            *l = l
+                .clone()
+                .with_color([255, 0, 0, 255])
+                .with_opacity(0.5)
+                .with_z_index(1);
+        });
+        engine.update_layer(blue, |l| {
+            *l = l
+                .clone()
+                .with_color([0, 0, 255, 255])
+                .with_opacity(0.5)
+                .with_z_index(2);
+        });
+
+        engine.composite_layers(&mut base);
+
+        // Red only: 255 * 0.5
+        assert_eq!(base.get_pixel(10, 10).unwrap(), &[128, 0, 0, 255]);
+        // Blue only
+        assert_eq!(base.get_pixel(90, 90).unwrap(), &[0, 0, 128, 255]);
+        // Overlap: red blended first, then blue on top halves it
+        assert_eq!(base.get_pixel(50, 50).unwrap(), &[64, 0, 128, 255]);
+        // Untouched
+        assert_eq!(base.get_pixel(90, 10).unwrap(), &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_mock_engine_composite_respects_translation_and_visibility() {
+        let engine = MockRenderEngine::new(20, 20);
+        let mut base = Frame::new(20, 20, PixelFormat::Rgba8).unwrap();
+
+        let moved = engine.add_layer(Rect::new(0, 0, 5, 5));
+        engine.update_layer(moved, |l| {
+            l.transform = LayerTransform::translate(10.0, 10.0);
+        });
+        let hidden = engine.add_layer(Rect::new(0, 0, 5, 5));
+        engine.update_layer(hidden, |l| l.visible = false);
+
+        engine.composite_layers(&mut base);
+
+        assert_eq!(base.get_pixel(2, 2).unwrap(), &[0, 0, 0, 0]);
+        assert_eq!(base.get_pixel(12, 12).unwrap(), &[255, 255, 255, 255]);
+        assert_eq!(base.get_pixel(15, 15).unwrap(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_mock_engine_capabilities() {
+        let engine = MockRenderEngine::new(800, 600);
+        let caps = engine.capabilities();
+
+        assert!(caps.supports_layers);
+        assert!(caps.supports_partial_repaint);
+        assert!(!caps.supports_idle_callbacks);
+        assert!(caps.max_texture_size >= 4096);
+        assert!(caps.max_texture_size.is_power_of_two());
+    }
+
+    #[test]
+    fn test_engine_capabilities_default_is_conservative() {
+        let caps = EngineCapabilities::default();
+
+        assert!(!caps.supports_layers);
+        assert!(!caps.supports_idle_callbacks);
+        assert!(!caps.supports_partial_repaint);
+        assert_eq!(caps.max_texture_size, 4096);
+    }
+
     #[test]
     fn test_mock_engine_needs_repaint() {
         let mut engine = MockRenderEngine::new(800, 600);
@@ -1629,6 +3646,103 @@ mod tests {
         assert!(engine.needs_repaint());
     }
 
+    // ==================== TiledRenderEngine Tests ====================
+
+    fn tiled_engine() -> TiledRenderEngine<MockRenderEngine> {
+        // 1000x600 in 256px tiles: 4 columns, 3 rows, edge tiles clipped
+        let mut engine = TiledRenderEngine::new(MockRenderEngine::new(1000, 600), 1000, 600);
+        engine.render_frame(&Viewport::new(1000, 600)).unwrap();
+        engine
+    }
+
+    #[test]
+    fn test_tiled_engine_grid() {
+        let engine = tiled_engine();
+        assert_eq!(engine.tile_size(), DEFAULT_TILE_SIZE);
+        assert_eq!(engine.tile_grid(), (4, 3));
+        assert_eq!(engine.dirty_tile_count(), 0);
+        assert!(!engine.needs_repaint());
+
+        let engine =
+            TiledRenderEngine::new(MockRenderEngine::new(100, 100), 100, 100).with_tile_size(32);
+        assert_eq!(engine.tile_grid(), (4, 4));
+        assert_eq!(engine.dirty_tile_count(), 16);
+    }
+
+    #[test]
+    fn test_tiled_engine_invalidate_marks_overlapping_tiles() {
+        let mut engine = tiled_engine();
+
+        // Top-left corner, inside a single tile
+        engine.invalidate(Some(Rect::new(0, 0, 100, 100)));
+        assert_eq!(engine.dirty_tile_count(), 1);
+        assert_eq!(engine.dirty_tiles(), vec![Rect::new(0, 0, 256, 256)]);
+
+        // Bottom-right corner: the clipped edge tile only
+        engine.clear_dirty_regions();
+        engine.invalidate(Some(Rect::new(950, 550, 100, 100)));
+        assert_eq!(engine.dirty_tiles(), vec![Rect::new(768, 512, 232, 88)]);
+
+        // Straddling a tile corner touches all four neighbours
+        engine.clear_dirty_regions();
+        engine.invalidate(Some(Rect::new(250, 250, 10, 10)));
+        assert_eq!(engine.dirty_tile_count(), 4);
+
+        // Off-surface regions are ignored
+        engine.clear_dirty_regions();
+        engine.invalidate(Some(Rect::new(-50, -50, 20, 20)));
+        assert_eq!(engine.dirty_tile_count(), 0);
+
+        engine.invalidate(None);
+        assert_eq!(engine.dirty_tile_count(), 12);
+    }
+
+    #[test]
+    fn test_tiled_engine_renders_only_dirty_tiles() {
+        let mut engine = tiled_engine();
+        let viewport = Viewport::new(1000, 600);
+
+        engine.invalidate(Some(Rect::new(300, 10, 5, 5)));
+        let frame = engine.render_dirty(&viewport).unwrap();
+        assert_eq!(frame.dirty_regions, vec![Rect::new(256, 0, 256, 256)]);
+        assert_eq!(engine.dirty_tile_count(), 0);
+        // The wrapped engine only painted the one tile
+        assert_eq!(engine.inner().pixels_painted(), 256 * 256);
+        assert_eq!((frame.width, frame.height), (1000, 600));
+        // Every tile was stitched into the frame
+        assert!(frame.data.chunks(4).all(|pixel| pixel[3] == 255));
+
+        // A new viewport size re-renders everything
+        let frame = engine.render_dirty(&Viewport::new(512, 256)).unwrap();
+        assert_eq!(frame.dirty_regions.len(), 2);
+        assert_eq!(engine.tile_grid(), (2, 1));
+    }
+
+    #[test]
+    fn test_tiled_engine_matches_full_render_when_scrolled() {
+        let viewport = Viewport {
+            scroll_x: 40,
+            scroll_y: 300,
+            ..Viewport::new(200, 150)
+        };
+        let expected = MockRenderEngine::new(200, 150)
+            .render_frame(&viewport)
+            .unwrap();
+
+        let mut engine =
+            TiledRenderEngine::new(MockRenderEngine::new(200, 150), 200, 150).with_tile_size(64);
+        let frame = engine.render_frame(&viewport).unwrap();
+        assert_eq!(frame.data, expected.data);
+        assert!(!engine.inner().needs_repaint());
+
+        // Repainting a few tiles leaves the stitched frame unchanged
+        engine.invalidate(Some(Rect::new(60, 60, 80, 10)));
+        let frame = engine.render_dirty(&viewport).unwrap();
+        assert_eq!(frame.dirty_regions.len(), 6);
+        assert_eq!(frame.data, expected.data);
+        assert!(!engine.inner().needs_repaint());
+    }
+
     // ==================== FrameScheduler Tests ====================
 
     #[test]
@@ -1666,6 +3780,25 @@ mod tests {
         assert!(scheduler.should_render());
     }
 
+    #[tokio::test]
+    async fn test_frame_scheduler_wait_for_next_frame() {
+        let mut scheduler = FrameScheduler::with_target_fps(10.0); // 100ms frames
+        scheduler.begin_frame();
+
+        let start = Instant::now();
+        scheduler.wait_for_next_frame().await;
+        let waited = start.elapsed();
+
+        assert!(waited >= Duration::from_millis(90), "waited {:?}", waited);
+        assert!(waited < Duration::from_millis(500), "waited {:?}", waited);
+        assert!(scheduler.should_render());
+
+        // Already due: returns without sleeping
+        let start = Instant::now();
+        scheduler.wait_for_next_frame().await;
+        assert!(start.elapsed() < Duration::from_millis(20));
+    }
+
     #[test]
     fn test_frame_scheduler_set_target_fps() {
         let mut scheduler = FrameScheduler::new();
@@ -1673,6 +3806,88 @@ mod tests {
         assert_eq!(scheduler.target_fps(), 120.0);
     }
 
+    #[test]
+    fn test_frame_scheduler_should_skip_frame() {
+        let mut scheduler = FrameScheduler::new();
+        assert!(!scheduler.should_skip_frame());
+
+        // On-target frames
+        for _ in 0..10 {
+            scheduler.record_frame_time(Duration::from_millis(16));
+        }
+        assert!(!scheduler.should_skip_frame());
+
+        // Renderer falls behind: ~40ms frames dominate the average
+        for _ in 0..30 {
+            scheduler.record_frame_time(Duration::from_millis(40));
+        }
+        assert!(scheduler.should_skip_frame());
+    }
+
+    #[test]
+    fn test_frame_scheduler_dropped_frame_count() {
+        let mut scheduler = FrameScheduler::new();
+        scheduler.begin_frame();
+        assert_eq!(scheduler.dropped_frame_count(), 0);
+
+        for _ in 0..60 {
+            scheduler.record_frame_time(Duration::from_millis(50));
+        }
+        scheduler.begin_frame();
+        scheduler.begin_frame();
+        assert_eq!(scheduler.dropped_frame_count(), 2);
+    }
+
+    #[test]
+    fn test_frame_scheduler_stats() {
+        let mut scheduler = FrameScheduler::new();
+        assert_eq!(scheduler.stats(), FrameStats::default());
+
+        // 50 frames at 16ms, 7 at 20ms and 3 at 50ms, shuffled by stepping
+        // through the sorted list with a stride coprime to its length
+        let sorted: Vec<u64> = [(16, 50), (20, 7), (50, 3)]
+            .iter()
+            .flat_map(|&(ms, n)| std::iter::repeat_n(ms, n))
+            .collect();
+        let expected_order: Vec<u64> = (0..60).map(|i| sorted[i * 7 % 60]).collect();
+        for &ms in &expected_order {
+            scheduler.record_frame_time(Duration::from_millis(ms));
+        }
+
+        let stats = scheduler.stats();
+        assert_eq!(stats.count, 60);
+        assert_eq!(stats.min, Duration::from_millis(16));
+        assert_eq!(stats.p50, Duration::from_millis(16));
+        assert_eq!(stats.p95, Duration::from_millis(20));
+        assert_eq!(stats.p99, Duration::from_millis(50));
+        assert_eq!(stats.max, Duration::from_millis(50));
+
+        // The live history keeps its arrival order
+        let history: Vec<u64> = scheduler
+            .frame_times
+            .iter()
+            .map(|d| d.as_millis() as u64)
+            .collect();
+        assert_eq!(history, expected_order);
+    }
+
+    #[test]
+    fn test_frame_scheduler_stats_window() {
+        let mut scheduler = FrameScheduler::new();
+        for ms in 1..=100 {
+            scheduler.record_frame_time(Duration::from_millis(ms));
+        }
+
+        // Only the last 60 frames (41..=100ms) are kept
+        let stats = scheduler.stats();
+        assert_eq!(stats.count, 60);
+        assert_eq!(stats.min, Duration::from_millis(41));
+        assert_eq!(stats.p50, Duration::from_millis(70));
+        assert_eq!(stats.p95, Duration::from_millis(97));
+        assert_eq!(stats.p99, Duration::from_millis(100));
+        assert_eq!(stats.max, Duration::from_millis(100));
+    }
+
     // ==================== RenderError Tests ====================
 
     #[test]
@@ -1837,6 +4052,102 @@ mod tests {
         assert!(div_elements.is_empty());
     }
 
+    /// html(1) > body(2) > [div#main.container(3) > [p.text(4), span.text.highlight(5)], p(6), "hi"(7)]
+    fn sample_dom() -> dom::StubDomIntegration {
+        use dom::{DomNode, ElementData, NodeId};
+
+        let mut html = DomNode::element(NodeId::new(1), ElementData::new("html"));
+        html.children = vec![NodeId::new(2)];
+        let mut body = DomNode::element(NodeId::new(2), ElementData::new("body"));
+        body.children = vec![NodeId::new(3), NodeId::new(6), NodeId::new(7)];
+        let mut div = DomNode::element(
+            NodeId::new(3),
+            ElementData::new("div")
+                .with_attribute("id", "main")
+                .with_attribute("class", "container"),
+        );
+        div.children = vec![NodeId::new(4), NodeId::new(5)];
+        let p = DomNode::element(
+            NodeId::new(4),
+            ElementData::new("p").with_attribute("class", "text"),
+        );
+        // Linked only via its parent pointer
+        let mut span = DomNode::element(
+            NodeId::new(5),
+            ElementData::new("span").with_attribute("class", "text highlight"),
+        );
+        span.parent = Some(NodeId::new(3));
+        let p2 = DomNode::element(NodeId::new(6), ElementData::new("P"));
+        let text = DomNode::text(NodeId::new(7), "hi");
+
+        dom::StubDomIntegration::from_nodes(vec![html, body, div, p, span, p2, text])
+    }
+
+    #[test]
+    fn test_stub_dom_tree_links() {
+        let dom = sample_dom();
+        let ids = |v: &[u64]| v.iter().map(|&i| dom::NodeId::new(i)).collect::<Vec<_>>();
+
+        assert_eq!(dom.get_document_root(), Some(dom::NodeId::new(1)));
+        let div = dom.get_node(dom::NodeId::new(3)).unwrap();
+        assert_eq!(div.parent, Some(dom::NodeId::new(2)));
+        assert_eq!(div.children, ids(&[4, 5]));
+        let text = dom.get_node(dom::NodeId::new(7)).unwrap();
+        assert_eq!(text.parent, Some(dom::NodeId::new(2)));
+        assert!(dom.get_computed_style(dom::NodeId::new(99)).is_none());
+    }
+
+    #[test]
+    fn test_stub_dom_queries() {
+        let dom = sample_dom();
+        let ids = |v: &[u64]| v.iter().map(|&i| dom::NodeId::new(i)).collect::<Vec<_>>();
+
+        assert_eq!(dom.get_element_by_id("main"), Some(dom::NodeId::new(3)));
+        assert_eq!(dom.get_element_by_id("missing"), None);
+        assert_eq!(dom.get_elements_by_class_name("text"), ids(&[4, 5]));
+        assert_eq!(dom.get_elements_by_class_name("highlight"), ids(&[5]));
+        assert_eq!(dom.get_elements_by_tag_name("p"), ids(&[4, 6]));
+        assert_eq!(dom.get_elements_by_tag_name("*").len(), 6);
+    }
+
+    #[test]
+    fn test_stub_dom_query_selector() {
+        let dom = sample_dom();
+        let ids = |v: &[u64]| v.iter().map(|&i| dom::NodeId::new(i)).collect::<Vec<_>>();
+
+        assert_eq!(dom.query_selector("p"), ids(&[4]));
+        assert_eq!(dom.query_selector_all("p"), ids(&[4, 6]));
+        assert_eq!(dom.query_selector("#main"), ids(&[3]));
+        assert_eq!(dom.query_selector_all(".text"), ids(&[4, 5]));
+        assert_eq!(dom.query_selector_all("span.text.highlight"), ids(&[5]));
+        assert_eq!(dom.query_selector_all("div#main.container"), ids(&[3]));
+        assert!(dom.query_selector_all("div.text").is_empty());
+
+        // Unsupported syntax matches nothing
+        assert!(dom.query_selector_all("div > p").is_empty());
+        assert!(dom.query_selector_all("").is_empty());
+        assert!(dom.query_selector_all("p.").is_empty());
+    }
+
+    #[test]
+    fn test_stub_dom_queries_survive_cycles() {
+        use dom::{DomNode, ElementData, NodeId};
+
+        // html(1) > div(2) > p(3), with p also listing html and itself as
+        // children, and html listing p a second time
+        let mut html = DomNode::element(NodeId::new(1), ElementData::new("html"));
+        html.children = vec![NodeId::new(2), NodeId::new(3)];
+        let mut div = DomNode::element(NodeId::new(2), ElementData::new("div"));
+        div.children = vec![NodeId::new(3)];
+        let mut p = DomNode::element(NodeId::new(3), ElementData::new("p"));
+        p.children = vec![NodeId::new(1), NodeId::new(3)];
+        let dom = dom::StubDomIntegration::from_nodes(vec![html, div, p]);
+
+        assert_eq!(dom.get_document_root(), Some(NodeId::new(1)));
+        assert_eq!(dom.get_elements_by_tag_name("*").len(), 3);
+        assert_eq!(dom.query_selector_all("p"), vec![NodeId::new(3)]);
+    }
+
     #[test]
     fn test_dom_node_serialization() {
         let data = dom::ElementData::new("span").with_attribute("class", "highlight");
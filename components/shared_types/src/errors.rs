@@ -69,6 +69,10 @@ pub enum TabError {
     /// Process isolation setup failed
     #[error("Process isolation failed: {0}")]
     ProcessIsolationFailed(String),
+
+    /// Operation is not valid for the tab's current state
+    #[error("Invalid tab state: {0}")]
+    InvalidState(String),
 }
 
 /// Errors that can occur during session operations
@@ -97,6 +101,14 @@ pub enum SessionError {
     /// Serialization/deserialization failed
     #[error("Session serialization error: {0}")]
     SerializationError(String),
+
+    /// Session was written by a newer, unsupported format version
+    #[error("Unsupported session format version: {0}")]
+    UnsupportedVersion(u32),
+
+    /// Session profile name is empty or contains unsupported characters
+    #[error("Invalid session profile name: {0}")]
+    InvalidProfile(String),
 }
 
 /// Errors that can occur during storage operations
@@ -239,5 +251,8 @@ mod tests {
 
         let ser_err = SessionError::SerializationError("parse error".to_string());
         assert!(ser_err.to_string().contains("parse error"));
+
+        let version_err = SessionError::UnsupportedVersion(7);
+        assert!(version_err.to_string().contains('7'));
     }
 }
@@ -118,9 +118,10 @@ fn test_tab_error_variants() {
         TabError::NotFound(tab_id),
         TabError::NavigationFailed("test".to_string()),
         TabError::ProcessIsolationFailed("test".to_string()),
+        TabError::InvalidState("test".to_string()),
     ];
 
-    assert_eq!(errors.len(), 4);
+    assert_eq!(errors.len(), 5);
 }
 
 #[test]
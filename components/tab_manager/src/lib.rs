@@ -74,10 +74,14 @@ pub mod process_isolation;
 pub mod session;
 
 use shared_types::{ProcessId, RenderSurfaceId, TabError, TabId, WindowId};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
+use tokio::sync::broadcast;
 use url::Url;
 
+/// Number of tab events buffered per subscriber before the oldest are dropped
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
 /// Tab content loading state for lazy loading support.
 ///
 /// Tabs start in `Unloaded` state and only load content when activated.
@@ -94,6 +98,50 @@ pub enum TabLoadState {
     Suspended,
 }
 
+/// Tab lifecycle event broadcast to subscribers of [`TabManager::subscribe`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TabEvent {
+    /// A tab was created
+    Created { tab_id: TabId, window_id: WindowId },
+    /// A tab was closed
+    Closed { tab_id: TabId },
+    /// A tab's URL changed (new navigation or history traversal)
+    Navigated { tab_id: TabId, url: Url },
+    /// A tab moved to a new load state
+    LoadStateChanged { tab_id: TabId, state: TabLoadState },
+    /// A tab's title changed
+    TitleChanged { tab_id: TabId, title: String },
+}
+
+/// Identifier for a tab group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GroupId(u64);
+
+impl GroupId {
+    /// Get the raw group number
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A color-coded group of tabs within a single window
+#[derive(Debug, Clone)]
+struct TabGroup {
+    window_id: WindowId,
+    color: String,
+}
+
+/// A page in the browser-wide history (never populated by private tabs)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryRecord {
+    pub url: Url,
+    pub title: String,
+    /// When the page was last visited
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Number of navigations to this URL
+    pub visit_count: u32,
+}
+
 /// Tab representation containing all tab state
 #[derive(Debug, Clone)]
 pub struct Tab {
@@ -105,6 +153,8 @@ pub struct Tab {
     pub can_go_back: bool,
     pub can_go_forward: bool,
     pub favicon: Option<Vec<u8>>,
+    /// MIME type of `favicon` (e.g. `image/png`)
+    pub favicon_mime: Option<String>,
     pub process_id: Option<ProcessId>,
     pub render_surface: RenderSurfaceId,
     /// Whether this tab is in private/incognito mode.
@@ -113,6 +163,8 @@ pub struct Tab {
     /// Current loading state for lazy loading support.
     /// Tabs start as Unloaded and only load content when activated.
     pub load_state: TabLoadState,
+    /// Tab group this tab belongs to, if any
+    pub group: Option<GroupId>,
 }
 
 /// Navigation history entry
@@ -147,6 +199,21 @@ impl NavigationHistory {
         self.current_index = Some(self.entries.len() - 1);
     }
 
+    /// Replace the current entry's URL, or push one if there is none
+    fn replace_current(&mut self, url: Url) {
+        match self.current_index {
+            Some(current) => self.entries[current].url = url,
+            None => self.push(url, String::new()),
+        }
+    }
+
+    /// Set the current entry's title
+    fn set_current_title(&mut self, title: String) {
+        if let Some(current) = self.current_index {
+            self.entries[current].title = title;
+        }
+    }
+
     fn can_go_back(&self) -> bool {
         if let Some(current) = self.current_index {
             current > 0
@@ -191,6 +258,12 @@ struct TabState {
     history: NavigationHistory,
     /// Timestamp of last access (for auto-suspend decisions)
     last_accessed: Instant,
+    /// Whether the tab is currently producing audio
+    audible: bool,
+    /// Whether the tab's audio is muted
+    muted: bool,
+    /// Reason the most recent load failed, cleared when a new load begins
+    load_error: Option<String>,
 }
 
 /// TabInfo is the public representation of tab state
@@ -207,6 +280,14 @@ pub struct TabInfo {
     pub is_private: bool,
     /// Current loading state for lazy loading support
     pub load_state: TabLoadState,
+    /// Tab group this tab belongs to, if any
+    pub group: Option<GroupId>,
+    /// Favicon image bytes, if one has been fetched
+    pub favicon: Option<Vec<u8>>,
+    /// MIME type of `favicon`
+    pub favicon_mime: Option<String>,
+    /// Whether the favicon may be written to disk (false for private tabs)
+    pub favicon_persistable: bool,
 }
 
 impl From<&Tab> for TabInfo {
@@ -221,6 +302,10 @@ impl From<&Tab> for TabInfo {
             can_go_forward: tab.can_go_forward,
             is_private: tab.is_private,
             load_state: tab.load_state,
+            group: tab.group,
+            favicon: tab.favicon.clone(),
+            favicon_mime: tab.favicon_mime.clone(),
+            favicon_persistable: tab.favicon.is_some() && !tab.is_private,
         }
     }
 }
@@ -278,6 +363,18 @@ pub struct TabManager {
     private_sessions: HashMap<TabId, PrivateSessionData>,
     /// Configuration for lazy tab loading
     lazy_load_config: LazyLoadConfig,
+    /// When enabled, only the tab holding audio focus may play audio
+    exclusive_audio: bool,
+    /// Tabs muted automatically by exclusive audio mode (not by the user)
+    auto_muted: HashSet<TabId>,
+    /// Lifecycle event channel
+    events: broadcast::Sender<TabEvent>,
+    /// Tab groups keyed by ID
+    groups: HashMap<GroupId, TabGroup>,
+    /// Next group number to hand out
+    next_group_id: u64,
+    /// Browser-wide history, ordered from least to most recently visited
+    global_history: Vec<HistoryRecord>,
 }
 
 impl TabManager {
@@ -287,6 +384,12 @@ impl TabManager {
             tabs: HashMap::new(),
             private_sessions: HashMap::new(),
             lazy_load_config: LazyLoadConfig::default(),
+            exclusive_audio: false,
+            auto_muted: HashSet::new(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            groups: HashMap::new(),
+            next_group_id: 0,
+            global_history: Vec::new(),
         }
     }
 
@@ -296,6 +399,12 @@ impl TabManager {
             tabs: HashMap::new(),
             private_sessions: HashMap::new(),
             lazy_load_config: config,
+            exclusive_audio: false,
+            auto_muted: HashSet::new(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            groups: HashMap::new(),
+            next_group_id: 0,
+            global_history: Vec::new(),
         }
     }
 
@@ -304,6 +413,19 @@ impl TabManager {
         self.tabs.len()
     }
 
+    /// Subscribe to tab lifecycle events.
+    ///
+    /// Receivers only see events fired after they subscribe. A receiver that
+    /// falls more than 64 events behind loses the oldest ones.
+    pub fn subscribe(&self) -> broadcast::Receiver<TabEvent> {
+        self.events.subscribe()
+    }
+
+    /// Broadcast an event (dropped if nobody is subscribed)
+    fn emit(&self, event: TabEvent) {
+        let _ = self.events.send(event);
+    }
+
     /// Create a new tab in a window.
     ///
     /// By default, tabs are created in `Unloaded` state to support lazy loading.
@@ -348,10 +470,12 @@ impl TabManager {
             can_go_back: history.can_go_back(),
             can_go_forward: history.can_go_forward(),
             favicon: None,
+            favicon_mime: None,
             process_id: None,
             render_surface,
             is_private: false,
             load_state: initial_load_state,
+            group: None,
         };
 
         self.tabs.insert(
@@ -360,9 +484,14 @@ impl TabManager {
                 tab,
                 history,
                 last_accessed: Instant::now(),
+                audible: false,
+                muted: false,
+                load_error: None,
             },
         );
 
+        self.emit(TabEvent::Created { tab_id, window_id });
+
         Ok(tab_id)
     }
 
@@ -417,10 +546,12 @@ impl TabManager {
             can_go_back: history.can_go_back(),
             can_go_forward: history.can_go_forward(),
             favicon: None,
+            favicon_mime: None,
             process_id: None,
             render_surface,
             is_private: true,
             load_state: initial_load_state,
+            group: None,
         };
 
         self.tabs.insert(
@@ -429,6 +560,9 @@ impl TabManager {
                 tab,
                 history,
                 last_accessed: Instant::now(),
+                audible: false,
+                muted: false,
+                load_error: None,
             },
         );
 
@@ -436,9 +570,156 @@ impl TabManager {
         self.private_sessions
             .insert(tab_id, PrivateSessionData::new());
 
+        self.emit(TabEvent::Created { tab_id, window_id });
+
         Ok(tab_id)
     }
 
+    /// Duplicate a tab, including its back/forward history.
+    ///
+    /// The copy opens in the same window with the source's URL, title, and
+    /// history position, and starts `Unloaded` (or `Loaded` when immediate
+    /// loading is enabled). Duplicating a private tab creates a private tab
+    /// with its own, empty session data.
+    pub fn duplicate_tab(&mut self, tab_id: TabId) -> Result<TabId, TabError> {
+        let source = self.tabs.get(&tab_id).ok_or(TabError::NotFound(tab_id))?;
+
+        let new_id = TabId::new();
+        let initial_load_state = if self.lazy_load_config.immediate_load {
+            TabLoadState::Loaded
+        } else {
+            TabLoadState::Unloaded
+        };
+
+        let tab = Tab {
+            id: new_id,
+            window_id: source.tab.window_id,
+            title: source.tab.title.clone(),
+            url: source.tab.url.clone(),
+            loading: false,
+            can_go_back: source.history.can_go_back(),
+            can_go_forward: source.history.can_go_forward(),
+            favicon: source.tab.favicon.clone(),
+            favicon_mime: source.tab.favicon_mime.clone(),
+            process_id: None,
+            render_surface: RenderSurfaceId::new(),
+            is_private: source.tab.is_private,
+            load_state: initial_load_state,
+            group: source.tab.group,
+        };
+        let window_id = tab.window_id;
+        let is_private = tab.is_private;
+
+        let state = TabState {
+            tab,
+            history: source.history.clone(),
+            last_accessed: Instant::now(),
+            audible: false,
+            muted: false,
+            load_error: None,
+        };
+        self.tabs.insert(new_id, state);
+
+        if is_private {
+            self.private_sessions
+                .insert(new_id, PrivateSessionData::new());
+        }
+
+        self.emit(TabEvent::Created {
+            tab_id: new_id,
+            window_id,
+        });
+
+        Ok(new_id)
+    }
+
+    /// Find an open, non-private tab showing `url`
+    ///
+    /// URLs are compared ignoring fragments and a trailing slash, so
+    /// `https://example.com/docs#intro` matches `https://example.com/docs/`.
+    pub fn find_tab_by_url(&self, url: &str) -> Option<TabId> {
+        let target = normalize_url_for_match(&Url::parse(url).ok()?);
+        self.tabs
+            .values()
+            .filter(|state| !state.tab.is_private)
+            .find(|state| {
+                state
+                    .tab
+                    .url
+                    .as_ref()
+                    .is_some_and(|tab_url| normalize_url_for_match(tab_url) == target)
+            })
+            .map(|state| state.tab.id)
+    }
+
+    /// Focus an existing tab showing `url`, or open a new one in `window_id`
+    ///
+    /// Returns the tab and whether it was an existing tab being reused.
+    pub async fn create_or_focus_tab(
+        &mut self,
+        window_id: WindowId,
+        url: String,
+    ) -> Result<(TabId, bool), TabError> {
+        if let Some(tab_id) = self.find_tab_by_url(&url) {
+            if let Some(state) = self.tabs.get_mut(&tab_id) {
+                state.last_accessed = Instant::now();
+            }
+            return Ok((tab_id, true));
+        }
+
+        let tab_id = self.create_tab(window_id, Some(url)).await?;
+        Ok((tab_id, false))
+    }
+
+    /// Get the tabs belonging to a window
+    pub fn tabs_in_window(&self, window_id: WindowId) -> Vec<TabId> {
+        self.tabs
+            .values()
+            .filter(|state| state.tab.window_id == window_id)
+            .map(|state| state.tab.id)
+            .collect()
+    }
+
+    /// Move a tab to another window
+    ///
+    /// History, load state, and private mode are preserved. Private tabs may
+    /// only join a window that already holds private tabs. Groups are scoped
+    /// to a window, so the tab leaves its group when it moves.
+    pub fn move_tab_to_window(
+        &mut self,
+        tab_id: TabId,
+        window_id: WindowId,
+    ) -> Result<(), TabError> {
+        let state = self.tabs.get(&tab_id).ok_or(TabError::NotFound(tab_id))?;
+        if state.tab.window_id == window_id {
+            return Ok(());
+        }
+
+        if state.tab.is_private {
+            let window_has_private_tabs = self
+                .tabs
+                .values()
+                .any(|other| other.tab.window_id == window_id && other.tab.is_private);
+            if !window_has_private_tabs {
+                return Err(TabError::InvalidState(format!(
+                    "Private tab {:?} can only move to a private window",
+                    tab_id
+                )));
+            }
+        }
+
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+        state.tab.window_id = window_id;
+        if let Some(group_id) = state.tab.group.take() {
+            self.drop_group_if_empty(group_id);
+        }
+
+        Ok(())
+    }
+
     /// Close a tab
     ///
     /// For private tabs, this also clears all associated private session data.
@@ -452,6 +733,12 @@ impl TabManager {
         if state.tab.is_private {
             self.private_sessions.remove(&tab_id);
         }
+        self.auto_muted.remove(&tab_id);
+        if let Some(group_id) = state.tab.group {
+            self.drop_group_if_empty(group_id);
+        }
+
+        self.emit(TabEvent::Closed { tab_id });
 
         Ok(())
     }
@@ -469,12 +756,25 @@ impl TabManager {
         // Add to history
         state.history.push(parsed_url.clone(), String::new());
 
-        // Update tab
-        state.tab.url = Some(parsed_url);
+        // Update tab; the new page supplies its own favicon
+        state.tab.url = Some(parsed_url.clone());
         state.tab.loading = false;
+        state.tab.favicon = None;
+        state.tab.favicon_mime = None;
         state.tab.can_go_back = state.history.can_go_back();
         state.tab.can_go_forward = state.history.can_go_forward();
 
+        // Private browsing never reaches the global history
+        if !state.tab.is_private {
+            let title = state.tab.title.clone();
+            self.record_visit(parsed_url.clone(), title);
+        }
+
+        self.emit(TabEvent::Navigated {
+            tab_id,
+            url: parsed_url,
+        });
+
         Ok(())
     }
 
@@ -515,11 +815,19 @@ impl TabManager {
             .go_back()
             .ok_or_else(|| TabError::NavigationFailed("No back history".to_string()))?;
 
-        state.tab.url = Some(entry.url.clone());
+        let url = entry.url.clone();
+        let title_changed = state.tab.title != entry.title;
+        state.tab.url = Some(url.clone());
         state.tab.title = entry.title.clone();
         state.tab.can_go_back = state.history.can_go_back();
         state.tab.can_go_forward = state.history.can_go_forward();
 
+        let title = state.tab.title.clone();
+        self.emit(TabEvent::Navigated { tab_id, url });
+        if title_changed {
+            self.emit(TabEvent::TitleChanged { tab_id, title });
+        }
+
         Ok(())
     }
 
@@ -535,14 +843,141 @@ impl TabManager {
             .go_forward()
             .ok_or_else(|| TabError::NavigationFailed("No forward history".to_string()))?;
 
-        state.tab.url = Some(entry.url.clone());
+        let url = entry.url.clone();
+        let title_changed = state.tab.title != entry.title;
+        state.tab.url = Some(url.clone());
         state.tab.title = entry.title.clone();
         state.tab.can_go_back = state.history.can_go_back();
         state.tab.can_go_forward = state.history.can_go_forward();
 
+        let title = state.tab.title.clone();
+        self.emit(TabEvent::Navigated { tab_id, url });
+        if title_changed {
+            self.emit(TabEvent::TitleChanged { tab_id, title });
+        }
+
+        Ok(())
+    }
+
+    /// Update a tab's title as reported by the page
+    ///
+    /// Updates the current history entry in place; no entry is added.
+    pub fn update_title(&mut self, tab_id: TabId, title: String) -> Result<(), TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+
+        if state.tab.title == title {
+            return Ok(());
+        }
+        state.tab.title = title.clone();
+        state.history.set_current_title(title.clone());
+
+        if !state.tab.is_private {
+            if let Some(url) = &state.tab.url {
+                if let Some(record) = self.global_history.iter_mut().find(|r| &r.url == url) {
+                    record.title = title.clone();
+                }
+            }
+        }
+
+        self.emit(TabEvent::TitleChanged { tab_id, title });
+        Ok(())
+    }
+
+    /// Update a tab's URL after a client-side redirect
+    ///
+    /// The current history entry is replaced rather than a new one pushed, so
+    /// going back skips the redirecting page.
+    pub fn update_url_from_redirect(&mut self, tab_id: TabId, url: Url) -> Result<(), TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+
+        state.history.replace_current(url.clone());
+        state.tab.url = Some(url.clone());
+        state.tab.can_go_back = state.history.can_go_back();
+        state.tab.can_go_forward = state.history.can_go_forward();
+
+        if !state.tab.is_private {
+            let title = state.tab.title.clone();
+            self.record_visit(url.clone(), title);
+        }
+
+        self.emit(TabEvent::Navigated { tab_id, url });
+        Ok(())
+    }
+
+    /// Add a visit to the global history, merging repeat visits to a URL
+    fn record_visit(&mut self, url: Url, title: String) {
+        let timestamp = chrono::Utc::now();
+        let record = match self.global_history.iter().position(|r| r.url == url) {
+            Some(index) => {
+                let mut record = self.global_history.remove(index);
+                record.visit_count += 1;
+                record.timestamp = timestamp;
+                if !title.is_empty() {
+                    record.title = title;
+                }
+                record
+            }
+            None => HistoryRecord {
+                url,
+                title,
+                timestamp,
+                visit_count: 1,
+            },
+        };
+        self.global_history.push(record);
+    }
+
+    /// Search the global history.
+    ///
+    /// Returns up to `limit` records whose URL or title contains `substring`
+    /// (case-insensitive), most recently visited first. An empty substring
+    /// matches everything.
+    pub fn query_history(&self, substring: &str, limit: usize) -> Vec<HistoryRecord> {
+        let needle = substring.to_lowercase();
+        self.global_history
+            .iter()
+            .rev()
+            .filter(|record| {
+                record.url.as_str().to_lowercase().contains(&needle)
+                    || record.title.to_lowercase().contains(&needle)
+            })
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Store the favicon fetched for a tab's current page
+    ///
+    /// Private tabs keep their favicon in memory like any other tab, but it is
+    /// reported as not persistable in [`TabInfo::favicon_persistable`].
+    pub fn set_favicon(
+        &mut self,
+        tab_id: TabId,
+        data: Vec<u8>,
+        mime: String,
+    ) -> Result<(), TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+
+        state.tab.favicon = Some(data);
+        state.tab.favicon_mime = Some(mime);
         Ok(())
     }
 
+    /// Get a tab's favicon bytes and MIME type
+    pub fn get_favicon(&self, tab_id: TabId) -> Option<(Vec<u8>, String)> {
+        let tab = &self.tabs.get(&tab_id)?.tab;
+        Some((tab.favicon.clone()?, tab.favicon_mime.clone()?))
+    }
+
     /// Get tab information
     pub fn get_tab_info(&self, tab_id: TabId) -> Option<TabInfo> {
         self.tabs
@@ -620,7 +1055,8 @@ impl TabManager {
     /// Load a tab's content (transition from Unloaded/Suspended to Loading then Loaded).
     ///
     /// This method triggers content loading for a tab. It should be called when
-    /// a tab is activated/selected by the user.
+    /// a tab is activated/selected by the user. It is a convenience for
+    /// `begin_load()` immediately followed by `complete_load()`.
     ///
     /// # State Transitions
     /// - `Unloaded` -> `Loading` -> `Loaded`
@@ -632,6 +1068,32 @@ impl TabManager {
     /// If auto-suspend is enabled and the number of loaded tabs exceeds the threshold,
     /// the least recently accessed tabs will be suspended.
     pub async fn load_tab(&mut self, tab_id: TabId) -> Result<(), TabError> {
+        let previous = self
+            .get_load_state(tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+
+        self.begin_load(tab_id).await?;
+
+        match previous {
+            TabLoadState::Unloaded | TabLoadState::Suspended => self.complete_load(tab_id).await,
+            TabLoadState::Loading | TabLoadState::Loaded => Ok(()),
+        }
+    }
+
+    /// Start loading a tab's content.
+    ///
+    /// Moves the tab to `Loading` and returns; the content side reports the
+    /// outcome with `complete_load()` or `fail_load()`.
+    ///
+    /// # State Transitions
+    /// - `Unloaded` -> `Loading`
+    /// - `Suspended` -> `Loading`
+    /// - `Loading` -> No change (already loading)
+    /// - `Loaded` -> No change (already loaded)
+    ///
+    /// # Errors
+    /// Returns `TabError::NotFound` if the tab does not exist.
+    pub async fn begin_load(&mut self, tab_id: TabId) -> Result<(), TabError> {
         let state = self
             .tabs
             .get_mut(&tab_id)
@@ -641,26 +1103,36 @@ impl TabManager {
         state.last_accessed = Instant::now();
 
         match state.tab.load_state {
-            TabLoadState::Loaded => {
-                // Already loaded, nothing to do
-                return Ok(());
-            }
-            TabLoadState::Loading => {
-                // Already loading, nothing to do
-                return Ok(());
-            }
+            TabLoadState::Loaded | TabLoadState::Loading => Ok(()),
             TabLoadState::Unloaded | TabLoadState::Suspended => {
-                // Transition to Loading
                 state.tab.load_state = TabLoadState::Loading;
                 state.tab.loading = true;
+                state.load_error = None;
+                self.emit(TabEvent::LoadStateChanged {
+                    tab_id,
+                    state: TabLoadState::Loading,
+                });
+                Ok(())
             }
         }
+    }
 
-        // Simulate loading completion (in real implementation, this would be async)
-        // For now, we immediately transition to Loaded
-        let state = self.tabs.get_mut(&tab_id).unwrap();
+    /// Finish loading a tab's content.
+    ///
+    /// Moves the tab from `Loading` to `Loaded`, then auto-suspends other
+    /// tabs if the loaded-tab threshold is exceeded.
+    ///
+    /// # Errors
+    /// Returns `TabError::NotFound` if the tab does not exist, or
+    /// `TabError::InvalidState` if the tab is not `Loading`.
+    pub async fn complete_load(&mut self, tab_id: TabId) -> Result<(), TabError> {
+        let state = self.loading_tab_mut(tab_id)?;
         state.tab.load_state = TabLoadState::Loaded;
         state.tab.loading = false;
+        self.emit(TabEvent::LoadStateChanged {
+            tab_id,
+            state: TabLoadState::Loaded,
+        });
 
         // Check if we need to auto-suspend other tabs
         self.auto_suspend_if_needed(tab_id);
@@ -668,6 +1140,49 @@ impl TabManager {
         Ok(())
     }
 
+    /// Abandon loading a tab's content.
+    ///
+    /// Moves the tab from `Loading` back to `Unloaded` and records `reason`,
+    /// available from `get_load_error()` until the next load begins.
+    ///
+    /// # Errors
+    /// Returns `TabError::NotFound` if the tab does not exist, or
+    /// `TabError::InvalidState` if the tab is not `Loading`.
+    pub async fn fail_load(&mut self, tab_id: TabId, reason: String) -> Result<(), TabError> {
+        let state = self.loading_tab_mut(tab_id)?;
+        state.tab.load_state = TabLoadState::Unloaded;
+        state.tab.loading = false;
+        state.load_error = Some(reason);
+        self.emit(TabEvent::LoadStateChanged {
+            tab_id,
+            state: TabLoadState::Unloaded,
+        });
+
+        Ok(())
+    }
+
+    /// Get the reason the tab's most recent load failed, if it did.
+    pub fn get_load_error(&self, tab_id: TabId) -> Option<String> {
+        self.tabs
+            .get(&tab_id)
+            .and_then(|state| state.load_error.clone())
+    }
+
+    /// Look up a tab that must currently be `Loading`
+    fn loading_tab_mut(&mut self, tab_id: TabId) -> Result<&mut TabState, TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+        if state.tab.load_state != TabLoadState::Loading {
+            return Err(TabError::InvalidState(format!(
+                "Tab is {:?}, not Loading",
+                state.tab.load_state
+            )));
+        }
+        Ok(state)
+    }
+
     /// Suspend a tab to free memory while preserving URL and title.
     ///
     /// Suspended tabs keep their URL and title but release their content
@@ -693,6 +1208,10 @@ impl TabManager {
                 state.tab.loading = false;
                 // In a real implementation, we would release content memory here
                 // but preserve URL, title, favicon, and history
+                self.emit(TabEvent::LoadStateChanged {
+                    tab_id,
+                    state: TabLoadState::Suspended,
+                });
             }
             TabLoadState::Unloaded | TabLoadState::Suspended => {
                 // Already unloaded or suspended, nothing to do
@@ -786,8 +1305,43 @@ impl TabManager {
             if let Some(state) = self.tabs.get_mut(&tab_id) {
                 state.tab.load_state = TabLoadState::Suspended;
                 state.tab.loading = false;
+                self.emit(TabEvent::LoadStateChanged {
+                    tab_id,
+                    state: TabLoadState::Suspended,
+                });
+            }
+        }
+    }
+
+    /// Suspend every loaded tab except the `keep_recent` most recently accessed.
+    ///
+    /// Intended for OS memory-pressure signals; unlike auto-suspend this is
+    /// independent of the configured threshold. Tabs that are still `Loading`
+    /// are never suspended. Returns the number of tabs suspended.
+    pub fn suspend_inactive(&mut self, keep_recent: usize) -> usize {
+        let mut loaded_tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .filter(|(_, state)| state.tab.load_state == TabLoadState::Loaded)
+            .map(|(id, state)| (*id, state.last_accessed))
+            .collect();
+
+        // Most recently accessed first; everything past `keep_recent` goes
+        loaded_tabs.sort_by_key(|(_, accessed)| std::cmp::Reverse(*accessed));
+
+        let mut suspended = 0;
+        for (tab_id, _) in loaded_tabs.into_iter().skip(keep_recent) {
+            if let Some(state) = self.tabs.get_mut(&tab_id) {
+                state.tab.load_state = TabLoadState::Suspended;
+                state.tab.loading = false;
+                self.emit(TabEvent::LoadStateChanged {
+                    tab_id,
+                    state: TabLoadState::Suspended,
+                });
+                suspended += 1;
             }
         }
+        suspended
     }
 
     /// Get the lazy load configuration.
@@ -799,36 +1353,225 @@ impl TabManager {
     pub fn set_immediate_load(&mut self, immediate: bool) {
         self.lazy_load_config.immediate_load = immediate;
     }
-}
 
-impl Default for TabManager {
-    fn default() -> Self {
-        Self::new()
+    // ==================== Audio Focus API ====================
+
+    /// Check whether a tab is currently producing audio.
+    pub fn is_audible(&self, tab_id: TabId) -> bool {
+        self.tabs
+            .get(&tab_id)
+            .map(|state| state.audible)
+            .unwrap_or(false)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Check whether a tab's audio is muted.
+    pub fn is_muted(&self, tab_id: TabId) -> bool {
+        self.tabs
+            .get(&tab_id)
+            .map(|state| state.muted)
+            .unwrap_or(false)
+    }
 
-    #[test]
-    fn test_navigation_history_basic() {
-        let mut history = NavigationHistory::new();
+    /// Mute or unmute a tab on behalf of the user.
+    ///
+    /// User mutes are never undone by exclusive audio mode.
+    pub fn set_muted(&mut self, tab_id: TabId, muted: bool) -> Result<(), TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+        state.muted = muted;
+        self.auto_muted.remove(&tab_id);
+        Ok(())
+    }
 
-        assert_eq!(history.can_go_back(), false);
-        assert_eq!(history.can_go_forward(), false);
+    /// Report whether a tab is producing audio.
+    ///
+    /// When exclusive audio mode is enabled and the tab becomes audible, it
+    /// takes audio focus and the IDs of tabs muted as a result are returned.
+    pub fn set_audible(&mut self, tab_id: TabId, audible: bool) -> Result<Vec<TabId>, TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+        state.audible = audible;
 
-        let url1 = Url::parse("https://example.com").unwrap();
-        history.push(url1.clone(), "Example".to_string());
+        if audible {
+            Ok(self.audio_focus_changed(tab_id))
+        } else {
+            Ok(Vec::new())
+        }
+    }
 
-        assert_eq!(history.can_go_back(), false);
-        assert_eq!(history.can_go_forward(), false);
+    /// Give audio focus to a tab (e.g. on activation or playback start).
+    ///
+    /// In exclusive audio mode every other audible, unmuted tab is muted and
+    /// remembered as auto-muted. Returns the tabs that were muted; empty when
+    /// the mode is disabled.
+    pub fn audio_focus_changed(&mut self, tab_id: TabId) -> Vec<TabId> {
+        if !self.exclusive_audio || !self.tabs.contains_key(&tab_id) {
+            return Vec::new();
+        }
 
-        let url2 = Url::parse("https://example.org").unwrap();
-        history.push(url2.clone(), "Example Org".to_string());
+        // The focused tab regains audio if we muted it earlier
+        if self.auto_muted.remove(&tab_id) {
+            if let Some(state) = self.tabs.get_mut(&tab_id) {
+                state.muted = false;
+            }
+        }
 
-        assert_eq!(history.can_go_back(), true);
-        assert_eq!(history.can_go_forward(), false);
+        let mut muted = Vec::new();
+        for (id, state) in self.tabs.iter_mut() {
+            if *id != tab_id && state.audible && !state.muted {
+                state.muted = true;
+                self.auto_muted.insert(*id);
+                muted.push(*id);
+            }
+        }
+        muted
+    }
+
+    /// Enable or disable exclusive audio mode.
+    ///
+    /// Disabling the mode unmutes every tab it muted automatically (user
+    /// mutes are left alone) and returns their IDs.
+    pub fn set_exclusive_audio(&mut self, enabled: bool) -> Vec<TabId> {
+        self.exclusive_audio = enabled;
+        if enabled {
+            return Vec::new();
+        }
+
+        let restored: Vec<TabId> = self.auto_muted.drain().collect();
+        for tab_id in &restored {
+            if let Some(state) = self.tabs.get_mut(tab_id) {
+                state.muted = false;
+            }
+        }
+        restored
+    }
+
+    /// Check whether exclusive audio mode is enabled.
+    pub fn is_exclusive_audio(&self) -> bool {
+        self.exclusive_audio
+    }
+
+    // ==================== Tab Groups API ====================
+
+    /// Create an empty tab group in a window.
+    ///
+    /// The group lasts until the last tab added to it is removed or closed.
+    pub fn create_group(&mut self, window_id: WindowId, color: String) -> GroupId {
+        let group_id = GroupId(self.next_group_id);
+        self.next_group_id += 1;
+        self.groups
+            .insert(group_id, TabGroup { window_id, color });
+        group_id
+    }
+
+    /// Add a tab to a group, moving it out of any group it was already in.
+    ///
+    /// # Errors
+    /// Returns `TabError::NotFound` if the tab does not exist, or
+    /// `TabError::InvalidState` if the group does not exist or belongs to
+    /// another window.
+    pub fn add_tab_to_group(&mut self, tab_id: TabId, group_id: GroupId) -> Result<(), TabError> {
+        let state = self.tabs.get(&tab_id).ok_or(TabError::NotFound(tab_id))?;
+        let group = self.groups.get(&group_id).ok_or_else(|| {
+            TabError::InvalidState(format!("Tab group {} not found", group_id.0))
+        })?;
+        if group.window_id != state.tab.window_id {
+            return Err(TabError::InvalidState(format!(
+                "Tab group {} belongs to a different window",
+                group_id.0
+            )));
+        }
+
+        let previous = self
+            .tabs
+            .get_mut(&tab_id)
+            .and_then(|state| state.tab.group.replace(group_id));
+        if let Some(previous) = previous.filter(|&previous| previous != group_id) {
+            self.drop_group_if_empty(previous);
+        }
+        Ok(())
+    }
+
+    /// Remove a tab from its group, if it has one.
+    ///
+    /// # Errors
+    /// Returns `TabError::NotFound` if the tab does not exist.
+    pub fn remove_tab_from_group(&mut self, tab_id: TabId) -> Result<(), TabError> {
+        let state = self
+            .tabs
+            .get_mut(&tab_id)
+            .ok_or(TabError::NotFound(tab_id))?;
+        if let Some(group_id) = state.tab.group.take() {
+            self.drop_group_if_empty(group_id);
+        }
+        Ok(())
+    }
+
+    /// Get the tabs in a group.
+    pub fn tabs_in_group(&self, group_id: GroupId) -> Vec<TabId> {
+        self.tabs
+            .values()
+            .filter(|state| state.tab.group == Some(group_id))
+            .map(|state| state.tab.id)
+            .collect()
+    }
+
+    /// Get a group's color, or `None` if the group does not exist.
+    pub fn group_color(&self, group_id: GroupId) -> Option<&str> {
+        self.groups.get(&group_id).map(|group| group.color.as_str())
+    }
+
+    /// Forget a group once its last tab has left it
+    fn drop_group_if_empty(&mut self, group_id: GroupId) {
+        let in_use = self
+            .tabs
+            .values()
+            .any(|state| state.tab.group == Some(group_id));
+        if !in_use {
+            self.groups.remove(&group_id);
+        }
+    }
+}
+
+/// Reduce a URL to the form used for duplicate-tab detection
+fn normalize_url_for_match(url: &Url) -> String {
+    let mut url = url.clone();
+    url.set_fragment(None);
+    url.as_str().trim_end_matches('/').to_string()
+}
+
+impl Default for TabManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_history_basic() {
+        let mut history = NavigationHistory::new();
+
+        assert_eq!(history.can_go_back(), false);
+        assert_eq!(history.can_go_forward(), false);
+
+        let url1 = Url::parse("https://example.com").unwrap();
+        history.push(url1.clone(), "Example".to_string());
+
+        assert_eq!(history.can_go_back(), false);
+        assert_eq!(history.can_go_forward(), false);
+
+        let url2 = Url::parse("https://example.org").unwrap();
+        history.push(url2.clone(), "Example Org".to_string());
+
+        assert_eq!(history.can_go_back(), true);
+        assert_eq!(history.can_go_forward(), false);
     }
 
     #[test]
@@ -1482,10 +2225,689 @@ mod tests {
         assert_eq!(format!("{:?}", suspended), "Suspended");
     }
 
+    // ==================== Audio Focus Tests ====================
+
+    #[tokio::test]
+    async fn test_exclusive_audio_mutes_previous_tab() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let tab1 = manager.create_tab(window_id, None).await.unwrap();
+        let tab2 = manager.create_tab(window_id, None).await.unwrap();
+
+        manager.set_exclusive_audio(true);
+        assert!(manager.set_audible(tab1, true).unwrap().is_empty());
+
+        let muted = manager.set_audible(tab2, true).unwrap();
+        assert_eq!(muted, vec![tab1]);
+        assert!(manager.is_muted(tab1));
+        assert!(!manager.is_muted(tab2));
+
+        // Focus back to tab1 unmutes it and mutes tab2
+        let muted = manager.audio_focus_changed(tab1);
+        assert_eq!(muted, vec![tab2]);
+        assert!(!manager.is_muted(tab1));
+        assert!(manager.is_muted(tab2));
+    }
+
+    #[tokio::test]
+    async fn test_disabling_exclusive_audio_restores_only_auto_muted() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let user_muted = manager.create_tab(window_id, None).await.unwrap();
+        let auto_muted = manager.create_tab(window_id, None).await.unwrap();
+        let focused = manager.create_tab(window_id, None).await.unwrap();
+
+        manager.set_muted(user_muted, true).unwrap();
+        manager.set_audible(user_muted, true).unwrap();
+        manager.set_audible(auto_muted, true).unwrap();
+
+        manager.set_exclusive_audio(true);
+        let muted = manager.set_audible(focused, true).unwrap();
+        assert_eq!(muted, vec![auto_muted]);
+
+        let restored = manager.set_exclusive_audio(false);
+        assert_eq!(restored, vec![auto_muted]);
+        assert!(!manager.is_muted(auto_muted));
+        assert!(manager.is_muted(user_muted));
+        assert!(!manager.is_exclusive_audio());
+    }
+
+    #[tokio::test]
+    async fn test_audio_focus_without_exclusive_mode_is_noop() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let tab1 = manager.create_tab(window_id, None).await.unwrap();
+        let tab2 = manager.create_tab(window_id, None).await.unwrap();
+
+        manager.set_audible(tab1, true).unwrap();
+        assert!(manager.set_audible(tab2, true).unwrap().is_empty());
+        assert!(!manager.is_muted(tab1));
+        assert!(manager.is_audible(tab1));
+        assert!(manager.set_audible(TabId::new(), true).is_err());
+    }
+
     #[test]
     fn test_lazy_load_config_default() {
         let config = LazyLoadConfig::default();
         assert_eq!(config.auto_suspend_threshold, 10);
         assert!(!config.immediate_load);
     }
+
+    #[tokio::test]
+    async fn test_events_for_create_navigate_close() {
+        let mut manager = TabManager::new();
+        let mut events = manager.subscribe();
+        let window_id = WindowId::new();
+
+        let tab_id = manager.create_tab(window_id, None).await.unwrap();
+        manager
+            .navigate(tab_id, "https://example.com/".to_string())
+            .await
+            .unwrap();
+        manager.load_tab(tab_id).await.unwrap();
+        manager.close_tab(tab_id).await.unwrap();
+
+        let expected = [
+            TabEvent::Created { tab_id, window_id },
+            TabEvent::Navigated {
+                tab_id,
+                url: Url::parse("https://example.com/").unwrap(),
+            },
+            TabEvent::LoadStateChanged {
+                tab_id,
+                state: TabLoadState::Loading,
+            },
+            TabEvent::LoadStateChanged {
+                tab_id,
+                state: TabLoadState::Loaded,
+            },
+            TabEvent::Closed { tab_id },
+        ];
+        for event in expected {
+            assert_eq!(events.try_recv().unwrap(), event);
+        }
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_title_keeps_history_length() {
+        let mut manager = TabManager::new();
+        let tab_id = manager
+            .create_tab(WindowId::new(), Some("https://a.example/".to_string()))
+            .await
+            .unwrap();
+        manager
+            .navigate(tab_id, "https://b.example/".to_string())
+            .await
+            .unwrap();
+        let mut events = manager.subscribe();
+
+        manager.update_title(tab_id, "Page B".to_string()).unwrap();
+        // Setting the same title again is not a change
+        manager.update_title(tab_id, "Page B".to_string()).unwrap();
+
+        assert_eq!(manager.tabs[&tab_id].history.entries.len(), 2);
+        assert_eq!(manager.get_tab_info(tab_id).unwrap().title, "Page B");
+        assert_eq!(manager.query_history("b.example", 1)[0].title, "Page B");
+        assert_eq!(
+            events.try_recv().unwrap(),
+            TabEvent::TitleChanged {
+                tab_id,
+                title: "Page B".to_string(),
+            }
+        );
+        assert!(events.try_recv().is_err());
+
+        // The title is restored when coming back to the entry
+        manager.go_back(tab_id).await.unwrap();
+        manager.go_forward(tab_id).await.unwrap();
+        assert_eq!(manager.get_tab_info(tab_id).unwrap().title, "Page B");
+
+        assert!(matches!(
+            manager.update_title(TabId::new(), "x".to_string()),
+            Err(TabError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_url_from_redirect_replaces_entry() {
+        let mut manager = TabManager::new();
+        let tab_id = manager
+            .create_tab(WindowId::new(), Some("https://a.example/".to_string()))
+            .await
+            .unwrap();
+        manager
+            .navigate(tab_id, "https://b.example/".to_string())
+            .await
+            .unwrap();
+        let mut events = manager.subscribe();
+
+        let target = Url::parse("https://b.example/landing").unwrap();
+        manager
+            .update_url_from_redirect(tab_id, target.clone())
+            .unwrap();
+
+        let info = manager.get_tab_info(tab_id).unwrap();
+        assert_eq!(info.url, Some(target.clone()));
+        assert!(info.can_go_back);
+        assert!(!info.can_go_forward);
+        assert_eq!(manager.tabs[&tab_id].history.entries.len(), 2);
+        assert_eq!(
+            events.try_recv().unwrap(),
+            TabEvent::Navigated {
+                tab_id,
+                url: target.clone(),
+            }
+        );
+
+        // Going back skips the redirecting page, and forward returns to the target
+        manager.go_back(tab_id).await.unwrap();
+        assert_eq!(
+            manager.get_tab_info(tab_id).unwrap().url.unwrap().as_str(),
+            "https://a.example/"
+        );
+        manager.go_forward(tab_id).await.unwrap();
+        assert_eq!(manager.get_tab_info(tab_id).unwrap().url, Some(target));
+    }
+
+    #[tokio::test]
+    async fn test_events_for_history_and_suspend() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let tab_id = manager
+            .create_tab(window_id, Some("https://a.example/".to_string()))
+            .await
+            .unwrap();
+        manager
+            .navigate(tab_id, "https://b.example/".to_string())
+            .await
+            .unwrap();
+        manager.load_tab(tab_id).await.unwrap();
+
+        let mut events = manager.subscribe();
+        manager.go_back(tab_id).await.unwrap();
+        manager.suspend_tab(tab_id).await.unwrap();
+
+        assert_eq!(
+            events.try_recv().unwrap(),
+            TabEvent::Navigated {
+                tab_id,
+                url: Url::parse("https://a.example/").unwrap(),
+            }
+        );
+        assert_eq!(
+            events.try_recv().unwrap(),
+            TabEvent::LoadStateChanged {
+                tab_id,
+                state: TabLoadState::Suspended,
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_tab_preserves_history() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let source = manager
+            .create_tab(window_id, Some("https://a.example/".to_string()))
+            .await
+            .unwrap();
+        manager
+            .navigate(source, "https://b.example/".to_string())
+            .await
+            .unwrap();
+        manager
+            .navigate(source, "https://c.example/".to_string())
+            .await
+            .unwrap();
+        manager.go_back(source).await.unwrap();
+        manager.load_tab(source).await.unwrap();
+
+        let copy = manager.duplicate_tab(source).unwrap();
+
+        let info = manager.get_tab_info(copy).unwrap();
+        assert_eq!(info.window_id, window_id);
+        assert_eq!(info.url.unwrap().as_str(), "https://b.example/");
+        assert_eq!(info.load_state, TabLoadState::Unloaded);
+        assert!(info.can_go_back);
+        assert!(info.can_go_forward);
+        assert_eq!(
+            manager.tabs[&copy].history.current_index,
+            manager.tabs[&source].history.current_index
+        );
+
+        manager.go_back(copy).await.unwrap();
+        let info = manager.get_tab_info(copy).unwrap();
+        assert_eq!(info.url.unwrap().as_str(), "https://a.example/");
+
+        // The source's history is unaffected
+        let info = manager.get_tab_info(source).unwrap();
+        assert_eq!(info.url.unwrap().as_str(), "https://b.example/");
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_tab_respects_private_mode() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let private = manager
+            .create_private_tab(window_id, Some("https://a.example/".to_string()))
+            .await
+            .unwrap();
+        let normal = manager.create_tab(window_id, None).await.unwrap();
+
+        let private_copy = manager.duplicate_tab(private).unwrap();
+        let normal_copy = manager.duplicate_tab(normal).unwrap();
+
+        assert!(manager.is_private(private_copy));
+        assert!(manager.get_private_session(private_copy).is_some());
+        assert!(!manager.is_private(normal_copy));
+        assert_eq!(manager.private_tab_count(), 2);
+        assert!(matches!(
+            manager.duplicate_tab(TabId::new()),
+            Err(TabError::NotFound(_))
+        ));
+    }
+    #[tokio::test]
+    async fn test_tab_groups_membership() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let tab1 = manager.create_tab(window_id, None).await.unwrap();
+        let tab2 = manager.create_tab(window_id, None).await.unwrap();
+        let tab3 = manager.create_tab(window_id, None).await.unwrap();
+
+        let group = manager.create_group(window_id, "blue".to_string());
+        manager.add_tab_to_group(tab1, group).unwrap();
+        manager.add_tab_to_group(tab2, group).unwrap();
+
+        let members = manager.tabs_in_group(group);
+        assert_eq!(members.len(), 2);
+        assert!(members.contains(&tab1) && members.contains(&tab2));
+        assert_eq!(manager.get_tab_info(tab1).unwrap().group, Some(group));
+        assert_eq!(manager.get_tab_info(tab3).unwrap().group, None);
+        assert_eq!(manager.group_color(group), Some("blue"));
+
+        manager.remove_tab_from_group(tab1).unwrap();
+        assert_eq!(manager.tabs_in_group(group), vec![tab2]);
+        assert_eq!(manager.get_tab_info(tab1).unwrap().group, None);
+    }
+
+    #[tokio::test]
+    async fn test_tab_group_dropped_when_emptied() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let tab1 = manager.create_tab(window_id, None).await.unwrap();
+        let tab2 = manager.create_tab(window_id, None).await.unwrap();
+
+        let group = manager.create_group(window_id, "red".to_string());
+        manager.add_tab_to_group(tab1, group).unwrap();
+        manager.add_tab_to_group(tab2, group).unwrap();
+
+        manager.close_tab(tab1).await.unwrap();
+        assert!(manager.group_color(group).is_some());
+        manager.remove_tab_from_group(tab2).unwrap();
+        assert!(manager.group_color(group).is_none());
+
+        // The group is gone, so it can't be joined again
+        assert!(matches!(
+            manager.add_tab_to_group(tab2, group),
+            Err(TabError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tab_group_rejects_other_window() {
+        let mut manager = TabManager::new();
+        let tab = manager.create_tab(WindowId::new(), None).await.unwrap();
+        let group = manager.create_group(WindowId::new(), "green".to_string());
+
+        assert!(matches!(
+            manager.add_tab_to_group(tab, group),
+            Err(TabError::InvalidState(_))
+        ));
+        assert!(matches!(
+            manager.add_tab_to_group(TabId::new(), group),
+            Err(TabError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_loading_state_is_observable() {
+        let mut manager = TabManager::new();
+        let tab_id = manager
+            .create_tab(WindowId::new(), Some("https://example.com".to_string()))
+            .await
+            .unwrap();
+
+        manager.begin_load(tab_id).await.unwrap();
+        assert_eq!(manager.get_load_state(tab_id), Some(TabLoadState::Loading));
+        assert!(manager.get_tab_info(tab_id).unwrap().loading);
+
+        // Beginning again while loading is a no-op
+        manager.begin_load(tab_id).await.unwrap();
+        assert_eq!(manager.get_load_state(tab_id), Some(TabLoadState::Loading));
+
+        manager.complete_load(tab_id).await.unwrap();
+        assert_eq!(manager.get_load_state(tab_id), Some(TabLoadState::Loaded));
+        assert!(!manager.get_tab_info(tab_id).unwrap().loading);
+
+        // Completing a load that isn't in progress is rejected
+        assert!(matches!(
+            manager.complete_load(tab_id).await,
+            Err(TabError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fail_load_reverts_to_unloaded() {
+        let mut manager = TabManager::new();
+        let tab_id = manager
+            .create_tab(WindowId::new(), Some("https://example.com".to_string()))
+            .await
+            .unwrap();
+
+        manager.begin_load(tab_id).await.unwrap();
+        manager
+            .fail_load(tab_id, "connection reset".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(manager.get_load_state(tab_id), Some(TabLoadState::Unloaded));
+        assert_eq!(
+            manager.get_load_error(tab_id),
+            Some("connection reset".to_string())
+        );
+
+        // A new attempt clears the previous error
+        manager.begin_load(tab_id).await.unwrap();
+        assert_eq!(manager.get_load_error(tab_id), None);
+    }
+
+    #[tokio::test]
+    async fn test_auto_suspend_waits_for_complete_load() {
+        let mut manager = TabManager::with_config(LazyLoadConfig {
+            auto_suspend_threshold: 1,
+            immediate_load: false,
+        });
+        let window_id = WindowId::new();
+        let first = manager.create_tab(window_id, None).await.unwrap();
+        let second = manager.create_tab(window_id, None).await.unwrap();
+        manager.load_tab(first).await.unwrap();
+
+        manager.begin_load(second).await.unwrap();
+        manager
+            .fail_load(second, "timed out".to_string())
+            .await
+            .unwrap();
+        assert_eq!(manager.get_load_state(first), Some(TabLoadState::Loaded));
+
+        manager.begin_load(second).await.unwrap();
+        assert_eq!(manager.get_load_state(first), Some(TabLoadState::Loaded));
+        manager.complete_load(second).await.unwrap();
+        assert_eq!(manager.get_load_state(first), Some(TabLoadState::Suspended));
+    }
+
+    #[tokio::test]
+    async fn test_suspend_inactive_keeps_most_recent() {
+        let mut manager = TabManager::with_config(LazyLoadConfig {
+            auto_suspend_threshold: 0,
+            immediate_load: false,
+        });
+        let window_id = WindowId::new();
+        let base = Instant::now();
+
+        let mut tabs = Vec::new();
+        for i in 0..5 {
+            let tab_id = manager.create_tab(window_id, None).await.unwrap();
+            manager.load_tab(tab_id).await.unwrap();
+            manager.tabs.get_mut(&tab_id).unwrap().last_accessed =
+                base + std::time::Duration::from_secs(i);
+            tabs.push(tab_id);
+        }
+        let loading = manager.create_tab(window_id, None).await.unwrap();
+        manager.begin_load(loading).await.unwrap();
+
+        let suspended = manager.suspend_inactive(2);
+
+        assert_eq!(suspended, 3);
+        for &tab_id in &tabs[..3] {
+            assert_eq!(manager.get_load_state(tab_id), Some(TabLoadState::Suspended));
+        }
+        for &tab_id in &tabs[3..] {
+            assert_eq!(manager.get_load_state(tab_id), Some(TabLoadState::Loaded));
+        }
+        assert_eq!(manager.get_load_state(loading), Some(TabLoadState::Loading));
+
+        // Nothing left to suspend beyond the kept tabs
+        assert_eq!(manager.suspend_inactive(2), 0);
+    }
+
+    #[tokio::test]
+    async fn test_global_history_counts_revisits() {
+        let mut manager = TabManager::new();
+        let tab_id = manager.create_tab(WindowId::new(), None).await.unwrap();
+
+        for url in [
+            "https://rust-lang.org/",
+            "https://example.com/",
+            "https://rust-lang.org/",
+        ] {
+            manager.navigate(tab_id, url.to_string()).await.unwrap();
+        }
+
+        let history = manager.query_history("", 10);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].url.as_str(), "https://rust-lang.org/");
+        assert_eq!(history[0].visit_count, 2);
+        assert_eq!(history[1].url.as_str(), "https://example.com/");
+        assert_eq!(history[1].visit_count, 1);
+
+        assert_eq!(manager.query_history("RUST", 10).len(), 1);
+        assert_eq!(manager.query_history("", 1).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_global_history_excludes_private_tabs() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let normal = manager.create_tab(window_id, None).await.unwrap();
+        let private = manager.create_private_tab(window_id, None).await.unwrap();
+
+        manager
+            .navigate(private, "https://secret.example/".to_string())
+            .await
+            .unwrap();
+        manager
+            .navigate(normal, "https://public.example/".to_string())
+            .await
+            .unwrap();
+
+        let history = manager.query_history("", 10);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].url.as_str(), "https://public.example/");
+        assert!(manager.query_history("secret", 10).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_set_and_get_favicon() {
+        let mut manager = TabManager::new();
+        let tab_id = manager.create_tab(WindowId::new(), None).await.unwrap();
+        assert_eq!(manager.get_favicon(tab_id), None);
+
+        let icon = vec![0x89, b'P', b'N', b'G'];
+        manager
+            .set_favicon(tab_id, icon.clone(), "image/png".to_string())
+            .unwrap();
+
+        assert_eq!(
+            manager.get_favicon(tab_id),
+            Some((icon.clone(), "image/png".to_string()))
+        );
+
+        let info = manager.get_tab_info(tab_id).unwrap();
+        assert_eq!(info.favicon, Some(icon));
+        assert_eq!(info.favicon_mime.as_deref(), Some("image/png"));
+        assert!(info.favicon_persistable);
+
+        assert!(matches!(
+            manager.set_favicon(TabId::new(), vec![], "image/png".to_string()),
+            Err(TabError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_navigate_clears_favicon() {
+        let mut manager = TabManager::new();
+        let tab_id = manager
+            .create_tab(WindowId::new(), Some("https://a.example/".to_string()))
+            .await
+            .unwrap();
+        manager
+            .set_favicon(tab_id, vec![1, 2, 3], "image/png".to_string())
+            .unwrap();
+
+        manager
+            .navigate(tab_id, "https://b.example/".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(manager.get_favicon(tab_id), None);
+        let info = manager.get_tab_info(tab_id).unwrap();
+        assert_eq!(info.favicon, None);
+        assert_eq!(info.favicon_mime, None);
+        assert!(!info.favicon_persistable);
+    }
+
+    #[tokio::test]
+    async fn test_private_favicon_not_persistable() {
+        let mut manager = TabManager::new();
+        let tab_id = manager
+            .create_private_tab(WindowId::new(), None)
+            .await
+            .unwrap();
+
+        manager
+            .set_favicon(tab_id, vec![1, 2, 3], "image/x-icon".to_string())
+            .unwrap();
+
+        let info = manager.get_tab_info(tab_id).unwrap();
+        assert_eq!(info.favicon, Some(vec![1, 2, 3]));
+        assert!(!info.favicon_persistable);
+    }
+
+    #[tokio::test]
+    async fn test_find_tab_by_url_ignores_fragment_and_trailing_slash() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+        let tab_id = manager
+            .create_tab(window_id, Some("https://example.com/docs#intro".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            manager.find_tab_by_url("https://example.com/docs#usage"),
+            Some(tab_id)
+        );
+        assert_eq!(
+            manager.find_tab_by_url("https://example.com/docs/"),
+            Some(tab_id)
+        );
+        assert_eq!(manager.find_tab_by_url("https://example.com/other"), None);
+        assert_eq!(manager.find_tab_by_url("not a url"), None);
+    }
+
+    #[tokio::test]
+    async fn test_find_tab_by_url_skips_private_tabs() {
+        let mut manager = TabManager::new();
+        manager
+            .create_private_tab(WindowId::new(), Some("https://example.com/".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(manager.find_tab_by_url("https://example.com/"), None);
+    }
+
+    #[tokio::test]
+    async fn test_create_or_focus_tab_reuses_existing() {
+        let mut manager = TabManager::new();
+        let window_id = WindowId::new();
+
+        let (first, reused) = manager
+            .create_or_focus_tab(window_id, "https://example.com/page".to_string())
+            .await
+            .unwrap();
+        assert!(!reused);
+
+        let (second, reused) = manager
+            .create_or_focus_tab(window_id, "https://example.com/page#section".to_string())
+            .await
+            .unwrap();
+        assert!(reused);
+        assert_eq!(second, first);
+        assert_eq!(manager.tab_count(), 1);
+
+        let (third, reused) = manager
+            .create_or_focus_tab(window_id, "https://example.com/other".to_string())
+            .await
+            .unwrap();
+        assert!(!reused);
+        assert_ne!(third, first);
+        assert_eq!(manager.tab_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_move_tab_to_window() {
+        let mut manager = TabManager::new();
+        let source = WindowId::new();
+        let destination = WindowId::new();
+
+        let tab_id = manager
+            .create_tab(source, Some("https://example.com".to_string()))
+            .await
+            .unwrap();
+        manager
+            .navigate(tab_id, "https://example.com/next".to_string())
+            .await
+            .unwrap();
+        let other = manager.create_tab(source, None).await.unwrap();
+        let group = manager.create_group(source, "blue".to_string());
+        manager.add_tab_to_group(tab_id, group).unwrap();
+
+        manager.move_tab_to_window(tab_id, destination).unwrap();
+
+        assert_eq!(manager.tabs_in_window(source), vec![other]);
+        assert_eq!(manager.tabs_in_window(destination), vec![tab_id]);
+
+        let info = manager.get_tab_info(tab_id).unwrap();
+        assert_eq!(info.window_id, destination);
+        assert!(info.can_go_back);
+        assert_eq!(info.group, None);
+        assert_eq!(manager.group_color(group), None);
+    }
+
+    #[tokio::test]
+    async fn test_move_private_tab_requires_private_window() {
+        let mut manager = TabManager::new();
+        let source = WindowId::new();
+        let normal_window = WindowId::new();
+        let private_window = WindowId::new();
+
+        let tab_id = manager.create_private_tab(source, None).await.unwrap();
+        manager.create_tab(normal_window, None).await.unwrap();
+        manager
+            .create_private_tab(private_window, None)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            manager.move_tab_to_window(tab_id, normal_window),
+            Err(TabError::InvalidState(_))
+        ));
+        assert_eq!(manager.tabs_in_window(source), vec![tab_id]);
+
+        manager.move_tab_to_window(tab_id, private_window).unwrap();
+        assert!(manager.is_private(tab_id));
+        assert_eq!(manager.tabs_in_window(private_window).len(), 2);
+        assert!(manager.tabs_in_window(source).is_empty());
+    }
 }
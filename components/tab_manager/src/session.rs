@@ -12,23 +12,193 @@
 //! - Save session state to JSON file
 //! - Handle multiple windows with multiple tabs each
 //! - Session snapshot on clean shutdown
+//! - Pluggable storage backends via [`SessionStore`]
+//! - Named profiles, each with its own session and crash lock
 
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use shared_types::{SessionError, TabId, WindowId};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tokio::fs;
+use tokio::sync::RwLock;
 
 /// Default auto-save interval in seconds
 pub const DEFAULT_AUTO_SAVE_INTERVAL_SECS: u64 = 30;
 
+/// Default minimum time between writes from [`SessionManager::save_if_dirty`]
+pub const DEFAULT_MIN_SAVE_INTERVAL: Duration = Duration::from_secs(2);
+
 /// Session file name
 const SESSION_FILE: &str = "session.json";
 
 /// Lock file name (dirty flag for crash detection)
 const LOCK_FILE: &str = "session.lock";
 
+/// Storage backend for session data
+///
+/// Session state and the crash lock are persisted as opaque byte blobs
+/// addressed by key, so sessions can be stored on disk, in a database, or
+/// through a sync service.
+#[async_trait]
+pub trait SessionStore: Send + Sync {
+    /// Write `bytes` under `key`, replacing any existing value
+    async fn write(&self, key: &str, bytes: &[u8]) -> Result<(), SessionError>;
+
+    /// Read the value stored under `key`, or `None` if it does not exist
+    async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, SessionError>;
+
+    /// Delete the value stored under `key` (no-op if missing)
+    async fn delete(&self, key: &str) -> Result<(), SessionError>;
+
+    /// Check whether a value is stored under `key`
+    async fn exists(&self, key: &str) -> Result<bool, SessionError>;
+
+    /// List the stored keys
+    ///
+    /// Stores that can't enumerate their contents return an empty list.
+    async fn keys(&self) -> Result<Vec<String>, SessionError> {
+        Ok(Vec::new())
+    }
+}
+
+/// Filesystem session store, one file per key in a directory
+#[derive(Debug, Clone)]
+pub struct FileSessionStore {
+    dir: PathBuf,
+}
+
+impl FileSessionStore {
+    /// Create a store rooted at `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Get the file path for a key
+    pub fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(key)
+    }
+}
+
+#[async_trait]
+impl SessionStore for FileSessionStore {
+    async fn write(&self, key: &str, bytes: &[u8]) -> Result<(), SessionError> {
+        // Keys may contain a profile subdirectory
+        let path = self.path_for(key);
+        let dir = path.parent().unwrap_or(&self.dir);
+        fs::create_dir_all(dir)
+            .await
+            .map_err(|e| SessionError::IoError(e.to_string()))?;
+
+        fs::write(&path, bytes)
+            .await
+            .map_err(|e| SessionError::IoError(format!("Failed to write {}: {}", key, e)))
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, SessionError> {
+        let path = self.path_for(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        fs::read(&path)
+            .await
+            .map(Some)
+            .map_err(|e| SessionError::IoError(format!("Failed to read {}: {}", key, e)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), SessionError> {
+        let path = self.path_for(key);
+        if path.exists() {
+            fs::remove_file(&path)
+                .await
+                .map_err(|e| SessionError::IoError(format!("Failed to remove {}: {}", key, e)))?;
+        }
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, SessionError> {
+        Ok(self.path_for(key).exists())
+    }
+
+    async fn keys(&self) -> Result<Vec<String>, SessionError> {
+        let mut keys = Vec::new();
+        let mut pending = vec![(self.dir.clone(), String::new())];
+        while let Some((dir, prefix)) = pending.pop() {
+            let mut entries = match fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(SessionError::IoError(e.to_string())),
+            };
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|e| SessionError::IoError(e.to_string()))?
+            {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let key = format!("{}{}", prefix, name);
+                let is_dir = entry
+                    .file_type()
+                    .await
+                    .map_err(|e| SessionError::IoError(e.to_string()))?
+                    .is_dir();
+                // Profiles are one level deep
+                if is_dir && prefix.is_empty() {
+                    pending.push((entry.path(), format!("{}/", key)));
+                } else if !is_dir {
+                    keys.push(key);
+                }
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// In-memory session store, useful for tests and ephemeral profiles
+#[derive(Debug, Default)]
+pub struct MemorySessionStore {
+    entries: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl MemorySessionStore {
+    /// Create an empty in-memory store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SessionStore for MemorySessionStore {
+    async fn write(&self, key: &str, bytes: &[u8]) -> Result<(), SessionError> {
+        self.entries
+            .write()
+            .await
+            .insert(key.to_string(), bytes.to_vec());
+        Ok(())
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, SessionError> {
+        Ok(self.entries.read().await.get(key).cloned())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), SessionError> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, SessionError> {
+        Ok(self.entries.read().await.contains_key(key))
+    }
+
+    async fn keys(&self) -> Result<Vec<String>, SessionError> {
+        Ok(self.entries.read().await.keys().cloned().collect())
+    }
+}
+
 /// Form data for a tab
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct FormData {
@@ -57,6 +227,8 @@ pub struct TabSessionState {
     pub pinned: bool,
     /// Whether this tab is muted
     pub muted: bool,
+    /// Raw id of the tab group this tab belonged to, if any (added in v2)
+    pub group_id: Option<u64>,
 }
 
 impl TabSessionState {
@@ -72,6 +244,7 @@ impl TabSessionState {
             history_index: 0,
             pinned: false,
             muted: false,
+            group_id: None,
         }
     }
 }
@@ -128,7 +301,10 @@ impl Default for SessionState {
 
 impl SessionState {
     /// Current session format version
-    pub const CURRENT_VERSION: u32 = 1;
+    ///
+    /// - v1: initial format
+    /// - v2: tabs record their `group_id`
+    pub const CURRENT_VERSION: u32 = 2;
 
     /// Create a new empty session state
     pub fn new() -> Self {
@@ -140,6 +316,49 @@ impl SessionState {
         }
     }
 
+    /// Parse a stored session, upgrading older format versions
+    ///
+    /// A missing `version` is treated as v1. Sessions from a newer format
+    /// version fail with [`SessionError::UnsupportedVersion`].
+    pub fn migrate(mut raw: serde_json::Value) -> Result<SessionState, SessionError> {
+        let version = match raw.get("version") {
+            None => 1,
+            Some(value) => value
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| {
+                    SessionError::Corrupted(format!("Invalid session version: {}", value))
+                })?,
+        };
+
+        if version > Self::CURRENT_VERSION {
+            return Err(SessionError::UnsupportedVersion(version));
+        }
+
+        if version < 2 {
+            // v1 -> v2: tabs gained an optional group id
+            for tab in raw
+                .get_mut("windows")
+                .and_then(|windows| windows.as_array_mut())
+                .into_iter()
+                .flatten()
+                .filter_map(|window| window.get_mut("tabs")?.as_array_mut())
+                .flatten()
+            {
+                if let Some(tab) = tab.as_object_mut() {
+                    tab.entry("group_id").or_insert(serde_json::Value::Null);
+                }
+            }
+        }
+
+        if let Some(session) = raw.as_object_mut() {
+            session.insert("version".to_string(), Self::CURRENT_VERSION.into());
+        }
+
+        serde_json::from_value(raw)
+            .map_err(|e| SessionError::Corrupted(format!("Invalid session data: {}", e)))
+    }
+
     /// Get total tab count across all windows
     pub fn tab_count(&self) -> usize {
         self.windows.iter().map(|w| w.tabs.len()).sum()
@@ -182,6 +401,15 @@ pub struct SessionConfig {
     pub auto_save_enabled: bool,
     /// Maximum number of sessions to keep in history
     pub max_session_history: usize,
+    /// Minimum time between writes from `save_if_dirty`, so bursts of
+    /// changes (e.g. rapid navigations) are coalesced into one write
+    pub min_save_interval: Duration,
+    /// Named profile whose session is used, stored in its own subdirectory
+    ///
+    /// `None` uses the session directory itself. Names are limited to ASCII
+    /// letters, digits, `-` and `_`; with any other name, store operations
+    /// fail with [`SessionError::InvalidProfile`].
+    pub profile: Option<String>,
 }
 
 impl Default for SessionConfig {
@@ -191,6 +419,8 @@ impl Default for SessionConfig {
             auto_save_interval_secs: DEFAULT_AUTO_SAVE_INTERVAL_SECS,
             auto_save_enabled: true,
             max_session_history: 5,
+            min_save_interval: DEFAULT_MIN_SAVE_INTERVAL,
+            profile: None,
         }
     }
 }
@@ -203,168 +433,282 @@ impl SessionConfig {
             ..Default::default()
         }
     }
+
+    /// Use the session of a named profile
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+}
+
+/// Check that a profile name is safe to use as a directory name
+fn validate_profile_name(name: &str) -> Result<(), SessionError> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(SessionError::InvalidProfile(name.to_string()))
+    }
 }
 
 /// Session manager for crash recovery
 ///
 /// Handles saving and restoring browser sessions, including crash detection
-/// via a dirty flag (lock file) mechanism.
+/// via a dirty flag (lock file) mechanism. Data is persisted through a
+/// [`SessionStore`], which defaults to [`FileSessionStore`] in the
+/// configured session directory.
 pub struct SessionManager {
     config: SessionConfig,
     current_session: SessionState,
+    store: Box<dyn SessionStore>,
+    /// Whether the session changed since it was last written
+    dirty: AtomicBool,
+    /// When the session was last written to the store
+    last_write: Mutex<Option<Instant>>,
 }
 
 impl SessionManager {
-    /// Create a new session manager
+    /// Create a new session manager backed by the filesystem
     pub fn new(config: SessionConfig) -> Self {
+        let store = Box::new(FileSessionStore::new(config.session_dir.clone()));
+        Self::with_store(config, store)
+    }
+
+    /// Create a session manager backed by a custom store
+    pub fn with_store(config: SessionConfig, store: Box<dyn SessionStore>) -> Self {
         Self {
             config,
             current_session: SessionState::new(),
+            store,
+            dirty: AtomicBool::new(false),
+            last_write: Mutex::new(None),
         }
     }
 
     /// Get the session file path
-    fn session_file_path(&self) -> PathBuf {
-        self.config.session_dir.join(SESSION_FILE)
+    fn session_file_path(&self) -> Result<PathBuf, SessionError> {
+        Ok(self.config.session_dir.join(self.key(SESSION_FILE)?))
+    }
+
+    /// Get the store key for an entry of the active profile
+    ///
+    /// The profile name is checked here rather than only in
+    /// [`Self::switch_profile`], since the config may name any profile and
+    /// an unchecked name could lead outside the session directory.
+    fn key(&self, name: &str) -> Result<String, SessionError> {
+        match &self.config.profile {
+            Some(profile) => {
+                validate_profile_name(profile)?;
+                Ok(format!("{}/{}", profile, name))
+            }
+            None => Ok(name.to_string()),
+        }
     }
 
-    /// Get the lock file path
-    fn lock_file_path(&self) -> PathBuf {
-        self.config.session_dir.join(LOCK_FILE)
+    /// Get the active profile, or `None` for the unnamed default
+    pub fn profile(&self) -> Option<&str> {
+        self.config.profile.as_deref()
+    }
+
+    /// List the named profiles with a saved session or crash lock
+    ///
+    /// The active profile is always included if its name is valid. Names are
+    /// sorted.
+    pub async fn list_profiles(&self) -> Vec<String> {
+        let mut profiles: Vec<String> = self
+            .store
+            .keys()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|key| {
+                let (profile, name) = key.split_once('/')?;
+                (name == SESSION_FILE || name == LOCK_FILE).then(|| profile.to_string())
+            })
+            .chain(
+                self.config
+                    .profile
+                    .clone()
+                    .filter(|profile| validate_profile_name(profile).is_ok()),
+            )
+            .collect();
+        profiles.sort();
+        profiles.dedup();
+        profiles
+    }
+
+    /// Switch to another named profile
+    ///
+    /// The in-memory session is replaced with an empty one; call
+    /// [`Self::restore_session`] to load the profile's saved session. The
+    /// previous profile is neither saved nor marked closed, so callers
+    /// should do that first.
+    pub fn switch_profile(&mut self, name: impl Into<String>) -> Result<(), SessionError> {
+        let name = name.into();
+        validate_profile_name(&name)?;
+
+        self.config.profile = Some(name);
+        self.current_session = SessionState::new();
+        self.dirty.store(false, Ordering::SeqCst);
+        *self.last_write.lock().unwrap() = None;
+        Ok(())
     }
 
     /// Check if the browser crashed during the last session
     ///
-    /// Returns true if a lock file exists (indicating abnormal shutdown)
+    /// Returns true if a lock entry exists (indicating abnormal shutdown).
+    /// Each profile has its own lock.
     pub async fn was_crash(&self) -> bool {
-        self.lock_file_path().exists()
+        let Ok(key) = self.key(LOCK_FILE) else {
+            return false;
+        };
+        self.store.exists(&key).await.unwrap_or(false)
     }
 
-    /// Create the lock file (dirty flag)
+    /// Create the lock entry (dirty flag)
     ///
     /// Called on startup to indicate session is active
     pub async fn mark_session_active(&self) -> Result<(), SessionError> {
-        fs::create_dir_all(&self.config.session_dir)
-            .await
-            .map_err(|e| SessionError::IoError(e.to_string()))?;
-
-        let lock_path = self.lock_file_path();
         let timestamp = Utc::now().to_rfc3339();
-        fs::write(&lock_path, timestamp)
+        self.store
+            .write(&self.key(LOCK_FILE)?, timestamp.as_bytes())
             .await
-            .map_err(|e| SessionError::IoError(format!("Failed to create lock file: {}", e)))?;
-
-        Ok(())
     }
 
-    /// Remove the lock file (clean shutdown)
+    /// Remove the lock entry (clean shutdown)
     ///
     /// Called on clean shutdown to indicate session ended normally
     pub async fn mark_session_closed(&self) -> Result<(), SessionError> {
-        let lock_path = self.lock_file_path();
-        if lock_path.exists() {
-            fs::remove_file(&lock_path)
-                .await
-                .map_err(|e| SessionError::IoError(format!("Failed to remove lock file: {}", e)))?;
-        }
-        Ok(())
+        self.store.delete(&self.key(LOCK_FILE)?).await
     }
 
-    /// Save the current session to disk
+    /// Save the current session to the store
     pub async fn save_session(&mut self) -> Result<(), SessionError> {
         self.current_session.last_saved = Utc::now();
+        self.dirty.store(false, Ordering::SeqCst);
 
-        fs::create_dir_all(&self.config.session_dir)
-            .await
-            .map_err(|e| SessionError::IoError(e.to_string()))?;
-
-        let json = serde_json::to_string_pretty(&self.current_session)
-            .map_err(|e| SessionError::SerializationError(e.to_string()))?;
-
-        let session_path = self.session_file_path();
-        fs::write(&session_path, json)
-            .await
-            .map_err(|e| SessionError::SaveFailed(e.to_string()))?;
+        let result = self.write_session(&self.current_session).await;
+        if result.is_err() {
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+        result
+    }
 
-        Ok(())
+    /// Record that the session changed and should be saved
+    pub fn mark_dirty(&self) {
+        self.dirty.store(true, Ordering::SeqCst);
     }
 
-    /// Restore session from disk
-    pub async fn restore_session(&mut self) -> Result<SessionState, SessionError> {
-        let session_path = self.session_file_path();
+    /// Check whether the session changed since it was last saved
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::SeqCst)
+    }
 
-        if !session_path.exists() {
-            return Err(SessionError::NotFound(session_path.display().to_string()));
+    /// Save the session if it changed since the last save
+    ///
+    /// Writes are debounced by [`SessionConfig::min_save_interval`]: if the
+    /// last write was more recent than that, nothing is written and the
+    /// session stays dirty for a later call. Returns whether a write happened.
+    pub async fn save_if_dirty(&self) -> Result<bool, SessionError> {
+        if !self.is_dirty() {
+            return Ok(false);
         }
 
-        let json = fs::read_to_string(&session_path)
-            .await
-            .map_err(|e| SessionError::IoError(e.to_string()))?;
+        let debounced = self
+            .last_write
+            .lock()
+            .unwrap()
+            .is_some_and(|at| at.elapsed() < self.config.min_save_interval);
+        if debounced {
+            return Ok(false);
+        }
 
-        let session: SessionState = serde_json::from_str(&json)
-            .map_err(|e| SessionError::Corrupted(format!("Invalid session JSON: {}", e)))?;
+        // Clear the flag before writing so changes made meanwhile are kept
+        self.dirty.store(false, Ordering::SeqCst);
 
-        // Check version compatibility
-        if session.version > SessionState::CURRENT_VERSION {
-            return Err(SessionError::Corrupted(format!(
-                "Session version {} is newer than supported version {}",
-                session.version,
-                SessionState::CURRENT_VERSION
-            )));
+        let mut session = self.current_session.clone();
+        session.last_saved = Utc::now();
+        if let Err(e) = self.write_session(&session).await {
+            self.dirty.store(true, Ordering::SeqCst);
+            return Err(e);
         }
+        Ok(true)
+    }
+
+    /// Serialize a session and write it to the store
+    async fn write_session(&self, session: &SessionState) -> Result<(), SessionError> {
+        let json = serde_json::to_string_pretty(session)
+            .map_err(|e| SessionError::SerializationError(e.to_string()))?;
+
+        self.store
+            .write(&self.key(SESSION_FILE)?, json.as_bytes())
+            .await
+            .map_err(|e| SessionError::SaveFailed(e.to_string()))?;
+
+        *self.last_write.lock().unwrap() = Some(Instant::now());
+        Ok(())
+    }
 
+    /// Restore session from the store
+    pub async fn restore_session(&mut self) -> Result<SessionState, SessionError> {
+        let session = self.load_stored_session().await?;
         self.current_session = session.clone();
         Ok(session)
     }
 
     /// Clear the saved session
     pub async fn clear_session(&mut self) -> Result<(), SessionError> {
-        let session_path = self.session_file_path();
-
-        if session_path.exists() {
-            fs::remove_file(&session_path)
-                .await
-                .map_err(|e| SessionError::IoError(format!("Failed to clear session: {}", e)))?;
-        }
-
+        self.store.delete(&self.key(SESSION_FILE)?).await?;
         self.current_session = SessionState::new();
         Ok(())
     }
 
+    /// Read and parse the stored session, migrating older formats
+    async fn load_stored_session(&self) -> Result<SessionState, SessionError> {
+        let bytes = self
+            .store
+            .read(&self.key(SESSION_FILE)?)
+            .await?
+            .ok_or_else(|| SessionError::NotFound(SESSION_FILE.to_string()))?;
+
+        let raw: serde_json::Value = serde_json::from_slice(&bytes)
+            .map_err(|e| SessionError::Corrupted(format!("Invalid session JSON: {}", e)))?;
+        SessionState::migrate(raw)
+    }
+
     /// Get a reference to the current session
     pub fn current_session(&self) -> &SessionState {
         &self.current_session
     }
 
     /// Get a mutable reference to the current session
+    ///
+    /// Marks the session dirty, since the caller may change it.
     pub fn current_session_mut(&mut self) -> &mut SessionState {
+        self.mark_dirty();
         &mut self.current_session
     }
 
     /// Update the current session state
     pub fn set_session(&mut self, session: SessionState) {
         self.current_session = session;
+        self.mark_dirty();
     }
 
     /// Check if a previous session exists that can be restored
     pub async fn has_restorable_session(&self) -> bool {
-        self.session_file_path().exists()
+        let Ok(key) = self.key(SESSION_FILE) else {
+            return false;
+        };
+        self.store.exists(&key).await.unwrap_or(false)
     }
 
     /// Get session info for restore dialog
     pub async fn get_session_info(&self) -> Result<SessionInfo, SessionError> {
-        let session_path = self.session_file_path();
-
-        if !session_path.exists() {
-            return Err(SessionError::NotFound(session_path.display().to_string()));
-        }
-
-        let json = fs::read_to_string(&session_path)
-            .await
-            .map_err(|e| SessionError::IoError(e.to_string()))?;
-
-        let session: SessionState =
-            serde_json::from_str(&json).map_err(|e| SessionError::Corrupted(e.to_string()))?;
+        let session = self.load_stored_session().await?;
 
         Ok(SessionInfo {
             window_count: session.window_count(),
@@ -376,13 +720,14 @@ impl SessionManager {
 
     /// Start auto-save background task
     ///
-    /// Returns a handle that can be used to stop the auto-save task
+    /// Returns a handle that can be used to stop the auto-save task, or
+    /// `None` if auto-save is disabled or the profile name is invalid
     pub fn start_auto_save(&self) -> Option<AutoSaveHandle> {
         if !self.config.auto_save_enabled {
             return None;
         }
 
-        let session_path = self.session_file_path();
+        let session_path = self.session_file_path().ok()?;
         let interval_secs = self.config.auto_save_interval_secs;
 
         Some(AutoSaveHandle {
@@ -481,7 +826,7 @@ mod tests {
         manager.save_session().await.unwrap();
 
         // Verify file exists
-        assert!(manager.session_file_path().exists());
+        assert!(manager.session_file_path().unwrap().exists());
 
         // Restore
         let restored = manager.restore_session().await.unwrap();
@@ -518,12 +863,12 @@ mod tests {
         manager.set_session(test_session);
         manager.save_session().await.unwrap();
 
-        assert!(manager.session_file_path().exists());
+        assert!(manager.session_file_path().unwrap().exists());
 
         // Clear session
         manager.clear_session().await.unwrap();
 
-        assert!(!manager.session_file_path().exists());
+        assert!(!manager.session_file_path().unwrap().exists());
         assert!(manager.current_session().is_empty());
     }
 
@@ -596,6 +941,7 @@ mod tests {
         assert!(tab.history.is_empty());
         assert!(!tab.pinned);
         assert!(!tab.muted);
+        assert!(tab.group_id.is_none());
     }
 
     #[test]
@@ -620,6 +966,7 @@ mod tests {
         );
         assert!(config.auto_save_enabled);
         assert_eq!(config.max_session_history, 5);
+        assert_eq!(config.min_save_interval, DEFAULT_MIN_SAVE_INTERVAL);
     }
 
     #[test]
@@ -643,6 +990,237 @@ mod tests {
         assert!(form_data.fields.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_session_manager_memory_store_round_trip() {
+        let config = SessionConfig::with_session_dir("/nonexistent/session/dir");
+        let mut manager = SessionManager::with_store(config, Box::new(MemorySessionStore::new()));
+
+        assert!(!manager.has_restorable_session().await);
+
+        let test_session = create_test_session_state();
+        manager.set_session(test_session.clone());
+        manager.save_session().await.unwrap();
+
+        assert!(manager.has_restorable_session().await);
+        assert!(!manager.session_file_path().unwrap().exists());
+
+        manager.set_session(SessionState::new());
+        let restored = manager.restore_session().await.unwrap();
+        assert_eq!(restored.windows, test_session.windows);
+        assert_eq!(manager.current_session().tab_count(), 2);
+
+        manager.clear_session().await.unwrap();
+        assert!(!manager.has_restorable_session().await);
+    }
+
+    #[tokio::test]
+    async fn test_session_manager_memory_store_crash_detection() {
+        let config = SessionConfig::with_session_dir("/nonexistent/session/dir");
+        let manager = SessionManager::with_store(config, Box::new(MemorySessionStore::new()));
+
+        assert!(!manager.was_crash().await);
+
+        manager.mark_session_active().await.unwrap();
+        assert!(manager.was_crash().await);
+
+        manager.mark_session_closed().await.unwrap();
+        assert!(!manager.was_crash().await);
+        assert!(!Path::new("/nonexistent/session/dir").exists());
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_corrupted_session() {
+        let store = MemorySessionStore::new();
+        store.write(SESSION_FILE, b"not json").await.unwrap();
+
+        let mut manager = SessionManager::with_store(SessionConfig::default(), Box::new(store));
+        let result = manager.restore_session().await;
+        assert!(matches!(result, Err(SessionError::Corrupted(_))));
+    }
+
+    /// A session as written by format v1, before tabs had group ids
+    const V1_SESSION_JSON: &str = r#"{
+        "windows": [{
+            "window_id": "00000000-0000-0000-0000-000000000001",
+            "tabs": [{
+                "tab_id": "00000000-0000-0000-0000-000000000002",
+                "url": "https://example.com",
+                "title": "Example",
+                "scroll_position": [0, 120],
+                "form_data": null,
+                "history": ["https://example.com"],
+                "history_index": 0,
+                "pinned": true,
+                "muted": false
+            }],
+            "active_tab_index": 0,
+            "bounds": null,
+            "maximized": false,
+            "minimized": false
+        }],
+        "last_saved": "2024-01-01T00:00:00Z",
+        "version": 1,
+        "focused_window_index": 0
+    }"#;
+
+    #[tokio::test]
+    async fn test_restore_migrates_v1_session() {
+        let store = MemorySessionStore::new();
+        store
+            .write(SESSION_FILE, V1_SESSION_JSON.as_bytes())
+            .await
+            .unwrap();
+
+        let mut manager = SessionManager::with_store(SessionConfig::default(), Box::new(store));
+        let restored = manager.restore_session().await.unwrap();
+
+        assert_eq!(restored.version, SessionState::CURRENT_VERSION);
+        assert_eq!(restored.tab_count(), 1);
+        let tab = &restored.windows[0].tabs[0];
+        assert_eq!(tab.url.as_deref(), Some("https://example.com"));
+        assert_eq!(tab.scroll_position, (0, 120));
+        assert!(tab.pinned);
+        assert_eq!(tab.group_id, None);
+    }
+
+    #[test]
+    fn test_migrate_treats_missing_version_as_v1() {
+        let mut raw: serde_json::Value = serde_json::from_str(V1_SESSION_JSON).unwrap();
+        raw.as_object_mut().unwrap().remove("version");
+
+        let session = SessionState::migrate(raw).unwrap();
+        assert_eq!(session.version, SessionState::CURRENT_VERSION);
+        assert_eq!(session.tab_count(), 1);
+    }
+
+    #[test]
+    fn test_migrate_current_version_round_trip() {
+        let mut session = create_test_session_state();
+        session.windows[0].tabs[0].group_id = Some(3);
+
+        let raw = serde_json::to_value(&session).unwrap();
+        assert_eq!(SessionState::migrate(raw).unwrap(), session);
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_newer_version() {
+        let mut raw: serde_json::Value = serde_json::from_str(V1_SESSION_JSON).unwrap();
+        raw["version"] = (SessionState::CURRENT_VERSION + 1).into();
+
+        let store = MemorySessionStore::new();
+        store
+            .write(SESSION_FILE, raw.to_string().as_bytes())
+            .await
+            .unwrap();
+
+        let mut manager = SessionManager::with_store(SessionConfig::default(), Box::new(store));
+        let result = manager.restore_session().await;
+        assert!(matches!(
+            result,
+            Err(SessionError::UnsupportedVersion(v)) if v == SessionState::CURRENT_VERSION + 1
+        ));
+    }
+
+    /// Store that counts session writes
+    struct CountingStore {
+        inner: MemorySessionStore,
+        writes: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl SessionStore for CountingStore {
+        async fn write(&self, key: &str, bytes: &[u8]) -> Result<(), SessionError> {
+            if key == SESSION_FILE {
+                self.writes.fetch_add(1, Ordering::SeqCst);
+            }
+            self.inner.write(key, bytes).await
+        }
+
+        async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, SessionError> {
+            self.inner.read(key).await
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), SessionError> {
+            self.inner.delete(key).await
+        }
+
+        async fn exists(&self, key: &str) -> Result<bool, SessionError> {
+            self.inner.exists(key).await
+        }
+    }
+
+    fn counting_manager(
+        min_save_interval: Duration,
+    ) -> (
+        SessionManager,
+        std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) {
+        let writes = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let store = CountingStore {
+            inner: MemorySessionStore::new(),
+            writes: writes.clone(),
+        };
+        let config = SessionConfig {
+            min_save_interval,
+            ..SessionConfig::default()
+        };
+        (SessionManager::with_store(config, Box::new(store)), writes)
+    }
+
+    #[tokio::test]
+    async fn test_save_if_dirty_skips_clean_session() {
+        let (manager, writes) = counting_manager(Duration::ZERO);
+
+        assert!(!manager.is_dirty());
+        assert!(!manager.save_if_dirty().await.unwrap());
+        assert_eq!(writes.load(Ordering::SeqCst), 0);
+
+        manager.mark_dirty();
+        assert!(manager.save_if_dirty().await.unwrap());
+        assert!(!manager.is_dirty());
+        assert!(!manager.save_if_dirty().await.unwrap());
+        assert_eq!(writes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_save_if_dirty_debounces_rapid_changes() {
+        let (manager, writes) = counting_manager(Duration::from_secs(60));
+
+        for _ in 0..10 {
+            manager.mark_dirty();
+            manager.save_if_dirty().await.unwrap();
+        }
+
+        assert_eq!(writes.load(Ordering::SeqCst), 1);
+        // The later changes are still pending
+        assert!(manager.is_dirty());
+    }
+
+    #[tokio::test]
+    async fn test_save_if_dirty_writes_again_after_interval() {
+        let (manager, writes) = counting_manager(Duration::from_millis(20));
+
+        manager.mark_dirty();
+        assert!(manager.save_if_dirty().await.unwrap());
+        manager.mark_dirty();
+        assert!(!manager.save_if_dirty().await.unwrap());
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(manager.save_if_dirty().await.unwrap());
+        assert_eq!(writes.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_set_session_marks_dirty() {
+        let (mut manager, _) = counting_manager(Duration::ZERO);
+
+        manager.set_session(create_test_session_state());
+        assert!(manager.is_dirty());
+
+        manager.save_session().await.unwrap();
+        assert!(!manager.is_dirty());
+    }
+
     #[tokio::test]
     async fn test_auto_save_handle() {
         let temp_dir = TempDir::new().unwrap();
@@ -666,4 +1244,109 @@ mod tests {
         let handle = manager.start_auto_save();
         assert!(handle.is_none());
     }
+
+    fn session_with_url(url: &str) -> SessionState {
+        let mut session = SessionState::new();
+        let mut window = WindowState::new(WindowId::new());
+        let mut tab = TabSessionState::new(TabId::new());
+        tab.url = Some(url.to_string());
+        window.tabs.push(tab);
+        session.add_window(window);
+        session
+    }
+
+    fn first_url(session: &SessionState) -> Option<&str> {
+        session.windows[0].tabs[0].url.as_deref()
+    }
+
+    #[tokio::test]
+    async fn test_profiles_are_isolated() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SessionConfig::with_session_dir(temp_dir.path()).with_profile("work");
+        let mut manager = SessionManager::new(config);
+        assert_eq!(manager.profile(), Some("work"));
+
+        manager.set_session(session_with_url("https://work.example/"));
+        manager.save_session().await.unwrap();
+        manager.mark_session_active().await.unwrap();
+        assert!(temp_dir.path().join("work").join(SESSION_FILE).exists());
+
+        manager.switch_profile("personal").unwrap();
+        assert!(manager.current_session().is_empty());
+        assert!(!manager.has_restorable_session().await);
+        // The work profile's lock doesn't leak into this one
+        assert!(!manager.was_crash().await);
+        manager.set_session(session_with_url("https://home.example/"));
+        manager.save_session().await.unwrap();
+
+        // A fresh manager restores each profile independently
+        let mut work = SessionManager::new(
+            SessionConfig::with_session_dir(temp_dir.path()).with_profile("work"),
+        );
+        let restored = work.restore_session().await.unwrap();
+        assert_eq!(first_url(&restored), Some("https://work.example/"));
+        assert!(work.was_crash().await);
+
+        let restored = manager.restore_session().await.unwrap();
+        assert_eq!(first_url(&restored), Some("https://home.example/"));
+        assert_eq!(restored.tab_count(), 1);
+
+        // The unnamed default profile is untouched
+        let default = SessionManager::new(SessionConfig::with_session_dir(temp_dir.path()));
+        assert!(!default.has_restorable_session().await);
+        assert_eq!(
+            default.list_profiles().await,
+            vec!["personal".to_string(), "work".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_profiles_with_memory_store() {
+        let config = SessionConfig::default().with_profile("work");
+        let mut manager = SessionManager::with_store(config, Box::new(MemorySessionStore::new()));
+
+        // The active profile is listed even before anything is saved
+        assert_eq!(manager.list_profiles().await, vec!["work"]);
+
+        manager.mark_session_active().await.unwrap();
+        manager.switch_profile("play").unwrap();
+        assert_eq!(manager.list_profiles().await, vec!["play", "work"]);
+
+        for name in ["", "../escape", "a/b", "space name"] {
+            assert!(matches!(
+                manager.switch_profile(name),
+                Err(SessionError::InvalidProfile(_))
+            ));
+        }
+        assert_eq!(manager.profile(), Some("play"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_config_profile_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let session_dir = temp_dir.path().join("sessions");
+        let config = SessionConfig::with_session_dir(&session_dir).with_profile("../escape");
+        let mut manager = SessionManager::new(config);
+
+        assert!(matches!(
+            manager.mark_session_active().await,
+            Err(SessionError::InvalidProfile(_))
+        ));
+        manager.set_session(session_with_url("https://example.com/"));
+        assert!(matches!(
+            manager.save_session().await,
+            Err(SessionError::InvalidProfile(_))
+        ));
+        assert!(matches!(
+            manager.restore_session().await,
+            Err(SessionError::InvalidProfile(_))
+        ));
+        assert!(!manager.was_crash().await);
+        assert!(!manager.has_restorable_session().await);
+        assert!(manager.list_profiles().await.is_empty());
+
+        // Nothing was written, inside or outside the session directory
+        assert!(!temp_dir.path().join("escape").exists());
+        assert!(!session_dir.exists());
+    }
 }
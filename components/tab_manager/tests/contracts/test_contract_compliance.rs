@@ -138,6 +138,10 @@ fn test_tab_info_has_required_fields() {
         can_go_forward: false,
         is_private: false,
         load_state: TabLoadState::Unloaded,
+        group: None,
+        favicon: None,
+        favicon_mime: None,
+        favicon_persistable: false,
     };
 
     // Verify all fields compile and have correct types
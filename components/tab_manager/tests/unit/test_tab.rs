@@ -25,10 +25,12 @@ fn test_tab_creation() {
         can_go_back: false,
         can_go_forward: false,
         favicon: None,
+        favicon_mime: None,
         process_id: None,
         render_surface,
         is_private: false,
         load_state: TabLoadState::Unloaded,
+        group: None,
     };
 
     assert_eq!(tab.id, tab_id);
@@ -65,10 +67,12 @@ fn test_tab_with_process_id() {
         can_go_back: false,
         can_go_forward: false,
         favicon: None,
+        favicon_mime: None,
         process_id: Some(process_id),
         render_surface,
         is_private: false,
         load_state: TabLoadState::Unloaded,
+        group: None,
     };
 
     assert_eq!(tab.process_id, Some(process_id));
@@ -95,10 +99,12 @@ fn test_tab_info_from_tab() {
         can_go_back: true,
         can_go_forward: false,
         favicon: None,
+        favicon_mime: None,
         process_id: None,
         render_surface,
         is_private: false,
         load_state: TabLoadState::Loaded,
+        group: None,
     };
 
     let info = TabInfo::from(&tab);
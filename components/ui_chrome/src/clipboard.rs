@@ -0,0 +1,98 @@
+//! Address Bar Clipboard Editing
+//!
+//! Pure string helpers behind the address bar's Cut/Copy/Paste/Select All
+//! actions. Selections are character (not byte) ranges, matching egui's
+//! text cursors, and are clamped to the text so stale selections are safe.
+//!
+//! Reading and writing the system clipboard happens in `UiChrome::render`;
+//! these helpers only compute the resulting text and cursor.
+
+use std::ops::Range;
+
+/// Convert a character range into a byte range within `text`, clamped to its length
+fn byte_range(text: &str, selection: &Range<usize>) -> Range<usize> {
+    let byte_at = |char_index: usize| {
+        text.char_indices()
+            .nth(char_index)
+            .map_or(text.len(), |(byte, _)| byte)
+    };
+    let start = byte_at(selection.start.min(selection.end));
+    let end = byte_at(selection.end.max(selection.start));
+    start..end
+}
+
+/// Get the selected text
+pub fn selected_text(text: &str, selection: &Range<usize>) -> String {
+    text[byte_range(text, selection)].to_string()
+}
+
+/// Remove the selection from `text`
+///
+/// Returns the remaining text and the removed text. The cursor ends up at the
+/// start of the removed range.
+pub fn cut_selection(text: &str, selection: &Range<usize>) -> (String, String) {
+    let bytes = byte_range(text, selection);
+    let cut = text[bytes.clone()].to_string();
+    let mut remaining = text.to_string();
+    remaining.replace_range(bytes, "");
+    (remaining, cut)
+}
+
+/// Replace the selection with `pasted`
+///
+/// Line breaks are dropped since the address bar is single-line. Returns the
+/// new text and the character index of the cursor after the pasted text.
+pub fn paste_over_selection(text: &str, selection: &Range<usize>, pasted: &str) -> (String, usize) {
+    let pasted: String = pasted
+        .chars()
+        .filter(|c| !matches!(c, '\r' | '\n'))
+        .collect();
+    let bytes = byte_range(text, selection);
+    let cursor = text[..bytes.start].chars().count() + pasted.chars().count();
+    let mut result = text.to_string();
+    result.replace_range(bytes, &pasted);
+    (result, cursor)
+}
+
+/// Selection covering all of `text`
+pub fn select_all(text: &str) -> Range<usize> {
+    0..text.chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selected_text_uses_char_indices() {
+        let text = "https://exämple.com";
+        assert_eq!(selected_text(text, &(8..15)), "exämple");
+        assert_eq!(selected_text(text, &(16..100)), "com");
+        assert_eq!(selected_text(text, &(3..3)), "");
+    }
+
+    #[test]
+    fn test_cut_selection_removes_text() {
+        let (remaining, cut) = cut_selection("https://example.com/path", &(19..24));
+        assert_eq!(remaining, "https://example.com");
+        assert_eq!(cut, "/path");
+    }
+
+    #[test]
+    fn test_paste_replaces_selection() {
+        let (text, cursor) = paste_over_selection("https://old.com", &(8..11), "new");
+        assert_eq!(text, "https://new.com");
+        assert_eq!(cursor, 11);
+
+        // Empty selection inserts at the cursor; line breaks are dropped
+        let (text, cursor) = paste_over_selection("ab", &(1..1), "x\r\ny");
+        assert_eq!(text, "axyb");
+        assert_eq!(cursor, 3);
+    }
+
+    #[test]
+    fn test_select_all() {
+        assert_eq!(select_all("héllo"), 0..5);
+        assert_eq!(select_all(""), 0..0);
+    }
+}
@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 use shared_types::TabId;
 use std::collections::VecDeque;
 
+/// Default number of recently closed tabs to remember
+pub const DEFAULT_MAX_RECENTLY_CLOSED: usize = 25;
+
 /// Information about a recently closed tab
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClosedTabInfo {
@@ -61,7 +64,7 @@ impl Default for RecentlyClosedTabs {
     fn default() -> Self {
         Self {
             tabs: VecDeque::new(),
-            max_tabs: 10, // Track last 10 closed tabs
+            max_tabs: DEFAULT_MAX_RECENTLY_CLOSED,
         }
     }
 }
@@ -76,14 +79,32 @@ impl RecentlyClosedTabs {
     }
 
     /// Add a closed tab to the tracker
+    ///
+    /// Tabs are kept ordered by `closed_at`, newest first. A tab next to an
+    /// entry with the same URL replaces it, so closing the same page
+    /// repeatedly leaves a single entry. The oldest entries are evicted
+    /// beyond the maximum.
     pub fn add(&mut self, tab: ClosedTabInfo) {
-        // Add to front (most recent)
-        self.tabs.push_front(tab);
-
-        // Trim to max size
-        while self.tabs.len() > self.max_tabs {
-            self.tabs.pop_back();
+        // Insert before older entries; ties go in front (most recent)
+        let index = self
+            .tabs
+            .iter()
+            .position(|t| t.closed_at <= tab.closed_at)
+            .unwrap_or(self.tabs.len());
+
+        // Dedupe against the neighbouring entries; tabs that never loaded a
+        // page have no URL and are always kept
+        if !tab.url.is_empty() {
+            if index > 0 && self.tabs[index - 1].url == tab.url {
+                return;
+            }
+            if self.tabs.get(index).is_some_and(|t| t.url == tab.url) {
+                self.tabs.remove(index);
+            }
         }
+        self.tabs.insert(index, tab);
+
+        self.trim();
     }
 
     /// Get all closed tabs (most recent first)
@@ -91,6 +112,40 @@ impl RecentlyClosedTabs {
         &self.tabs
     }
 
+    /// Get all closed tabs sorted by `closed_at`, newest first
+    ///
+    /// Unlike [`Self::get_all`], this doesn't rely on the stored order, which
+    /// may come from an older session file.
+    pub fn newest_first(&self) -> Vec<&ClosedTabInfo> {
+        let mut tabs: Vec<_> = self.tabs.iter().collect();
+        tabs.sort_by_key(|tab| std::cmp::Reverse(tab.closed_at));
+        tabs
+    }
+
+    /// Get the maximum number of closed tabs tracked
+    pub fn max_tabs(&self) -> usize {
+        self.max_tabs
+    }
+
+    /// Set the maximum number of closed tabs tracked, evicting the oldest
+    /// entries beyond it
+    pub fn set_max_tabs(&mut self, max_tabs: usize) {
+        self.max_tabs = max_tabs;
+        self.trim();
+    }
+
+    /// Evict the oldest entries beyond the maximum
+    fn trim(&mut self) {
+        while self.tabs.len() > self.max_tabs {
+            self.tabs.pop_back();
+        }
+    }
+
+    /// Remove and return the most recently closed tab
+    pub fn pop_most_recent(&mut self) -> Option<ClosedTabInfo> {
+        self.tabs.pop_front()
+    }
+
     /// Remove a tab from the tracker (when it's restored)
     pub fn remove(&mut self, tab_id: TabId) -> Option<ClosedTabInfo> {
         if let Some(index) = self.tabs.iter().position(|t| t.id == tab_id) {
@@ -238,7 +293,7 @@ impl CrashRecoveryUi {
                     });
                 } else {
                     egui::ScrollArea::vertical().show(ui, |ui| {
-                        for closed_tab in self.recently_closed.get_all() {
+                        for closed_tab in self.recently_closed.newest_first() {
                             ui.group(|ui| {
                                 ui.horizontal(|ui| {
                                     ui.vertical(|ui| {
@@ -297,6 +352,18 @@ impl CrashRecoveryUi {
         self.recently_closed.add(tab);
     }
 
+    /// Get the maximum number of recently closed tabs remembered
+    pub fn max_recently_closed(&self) -> usize {
+        self.recently_closed.max_tabs()
+    }
+
+    /// Set the maximum number of recently closed tabs remembered
+    ///
+    /// The oldest entries beyond the new maximum are dropped.
+    pub fn set_max_recently_closed(&mut self, max: usize) {
+        self.recently_closed.set_max_tabs(max);
+    }
+
     /// Remove a tab from recently closed (when restored)
     pub fn remove_closed_tab(&mut self, tab_id: TabId) -> Option<ClosedTabInfo> {
         self.recently_closed.remove(tab_id)
@@ -421,4 +488,65 @@ mod tests {
 
         assert_eq!(ui.closed_tab_count(), 1);
     }
+
+    fn closed_tab(title: &str, url: &str, closed_at: u64) -> ClosedTabInfo {
+        ClosedTabInfo {
+            id: TabId::new(),
+            title: title.to_string(),
+            url: url.to_string(),
+            closed_at,
+        }
+    }
+
+    #[test]
+    fn test_recently_closed_capped_newest_first() {
+        let mut ui = CrashRecoveryUi::new();
+        assert_eq!(ui.max_recently_closed(), DEFAULT_MAX_RECENTLY_CLOSED);
+
+        for i in 0..30 {
+            let title = format!("Tab {}", i);
+            let url = format!("https://example{}.com", i);
+            ui.add_closed_tab(closed_tab(&title, &url, 1_700_000_000 + i));
+        }
+
+        assert_eq!(ui.closed_tab_count(), 25);
+        let titles: Vec<_> = ui
+            .recently_closed
+            .newest_first()
+            .iter()
+            .map(|t| t.title.clone())
+            .collect();
+        assert_eq!(titles.first().unwrap(), "Tab 29");
+        assert_eq!(titles.last().unwrap(), "Tab 5");
+
+        ui.set_max_recently_closed(3);
+        assert_eq!(ui.closed_tab_count(), 3);
+        assert_eq!(ui.recently_closed.get_all()[2].title, "Tab 27");
+    }
+
+    #[test]
+    fn test_recently_closed_sorted_and_deduped() {
+        let mut tracker = RecentlyClosedTabs::default();
+
+        tracker.add(closed_tab("A", "https://a.example", 100));
+        tracker.add(closed_tab("C", "https://c.example", 300));
+        // Arrives late but is placed by its close time
+        tracker.add(closed_tab("B", "https://b.example", 200));
+        let titles: Vec<_> = tracker.get_all().iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["C", "B", "A"]);
+
+        // Closing the same page again replaces the previous entry
+        tracker.add(closed_tab("C again", "https://c.example", 400));
+        assert_eq!(tracker.count(), 3);
+        assert_eq!(tracker.get_all()[0].title, "C again");
+
+        // Non-consecutive duplicates are kept
+        tracker.add(closed_tab("A again", "https://a.example", 500));
+        assert_eq!(tracker.count(), 4);
+
+        // Blank tabs have nothing to dedupe on
+        tracker.add(closed_tab("New Tab", "", 600));
+        tracker.add(closed_tab("New Tab", "", 700));
+        assert_eq!(tracker.count(), 6);
+    }
 }
@@ -27,8 +27,13 @@
 
 use egui::Color32;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
 use std::time::{Duration, SystemTime};
 
+/// Default cap on captured request/response body size (5 MB)
+pub const DEFAULT_MAX_BODY_SIZE: usize = 5 * 1024 * 1024;
+
 /// Tab selection for the developer tools panel
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum DevToolsTab {
@@ -134,6 +139,39 @@ impl ConsoleLevel {
             ConsoleLevel::Debug => "[DEBUG]",
         }
     }
+
+    /// Get the display label for this level in the level filter
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConsoleLevel::Log => "Log",
+            ConsoleLevel::Info => "Info",
+            ConsoleLevel::Warn => "Warnings",
+            ConsoleLevel::Error => "Errors",
+            ConsoleLevel::Debug => "Verbose",
+        }
+    }
+
+    /// Get the severity rank used for level filtering
+    ///
+    /// Debug is the least severe; Log and Info rank equally.
+    pub fn severity(&self) -> u8 {
+        match self {
+            ConsoleLevel::Debug => 0,
+            ConsoleLevel::Log | ConsoleLevel::Info => 1,
+            ConsoleLevel::Warn => 2,
+            ConsoleLevel::Error => 3,
+        }
+    }
+
+    /// Levels offered by the console level filter, least severe first
+    pub fn filter_levels() -> &'static [ConsoleLevel] {
+        &[
+            ConsoleLevel::Debug,
+            ConsoleLevel::Info,
+            ConsoleLevel::Warn,
+            ConsoleLevel::Error,
+        ]
+    }
 }
 
 /// A single console message
@@ -284,19 +322,110 @@ impl NetworkStatus {
     }
 }
 
+/// A phase of a network request, in the order they occur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NetworkPhase {
+    /// DNS lookup
+    Dns,
+    /// TCP connection setup
+    Connect,
+    /// TLS handshake
+    Tls,
+    /// Sending the request
+    Send,
+    /// Waiting for the first byte (TTFB)
+    Wait,
+    /// Downloading the response
+    Receive,
+}
+
+impl NetworkPhase {
+    /// All phases, in the order they occur
+    pub const ALL: [NetworkPhase; 6] = [
+        NetworkPhase::Dns,
+        NetworkPhase::Connect,
+        NetworkPhase::Tls,
+        NetworkPhase::Send,
+        NetworkPhase::Wait,
+        NetworkPhase::Receive,
+    ];
+
+    /// Get the display label for this phase
+    pub fn label(&self) -> &'static str {
+        match self {
+            NetworkPhase::Dns => "DNS Lookup",
+            NetworkPhase::Connect => "Initial Connection",
+            NetworkPhase::Tls => "SSL/TLS",
+            NetworkPhase::Send => "Request Sent",
+            NetworkPhase::Wait => "Waiting (TTFB)",
+            NetworkPhase::Receive => "Content Download",
+        }
+    }
+
+    /// Get the waterfall color for this phase
+    pub fn color(&self) -> Color32 {
+        match self {
+            NetworkPhase::Dns => Color32::from_rgb(0, 150, 136),
+            NetworkPhase::Connect => Color32::from_rgb(255, 152, 0),
+            NetworkPhase::Tls => Color32::from_rgb(156, 39, 176),
+            NetworkPhase::Send => Color32::from_rgb(158, 158, 158),
+            NetworkPhase::Wait => Color32::from_rgb(76, 175, 80),
+            NetworkPhase::Receive => Color32::from_rgb(33, 150, 243),
+        }
+    }
+}
+
 /// Timing information for a network request
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NetworkTiming {
-    /// Time to establish connection (ms)
-    pub connect_time_ms: u64,
-    /// Time to first byte (ms)
-    pub ttfb_ms: u64,
-    /// Total duration (ms)
-    pub total_time_ms: u64,
+    /// DNS lookup time
+    pub dns: Duration,
+    /// TCP connection time (excluding TLS)
+    pub connect: Duration,
+    /// TLS handshake time
+    pub tls: Duration,
+    /// Time spent sending the request
+    pub send: Duration,
+    /// Time to first byte after the request was sent
+    pub wait: Duration,
+    /// Time spent downloading the response
+    pub receive: Duration,
     /// Download size in bytes
     pub download_size: u64,
 }
 
+impl NetworkTiming {
+    /// Get the duration of a phase
+    pub fn phase(&self, phase: NetworkPhase) -> Duration {
+        match phase {
+            NetworkPhase::Dns => self.dns,
+            NetworkPhase::Connect => self.connect,
+            NetworkPhase::Tls => self.tls,
+            NetworkPhase::Send => self.send,
+            NetworkPhase::Wait => self.wait,
+            NetworkPhase::Receive => self.receive,
+        }
+    }
+
+    /// Set the duration of a phase
+    pub fn set_phase(&mut self, phase: NetworkPhase, duration: Duration) {
+        let slot = match phase {
+            NetworkPhase::Dns => &mut self.dns,
+            NetworkPhase::Connect => &mut self.connect,
+            NetworkPhase::Tls => &mut self.tls,
+            NetworkPhase::Send => &mut self.send,
+            NetworkPhase::Wait => &mut self.wait,
+            NetworkPhase::Receive => &mut self.receive,
+        };
+        *slot = duration;
+    }
+
+    /// Total duration across all phases
+    pub fn total(&self) -> Duration {
+        NetworkPhase::ALL.iter().map(|p| self.phase(*p)).sum()
+    }
+}
+
 /// A network request entry for the inspector
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkInspectorEntry {
@@ -316,12 +445,22 @@ pub struct NetworkInspectorEntry {
     pub request_headers: Vec<(String, String)>,
     /// Response headers
     pub response_headers: Vec<(String, String)>,
-    /// Request body (if captured)
-    pub request_body: Option<String>,
-    /// Response body preview (truncated if large)
-    pub response_preview: Option<String>,
+    /// Request body (if captured, truncated to the configured cap)
+    pub request_body: Option<Vec<u8>>,
+    /// Whether `request_body` was cut short by the size cap
+    #[serde(default)]
+    pub request_body_truncated: bool,
+    /// Response body (if captured, truncated to the configured cap)
+    #[serde(default)]
+    pub response_body: Option<Vec<u8>>,
+    /// Whether `response_body` was cut short by the size cap
+    #[serde(default)]
+    pub response_body_truncated: bool,
     /// Timestamp when request started
     pub start_time: u64,
+    /// Start offsets of phases that have begun but not yet ended
+    #[serde(skip)]
+    open_phases: HashMap<NetworkPhase, Duration>,
 }
 
 impl NetworkInspectorEntry {
@@ -342,11 +481,30 @@ impl NetworkInspectorEntry {
             request_headers: Vec::new(),
             response_headers: Vec::new(),
             request_body: None,
-            response_preview: None,
+            request_body_truncated: false,
+            response_body: None,
+            response_body_truncated: false,
             start_time,
+            open_phases: HashMap::new(),
         }
     }
 
+    /// Mark the start of a phase, `at` the given offset from the request start
+    pub fn start_phase(&mut self, phase: NetworkPhase, at: Duration) {
+        self.open_phases.insert(phase, at);
+    }
+
+    /// Mark the end of a phase, `at` the given offset from the request start
+    ///
+    /// Records and returns the phase duration. Returns `None` if the phase
+    /// was never started.
+    pub fn end_phase(&mut self, phase: NetworkPhase, at: Duration) -> Option<Duration> {
+        let start = self.open_phases.remove(&phase)?;
+        let duration = at.saturating_sub(start);
+        self.timing.set_phase(phase, duration);
+        Some(duration)
+    }
+
     /// Mark the request as complete
     pub fn complete(&mut self, status_code: u16, content_type: Option<String>) {
         self.status = NetworkStatus::Complete(status_code);
@@ -358,6 +516,110 @@ impl NetworkInspectorEntry {
         self.status = NetworkStatus::Failed(error.into());
     }
 
+    /// Store the request body, keeping at most `max_size` bytes
+    pub fn set_request_body(&mut self, mut bytes: Vec<u8>, max_size: usize) {
+        self.request_body_truncated = bytes.len() > max_size;
+        bytes.truncate(max_size);
+        self.request_body = Some(bytes);
+    }
+
+    /// Store the response body, keeping at most `max_size` bytes
+    ///
+    /// The download size records the full, untruncated length.
+    pub fn set_response_body(&mut self, mut bytes: Vec<u8>, max_size: usize) {
+        self.timing.download_size = bytes.len() as u64;
+        self.response_body_truncated = bytes.len() > max_size;
+        bytes.truncate(max_size);
+        self.response_body = Some(bytes);
+    }
+
+    /// Get the request body as text, if it is valid UTF-8
+    pub fn request_body_text(&self) -> Option<&str> {
+        std::str::from_utf8(self.request_body.as_deref()?).ok()
+    }
+
+    /// Get a text preview of the response body for text and JSON responses
+    pub fn response_preview(&self) -> Option<String> {
+        if !is_text_mime(self.content_type.as_deref()?) {
+            return None;
+        }
+        // Truncation may split a character, so decode lossily
+        Some(String::from_utf8_lossy(self.response_body.as_deref()?).into_owned())
+    }
+
+    /// Convert to an HTTP Archive (HAR 1.2) entry
+    fn to_har_entry(&self) -> serde_json::Value {
+        let started = chrono::DateTime::from_timestamp_millis(self.start_time as i64)
+            .unwrap_or_default()
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let headers = |headers: &[(String, String)]| {
+            headers
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect::<Vec<_>>()
+        };
+        let status = match self.status {
+            NetworkStatus::Complete(code) => code,
+            _ => 0,
+        };
+        let mime_type = self.content_type.clone().unwrap_or_default();
+        let timing = &self.timing;
+
+        let mut request = json!({
+            "method": self.method.as_str(),
+            "url": self.url,
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": headers(&self.request_headers),
+            "queryString": [],
+            "headersSize": -1,
+            "bodySize": self.request_body.as_ref().map_or(0, Vec::len),
+        });
+        if let Some(text) = self.request_body_text() {
+            request["postData"] = json!({ "mimeType": "", "text": text });
+        }
+
+        let mut content = json!({
+            "size": timing.download_size,
+            "mimeType": mime_type,
+        });
+        if let Some(text) = self.response_preview() {
+            content["text"] = json!(text);
+        }
+
+        let mut entry = json!({
+            "startedDateTime": started,
+            "time": har_ms(timing.total()),
+            "request": request,
+            "response": {
+                "status": status,
+                "statusText": "",
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": headers(&self.response_headers),
+                "content": content,
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": if status == 0 { -1 } else { timing.download_size as i64 },
+            },
+            "cache": {},
+            "timings": {
+                "blocked": -1,
+                "dns": har_ms(timing.dns),
+                "ssl": har_ms(timing.tls),
+                // HAR counts the TLS handshake as part of the connection
+                "connect": har_ms(timing.connect + timing.tls),
+                "send": har_ms(timing.send),
+                "wait": har_ms(timing.wait),
+                "receive": har_ms(timing.receive),
+            },
+        });
+        if let NetworkStatus::Failed(error) = &self.status {
+            entry["_error"] = json!(error);
+        }
+        entry
+    }
+
     /// Get a short display name for the URL (last path segment or domain)
     pub fn display_name(&self) -> String {
         if let Some(path) = self.url.split('/').last() {
@@ -386,16 +648,33 @@ pub struct DevToolsConfig {
     pub max_size: f32,
     /// Tab order (customizable)
     pub tab_order: Vec<DevToolsTab>,
-    /// Whether to preserve logs on navigation
+    /// Whether to preserve console logs on navigation
     pub preserve_log: bool,
+    /// Whether to preserve network entries on navigation
+    #[serde(default)]
+    pub preserve_network_on_navigate: bool,
     /// Maximum number of console messages to keep
     pub max_console_messages: usize,
     /// Maximum number of network entries to keep
     pub max_network_entries: usize,
     /// Console filter text
     pub console_filter: String,
+    /// Least severe console level shown
+    #[serde(default = "default_console_min_level")]
+    pub console_min_level: ConsoleLevel,
     /// Network filter text
     pub network_filter: String,
+    /// Maximum captured size of each request/response body in bytes
+    #[serde(default = "default_max_body_size")]
+    pub max_body_size: usize,
+}
+
+fn default_console_min_level() -> ConsoleLevel {
+    ConsoleLevel::Debug
+}
+
+fn default_max_body_size() -> usize {
+    DEFAULT_MAX_BODY_SIZE
 }
 
 impl Default for DevToolsConfig {
@@ -406,10 +685,13 @@ impl Default for DevToolsConfig {
             max_size: 600.0,
             tab_order: DevToolsTab::all().to_vec(),
             preserve_log: false,
+            preserve_network_on_navigate: false,
             max_console_messages: 1000,
             max_network_entries: 500,
             console_filter: String::new(),
+            console_min_level: default_console_min_level(),
             network_filter: String::new(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
         }
     }
 }
@@ -583,17 +865,24 @@ impl DevToolsPanel {
         &self.console_messages
     }
 
-    /// Get console messages matching the current filter
+    /// Get console messages matching the current level and text filters
     pub fn filtered_console_messages(&self) -> Vec<&ConsoleMessage> {
-        if self.config.console_filter.is_empty() {
-            self.console_messages.iter().collect()
-        } else {
-            let filter = self.config.console_filter.to_lowercase();
-            self.console_messages
-                .iter()
-                .filter(|m| m.message.to_lowercase().contains(&filter))
-                .collect()
-        }
+        self.filtered_messages(self.config.console_min_level, &self.config.console_filter)
+    }
+
+    /// Get console messages at or above `min_level` whose content contains
+    /// `text_filter` (case-insensitive; empty matches everything)
+    pub fn filtered_messages(
+        &self,
+        min_level: ConsoleLevel,
+        text_filter: &str,
+    ) -> Vec<&ConsoleMessage> {
+        let filter = text_filter.to_lowercase();
+        self.console_messages
+            .iter()
+            .filter(|m| m.level.severity() >= min_level.severity())
+            .filter(|m| filter.is_empty() || m.message.to_lowercase().contains(&filter))
+            .collect()
     }
 
     /// Set the console filter
@@ -601,6 +890,11 @@ impl DevToolsPanel {
         self.config.console_filter = filter.into();
     }
 
+    /// Set the least severe console level shown
+    pub fn set_console_min_level(&mut self, level: ConsoleLevel) {
+        self.config.console_min_level = level;
+    }
+
     // Network methods
 
     /// Start tracking a new network request
@@ -610,14 +904,23 @@ impl DevToolsPanel {
 
         let entry = NetworkInspectorEntry::new(id, method, url);
         self.network_entries.push(entry);
+        self.trim_network_entries();
+
+        id
+    }
 
-        // Trim old entries if over limit
+    /// Set the maximum number of network entries, dropping the oldest if over
+    pub fn set_max_network_entries(&mut self, max: usize) {
+        self.config.max_network_entries = max;
+        self.trim_network_entries();
+    }
+
+    /// Drop the oldest network entries beyond the configured cap
+    fn trim_network_entries(&mut self) {
         if self.network_entries.len() > self.config.max_network_entries {
             let excess = self.network_entries.len() - self.config.max_network_entries;
             self.network_entries.drain(0..excess);
         }
-
-        id
     }
 
     /// Update a network request with completion info
@@ -639,6 +942,71 @@ impl DevToolsPanel {
         }
     }
 
+    /// Mark the start of a request phase, `at` an offset from the request start
+    pub fn start_network_phase(&mut self, id: u64, phase: NetworkPhase, at: Duration) {
+        if let Some(entry) = self.network_entries.iter_mut().find(|e| e.id == id) {
+            entry.start_phase(phase, at);
+        }
+    }
+
+    /// Mark the end of a request phase, `at` an offset from the request start
+    pub fn end_network_phase(&mut self, id: u64, phase: NetworkPhase, at: Duration) {
+        if let Some(entry) = self.network_entries.iter_mut().find(|e| e.id == id) {
+            entry.end_phase(phase, at);
+        }
+    }
+
+    /// Capture the body sent with a network request
+    ///
+    /// Bodies larger than `max_body_size` are truncated and flagged.
+    pub fn set_request_body(&mut self, id: u64, bytes: Vec<u8>) {
+        let max_size = self.config.max_body_size;
+        if let Some(entry) = self.network_entries.iter_mut().find(|e| e.id == id) {
+            entry.set_request_body(bytes, max_size);
+        }
+    }
+
+    /// Capture the body received for a network request
+    ///
+    /// Bodies larger than `max_body_size` are truncated and flagged.
+    pub fn set_response_body(&mut self, id: u64, bytes: Vec<u8>) {
+        let max_size = self.config.max_body_size;
+        if let Some(entry) = self.network_entries.iter_mut().find(|e| e.id == id) {
+            entry.set_response_body(bytes, max_size);
+        }
+    }
+
+    /// Set the maximum captured body size for future captures
+    pub fn set_max_body_size(&mut self, max: usize) {
+        self.config.max_body_size = max;
+    }
+
+    /// Get a network entry by ID
+    pub fn network_entry(&self, id: u64) -> Option<&NetworkInspectorEntry> {
+        self.network_entries.iter().find(|e| e.id == id)
+    }
+
+    /// Export all captured network entries as HTTP Archive (HAR 1.2) JSON
+    pub fn export_har(&self) -> Result<String, serde_json::Error> {
+        let entries: Vec<_> = self
+            .network_entries
+            .iter()
+            .map(NetworkInspectorEntry::to_har_entry)
+            .collect();
+        let har = json!({
+            "log": {
+                "version": "1.2",
+                "creator": {
+                    "name": "CortenBrowser DevTools",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "pages": [],
+                "entries": entries,
+            }
+        });
+        serde_json::to_string_pretty(&har)
+    }
+
     /// Clear all network entries
     pub fn clear_network(&mut self) {
         self.network_entries.clear();
@@ -672,14 +1040,35 @@ impl DevToolsPanel {
         self.state.selected_network_entry = id;
     }
 
-    /// Clear on navigation (if preserve_log is false)
+    /// Clear on navigation
+    ///
+    /// Console messages are kept when `preserve_log` is set and network
+    /// entries when `preserve_network_on_navigate` is set.
     pub fn on_navigation(&mut self) {
         if !self.config.preserve_log {
             self.clear_console();
+        }
+        if !self.config.preserve_network_on_navigate {
             self.clear_network();
         }
     }
 
+    /// Set whether console messages survive navigation
+    pub fn set_preserve_log(&mut self, preserve: bool) {
+        self.config.preserve_log = preserve;
+    }
+
+    /// Clear captured data when the inspected page navigates
+    ///
+    /// The host calls this on every navigation. Nothing is cleared while
+    /// `preserve_log` is set; otherwise this behaves like [`Self::on_navigation`].
+    pub fn clear_on_navigate(&mut self) {
+        if self.config.preserve_log {
+            return;
+        }
+        self.on_navigation();
+    }
+
     /// Show the developer tools panel in egui
     pub fn show(&mut self, ctx: &egui::Context) {
         if !self.state.visible {
@@ -810,9 +1199,25 @@ impl DevToolsPanel {
                 self.config.console_filter = filter;
             }
 
+            egui::ComboBox::from_id_salt("console_min_level")
+                .selected_text(self.config.console_min_level.label())
+                .show_ui(ui, |ui| {
+                    for level in ConsoleLevel::filter_levels() {
+                        ui.selectable_value(
+                            &mut self.config.console_min_level,
+                            *level,
+                            level.label(),
+                        );
+                    }
+                });
+
             ui.separator();
 
             ui.checkbox(&mut self.state.console_auto_scroll, "Auto-scroll");
+
+            ui.separator();
+
+            ui.checkbox(&mut self.config.preserve_log, "Preserve log");
         });
 
         ui.separator();
@@ -862,6 +1267,16 @@ impl DevToolsPanel {
                 self.clear_network();
             }
 
+            if ui
+                .button("Copy as HAR")
+                .on_hover_text("Copy all requests as HTTP Archive JSON")
+                .clicked()
+            {
+                if let Ok(har) = self.export_har() {
+                    ui.ctx().copy_text(har);
+                }
+            }
+
             ui.separator();
 
             // Filter input
@@ -873,7 +1288,10 @@ impl DevToolsPanel {
 
             ui.separator();
 
-            ui.checkbox(&mut self.config.preserve_log, "Preserve log");
+            ui.checkbox(
+                &mut self.config.preserve_network_on_navigate,
+                "Preserve network log",
+            );
         });
 
         ui.separator();
@@ -893,22 +1311,38 @@ impl DevToolsPanel {
                     e.timing.download_size,
                     e.url.clone(),
                     e.content_type.clone(),
-                    e.timing.connect_time_ms,
-                    e.timing.ttfb_ms,
-                    e.timing.total_time_ms,
+                    e.timing.clone(),
                 )
             })
             .collect();
 
+        // Scale waterfall bars so the slowest request fills the column
+        let waterfall_scale = entries_data
+            .iter()
+            .map(|(.., timing)| timing.total())
+            .max()
+            .unwrap_or_default();
+
         let selected_id = self.state.selected_network_entry;
         let mut new_selection: Option<u64> = None;
 
+        let selected_bodies = selected_id.and_then(|id| self.network_entry(id)).map(|e| {
+            (
+                e.request_body_text().map(str::to_string),
+                e.request_body_truncated,
+                e.response_preview(),
+                e.response_body_truncated,
+            )
+        });
+
         ui.columns(2, |columns| {
             // Request list
             egui::ScrollArea::vertical()
                 .id_salt("network_list")
                 .show(&mut columns[0], |ui| {
-                    for (id, method, status, display_name, download_size, ..) in &entries_data {
+                    for (id, method, status, display_name, download_size, .., timing) in
+                        &entries_data
+                    {
                         let selected = selected_id == Some(*id);
                         ui.horizontal(|ui| {
                             // Status indicator
@@ -923,15 +1357,17 @@ impl DevToolsPanel {
                                 new_selection = Some(*id);
                             }
 
-                            // Size (if available)
-                            if *download_size > 0 {
-                                ui.with_layout(
-                                    egui::Layout::right_to_left(egui::Align::Center),
-                                    |ui| {
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    render_waterfall(ui, timing, waterfall_scale, 80.0);
+
+                                    // Size (if available)
+                                    if *download_size > 0 {
                                         ui.small(format_size(*download_size));
-                                    },
-                                );
-                            }
+                                    }
+                                },
+                            );
                         });
                     }
 
@@ -943,18 +1379,8 @@ impl DevToolsPanel {
             // Details panel
             columns[1].vertical(|ui| {
                 if let Some(id) = selected_id {
-                    if let Some((
-                        _,
-                        method,
-                        status,
-                        _,
-                        download_size,
-                        url,
-                        content_type,
-                        connect_time,
-                        ttfb,
-                        total_time,
-                    )) = entries_data.iter().find(|(eid, ..)| *eid == id)
+                    if let Some((_, method, status, _, download_size, url, content_type, timing)) =
+                        entries_data.iter().find(|(eid, ..)| *eid == id)
                     {
                         ui.heading("Request Details");
                         ui.separator();
@@ -989,10 +1415,32 @@ impl DevToolsPanel {
 
                         ui.separator();
                         ui.strong("Timing:");
-                        ui.label(format!("Connect: {}ms", connect_time));
-                        ui.label(format!("TTFB: {}ms", ttfb));
-                        ui.label(format!("Total: {}ms", total_time));
+                        render_waterfall(ui, timing, timing.total(), ui.available_width());
+                        for phase in NetworkPhase::ALL {
+                            ui.horizontal(|ui| {
+                                ui.colored_label(phase.color(), "■");
+                                ui.label(format!(
+                                    "{}: {:.1}ms",
+                                    phase.label(),
+                                    har_ms(timing.phase(phase))
+                                ));
+                            });
+                        }
+                        ui.label(format!("Total: {:.1}ms", har_ms(timing.total())));
                         ui.label(format!("Size: {}", format_size(*download_size)));
+
+                        if let Some((request_text, request_truncated, preview, truncated)) =
+                            &selected_bodies
+                        {
+                            if let Some(text) = request_text {
+                                ui.separator();
+                                render_body_preview(ui, "Request Body:", text, *request_truncated);
+                            }
+                            if let Some(text) = preview {
+                                ui.separator();
+                                render_body_preview(ui, "Response:", text, *truncated);
+                            }
+                        }
                     }
                 } else {
                     ui.label("Select a request to view details.");
@@ -1046,6 +1494,67 @@ impl Default for DevToolsPanel {
     }
 }
 
+/// Check whether a MIME type is text-like enough to preview as a string
+fn is_text_mime(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+    mime.starts_with("text/")
+        || mime.ends_with("json")
+        || mime.ends_with("+xml")
+        || matches!(
+            mime.as_str(),
+            "application/xml" | "application/javascript" | "application/x-www-form-urlencoded"
+        )
+}
+
+/// Render a captured body as a scrollable monospace preview
+fn render_body_preview(ui: &mut egui::Ui, heading: &str, text: &str, truncated: bool) {
+    ui.horizontal(|ui| {
+        ui.strong(heading);
+        if truncated {
+            ui.small("(truncated)");
+        }
+    });
+    egui::ScrollArea::vertical()
+        .id_salt(heading)
+        .max_height(150.0)
+        .show(ui, |ui| {
+            ui.monospace(text);
+        });
+}
+
+/// Paint a waterfall bar with one segment per request phase
+///
+/// `scale` is the duration that spans the full `width`.
+fn render_waterfall(ui: &mut egui::Ui, timing: &NetworkTiming, scale: Duration, width: f32) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 8.0), egui::Sense::hover());
+    if scale.is_zero() {
+        return;
+    }
+    let mut x = rect.left();
+    for phase in NetworkPhase::ALL {
+        let fraction = timing.phase(phase).as_secs_f32() / scale.as_secs_f32();
+        let segment_width = fraction * rect.width();
+        if segment_width > 0.0 {
+            let segment = egui::Rect::from_min_size(
+                egui::pos2(x, rect.top()),
+                egui::vec2(segment_width, rect.height()),
+            );
+            ui.painter().rect_filled(segment, 0.0, phase.color());
+            x += segment_width;
+        }
+    }
+}
+
+/// Convert a duration to fractional milliseconds, as used by HAR timings
+fn har_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
 /// Format a byte size as human-readable string
 fn format_size(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -1266,8 +1775,10 @@ mod tests {
         assert_eq!(config.max_size, 600.0);
         assert_eq!(config.tab_order.len(), 6);
         assert!(!config.preserve_log);
+        assert!(!config.preserve_network_on_navigate);
         assert_eq!(config.max_console_messages, 1000);
         assert_eq!(config.max_network_entries, 500);
+        assert_eq!(config.max_body_size, DEFAULT_MAX_BODY_SIZE);
     }
 
     // DevToolsState tests
@@ -1389,6 +1900,45 @@ mod tests {
         assert_eq!(filtered.len(), 2);
     }
 
+    #[test]
+    fn test_devtools_filtered_messages_by_level() {
+        let mut panel = DevToolsPanel::default();
+
+        panel.console_debug("debug detail");
+        panel.console_log("page loaded");
+        panel.console_info("info note");
+        panel.console_warn("deprecated api");
+        panel.console_error("failed to fetch");
+        panel.console_error("uncaught exception");
+
+        assert_eq!(panel.filtered_messages(ConsoleLevel::Debug, "").len(), 6);
+        assert_eq!(panel.filtered_messages(ConsoleLevel::Info, "").len(), 5);
+        assert_eq!(panel.filtered_messages(ConsoleLevel::Warn, "").len(), 3);
+
+        let errors = panel.filtered_messages(ConsoleLevel::Error, "");
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|m| m.level == ConsoleLevel::Error));
+    }
+
+    #[test]
+    fn test_devtools_filtered_messages_level_and_text() {
+        let mut panel = DevToolsPanel::default();
+
+        panel.console_log("Fetch started");
+        panel.console_error("fetch failed");
+        panel.console_error("render failed");
+
+        let matches = panel.filtered_messages(ConsoleLevel::Error, "FETCH");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].message, "fetch failed");
+
+        // The stored filter state drives filtered_console_messages
+        panel.set_console_filter("fetch");
+        assert_eq!(panel.filtered_console_messages().len(), 2);
+        panel.set_console_min_level(ConsoleLevel::Error);
+        assert_eq!(panel.filtered_console_messages().len(), 1);
+    }
+
     #[test]
     fn test_devtools_console_message_limit() {
         let mut config = DevToolsConfig::default();
@@ -1429,6 +1979,135 @@ mod tests {
         assert_eq!(entry.content_type, Some("application/json".to_string()));
     }
 
+    #[test]
+    fn test_devtools_network_body_capture() {
+        let mut panel = DevToolsPanel::default();
+
+        let id = panel.add_network_request(HttpMethod::POST, "https://example.com/api");
+        panel.set_request_body(id, br#"{"name":"test"}"#.to_vec());
+        panel.complete_network_request(id, 200, Some("application/json; charset=utf-8".into()));
+        panel.set_response_body(id, br#"{"ok":true}"#.to_vec());
+
+        let entry = panel.network_entry(id).unwrap();
+        assert_eq!(entry.request_body_text(), Some(r#"{"name":"test"}"#));
+        assert_eq!(entry.response_body.as_deref(), Some(&br#"{"ok":true}"#[..]));
+        assert_eq!(entry.response_preview().as_deref(), Some(r#"{"ok":true}"#));
+        assert!(!entry.request_body_truncated);
+        assert!(!entry.response_body_truncated);
+        assert_eq!(entry.timing.download_size, 11);
+    }
+
+    #[test]
+    fn test_devtools_network_body_truncation() {
+        let mut panel = DevToolsPanel::default();
+        panel.set_max_body_size(4);
+
+        let id = panel.add_network_request(HttpMethod::GET, "https://example.com/big");
+        panel.set_response_body(id, b"0123456789".to_vec());
+
+        let entry = panel.network_entry(id).unwrap();
+        assert_eq!(entry.response_body.as_deref(), Some(&b"0123"[..]));
+        assert!(entry.response_body_truncated);
+        // Download size still reflects the full body
+        assert_eq!(entry.timing.download_size, 10);
+
+        panel.set_request_body(id, b"abcd".to_vec());
+        let entry = panel.network_entry(id).unwrap();
+        assert!(!entry.request_body_truncated);
+    }
+
+    #[test]
+    fn test_network_entry_preview_only_for_text() {
+        let mut entry = NetworkInspectorEntry::new(1, HttpMethod::GET, "https://example.com/a.png");
+        entry.complete(200, Some("image/png".to_string()));
+        entry.set_response_body(vec![0x89, b'P', b'N', b'G'], DEFAULT_MAX_BODY_SIZE);
+        assert_eq!(entry.response_preview(), None);
+
+        entry.content_type = Some("text/html".to_string());
+        assert!(entry.response_preview().is_some());
+        assert!(is_text_mime("application/ld+json"));
+        assert!(is_text_mime("image/svg+xml"));
+        assert!(!is_text_mime("application/octet-stream"));
+    }
+
+    #[test]
+    fn test_devtools_export_har() {
+        let mut panel = DevToolsPanel::default();
+
+        let page = panel.add_network_request(HttpMethod::GET, "https://example.com/");
+        panel.complete_network_request(page, 200, Some("text/html".to_string()));
+        let api = panel.add_network_request(HttpMethod::POST, "https://example.com/api");
+        panel.complete_network_request(api, 404, None);
+        panel.set_request_body(api, b"q=1".to_vec());
+
+        let har: serde_json::Value = serde_json::from_str(&panel.export_har().unwrap()).unwrap();
+        assert_eq!(har["log"]["version"], "1.2");
+
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["request"]["url"], "https://example.com/");
+        assert_eq!(entries[0]["request"]["method"], "GET");
+        assert_eq!(entries[0]["response"]["status"], 200);
+        assert_eq!(entries[0]["response"]["content"]["mimeType"], "text/html");
+        assert_eq!(entries[1]["request"]["url"], "https://example.com/api");
+        assert_eq!(entries[1]["response"]["status"], 404);
+        assert_eq!(entries[1]["request"]["postData"]["text"], "q=1");
+
+        let started = entries[0]["startedDateTime"].as_str().unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(started).is_ok());
+    }
+
+    #[test]
+    fn test_network_phase_durations() {
+        let mut entry = NetworkInspectorEntry::new(1, HttpMethod::GET, "https://example.com/");
+        let ms = Duration::from_millis;
+
+        let marks = [
+            (NetworkPhase::Dns, 0, 12),
+            (NetworkPhase::Connect, 12, 40),
+            (NetworkPhase::Tls, 40, 85),
+            (NetworkPhase::Send, 85, 86),
+            (NetworkPhase::Wait, 86, 186),
+            (NetworkPhase::Receive, 186, 230),
+        ];
+        for (phase, start, end) in marks {
+            entry.start_phase(phase, ms(start));
+            assert_eq!(entry.end_phase(phase, ms(end)), Some(ms(end - start)));
+        }
+
+        assert_eq!(entry.timing.dns, ms(12));
+        assert_eq!(entry.timing.connect, ms(28));
+        assert_eq!(entry.timing.tls, ms(45));
+        assert_eq!(entry.timing.send, ms(1));
+        assert_eq!(entry.timing.wait, ms(100));
+        assert_eq!(entry.timing.receive, ms(44));
+        assert_eq!(entry.timing.total(), ms(230));
+    }
+
+    #[test]
+    fn test_network_phase_end_without_start() {
+        let mut panel = DevToolsPanel::default();
+        let id = panel.add_network_request(HttpMethod::GET, "https://example.com/");
+
+        // Ending a phase that never started records nothing
+        panel.end_network_phase(id, NetworkPhase::Wait, Duration::from_millis(50));
+        assert_eq!(
+            panel.network_entry(id).unwrap().timing.total(),
+            Duration::ZERO
+        );
+
+        // Phases may overlap; each is measured independently
+        panel.start_network_phase(id, NetworkPhase::Send, Duration::from_millis(10));
+        panel.start_network_phase(id, NetworkPhase::Wait, Duration::from_millis(12));
+        panel.end_network_phase(id, NetworkPhase::Send, Duration::from_millis(15));
+        panel.end_network_phase(id, NetworkPhase::Wait, Duration::from_millis(62));
+
+        let timing = &panel.network_entry(id).unwrap().timing;
+        assert_eq!(timing.send, Duration::from_millis(5));
+        assert_eq!(timing.wait, Duration::from_millis(50));
+        assert_eq!(timing.total(), Duration::from_millis(55));
+    }
+
     #[test]
     fn test_devtools_network_fail() {
         let mut panel = DevToolsPanel::default();
@@ -1500,6 +2179,7 @@ mod tests {
     fn test_devtools_on_navigation_preserves_when_enabled() {
         let mut config = DevToolsConfig::default();
         config.preserve_log = true;
+        config.preserve_network_on_navigate = true;
         let mut panel = DevToolsPanel::new(config);
 
         panel.console_log("test");
@@ -1511,6 +2191,87 @@ mod tests {
         assert_eq!(panel.network_entries().len(), 1);
     }
 
+    #[test]
+    fn test_devtools_on_navigation_flags_are_independent() {
+        let mut panel = DevToolsPanel::new(DevToolsConfig {
+            preserve_network_on_navigate: true,
+            ..Default::default()
+        });
+
+        panel.console_log("test");
+        panel.add_network_request(HttpMethod::GET, "https://example.com");
+        panel.on_navigation();
+
+        assert_eq!(panel.console_messages().len(), 0);
+        assert_eq!(panel.network_entries().len(), 1);
+
+        let mut panel = DevToolsPanel::new(DevToolsConfig {
+            preserve_log: true,
+            ..Default::default()
+        });
+
+        panel.console_log("test");
+        panel.add_network_request(HttpMethod::GET, "https://example.com");
+        panel.on_navigation();
+
+        assert_eq!(panel.console_messages().len(), 1);
+        assert_eq!(panel.network_entries().len(), 0);
+    }
+
+    #[test]
+    fn test_devtools_clear_on_navigate_respects_preserve_log() {
+        let mut panel = DevToolsPanel::new(DevToolsConfig {
+            preserve_log: true,
+            ..Default::default()
+        });
+
+        panel.console_log("before navigation");
+        panel.add_network_request(HttpMethod::GET, "https://example.com");
+        panel.clear_on_navigate();
+
+        assert_eq!(panel.console_messages().len(), 1);
+        assert_eq!(panel.network_entries().len(), 1);
+
+        panel.set_preserve_log(false);
+        panel.clear_on_navigate();
+
+        assert!(panel.console_messages().is_empty());
+        assert!(panel.network_entries().is_empty());
+    }
+
+    #[test]
+    fn test_devtools_explicit_clear() {
+        let mut panel = DevToolsPanel::new(DevToolsConfig {
+            preserve_log: true,
+            ..Default::default()
+        });
+
+        panel.console_log("message");
+        panel.add_network_request(HttpMethod::GET, "https://example.com");
+
+        // Explicit clears ignore the preserve setting
+        panel.clear_console();
+        assert!(panel.console_messages().is_empty());
+        assert_eq!(panel.network_entries().len(), 1);
+
+        panel.clear_network();
+        assert!(panel.network_entries().is_empty());
+    }
+
+    #[test]
+    fn test_devtools_set_max_network_entries_trims_oldest() {
+        let mut panel = DevToolsPanel::default();
+
+        for i in 0..5 {
+            panel.add_network_request(HttpMethod::GET, format!("https://example.com/{}", i));
+        }
+
+        panel.set_max_network_entries(2);
+        assert_eq!(panel.network_entries().len(), 2);
+        assert!(panel.network_entries()[0].url.ends_with("/3"));
+        assert!(panel.network_entries()[1].url.ends_with("/4"));
+    }
+
     #[test]
     fn test_devtools_network_selection() {
         let mut panel = DevToolsPanel::default();
@@ -69,21 +69,28 @@
 //! let job_id = print_manager.create_job("Document.pdf".to_string(), 10);
 //! ```
 
+pub mod clipboard;
 pub mod crash_recovery;
 pub mod devtools;
 pub mod menu;
 pub mod print;
 pub mod settings_ui;
 pub mod tab_drag_ui;
+pub mod tab_switcher;
 pub mod theme;
 
 use crash_recovery::{ClosedTabInfo, CrashRecoveryUi};
 use shared_types::{ComponentError, DownloadId, KeyboardShortcut, TabId};
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use tab_drag_ui::{TabDragState, TabDragVisuals, TabOverflowHandler};
+use tab_switcher::TabSwitcher;
 
 // Re-export theme types for convenience
-pub use theme::{Theme, ThemeManager, ThemeMode};
+pub use theme::{
+    CustomPalette, PlatformThemeDetector, SystemThemeDetector, Theme, ThemeChangeCallback,
+    ThemeManager, ThemeMode,
+};
 
 // Re-export devtools types for convenience
 pub use devtools::{
@@ -99,10 +106,13 @@ pub use print::{
 };
 
 // Re-export menu types for convenience
-pub use menu::{MenuAction, MenuBar, PanelType, UiAction};
+pub use menu::{MenuAction, MenuBar, PanelType, UiAction, ZoomConfig};
 
 // Re-export settings UI types for convenience
-pub use settings_ui::{SettingsTab, SettingsUi};
+pub use settings_ui::{SettingMatch, SettingsTab, SettingsUi};
+
+// Re-export tab switcher types for convenience
+pub use tab_switcher::TabSwitchResult;
 
 /// State for a single tab
 #[derive(Debug, Clone)]
@@ -115,8 +125,64 @@ pub struct TabState {
 
     /// Whether the tab is currently loading
     pub loading: bool,
+
+    /// URL the tab is showing (empty for a blank tab)
+    pub url: String,
+
+    /// Whether the tab is playing audio
+    pub audible: bool,
+
+    /// Whether the tab's audio is muted
+    pub muted: bool,
+
+    /// Whether the tab is pinned to the left of the tab bar
+    pub pinned: bool,
+
+    /// Whether the tab is shown in reader mode
+    pub reader_mode: bool,
+}
+
+/// Navigation intent raised by the user through the chrome
+///
+/// Queued by toolbar buttons, shortcuts, and the address bar, and drained by
+/// the host each frame via [`UiChrome::take_pending_commands`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavigationCommand {
+    /// Go back in the active tab's history
+    Back,
+    /// Go forward in the active tab's history
+    Forward,
+    /// Reload the active tab
+    Reload,
+    /// Navigate the active tab to the given address bar input
+    Navigate(String),
+    /// Stop loading the active tab
+    Stop,
+    /// Turn reader mode on or off for a tab
+    SetReaderMode {
+        /// Tab to re-layout
+        tab: TabId,
+        /// Whether reader mode is now enabled
+        enabled: bool,
+    },
+    /// Set a tab's page zoom, in percent
+    SetZoom {
+        /// Tab to zoom
+        tab: TabId,
+        /// New zoom level in percent
+        percent: u16,
+    },
 }
 
+/// Default smallest per-tab zoom level, in percent
+pub const MIN_ZOOM_PERCENT: u16 = 25;
+
+/// Default largest per-tab zoom level, in percent
+pub const MAX_ZOOM_PERCENT: u16 = 300;
+
+/// Default per-tab zoom level, in percent
+pub const DEFAULT_ZOOM_PERCENT: u16 = 100;
+
 /// Types of context menus
 #[derive(Debug, Clone, PartialEq)]
 pub enum ContextMenuType {
@@ -165,6 +231,11 @@ impl TabState {
             id: TabId::new(),
             title,
             loading: false,
+            url: String::new(),
+            audible: false,
+            muted: false,
+            pinned: false,
+            reader_mode: false,
         }
     }
 
@@ -174,6 +245,11 @@ impl TabState {
             id,
             title,
             loading: false,
+            url: String::new(),
+            audible: false,
+            muted: false,
+            pinned: false,
+            reader_mode: false,
         }
     }
 }
@@ -205,6 +281,19 @@ pub struct UiChrome {
     /// Whether the address bar has focus
     address_bar_focused: bool,
 
+    /// Selected character range in the address bar (empty when just a cursor)
+    address_bar_selection: Range<usize>,
+
+    /// Whether `address_bar_selection` was changed programmatically and must be
+    /// pushed back to the text field
+    address_bar_selection_dirty: bool,
+
+    /// Last text known to be on the clipboard (copied here or pasted into the UI)
+    clipboard_text: Option<String>,
+
+    /// Text to place on the system clipboard on the next frame
+    pending_clipboard_output: Option<String>,
+
     /// Whether the settings panel is visible
     settings_panel_visible: bool,
 
@@ -249,6 +338,18 @@ pub struct UiChrome {
 
     /// Crash recovery UI (session restore dialog and recently closed tabs)
     crash_recovery: CrashRecoveryUi,
+
+    /// Tab search overlay (quick tab switcher)
+    tab_switcher: TabSwitcher,
+
+    /// Tab IDs ordered from most to least recently active
+    recent_tabs: Vec<TabId>,
+
+    /// Navigation commands waiting to be drained by the host
+    pending_commands: Vec<NavigationCommand>,
+
+    /// Per-tab zoom level in percent (tabs without an entry are at 100%)
+    tab_zoom: HashMap<TabId, u16>,
 }
 
 impl UiChrome {
@@ -266,6 +367,10 @@ impl UiChrome {
             tab_order: vec![tab_id],
             active_tab_index: 0,
             address_bar_focused: false,
+            address_bar_selection: 0..0,
+            address_bar_selection_dirty: false,
+            clipboard_text: None,
+            pending_clipboard_output: None,
             settings_panel_visible: false,
             history_panel_visible: false,
             downloads_panel_visible: false,
@@ -281,6 +386,10 @@ impl UiChrome {
             tab_overflow: TabOverflowHandler::new(),
             blocked_content_count: 0,
             crash_recovery: CrashRecoveryUi::new(),
+            tab_switcher: TabSwitcher::new(),
+            recent_tabs: vec![tab_id],
+            pending_commands: Vec::new(),
+            tab_zoom: HashMap::new(),
         }
     }
 
@@ -314,6 +423,64 @@ impl UiChrome {
         self.tabs.get(&tab_id).map(|t| t.loading)
     }
 
+    /// Check if a tab is playing audio
+    pub fn is_tab_audible(&self, tab_id: TabId) -> Option<bool> {
+        self.tabs.get(&tab_id).map(|t| t.audible)
+    }
+
+    /// Check if a tab is muted
+    pub fn is_tab_muted(&self, tab_id: TabId) -> Option<bool> {
+        self.tabs.get(&tab_id).map(|t| t.muted)
+    }
+
+    /// Check if a tab is in reader mode
+    pub fn is_reader_mode(&self, tab_id: TabId) -> Option<bool> {
+        self.tabs.get(&tab_id).map(|t| t.reader_mode)
+    }
+
+    /// Toggle reader mode for a tab, returning the new state
+    ///
+    /// Reader mode can only be turned on for http(s) pages; turning it off is
+    /// always allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::ResourceNotFound` if the tab doesn't exist
+    /// Returns `ComponentError::InvalidState` if enabling on a non-http(s) URL
+    pub fn toggle_reader_mode(&mut self, tab_id: TabId) -> Result<bool, ComponentError> {
+        let tab = self.tabs.get_mut(&tab_id).ok_or_else(|| {
+            ComponentError::ResourceNotFound(format!("Tab {:?} not found", tab_id))
+        })?;
+
+        if !tab.reader_mode && !is_http_url(&tab.url) {
+            return Err(ComponentError::InvalidState(format!(
+                "Reader mode is not available for '{}'",
+                tab.url
+            )));
+        }
+
+        tab.reader_mode = !tab.reader_mode;
+        let enabled = tab.reader_mode;
+        self.pending_commands.push(NavigationCommand::SetReaderMode {
+            tab: tab_id,
+            enabled,
+        });
+        Ok(enabled)
+    }
+
+    /// Check if a tab is pinned
+    pub fn is_tab_pinned(&self, tab_id: TabId) -> Option<bool> {
+        self.tabs.get(&tab_id).map(|t| t.pinned)
+    }
+
+    /// Number of pinned tabs (they always occupy the front of the tab order)
+    fn pinned_tab_count(&self) -> usize {
+        self.tab_order
+            .iter()
+            .filter(|id| self.tabs.get(id).is_some_and(|t| t.pinned))
+            .count()
+    }
+
     /// Get the currently active tab ID
     pub fn active_tab_id(&self) -> Option<TabId> {
         self.tab_order.get(self.active_tab_index).copied()
@@ -324,6 +491,80 @@ impl UiChrome {
         self.address_bar_focused
     }
 
+    /// Get the selected character range in the address bar
+    pub fn address_bar_selection(&self) -> Range<usize> {
+        self.address_bar_selection.clone()
+    }
+
+    /// Set the selected character range in the address bar
+    pub fn set_address_bar_selection(&mut self, selection: Range<usize>) {
+        self.address_bar_selection = selection;
+        self.address_bar_selection_dirty = true;
+    }
+
+    /// Copy the address bar selection to the clipboard
+    ///
+    /// With nothing selected the whole address is copied. Returns the copied text.
+    pub fn address_bar_copy(&mut self) -> String {
+        let mut text = clipboard::selected_text(&self.address_bar_text, &self.address_bar_selection);
+        if text.is_empty() {
+            text = self.address_bar_text.clone();
+        }
+        self.write_clipboard(text.clone());
+        text
+    }
+
+    /// Cut the address bar selection to the clipboard, returning the cut text
+    pub fn address_bar_cut(&mut self) -> String {
+        let (remaining, cut) =
+            clipboard::cut_selection(&self.address_bar_text, &self.address_bar_selection);
+        if !cut.is_empty() {
+            let cursor = self.address_bar_selection.start.min(self.address_bar_selection.end);
+            self.address_bar_text = remaining;
+            self.set_address_bar_selection(cursor..cursor);
+            self.write_clipboard(cut.clone());
+        }
+        cut
+    }
+
+    /// Replace the address bar selection with `pasted`
+    pub fn address_bar_paste(&mut self, pasted: &str) {
+        let (text, cursor) = clipboard::paste_over_selection(
+            &self.address_bar_text,
+            &self.address_bar_selection,
+            pasted,
+        );
+        self.address_bar_text = text;
+        self.set_address_bar_selection(cursor..cursor);
+    }
+
+    /// Select all address bar text
+    pub fn address_bar_select_all(&mut self) {
+        let selection = clipboard::select_all(&self.address_bar_text);
+        self.set_address_bar_selection(selection);
+    }
+
+    /// Paste the last known clipboard text into the address bar
+    ///
+    /// egui only delivers clipboard contents through paste events, so this
+    /// uses the most recent text copied or pasted in the UI. Returns `false`
+    /// if none is known yet.
+    fn paste_known_clipboard(&mut self) -> bool {
+        match self.clipboard_text.clone() {
+            Some(text) => {
+                self.address_bar_paste(&text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Queue text for the system clipboard
+    fn write_clipboard(&mut self, text: String) {
+        self.clipboard_text = Some(text.clone());
+        self.pending_clipboard_output = Some(text);
+    }
+
     /// Add a new tab with the given title
     pub fn add_tab(&mut self, title: String) -> TabId {
         let tab = TabState::new(title);
@@ -334,6 +575,7 @@ impl UiChrome {
 
         // Set the new tab as active
         self.active_tab_index = self.tab_order.len() - 1;
+        self.note_active_tab();
 
         tab_id
     }
@@ -350,6 +592,7 @@ impl UiChrome {
             })?;
 
         self.active_tab_index = index;
+        self.note_active_tab();
         Ok(())
     }
 
@@ -385,6 +628,53 @@ impl UiChrome {
         Ok(())
     }
 
+    /// Update a tab's URL
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::ResourceNotFound` if the tab doesn't exist
+    pub fn set_tab_url(&mut self, tab_id: TabId, url: String) -> Result<(), ComponentError> {
+        let tab = self.tabs.get_mut(&tab_id).ok_or_else(|| {
+            ComponentError::ResourceNotFound(format!("Tab {:?} not found", tab_id))
+        })?;
+
+        tab.url = url;
+        Ok(())
+    }
+
+    /// Get a tab's URL
+    pub fn get_tab_url(&self, tab_id: TabId) -> Option<&str> {
+        self.tabs.get(&tab_id).map(|tab| tab.url.as_str())
+    }
+
+    /// Update whether a tab is playing audio
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::ResourceNotFound` if the tab doesn't exist
+    pub fn set_tab_audible(&mut self, tab_id: TabId, audible: bool) -> Result<(), ComponentError> {
+        let tab = self.tabs.get_mut(&tab_id).ok_or_else(|| {
+            ComponentError::ResourceNotFound(format!("Tab {:?} not found", tab_id))
+        })?;
+
+        tab.audible = audible;
+        Ok(())
+    }
+
+    /// Toggle a tab's mute state, returning the new state
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::ResourceNotFound` if the tab doesn't exist
+    pub fn toggle_tab_mute(&mut self, tab_id: TabId) -> Result<bool, ComponentError> {
+        let tab = self.tabs.get_mut(&tab_id).ok_or_else(|| {
+            ComponentError::ResourceNotFound(format!("Tab {:?} not found", tab_id))
+        })?;
+
+        tab.muted = !tab.muted;
+        Ok(tab.muted)
+    }
+
     /// Handle address bar input
     ///
     /// # Errors
@@ -395,6 +685,108 @@ impl UiChrome {
         Ok(())
     }
 
+    /// Drain the navigation commands queued since the last call
+    pub fn take_pending_commands(&mut self) -> Vec<NavigationCommand> {
+        std::mem::take(&mut self.pending_commands)
+    }
+
+    /// Handle the back button
+    pub fn go_back(&mut self) {
+        self.pending_commands.push(NavigationCommand::Back);
+    }
+
+    /// Handle the forward button
+    pub fn go_forward(&mut self) {
+        self.pending_commands.push(NavigationCommand::Forward);
+    }
+
+    /// Handle the reload button
+    pub fn reload(&mut self) {
+        self.pending_commands.push(NavigationCommand::Reload);
+    }
+
+    /// Handle the stop button
+    pub fn stop_loading(&mut self) {
+        self.pending_commands.push(NavigationCommand::Stop);
+    }
+
+    /// Handle Enter in the address bar (or the Go button)
+    ///
+    /// Queues a navigation to the trimmed address bar text and records it as
+    /// the active tab's URL; empty input is ignored.
+    pub fn submit_address_bar(&mut self) {
+        let target = self.address_bar_text.trim().to_string();
+        if target.is_empty() {
+            return;
+        }
+
+        if let Some(tab) = self
+            .active_tab_id()
+            .and_then(|id| self.tabs.get_mut(&id))
+        {
+            tab.url = target.clone();
+        }
+        self.pending_commands.push(NavigationCommand::Navigate(target));
+    }
+
+    /// Get the active tab's zoom level, in percent
+    pub fn active_tab_zoom(&self) -> u16 {
+        self.active_tab_id()
+            .and_then(|id| self.tab_zoom.get(&id).copied())
+            .unwrap_or(DEFAULT_ZOOM_PERCENT)
+    }
+
+    /// Get the zoom bounds and step
+    pub fn zoom_config(&self) -> ZoomConfig {
+        self.menu_bar.zoom_config()
+    }
+
+    /// Set the zoom bounds and step used by zoom in/out
+    ///
+    /// Tabs already zoomed outside the new range keep their level until
+    /// they are next zoomed.
+    pub fn set_zoom_config(&mut self, config: ZoomConfig) {
+        self.menu_bar.set_zoom_config(config);
+    }
+
+    /// Zoom the active tab in by one step, returning the new level
+    pub fn zoom_in(&mut self) -> u16 {
+        self.menu_bar.set_zoom_level(u32::from(self.active_tab_zoom()));
+        let zoom = self.menu_bar.zoom_in();
+        self.set_active_tab_zoom(zoom)
+    }
+
+    /// Zoom the active tab out by one step, returning the new level
+    pub fn zoom_out(&mut self) -> u16 {
+        self.menu_bar.set_zoom_level(u32::from(self.active_tab_zoom()));
+        let zoom = self.menu_bar.zoom_out();
+        self.set_active_tab_zoom(zoom)
+    }
+
+    /// Reset the active tab's zoom to 100%, returning the new level
+    pub fn reset_zoom(&mut self) -> u16 {
+        self.set_active_tab_zoom(u32::from(DEFAULT_ZOOM_PERCENT))
+    }
+
+    /// Clamp and store the active tab's zoom, queueing a command if it changed
+    fn set_active_tab_zoom(&mut self, zoom: u32) -> u16 {
+        let zoom = self.menu_bar.zoom_config().clamp(zoom);
+        let zoom = u16::try_from(zoom).unwrap_or(u16::MAX);
+        let Some(tab_id) = self.active_tab_id() else {
+            return DEFAULT_ZOOM_PERCENT;
+        };
+
+        let previous = self.tab_zoom.insert(tab_id, zoom).unwrap_or(DEFAULT_ZOOM_PERCENT);
+        if previous != zoom {
+            self.pending_commands.push(NavigationCommand::SetZoom {
+                tab: tab_id,
+                percent: zoom,
+            });
+        }
+        self.menu_bar.set_zoom_level(u32::from(zoom));
+        zoom
+    }
+
     /// Handle keyboard shortcuts
     ///
     /// # Errors
@@ -421,8 +813,12 @@ impl UiChrome {
 
                 // Remove the active tab
                 if let Some(tab_id) = self.active_tab_id() {
+                    self.record_closed_tab(tab_id);
+
                     // Remove from tabs map
                     self.tabs.remove(&tab_id);
+                    self.tab_zoom.remove(&tab_id);
+                    self.recent_tabs.retain(|&id| id != tab_id);
 
                     // Remove from tab order
                     self.tab_order.remove(self.active_tab_index);
@@ -443,8 +839,15 @@ impl UiChrome {
             }
 
             KeyboardShortcut::F5 | KeyboardShortcut::CtrlR => {
-                // Reload - would send message to active tab via message bus
-                // For now, just acknowledge the shortcut
+                self.reload();
+                Ok(())
+            }
+
+            KeyboardShortcut::CtrlShiftT => {
+                // Reopen the most recently closed tab
+                if let Some((title, url)) = self.reopen_last_closed_tab() {
+                    self.open_restored_tab(title, url);
+                }
                 Ok(())
             }
 
@@ -481,11 +884,33 @@ impl UiChrome {
             })?;
 
         // Get tab info before removing for recently closed tracking
+        self.record_closed_tab(tab_id);
+
+        // Remove from tabs map
+        self.tabs.remove(&tab_id);
+        self.tab_zoom.remove(&tab_id);
+        self.recent_tabs.retain(|&id| id != tab_id);
+
+        // Remove from tab order
+        self.tab_order.remove(position);
+
+        // Adjust active index if needed
+        if position <= self.active_tab_index && self.active_tab_index > 0 {
+            self.active_tab_index -= 1;
+        } else if self.active_tab_index >= self.tab_order.len() {
+            self.active_tab_index = self.tab_order.len() - 1;
+        }
+
+        Ok(())
+    }
+
+    /// Add a tab that is about to be closed to the recently closed list
+    fn record_closed_tab(&mut self, tab_id: TabId) {
         if let Some(tab) = self.tabs.get(&tab_id) {
             let closed_tab = ClosedTabInfo {
                 id: tab_id,
                 title: tab.title.clone(),
-                url: self.address_bar_text.clone(), // In real usage, should track per-tab URLs
+                url: tab.url.clone(),
                 closed_at: std::time::SystemTime::now()
                     .duration_since(std::time::UNIX_EPOCH)
                     .unwrap_or_default()
@@ -494,23 +919,76 @@ impl UiChrome {
 
             self.crash_recovery.add_closed_tab(closed_tab);
         }
+    }
 
-        // Remove from tabs map
-        self.tabs.remove(&tab_id);
+    /// Remove a tab from the recently closed list
+    ///
+    /// Returns its `(title, url)` so the host can recreate the tab and
+    /// navigate it, or `None` if the tab isn't in the list.
+    pub fn restore_closed_tab(&mut self, tab_id: TabId) -> Option<(String, String)> {
+        self.crash_recovery
+            .remove_closed_tab(tab_id)
+            .map(|tab| (tab.title, tab.url))
+    }
 
-        // Remove from tab order
-        self.tab_order.remove(position);
+    /// Remove the most recently closed tab from the recently closed list
+    ///
+    /// Returns its `(title, url)`, or `None` if no tabs have been closed.
+    pub fn reopen_last_closed_tab(&mut self) -> Option<(String, String)> {
+        self.crash_recovery
+            .recently_closed
+            .pop_most_recent()
+            .map(|tab| (tab.title, tab.url))
+    }
+
+    /// Open a tab for a restored entry and queue navigation to its URL
+    fn open_restored_tab(&mut self, title: String, url: String) {
+        let tab_id = self.add_tab(title);
+        if !url.is_empty() {
+            let _ = self.set_tab_url(tab_id, url.clone());
+            self.pending_commands.push(NavigationCommand::Navigate(url));
+        }
+    }
 
-        // Adjust active index if needed
-        if position <= self.active_tab_index && self.active_tab_index > 0 {
-            self.active_tab_index -= 1;
-        } else if self.active_tab_index >= self.tab_order.len() {
-            self.active_tab_index = self.tab_order.len() - 1;
+    /// Close every tab except `keep`, which becomes the active tab
+    ///
+    /// Closed tabs are recorded in the recently closed list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::ResourceNotFound` if `keep` doesn't exist
+    pub fn close_other_tabs(&mut self, keep: TabId) -> Result<(), ComponentError> {
+        if !self.tabs.contains_key(&keep) {
+            return Err(ComponentError::ResourceNotFound(format!(
+                "Tab {:?} not found",
+                keep
+            )));
+        }
+
+        let others: Vec<TabId> = self
+            .tab_order
+            .iter()
+            .copied()
+            .filter(|&id| id != keep)
+            .collect();
+        for tab_id in others {
+            self.close_tab(tab_id)?;
         }
 
+        self.active_tab_index = 0;
+        self.note_active_tab();
         Ok(())
     }
 
+    /// Close all tabs, leaving only the last one in the tab strip open
+    ///
+    /// Closed tabs are recorded in the recently closed list.
+    pub fn close_all_tabs_except_last(&mut self) {
+        if let Some(&last) = self.tab_order.last() {
+            let _ = self.close_other_tabs(last);
+        }
+    }
+
     /// Switch to the next tab (wraps around)
     pub fn switch_to_next_tab(&mut self) -> Result<(), ComponentError> {
         if self.tab_order.is_empty() {
@@ -520,6 +998,7 @@ impl UiChrome {
         }
 
         self.active_tab_index = (self.active_tab_index + 1) % self.tab_order.len();
+        self.note_active_tab();
         Ok(())
     }
 
@@ -536,6 +1015,7 @@ impl UiChrome {
         } else {
             self.active_tab_index -= 1;
         }
+        self.note_active_tab();
         Ok(())
     }
 
@@ -554,9 +1034,103 @@ impl UiChrome {
         }
 
         self.active_tab_index = tab_number - 1; // Convert to 0-indexed
+        self.note_active_tab();
         Ok(())
     }
 
+    /// Move the active tab to the front of the recently-used list
+    fn note_active_tab(&mut self) {
+        if let Some(tab_id) = self.active_tab_id() {
+            if self.recent_tabs.first() != Some(&tab_id) {
+                self.recent_tabs.retain(|&id| id != tab_id);
+                self.recent_tabs.insert(0, tab_id);
+            }
+        }
+    }
+
+    /// Fuzzy-match open tabs by title and URL
+    ///
+    /// Results are ranked by match quality, with ties broken by how recently
+    /// each tab was active.
+    pub fn tab_switcher_results(&self, query: &str) -> Vec<TabSwitchResult> {
+        let mut ordered: Vec<TabId> = self
+            .recent_tabs
+            .iter()
+            .copied()
+            .filter(|id| self.tabs.contains_key(id))
+            .collect();
+        for &tab_id in &self.tab_order {
+            if !ordered.contains(&tab_id) {
+                ordered.push(tab_id);
+            }
+        }
+
+        let candidates = ordered.into_iter().filter_map(|tab_id| {
+            let tab = self.tabs.get(&tab_id)?;
+            Some((tab_id, tab.title.as_str(), tab.url.as_str()))
+        });
+        tab_switcher::rank_tabs(query, candidates)
+    }
+
+    /// Filter open tabs whose title or URL contains `query`, case-insensitively
+    ///
+    /// Results follow the tab bar order.
+    pub fn filter_tabs(&self, query: &str) -> Vec<TabId> {
+        let candidates = self.tab_order.iter().filter_map(|&tab_id| {
+            let tab = self.tabs.get(&tab_id)?;
+            Some((tab_id, tab.title.as_str(), tab.url.as_str()))
+        });
+        tab_switcher::filter_tabs(query, candidates)
+    }
+
+    /// Get the current tab search query (empty when not searching)
+    pub fn tab_search_query(&self) -> &str {
+        &self.tab_switcher.query
+    }
+
+    /// Get the tab switcher overlay state
+    pub fn tab_switcher(&self) -> &TabSwitcher {
+        &self.tab_switcher
+    }
+
+    /// Toggle the tab switcher overlay (Ctrl+Shift+A)
+    pub fn toggle_tab_switcher(&mut self) {
+        if self.tab_switcher.visible {
+            self.tab_switcher.close();
+        } else {
+            self.tab_switcher.open();
+            let results = self.tab_switcher_results("");
+            self.tab_switcher.set_results(results);
+        }
+    }
+
+    /// Update the tab switcher query and re-rank results
+    pub fn set_tab_switcher_query(&mut self, query: String) {
+        let results = self.tab_switcher_results(&query);
+        self.tab_switcher.query = query;
+        self.tab_switcher.set_results(results);
+    }
+
+    /// Move the tab switcher selection down
+    pub fn tab_switcher_select_next(&mut self) {
+        self.tab_switcher.select_next();
+    }
+
+    /// Move the tab switcher selection up
+    pub fn tab_switcher_select_previous(&mut self) {
+        self.tab_switcher.select_previous();
+    }
+
+    /// Activate the selected tab switcher result and close the overlay
+    ///
+    /// Returns the activated tab, or `None` if nothing was selected.
+    pub fn activate_tab_switcher_selection(&mut self) -> Option<TabId> {
+        let tab_id = self.tab_switcher.selected_result()?.tab_id;
+        self.set_active_tab(tab_id).ok()?;
+        self.tab_switcher.close();
+        Some(tab_id)
+    }
+
     /// Check if settings panel is visible
     pub fn is_settings_panel_visible(&self) -> bool {
         self.settings_panel_visible
@@ -646,6 +1220,29 @@ impl UiChrome {
         &self.downloads
     }
 
+    /// Get the combined progress (0.0 to 1.0) of active downloads
+    ///
+    /// Downloads of unknown size are left out, since they can't contribute
+    /// a fraction. Returns `None` when no active download has a known size.
+    pub fn aggregate_download_progress(&self) -> Option<f32> {
+        let (downloaded, total) = self
+            .downloads
+            .iter()
+            .filter(|d| {
+                matches!(
+                    d.status,
+                    DownloadDisplayStatus::Downloading | DownloadDisplayStatus::Paused
+                ) && d.total_bytes > 0
+            })
+            .fold((0u64, 0u64), |(downloaded, total), d| {
+                (
+                    downloaded + d.downloaded_bytes.min(d.total_bytes),
+                    total + d.total_bytes,
+                )
+            });
+        (total > 0).then(|| downloaded as f32 / total as f32)
+    }
+
     /// Clear completed downloads from the list
     pub fn clear_completed_downloads(&mut self) {
         self.downloads
@@ -784,13 +1381,45 @@ impl UiChrome {
         self.crash_recovery.closed_tab_count()
     }
 
+    /// Get recently closed tabs (most recent first)
+    pub fn recently_closed_tabs(&self) -> &std::collections::VecDeque<ClosedTabInfo> {
+        self.crash_recovery.recently_closed.get_all()
+    }
+
+    /// Pin or unpin a tab, returning the new pinned state
+    ///
+    /// The tab order is re-sorted so pinned tabs precede unpinned ones, with
+    /// the relative order within each group preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::ResourceNotFound` if the tab doesn't exist
+    pub fn toggle_pin(&mut self, tab_id: TabId) -> Result<bool, ComponentError> {
+        let tab = self.tabs.get_mut(&tab_id).ok_or_else(|| {
+            ComponentError::ResourceNotFound(format!("Tab {:?} not found", tab_id))
+        })?;
+        tab.pinned = !tab.pinned;
+        let pinned = tab.pinned;
+
+        let active = self.active_tab_id();
+        let tabs = &self.tabs;
+        self.tab_order
+            .sort_by_key(|id| !tabs.get(id).is_some_and(|t| t.pinned));
+        if let Some(index) = active.and_then(|id| self.tab_order.iter().position(|&t| t == id)) {
+            self.active_tab_index = index;
+        }
+
+        Ok(pinned)
+    }
+
     /// Reorder tabs based on drag-and-drop operation
     ///
     /// Moves a tab from `from_index` to `to_index` in the tab order
     ///
     /// # Errors
     ///
-    /// Returns `ComponentError::InvalidState` if indices are out of range
+    /// Returns `ComponentError::InvalidState` if indices are out of range, or
+    /// if the move would place a pinned tab after an unpinned one (or vice versa)
     pub fn reorder_tab(&mut self, from_index: usize, to_index: usize) -> Result<(), ComponentError> {
         if from_index >= self.tab_order.len() {
             return Err(ComponentError::InvalidState(format!(
@@ -812,16 +1441,30 @@ impl UiChrome {
             return Ok(()); // No-op
         }
 
-        // Remove tab from original position
-        let tab_id = self.tab_order.remove(from_index);
-
-        // Insert at new position (adjust for removal)
+        // Insert position once the tab has been removed
         let insert_index = if to_index > from_index {
             to_index - 1
         } else {
             to_index
         };
 
+        // Pinned tabs must stay in front of unpinned ones
+        let pinned_count = self.pinned_tab_count();
+        let moving_pinned = from_index < pinned_count;
+        let crosses_boundary = if moving_pinned {
+            insert_index >= pinned_count
+        } else {
+            insert_index < pinned_count
+        };
+        if crosses_boundary {
+            return Err(ComponentError::InvalidState(
+                "Cannot move tabs between the pinned and unpinned groups".to_string(),
+            ));
+        }
+
+        // Remove tab from original position
+        let tab_id = self.tab_order.remove(from_index);
+
         self.tab_order.insert(insert_index, tab_id);
 
         // Update active index if needed
@@ -836,6 +1479,29 @@ impl UiChrome {
         Ok(())
     }
 
+    /// Move the active tab one position to the left
+    ///
+    /// Keyboard alternative to dragging (Ctrl+Shift+PageUp). The tab stays
+    /// put at the start of the tab bar or of its pinned/unpinned group.
+    ///
+    /// Returns `true` if the tab moved
+    pub fn move_active_tab_left(&mut self) -> bool {
+        let index = self.active_tab_index;
+        index > 0 && index < self.tab_order.len() && self.reorder_tab(index, index - 1).is_ok()
+    }
+
+    /// Move the active tab one position to the right
+    ///
+    /// Keyboard alternative to dragging (Ctrl+Shift+PageDown). The tab stays
+    /// put at the end of the tab bar or of its pinned/unpinned group.
+    ///
+    /// Returns `true` if the tab moved
+    pub fn move_active_tab_right(&mut self) -> bool {
+        let index = self.active_tab_index;
+        // `reorder_tab` targets the slot before removal, hence + 2
+        index + 1 < self.tab_order.len() && self.reorder_tab(index, index + 2).is_ok()
+    }
+
     /// Load settings from a settings manager
     ///
     /// This should be called when initializing the UI or when settings need to be refreshed
@@ -907,18 +1573,13 @@ impl UiChrome {
                         // TODO: Implement show all bookmarks
                     }
                     UiAction::ZoomIn => {
-                        // TODO: Implement zoom in
-                        let current = self.menu_bar.zoom_level();
-                        self.menu_bar.set_zoom_level((current + 10).min(300));
+                        self.zoom_in();
                     }
                     UiAction::ZoomOut => {
-                        // TODO: Implement zoom out
-                        let current = self.menu_bar.zoom_level();
-                        self.menu_bar.set_zoom_level((current.saturating_sub(10)).max(25));
+                        self.zoom_out();
                     }
                     UiAction::ResetZoom => {
-                        // TODO: Implement reset zoom
-                        self.menu_bar.set_zoom_level(100);
+                        self.reset_zoom();
                     }
                     UiAction::FullScreen => {
                         // TODO: Implement full screen toggle
@@ -929,9 +1590,21 @@ impl UiChrome {
                     UiAction::About => {
                         // TODO: Implement about dialog
                     }
-                    UiAction::Undo | UiAction::Redo | UiAction::Cut | UiAction::Copy
-                    | UiAction::Paste | UiAction::SelectAll => {
-                        // TODO: Implement clipboard operations
+                    // The address bar is the chrome's only editable text
+                    UiAction::Cut => {
+                        self.address_bar_cut();
+                    }
+                    UiAction::Copy => {
+                        self.address_bar_copy();
+                    }
+                    UiAction::Paste => {
+                        self.paste_known_clipboard();
+                    }
+                    UiAction::SelectAll => {
+                        self.address_bar_select_all();
+                    }
+                    UiAction::Undo | UiAction::Redo => {
+                        // TODO: Implement undo/redo
                     }
                     UiAction::ClearHistory => {
                         // TODO: Implement clear history
@@ -960,6 +1633,26 @@ impl UiChrome {
         // Handle keyboard shortcuts
         self.handle_keyboard_input(ctx);
 
+        // Remember pasted text so the Paste actions can reuse it
+        ctx.input(|i| {
+            for event in &i.events {
+                if let egui::Event::Paste(text) = event {
+                    self.clipboard_text = Some(text.clone());
+                }
+            }
+        });
+
+        // Flush text copied by menu actions to the system clipboard
+        if let Some(text) = self.pending_clipboard_output.take() {
+            ctx.output_mut(|o| o.copied_text = text);
+        }
+
+        // Track tab activations made by clicks since the last frame
+        self.note_active_tab();
+
+        // Show the active tab's zoom in the menu
+        self.menu_bar.set_zoom_level(u32::from(self.active_tab_zoom()));
+
         // Update menu bar state based on current UI state
         self.menu_bar.set_tab_state(
             !self.tabs.is_empty(),
@@ -982,22 +1675,51 @@ impl UiChrome {
         egui::TopBottomPanel::top("toolbar").show(ctx, |ui| {
             ui.horizontal(|ui| {
                 if ui.button("◀").clicked() {
-                    // Go back - would send message via message bus
+                    self.go_back();
                 }
                 if ui.button("▶").clicked() {
-                    // Go forward - would send message via message bus
+                    self.go_forward();
                 }
-                if ui.button("⟳").clicked() {
-                    // Reload - would send message via message bus
+
+                // Stop while loading, reload otherwise
+                let loading = self
+                    .active_tab_id()
+                    .and_then(|id| self.is_tab_loading(id))
+                    .unwrap_or(false);
+                if loading {
+                    if ui.button("✕").clicked() {
+                        self.stop_loading();
+                    }
+                } else if ui.button("⟳").clicked() {
+                    self.reload();
                 }
 
                 // Address bar with context menu support
+                let address_bar_id = egui::Id::new("address_bar");
+                if self.address_bar_selection_dirty {
+                    let mut state =
+                        egui::widgets::text_edit::TextEditState::load(ui.ctx(), address_bar_id)
+                            .unwrap_or_default();
+                    state.cursor.set_char_range(Some(egui::text::CCursorRange::two(
+                        egui::text::CCursor::new(self.address_bar_selection.start),
+                        egui::text::CCursor::new(self.address_bar_selection.end),
+                    )));
+                    state.store(ui.ctx(), address_bar_id);
+                    self.address_bar_selection_dirty = false;
+                }
+
                 let mut address_text = self.address_bar_text.clone();
-                let response = ui.text_edit_singleline(&mut address_text);
+                let output = egui::TextEdit::singleline(&mut address_text)
+                    .id(address_bar_id)
+                    .show(ui);
+                let response = output.response;
 
                 if response.changed() {
                     self.address_bar_text = address_text;
                 }
+                if let Some(cursor_range) = output.cursor_range {
+                    self.address_bar_selection = cursor_range.as_sorted_char_range();
+                }
 
                 // Track focus state
                 self.address_bar_focused = response.has_focus();
@@ -1007,14 +1729,32 @@ impl UiChrome {
                     self.show_address_bar_context_menu();
                 }
 
+                // Reader mode toggle for http(s) pages
+                if let Some(tab_id) = self.active_tab_id() {
+                    let available = self.get_tab_url(tab_id).is_some_and(is_http_url);
+                    let enabled = self.is_reader_mode(tab_id).unwrap_or(false);
+                    let reader_button = ui
+                        .add_enabled(
+                            available || enabled,
+                            egui::SelectableLabel::new(enabled, "📖"),
+                        )
+                        .on_hover_text("Reader mode");
+                    if reader_button.clicked() {
+                        let _ = self.toggle_reader_mode(tab_id);
+                    }
+                }
+
                 if ui.button("Go").clicked()
                     || (response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)))
                 {
-                    // Navigate - would send message via message bus
+                    self.submit_address_bar();
                 }
             });
         });
 
+        // Tab search box, shown above the tab bar while searching
+        self.render_tab_search_box(ctx);
+
         // Tab bar with drag-and-drop support
         egui::TopBottomPanel::top("tabs").show(ctx, |ui| {
             ui.horizontal(|ui| {
@@ -1025,13 +1765,15 @@ impl UiChrome {
         // Render context menus
         self.render_context_menu(ctx);
 
+        // Tab search overlay
+        self.render_tab_switcher(ctx);
+
         // Recently closed tabs menu (if visible)
         egui::CentralPanel::default().show(ctx, |ui| {
             if let Some(tab_id) = self.crash_recovery.render_recently_closed_menu(ui) {
-                // User clicked to restore a tab - would need to restore from session manager
-                // For now, just remove from recently closed list
-                self.crash_recovery.remove_closed_tab(tab_id);
-                // TODO: Actually restore the tab with its URL and history
+                if let Some((title, url)) = self.restore_closed_tab(tab_id) {
+                    self.open_restored_tab(title, url);
+                }
             }
         });
 
@@ -1243,6 +1985,9 @@ impl UiChrome {
                     // Download count badge
                     if self.download_count > 0 {
                         ui.label(format!("⬇ {}", self.download_count));
+                        if let Some(progress) = self.aggregate_download_progress() {
+                            ui.add(egui::ProgressBar::new(progress).desired_width(80.0));
+                        }
                     }
 
                     // Blocked content count badge from ad blocker
@@ -1268,10 +2013,12 @@ impl UiChrome {
                 ui.label("Ctrl+Tab: Next tab");
                 ui.label("Ctrl+Shift+Tab: Previous tab");
                 ui.label("Ctrl+1-9: Switch to tab N");
+                ui.label("Ctrl+Shift+A: Search tabs");
                 ui.label("Ctrl+D: Bookmark page");
                 ui.label("Ctrl+H: History panel");
                 ui.label("Ctrl+J: Downloads panel");
                 ui.label("Ctrl+,: Settings panel");
+                ui.label("Ctrl+= / Ctrl+- / Ctrl+0: Zoom in / out / reset");
             });
         });
 
@@ -1280,10 +2027,19 @@ impl UiChrome {
 
     /// Handle keyboard input for shortcuts
     fn handle_keyboard_input(&mut self, ctx: &egui::Context) {
+        // Ctrl+=/-/0 zoom the page; egui would otherwise also scale the
+        // whole chrome on the same keys
+        ctx.options_mut(|o| o.zoom_with_keyboard = false);
+
         ctx.input(|i| {
             let ctrl = i.modifiers.ctrl;
             let shift = i.modifiers.shift;
 
+            // Ctrl+Shift+T: Reopen closed tab
+            if ctrl && shift && i.key_pressed(egui::Key::T) {
+                let _ = self.handle_keyboard_shortcut(KeyboardShortcut::CtrlShiftT);
+            }
+
             // Ctrl+Tab: Next tab
             if ctrl && !shift && i.key_pressed(egui::Key::Tab) {
                 let _ = self.switch_to_next_tab();
@@ -1294,6 +2050,14 @@ impl UiChrome {
                 let _ = self.switch_to_previous_tab();
             }
 
+            // Ctrl+Shift+PageUp / PageDown: Move the active tab
+            if ctrl && shift && i.key_pressed(egui::Key::PageUp) {
+                self.move_active_tab_left();
+            }
+            if ctrl && shift && i.key_pressed(egui::Key::PageDown) {
+                self.move_active_tab_right();
+            }
+
             // Ctrl+1-9: Switch to tab number
             for (key, num) in [
                 (egui::Key::Num1, 1),
@@ -1311,6 +2075,17 @@ impl UiChrome {
                 }
             }
 
+            // Ctrl+= / Ctrl+- / Ctrl+0: Zoom in, out, reset
+            if ctrl && (i.key_pressed(egui::Key::Equals) || i.key_pressed(egui::Key::Plus)) {
+                self.zoom_in();
+            }
+            if ctrl && i.key_pressed(egui::Key::Minus) {
+                self.zoom_out();
+            }
+            if ctrl && i.key_pressed(egui::Key::Num0) {
+                self.reset_zoom();
+            }
+
             // Ctrl+D: Bookmark current page
             if ctrl && i.key_pressed(egui::Key::D) {
                 let _ = self.bookmark_current_page();
@@ -1330,14 +2105,97 @@ impl UiChrome {
             if ctrl && i.key_pressed(egui::Key::Comma) {
                 self.toggle_settings_panel();
             }
+
+            // Ctrl+Shift+A: Toggle tab search overlay
+            if ctrl && shift && i.key_pressed(egui::Key::A) {
+                self.toggle_tab_switcher();
+            } else if self.tab_switcher.visible {
+                if i.key_pressed(egui::Key::ArrowDown) {
+                    self.tab_switcher_select_next();
+                }
+                if i.key_pressed(egui::Key::ArrowUp) {
+                    self.tab_switcher_select_previous();
+                }
+                if i.key_pressed(egui::Key::Enter) {
+                    self.activate_tab_switcher_selection();
+                }
+                if i.key_pressed(egui::Key::Escape) {
+                    self.tab_switcher.close();
+                }
+            }
         });
     }
 
+    /// Render the tab search overlay
+    fn render_tab_switcher(&mut self, ctx: &egui::Context) {
+        if !self.tab_switcher.visible {
+            return;
+        }
+
+        let mut clicked: Option<usize> = None;
+
+        egui::Window::new("🔍 Search Tabs")
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+            .show(ctx, |ui| {
+                if self.tab_switcher.results.is_empty() {
+                    ui.label("No matching tabs");
+                }
+
+                for (index, result) in self.tab_switcher.results.iter().enumerate() {
+                    let label = if result.url.is_empty() {
+                        result.title.clone()
+                    } else {
+                        format!("{} — {}", result.title, result.url)
+                    };
+                    if ui
+                        .selectable_label(index == self.tab_switcher.selected, label)
+                        .clicked()
+                    {
+                        clicked = Some(index);
+                    }
+                }
+            });
+
+        if let Some(index) = clicked {
+            self.tab_switcher.selected = index;
+            self.activate_tab_switcher_selection();
+        }
+    }
+
+    /// Render the tab search box above the tab bar
+    fn render_tab_search_box(&mut self, ctx: &egui::Context) {
+        if !self.tab_switcher.visible {
+            return;
+        }
+
+        let mut query = self.tab_switcher.query.clone();
+
+        egui::TopBottomPanel::top("tab_search").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("🔍");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut query)
+                        .hint_text("Search tabs")
+                        .desired_width(200.0),
+                );
+                response.request_focus();
+            });
+        });
+
+        if query != self.tab_switcher.query {
+            self.set_tab_switcher_query(query);
+        }
+    }
+
     /// Render the tab bar with drag-and-drop support
     fn render_tab_bar(&mut self, ui: &mut egui::Ui) -> Result<(), ComponentError> {
         use tab_drag_ui::{render_drop_indicator, render_ghost_tab};
 
         let mut tab_to_close: Option<TabId> = None;
+        let mut tab_to_toggle_mute: Option<TabId> = None;
         let mut tab_for_context_menu: Option<TabId> = None;
         let mut tab_rects: Vec<(TabId, egui::Rect)> = Vec::new();
 
@@ -1350,18 +2208,60 @@ impl UiChrome {
             ui.add_space(-self.tab_overflow.offset());
         }
 
+        // While searching, only show tabs matching the query
+        let visible_tabs: Option<HashSet<TabId>> = if self.tab_switcher.visible {
+            Some(self.filter_tabs(&self.tab_switcher.query).into_iter().collect())
+        } else {
+            None
+        };
+
         // Render tabs in order
         for (index, &tab_id) in self.tab_order.iter().enumerate() {
+            if visible_tabs
+                .as_ref()
+                .is_some_and(|visible| !visible.contains(&tab_id))
+            {
+                continue;
+            }
+
             if let Some(tab) = self.tabs.get(&tab_id) {
                 let is_active = index == self.active_tab_index;
                 let is_being_dragged = self.tab_drag_state.dragging_tab == Some(tab_id);
 
-                // Show loading indicator if tab is loading
-                let label = if tab.loading {
+                // Show loading indicator if tab is loading; pinned tabs are
+                // icon-only, using the title's first letter as the icon
+                let label = if tab.pinned {
+                    if tab.loading {
+                        "⟳".to_string()
+                    } else {
+                        tab.title
+                            .chars()
+                            .next()
+                            .map(|c| c.to_uppercase().to_string())
+                            .unwrap_or_else(|| "📌".to_string())
+                    }
+                } else if tab.loading {
                     format!("⟳ {}", tab.title)
                 } else {
                     tab.title.clone()
                 };
+                let tooltip = if tab.pinned && tab.url.is_empty() {
+                    tab.title.clone()
+                } else if tab.pinned {
+                    format!("{}\n{}", tab.title, tab.url)
+                } else {
+                    tab.url.clone()
+                };
+                let pinned = tab.pinned;
+
+                // Audio indicator: muted takes precedence over playing
+                let audio_glyph = if tab.muted {
+                    Some("🔇")
+                } else if tab.audible {
+                    Some("🔊")
+                } else {
+                    None
+                };
 
                 // Dim the tab if it's being dragged
                 let alpha_multiplier = if is_being_dragged { 0.3 } else { 1.0 };
@@ -1372,8 +2272,20 @@ impl UiChrome {
                             ui.style().visuals.widgets.inactive.bg_fill.linear_multiply(alpha_multiplier);
                     }
 
+                    // Clicking (or middle-clicking) the audio indicator toggles mute
+                    if let Some(glyph) = audio_glyph {
+                        let indicator =
+                            ui.add(egui::Label::new(glyph).sense(egui::Sense::click()));
+                        if indicator.clicked() || indicator.middle_clicked() {
+                            tab_to_toggle_mute = Some(tab_id);
+                        }
+                    }
+
                     // Tab with hover effect
-                    let tab_response = ui.selectable_label(is_active, &label);
+                    let mut tab_response = ui.selectable_label(is_active, &label);
+                    if !tooltip.is_empty() {
+                        tab_response = tab_response.on_hover_text(&tooltip);
+                    }
                     let tab_rect = tab_response.rect;
 
                     // Store rect for drop target calculation
@@ -1400,8 +2312,8 @@ impl UiChrome {
                         tab_for_context_menu = Some(tab_id);
                     }
 
-                    // Close button (X)
-                    if ui.small_button("✕").clicked() {
+                    // Close button (X), hidden on pinned tabs to keep them narrow
+                    if !pinned && ui.small_button("✕").clicked() {
                         tab_to_close = Some(tab_id);
                     }
                 });
@@ -1455,6 +2367,10 @@ impl UiChrome {
         });
 
         // Process deferred actions
+        if let Some(tab_id) = tab_to_toggle_mute {
+            let _ = self.toggle_tab_mute(tab_id);
+        }
+
         if let Some(tab_id) = tab_to_close {
             let _ = self.close_tab(tab_id);
         }
@@ -1494,12 +2410,21 @@ impl UiChrome {
                                     let _ = self.close_tab(tab_id);
                                     self.close_context_menu();
                                 }
+                                let pin_label = if self.is_tab_pinned(tab_id) == Some(true) {
+                                    "Unpin Tab"
+                                } else {
+                                    "Pin Tab"
+                                };
+                                if ui.button(pin_label).clicked() {
+                                    let _ = self.toggle_pin(tab_id);
+                                    self.close_context_menu();
+                                }
                                 if ui.button("Close Other Tabs").clicked() {
-                                    // Close all tabs except this one
+                                    let _ = self.close_other_tabs(tab_id);
                                     self.close_context_menu();
                                 }
                                 if ui.button("Close All Tabs").clicked() {
-                                    // Close all tabs (except last one)
+                                    self.close_all_tabs_except_last();
                                     self.close_context_menu();
                                 }
                             });
@@ -1516,11 +2441,19 @@ impl UiChrome {
                         .show(ctx, |ui| {
                             egui::Frame::menu(ui.style()).show(ui, |ui| {
                                 if ui.button("Copy").clicked() {
-                                    // Copy address bar text to clipboard
+                                    let text = self.address_bar_copy();
+                                    ctx.output_mut(|o| o.copied_text = text);
+                                    self.pending_clipboard_output = None;
                                     self.close_context_menu();
                                 }
-                                if ui.button("Paste").clicked() {
-                                    // Paste from clipboard to address bar
+                                if ui
+                                    .add_enabled(
+                                        self.clipboard_text.is_some(),
+                                        egui::Button::new("Paste"),
+                                    )
+                                    .clicked()
+                                {
+                                    self.paste_known_clipboard();
                                     self.close_context_menu();
                                 }
                             });
@@ -1536,6 +2469,12 @@ impl UiChrome {
     }
 }
 
+/// Whether `url` uses the http or https scheme
+fn is_http_url(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
 impl Default for UiChrome {
     fn default() -> Self {
         Self::new()
@@ -51,6 +51,36 @@ pub enum UiAction {
     ShowDocumentation,
 }
 
+/// Zoom range and step used by the View menu's zoom actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoomConfig {
+    /// Smallest zoom level, in percent
+    pub min: u32,
+    /// Largest zoom level, in percent
+    pub max: u32,
+    /// Change per zoom in/out step, in percent
+    pub step: u32,
+}
+
+impl Default for ZoomConfig {
+    fn default() -> Self {
+        Self {
+            min: u32::from(crate::MIN_ZOOM_PERCENT),
+            max: u32::from(crate::MAX_ZOOM_PERCENT),
+            step: 10,
+        }
+    }
+}
+
+impl ZoomConfig {
+    /// Clamp a zoom level into the configured range
+    ///
+    /// Never panics, even if `min` is greater than `max`; `max` wins.
+    pub fn clamp(&self, zoom: u32) -> u32 {
+        zoom.max(self.min).min(self.max)
+    }
+}
+
 /// Menu bar state and rendering
 pub struct MenuBar {
     /// Whether there's history to go back to
@@ -67,6 +97,8 @@ pub struct MenuBar {
     can_redo: bool,
     /// Current zoom level (100 = normal)
     zoom_level: u32,
+    /// Zoom bounds and step
+    zoom_config: ZoomConfig,
 }
 
 impl Default for MenuBar {
@@ -79,6 +111,7 @@ impl Default for MenuBar {
             can_undo: false,
             can_redo: false,
             zoom_level: 100,
+            zoom_config: ZoomConfig::default(),
         }
     }
 }
@@ -117,6 +150,31 @@ impl MenuBar {
         self.zoom_level
     }
 
+    /// Set the zoom bounds and step, clamping the current level into range
+    pub fn set_zoom_config(&mut self, config: ZoomConfig) {
+        self.zoom_config = config;
+        self.zoom_level = config.clamp(self.zoom_level);
+    }
+
+    /// Get the zoom bounds and step
+    pub fn zoom_config(&self) -> ZoomConfig {
+        self.zoom_config
+    }
+
+    /// Zoom in by one step, returning the new level
+    pub fn zoom_in(&mut self) -> u32 {
+        let zoom = self.zoom_level.saturating_add(self.zoom_config.step);
+        self.zoom_level = self.zoom_config.clamp(zoom);
+        self.zoom_level
+    }
+
+    /// Zoom out by one step, returning the new level
+    pub fn zoom_out(&mut self) -> u32 {
+        let zoom = self.zoom_level.saturating_sub(self.zoom_config.step);
+        self.zoom_level = self.zoom_config.clamp(zoom);
+        self.zoom_level
+    }
+
     /// Render the menu bar and return any triggered action
     pub fn render(&self, ui: &mut egui::Ui) -> Option<MenuAction> {
         let mut action = None;
@@ -404,4 +462,52 @@ mod tests {
         menu.set_zoom_level(150);
         assert_eq!(menu.zoom_level, 150);
     }
+
+    #[test]
+    fn test_custom_zoom_step_and_bounds() {
+        let mut menu = MenuBar::new();
+        menu.set_zoom_config(ZoomConfig {
+            min: 50,
+            max: 200,
+            step: 25,
+        });
+
+        assert_eq!(menu.zoom_in(), 125);
+        assert_eq!(menu.zoom_in(), 150);
+        menu.set_zoom_level(100);
+        assert_eq!(menu.zoom_out(), 75);
+        assert_eq!(menu.zoom_out(), 50);
+        assert_eq!(menu.zoom_out(), 50);
+
+        // Narrowing the range pulls the current level into it
+        menu.set_zoom_level(180);
+        menu.set_zoom_config(ZoomConfig {
+            min: 100,
+            max: 150,
+            step: 10,
+        });
+        assert_eq!(menu.zoom_level(), 150);
+    }
+
+    #[test]
+    fn test_zoom_in_saturates_at_max() {
+        let mut menu = MenuBar::new();
+        menu.set_zoom_config(ZoomConfig {
+            min: 10,
+            max: u32::MAX - 5,
+            step: u32::MAX / 2,
+        });
+
+        for _ in 0..10 {
+            menu.zoom_in();
+        }
+        assert_eq!(menu.zoom_level(), u32::MAX - 5);
+        assert_eq!(menu.zoom_out(), u32::MAX - 5 - u32::MAX / 2);
+
+        let mut menu = MenuBar::new();
+        for _ in 0..100 {
+            menu.zoom_in();
+        }
+        assert_eq!(menu.zoom_level(), 300);
+    }
 }
@@ -67,6 +67,11 @@ impl PaperSize {
         (w_mm * mm_to_pt, h_mm * mm_to_pt)
     }
 
+    /// Short alias for [`Self::dimensions_points`]
+    pub fn dimensions_pt(&self) -> (f32, f32) {
+        self.dimensions_points()
+    }
+
     /// Get the display name for this paper size
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -274,42 +279,51 @@ impl PageRange {
         PageRange::Pages(pages)
     }
 
-    /// Parse a page range string like "1-5" or "1,3,5-7"
-    pub fn parse(input: &str) -> Result<Self, String> {
+    /// Parse a page range string like "1-5" or "1,3,5-7" into concrete pages
+    ///
+    /// Returns the sorted, de-duplicated 1-based page numbers. An empty input
+    /// or "all" selects every page. Pages outside `1..=total_pages` and
+    /// inverted ranges such as "5-3" are rejected.
+    pub fn parse(input: &str, total_pages: u32) -> Result<Vec<u32>, PrintError> {
         let input = input.trim();
         if input.is_empty() || input.eq_ignore_ascii_case("all") {
-            return Ok(PageRange::All);
+            return Ok((1..=total_pages).collect());
         }
 
+        let parse_page = |text: &str| -> Result<u32, PrintError> {
+            let page: u32 = text.trim().parse().map_err(|_| {
+                PrintError::InvalidPageRange(format!("'{}' is not a page number", text.trim()))
+            })?;
+            if page == 0 || page > total_pages {
+                return Err(PrintError::InvalidPageRange(format!(
+                    "page {} is outside 1-{}",
+                    page, total_pages
+                )));
+            }
+            Ok(page)
+        };
+
         let mut pages = Vec::new();
         for part in input.split(',') {
-            let part = part.trim();
-            if part.contains('-') {
-                let mut parts = part.splitn(2, '-');
-                let start: u32 = parts
-                    .next()
-                    .unwrap_or("1")
-                    .trim()
-                    .parse()
-                    .map_err(|_| format!("Invalid page number in '{}'", part))?;
-                let end: u32 = parts
-                    .next()
-                    .unwrap_or("1")
-                    .trim()
-                    .parse()
-                    .map_err(|_| format!("Invalid page number in '{}'", part))?;
-                for p in start..=end {
-                    pages.push(p);
+            match part.split_once('-') {
+                Some((start, end)) => {
+                    let start = parse_page(start)?;
+                    let end = parse_page(end)?;
+                    if start > end {
+                        return Err(PrintError::InvalidPageRange(format!(
+                            "range '{}' is inverted",
+                            part.trim()
+                        )));
+                    }
+                    pages.extend(start..=end);
                 }
-            } else {
-                let page: u32 = part
-                    .parse()
-                    .map_err(|_| format!("Invalid page number: '{}'", part))?;
-                pages.push(page);
+                None => pages.push(parse_page(part)?),
             }
         }
 
-        Ok(PageRange::pages(pages))
+        pages.sort_unstable();
+        pages.dedup();
+        Ok(pages)
     }
 
     /// Check if a page number (1-indexed) is included in this range
@@ -345,7 +359,7 @@ pub enum PrintDestination {
     /// Print to a specific printer by name
     Printer(String),
     /// Save as PDF to specified path
-    Pdf(PathBuf),
+    SaveAsPdf { path: PathBuf },
 }
 
 impl Default for PrintDestination {
@@ -453,6 +467,18 @@ impl PrintSettings {
         self
     }
 
+    /// Set the page range from user input like "1-3,5"
+    ///
+    /// The input is validated against `total_pages`; see [`PageRange::parse`].
+    pub fn with_page_range_input(
+        mut self,
+        input: &str,
+        total_pages: u32,
+    ) -> Result<Self, PrintError> {
+        self.page_range = PageRange::Pages(PageRange::parse(input, total_pages)?);
+        Ok(self)
+    }
+
     /// Set destination
     pub fn with_destination(mut self, destination: PrintDestination) -> Self {
         self.destination = destination;
@@ -476,6 +502,13 @@ impl PrintSettings {
         let (w, h) = self.page_dimensions_mm();
         self.margins.printable_area(w, h)
     }
+
+    /// Get printable area in points (page size after orientation, minus margins)
+    pub fn printable_area(&self) -> (f32, f32) {
+        let (w, h) = self.page_dimensions_points();
+        let (top, bottom, left, right) = self.margins.to_points();
+        ((w - left - right).max(0.0), (h - top - bottom).max(0.0))
+    }
 }
 
 /// Print job status
@@ -1218,20 +1251,44 @@ impl PrintManager {
         show_system_print_dialog(&self.default_settings)
     }
 
-    /// Print to PDF
-    pub fn print_to_pdf(&mut self, path: PathBuf, title: String, pages: u32) -> Result<PrintJobId, PrintError> {
-        let mut settings = self.preview.settings().clone();
-        settings.destination = PrintDestination::Pdf(path);
-
-        let mut job = PrintJob::new(title, pages, settings);
+    /// Print a job to a PDF file
+    ///
+    /// Writes one page per page selected by the job's page range, sized by its
+    /// paper size, orientation, margins, and scale. The job moves through
+    /// `Printing` to `Completed`, or to `Failed` if the file can't be written;
+    /// either way it is tracked by the manager.
+    pub fn print_to_pdf(
+        &mut self,
+        mut job: PrintJob,
+        path: PathBuf,
+    ) -> Result<PrintJobId, PrintError> {
         let id = job.id;
+        let page_count = job.settings.page_range.page_count(job.total_pages);
+        if page_count == 0 {
+            return Err(PrintError::InvalidSettings(
+                "No pages selected for printing".to_string(),
+            ));
+        }
 
-        // Simulate PDF generation (in real implementation, this would render to PDF)
+        job.settings.destination = PrintDestination::SaveAsPdf { path: path.clone() };
         job.start();
-        job.complete();
+
+        let pdf = build_pdf(&job.settings, &job.title, page_count);
+        let result = std::fs::write(&path, pdf)
+            .map_err(|e| PrintError::PdfFailed(format!("{}: {}", path.display(), e)));
+
+        match &result {
+            Ok(()) => {
+                for _ in 0..page_count {
+                    job.page_printed();
+                }
+                job.complete();
+            }
+            Err(e) => job.fail(e.to_string()),
+        }
 
         self.jobs.push(job);
-        Ok(id)
+        result.map(|()| id)
     }
 
     /// Render the print manager UI (preview and job list)
@@ -1254,6 +1311,91 @@ pub struct PrintManagerResponse {
     pub jobs_updated: bool,
 }
 
+/// Build a minimal PDF document with `page_count` blank pages
+///
+/// Each page's media box comes from the paper size and orientation, and its
+/// content stream outlines the printable area inside the margins at the
+/// configured scale. `scale` is a public field, so it is clamped here the
+/// same way [`PrintSettings::with_scale`] clamps it.
+fn build_pdf(settings: &PrintSettings, title: &str, page_count: u32) -> Vec<u8> {
+    let (page_w, page_h) = settings.page_dimensions_points();
+    let (_, bottom, left, _) = settings.margins.to_points();
+    let (area_w, area_h) = settings.printable_area();
+    let scale = settings.scale.clamp(1, 200) as f32 / 100.0;
+    let content = format!(
+        "q {scale:.2} 0 0 {scale:.2} {left:.2} {bottom:.2} cm 0 0 {:.2} {:.2} re S Q\n",
+        area_w / scale,
+        area_h / scale,
+    );
+
+    // Object numbers: 1 catalog, 2 page tree, 3 info, then a page and its
+    // content stream for each page
+    let page_obj = |i: u32| 4 + i * 2;
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..page_count)
+                .map(|i| format!("{} 0 R", page_obj(i)))
+                .collect::<Vec<_>>()
+                .join(" "),
+            page_count
+        ),
+        format!(
+            "<< /Title ({}) /Producer (CortenBrowser) >>",
+            escape_pdf_string(title)
+        ),
+    ];
+    for i in 0..page_count {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] /Contents {} 0 R >>",
+            page_w,
+            page_h,
+            page_obj(i) + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+    }
+
+    let xref_offset = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info 3 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+/// Escape a string for use inside a PDF literal string
+fn escape_pdf_string(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .flat_map(|c| match c {
+            '(' | ')' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
 /// Errors that can occur during printing
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum PrintError {
@@ -1272,6 +1414,9 @@ pub enum PrintError {
     /// Invalid settings
     #[error("Invalid print settings: {0}")]
     InvalidSettings(String),
+    /// Page range input could not be parsed or names nonexistent pages
+    #[error("Invalid page range: {0}")]
+    InvalidPageRange(String),
     /// Platform not supported
     #[error("Printing not supported on this platform")]
     UnsupportedPlatform,
@@ -1544,19 +1689,51 @@ mod tests {
 
     #[test]
     fn test_page_range_parse() {
-        let range = PageRange::parse("1-5").unwrap();
-        assert_eq!(range.page_count(10), 5);
+        assert_eq!(PageRange::parse("1-5", 10).unwrap(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(PageRange::parse("1,3,5", 10).unwrap(), vec![1, 3, 5]);
+        assert_eq!(
+            PageRange::parse("1-3,5,8-10", 10).unwrap(),
+            vec![1, 2, 3, 5, 8, 9, 10]
+        );
+        assert_eq!(PageRange::parse("all", 3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(PageRange::parse("", 2).unwrap(), vec![1, 2]);
 
-        let range = PageRange::parse("1,3,5").unwrap();
-        assert_eq!(range.page_count(10), 3);
+        // Overlaps are merged and the result is sorted
+        assert_eq!(PageRange::parse("4,1-2,2", 5).unwrap(), vec![1, 2, 4]);
 
-        let range = PageRange::parse("1-3,5,7-9").unwrap();
-        assert_eq!(range.page_count(10), 7);
+        assert!(PageRange::parse("invalid", 10).is_err());
+    }
 
-        let range = PageRange::parse("all").unwrap();
-        assert!(matches!(range, PageRange::All));
+    #[test]
+    fn test_page_range_parse_whitespace() {
+        assert_eq!(
+            PageRange::parse(" 1 - 3 ,  5 ,8- 10 ", 10).unwrap(),
+            vec![1, 2, 3, 5, 8, 9, 10]
+        );
+    }
 
-        assert!(PageRange::parse("invalid").is_err());
+    #[test]
+    fn test_page_range_parse_rejects_invalid_pages() {
+        for input in ["5-3", "0", "0-2", "11", "3-12", "1,,2", "-3"] {
+            assert!(
+                matches!(
+                    PageRange::parse(input, 10),
+                    Err(PrintError::InvalidPageRange(_))
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_print_settings_page_range_input() {
+        let settings = PrintSettings::new()
+            .with_page_range_input("2-3", 5)
+            .unwrap();
+        assert_eq!(settings.page_range, PageRange::Pages(vec![2, 3]));
+        assert_eq!(settings.page_range.page_count(5), 2);
+
+        assert!(PrintSettings::new().with_page_range_input("6", 5).is_err());
     }
 
     // PrintSettings tests
@@ -1602,6 +1779,51 @@ mod tests {
         assert!(w > h); // Landscape should be wider
     }
 
+    #[test]
+    fn test_paper_size_dimensions_pt() {
+        let (w, h) = PaperSize::Letter.dimensions_pt();
+        assert!((w - 612.0).abs() < 0.1);
+        assert!((h - 792.0).abs() < 0.1);
+
+        let (w, h) = PaperSize::Legal.dimensions_pt();
+        assert!((w - 612.0).abs() < 0.1);
+        assert!((h - 1008.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_print_settings_printable_area_orientation() {
+        let portrait = PrintSettings::new().with_margins(PrintMargins::none());
+        let (w, h) = portrait.printable_area();
+        assert!((w - 595.3).abs() < 0.1);
+        assert!((h - 841.9).abs() < 0.1);
+
+        let landscape = portrait.with_orientation(Orientation::Landscape);
+        let (w, h) = landscape.printable_area();
+        assert!((w - 841.9).abs() < 0.1);
+        assert!((h - 595.3).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_print_settings_printable_area_margins() {
+        // 1 inch margins remove 72pt from each side
+        let settings = PrintSettings::new()
+            .with_paper_size(PaperSize::Letter)
+            .with_margins(PrintMargins::default());
+        let (w, h) = settings.printable_area();
+        assert!((w - 468.0).abs() < 0.1);
+        assert!((h - 648.0).abs() < 0.1);
+
+        // Uneven margins apply to their own edges
+        let settings = settings.with_margins(PrintMargins::custom(0.0, 25.4, 12.7, 0.0));
+        let (w, h) = settings.printable_area();
+        assert!((w - 576.0).abs() < 0.1);
+        assert!((h - 720.0).abs() < 0.1);
+
+        // Margins larger than the page leave no printable area
+        let settings = settings.with_margins(PrintMargins::all(200.0));
+        assert_eq!(settings.printable_area(), (0.0, 0.0));
+    }
+
     // PrintJobStatus tests
     #[test]
     fn test_job_status_terminal() {
@@ -1826,6 +2048,71 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    // PDF export tests
+    #[test]
+    fn test_print_manager_print_to_pdf() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.pdf");
+        let mut manager = PrintManager::new();
+
+        let settings = PrintSettings::new()
+            .with_orientation(Orientation::Landscape)
+            .with_page_range(PageRange::range(2, 3));
+        let job = PrintJob::new("Report (draft)".to_string(), 5, settings);
+        let id = manager.print_to_pdf(job, path.clone()).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(!bytes.is_empty());
+        let pdf = String::from_utf8(bytes).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.trim_end().ends_with("%%EOF"));
+        assert!(pdf.contains("/Count 2"));
+        // A4 landscape is 842 x 595 points
+        assert!(pdf.contains("/MediaBox [0 0 841.89 595.28]"));
+        assert!(pdf.contains("/Title (Report \\(draft\\))"));
+
+        let job = manager.get_job(id).unwrap();
+        assert_eq!(job.status, PrintJobStatus::Completed);
+        assert_eq!(
+            job.settings.destination,
+            PrintDestination::SaveAsPdf { path }
+        );
+    }
+
+    #[test]
+    fn test_print_manager_print_to_pdf_invalid_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("page.pdf");
+        let mut manager = PrintManager::new();
+
+        let job = PrintJob::new("Page".to_string(), 1, PrintSettings::default());
+        let id = job.id;
+        let result = manager.print_to_pdf(job, path.clone());
+
+        assert!(matches!(result, Err(PrintError::PdfFailed(_))));
+        assert!(!path.exists());
+        assert_eq!(manager.get_job(id).unwrap().status, PrintJobStatus::Failed);
+    }
+
+    #[test]
+    fn test_print_manager_print_to_pdf_zero_scale() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.pdf");
+        let mut manager = PrintManager::new();
+
+        // Setting the field directly bypasses with_scale's clamp
+        let settings = PrintSettings {
+            scale: 0,
+            ..PrintSettings::default()
+        };
+        let job = PrintJob::new("Page".to_string(), 1, settings);
+        manager.print_to_pdf(job, path.clone()).unwrap();
+
+        let pdf = String::from_utf8(std::fs::read(&path).unwrap()).unwrap();
+        assert!(pdf.contains("q 0.01 0 0 0.01 "));
+        assert!(!pdf.contains("inf") && !pdf.contains("NaN"));
+    }
+
     // Serialization tests
     #[test]
     fn test_paper_size_serialization() {
@@ -11,7 +11,8 @@
 use egui::{Context, ScrollArea, Ui};
 use serde::{Deserialize, Serialize};
 use settings_manager::SettingValue;
-use std::collections::HashMap;
+use shared_types::ComponentError;
+use std::collections::{BTreeMap, HashMap};
 
 /// Settings tab selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,6 +63,317 @@ impl SettingsTab {
     }
 }
 
+/// Type of a setting's value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    String,
+    Integer,
+    Float,
+    Boolean,
+}
+
+impl SettingKind {
+    /// Get the kind of a value
+    pub fn of(value: &SettingValue) -> Self {
+        match value {
+            SettingValue::String(_) => Self::String,
+            SettingValue::Integer(_) => Self::Integer,
+            SettingValue::Float(_) => Self::Float,
+            SettingValue::Boolean(_) => Self::Boolean,
+        }
+    }
+
+    /// Get the kind's display name
+    pub fn name(&self) -> &str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Float => "number",
+            Self::Boolean => "boolean",
+        }
+    }
+
+    /// Convert a JSON value to a setting value of this kind
+    fn parse_json(&self, value: &serde_json::Value) -> Option<SettingValue> {
+        match (self, value) {
+            (Self::String, serde_json::Value::String(s)) => Some(SettingValue::String(s.clone())),
+            (Self::Integer, serde_json::Value::Number(n)) => n.as_i64().map(SettingValue::Integer),
+            (Self::Float, serde_json::Value::Number(n)) => n.as_f64().map(SettingValue::Float),
+            (Self::Boolean, serde_json::Value::Bool(b)) => Some(SettingValue::Boolean(*b)),
+            _ => None,
+        }
+    }
+}
+
+/// A setting shown in the settings panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingDefinition {
+    /// Settings key
+    pub key: &'static str,
+    /// Tab the setting appears on
+    pub tab: SettingsTab,
+    /// Type of the setting's value
+    pub kind: SettingKind,
+    /// Label shown next to the control
+    pub label: &'static str,
+    /// Help text shown below the control (empty if none)
+    pub description: &'static str,
+}
+
+impl SettingDefinition {
+    /// Check whether the key, label or description contains `query`,
+    /// ignoring case
+    fn matches(&self, query: &str) -> bool {
+        [self.key, self.label, self.description]
+            .iter()
+            .any(|text| text.to_lowercase().contains(query))
+    }
+}
+
+/// A setting matching a [`SettingsUi::filter`] query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingMatch {
+    /// Tab the setting appears on
+    pub tab: SettingsTab,
+    /// Settings key
+    pub key: &'static str,
+    /// Label shown next to the control
+    pub label: &'static str,
+}
+
+/// Every setting shown in the settings panel, in display order
+pub const SETTINGS: &[SettingDefinition] = &[
+    SettingDefinition {
+        key: "general.home_page",
+        tab: SettingsTab::General,
+        kind: SettingKind::String,
+        label: "Home Page",
+        description: "The page to load when opening a new tab",
+    },
+    SettingDefinition {
+        key: "general.startup_behavior",
+        tab: SettingsTab::General,
+        kind: SettingKind::String,
+        label: "On Startup",
+        description: "",
+    },
+    SettingDefinition {
+        key: "general.default_search_engine",
+        tab: SettingsTab::General,
+        kind: SettingKind::String,
+        label: "Default Search Engine",
+        description: "",
+    },
+    SettingDefinition {
+        key: "ui.theme",
+        tab: SettingsTab::Appearance,
+        kind: SettingKind::String,
+        label: "Theme",
+        description: "Select the color theme for the browser",
+    },
+    SettingDefinition {
+        key: "ui.font_size",
+        tab: SettingsTab::Appearance,
+        kind: SettingKind::Integer,
+        label: "Font Size",
+        description: "Default font size for browser UI",
+    },
+    SettingDefinition {
+        key: "appearance.toolbar_customization",
+        tab: SettingsTab::Appearance,
+        kind: SettingKind::String,
+        label: "Toolbar Style",
+        description: "Customize the toolbar appearance",
+    },
+    SettingDefinition {
+        key: "ui.show_bookmarks_bar",
+        tab: SettingsTab::Appearance,
+        kind: SettingKind::Boolean,
+        label: "Show bookmarks bar",
+        description: "",
+    },
+    SettingDefinition {
+        key: "ui.animations_enabled",
+        tab: SettingsTab::Appearance,
+        kind: SettingKind::Boolean,
+        label: "Enable animations",
+        description: "",
+    },
+    SettingDefinition {
+        key: "privacy.do_not_track",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Send \"Do Not Track\" request",
+        description: "Request that websites not track your browsing activity",
+    },
+    SettingDefinition {
+        key: "privacy.tracking_protection",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Enable tracking protection",
+        description: "Block known trackers and third-party cookies",
+    },
+    SettingDefinition {
+        key: "privacy.cookie_policy",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::String,
+        label: "Cookie Policy",
+        description: "",
+    },
+    SettingDefinition {
+        key: "privacy.clear_on_exit",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Clear browsing data on exit",
+        description: "Clear cookies and site data when closing the browser",
+    },
+    SettingDefinition {
+        key: "privacy.ad_blocker_enabled",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Enable ad blocker",
+        description: "Block ads, trackers, and malicious content",
+    },
+    SettingDefinition {
+        key: "privacy.block_malware_domains",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Block malware domains",
+        description: "Protect against known malicious websites",
+    },
+    SettingDefinition {
+        key: "privacy.block_tracking_scripts",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Block tracking scripts",
+        description: "Prevent websites from tracking your activity",
+    },
+    SettingDefinition {
+        key: "privacy.hide_blocked_elements",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Hide blocked elements",
+        description: "Remove blocked ad elements from pages",
+    },
+    SettingDefinition {
+        key: "privacy.aggressive_blocking",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::Boolean,
+        label: "Aggressive blocking mode",
+        description: "Block more aggressively (may break some websites)",
+    },
+    SettingDefinition {
+        key: "privacy.filter_lists",
+        tab: SettingsTab::Privacy,
+        kind: SettingKind::String,
+        label: "Filter Lists",
+        description: "Choose which filter lists to use for blocking",
+    },
+    SettingDefinition {
+        key: "security.safe_browsing",
+        tab: SettingsTab::Security,
+        kind: SettingKind::Boolean,
+        label: "Enable safe browsing",
+        description: "Protect against dangerous sites and downloads",
+    },
+    SettingDefinition {
+        key: "security.enable_sandbox",
+        tab: SettingsTab::Security,
+        kind: SettingKind::Boolean,
+        label: "Enable sandboxing",
+        description: "Isolate web content in a security sandbox",
+    },
+    SettingDefinition {
+        key: "security.allow_javascript",
+        tab: SettingsTab::Security,
+        kind: SettingKind::Boolean,
+        label: "Allow JavaScript",
+        description: "Enable JavaScript on all websites (some sites may not work if disabled)",
+    },
+    SettingDefinition {
+        key: "security.block_third_party_cookies",
+        tab: SettingsTab::Security,
+        kind: SettingKind::Boolean,
+        label: "Block third-party cookies",
+        description: "",
+    },
+    SettingDefinition {
+        key: "security.password_manager_enabled",
+        tab: SettingsTab::Security,
+        kind: SettingKind::Boolean,
+        label: "Enable password manager",
+        description: "Offer to save and auto-fill passwords",
+    },
+    SettingDefinition {
+        key: "downloads.default_location",
+        tab: SettingsTab::Downloads,
+        kind: SettingKind::String,
+        label: "Download Location",
+        description: "",
+    },
+    SettingDefinition {
+        key: "downloads.ask_where_to_save",
+        tab: SettingsTab::Downloads,
+        kind: SettingKind::Boolean,
+        label: "Ask where to save each file before downloading",
+        description: "",
+    },
+    SettingDefinition {
+        key: "advanced.hardware_acceleration",
+        tab: SettingsTab::Advanced,
+        kind: SettingKind::Boolean,
+        label: "Use hardware acceleration when available",
+        description: "Improves performance but may cause issues on some systems",
+    },
+    SettingDefinition {
+        key: "network.enable_http2",
+        tab: SettingsTab::Advanced,
+        kind: SettingKind::Boolean,
+        label: "Enable HTTP/2",
+        description: "",
+    },
+    SettingDefinition {
+        key: "network.enable_http3",
+        tab: SettingsTab::Advanced,
+        kind: SettingKind::Boolean,
+        label: "Enable HTTP/3 (QUIC)",
+        description: "",
+    },
+    SettingDefinition {
+        key: "advanced.proxy_enabled",
+        tab: SettingsTab::Advanced,
+        kind: SettingKind::Boolean,
+        label: "Use proxy server",
+        description: "",
+    },
+    SettingDefinition {
+        key: "advanced.proxy_host",
+        tab: SettingsTab::Advanced,
+        kind: SettingKind::String,
+        label: "Proxy Host",
+        description: "",
+    },
+    SettingDefinition {
+        key: "advanced.proxy_port",
+        tab: SettingsTab::Advanced,
+        kind: SettingKind::Integer,
+        label: "Proxy Port",
+        description: "",
+    },
+];
+
+/// Look up a setting in [`SETTINGS`]
+///
+/// # Panics
+///
+/// Panics if `key` isn't listed; the panel only renders listed settings.
+fn definition(key: &str) -> &'static SettingDefinition {
+    SETTINGS
+        .iter()
+        .find(|setting| setting.key == key)
+        .unwrap_or_else(|| panic!("setting '{}' is missing from SETTINGS", key))
+}
+
 /// Settings UI state and configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SettingsUi {
@@ -76,6 +388,14 @@ pub struct SettingsUi {
     /// Whether settings have been modified and not saved
     #[serde(skip)]
     pub has_unsaved_changes: bool,
+
+    /// Entries skipped by the last import
+    #[serde(skip)]
+    import_warnings: Vec<String>,
+
+    /// Text in the settings search box
+    #[serde(skip)]
+    pub search_query: String,
 }
 
 impl Default for SettingsUi {
@@ -84,6 +404,8 @@ impl Default for SettingsUi {
             current_tab: SettingsTab::General,
             settings_cache: HashMap::new(),
             has_unsaved_changes: false,
+            import_warnings: Vec::new(),
+            search_query: String::new(),
         }
     }
 }
@@ -145,6 +467,103 @@ impl SettingsUi {
         }
     }
 
+    /// Export all settings as a JSON object of key/value pairs
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::InvalidState` if a value can't be represented
+    /// in JSON (a non-finite float)
+    pub fn export_json(&self) -> Result<String, ComponentError> {
+        // Sorted keys keep exports stable and diffable
+        let mut map = BTreeMap::new();
+        for (key, value) in &self.settings_cache {
+            let json = match value {
+                SettingValue::String(s) => serde_json::Value::from(s.as_str()),
+                SettingValue::Integer(i) => serde_json::Value::from(*i),
+                SettingValue::Boolean(b) => serde_json::Value::from(*b),
+                SettingValue::Float(f) => serde_json::Number::from_f64(*f)
+                    .map(serde_json::Value::Number)
+                    .ok_or_else(|| {
+                        ComponentError::InvalidState(format!(
+                            "Setting '{}' has a non-finite value",
+                            key
+                        ))
+                    })?,
+            };
+            map.insert(key.as_str(), json);
+        }
+
+        serde_json::to_string_pretty(&map)
+            .map_err(|e| ComponentError::InvalidState(format!("Failed to export settings: {}", e)))
+    }
+
+    /// Import settings exported by [`Self::export_json`]
+    ///
+    /// Keys that are neither shown in the settings panel nor already loaded
+    /// are skipped and reported by [`Self::import_warnings`]. Imported values
+    /// are unsaved until [`Self::mark_saved`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComponentError::InvalidState` if the JSON isn't an object or
+    /// a value has the wrong type for its key; nothing is imported then
+    pub fn import_json(&mut self, json: &str) -> Result<(), ComponentError> {
+        let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)
+            .map_err(|e| ComponentError::InvalidState(format!("Invalid settings JSON: {}", e)))?;
+
+        let mut imported = Vec::new();
+        let mut warnings = Vec::new();
+        for (key, json_value) in map {
+            let kind = SETTINGS
+                .iter()
+                .find(|setting| setting.key == key)
+                .map(|setting| setting.kind)
+                .or_else(|| self.settings_cache.get(&key).map(SettingKind::of));
+            let Some(kind) = kind else {
+                warnings.push(format!("Skipped unknown setting '{}'", key));
+                continue;
+            };
+
+            let value = kind.parse_json(&json_value).ok_or_else(|| {
+                ComponentError::InvalidState(format!(
+                    "Setting '{}' expects a {} value, got {}",
+                    key,
+                    kind.name(),
+                    json_value
+                ))
+            })?;
+            imported.push((key, value));
+        }
+
+        for (key, value) in imported {
+            self.update_setting(key, value);
+        }
+        self.import_warnings = warnings;
+        Ok(())
+    }
+
+    /// Get the warnings from the last [`Self::import_json`]
+    pub fn import_warnings(&self) -> &[String] {
+        &self.import_warnings
+    }
+
+    /// Find settings whose key, label or description contains `query`
+    ///
+    /// Matching ignores case. Results are in display order, so they group by
+    /// tab; an empty query matches every setting.
+    pub fn filter(&self, query: &str) -> Vec<SettingMatch> {
+        let query = query.trim().to_lowercase();
+        SETTINGS
+            .iter()
+            .filter(|setting| setting.matches(&query))
+            .map(|setting| SettingMatch {
+                tab: setting.tab,
+                key: setting.key,
+                label: setting.label,
+            })
+            .collect()
+    }
+
     /// Render the settings panel
     pub fn show(&mut self, ctx: &Context) {
         egui::SidePanel::left("settings_tabs")
@@ -162,14 +581,34 @@ impl SettingsUi {
     /// Render the tab list on the left side
     fn render_tab_list(&mut self, ui: &mut Ui) {
         ui.heading("Settings");
+        ui.add(egui::TextEdit::singleline(&mut self.search_query).hint_text("🔍 Search settings"));
         ui.separator();
 
-        for tab in SettingsTab::all() {
-            let is_selected = self.current_tab == tab;
-            let label = format!("{} {}", tab.icon(), tab.name());
+        if self.search_query.trim().is_empty() {
+            for tab in SettingsTab::all() {
+                let is_selected = self.current_tab == tab;
+                let label = format!("{} {}", tab.icon(), tab.name());
 
-            if ui.selectable_label(is_selected, label).clicked() {
-                self.current_tab = tab;
+                if ui.selectable_label(is_selected, label).clicked() {
+                    self.current_tab = tab;
+                }
+            }
+        } else {
+            // Flat list of matches; picking one jumps to its tab
+            let matches = self.filter(&self.search_query);
+            if matches.is_empty() {
+                ui.label("No matching settings");
+            }
+            for setting in matches {
+                let label = format!("{} {}", setting.tab.icon(), setting.label);
+                if ui
+                    .selectable_label(false, label)
+                    .on_hover_text(setting.tab.name())
+                    .clicked()
+                {
+                    self.current_tab = setting.tab;
+                    self.search_query.clear();
+                }
             }
         }
 
@@ -195,6 +634,31 @@ impl SettingsUi {
         });
     }
 
+    /// Render a setting's label as a heading
+    fn render_label(ui: &mut Ui, key: &str) {
+        ui.label(egui::RichText::new(definition(key).label).strong());
+    }
+
+    /// Render a setting's description, if it has one
+    fn render_description(ui: &mut Ui, key: &str) {
+        let description = definition(key).description;
+        if !description.is_empty() {
+            ui.label(description);
+        }
+    }
+
+    /// Render a boolean setting as a checkbox followed by its description
+    ///
+    /// Returns the setting's current value.
+    fn render_checkbox(&mut self, ui: &mut Ui, key: &str, default: bool) -> bool {
+        let mut value = self.get_bool(key, default);
+        if ui.checkbox(&mut value, definition(key).label).changed() {
+            self.update_setting(key.to_string(), SettingValue::Boolean(value));
+        }
+        Self::render_description(ui, key);
+        value
+    }
+
     /// Render the currently selected tab content
     fn render_current_tab(&mut self, ui: &mut Ui) {
         ScrollArea::vertical().show(ui, |ui| {
@@ -220,16 +684,16 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Home page
-        ui.label(egui::RichText::new("Home Page").strong());
+        Self::render_label(ui, "general.home_page");
         let mut home_page = self.get_string("general.home_page", "about:blank");
         if ui.text_edit_singleline(&mut home_page).changed() {
             self.update_setting("general.home_page".to_string(), SettingValue::String(home_page));
         }
-        ui.label("The page to load when opening a new tab");
+        Self::render_description(ui, "general.home_page");
         ui.add_space(10.0);
 
         // Startup behavior
-        ui.label(egui::RichText::new("On Startup").strong());
+        Self::render_label(ui, "general.startup_behavior");
         let mut startup = self.get_string("general.startup_behavior", "new_tab");
         egui::ComboBox::from_id_salt("startup_behavior")
             .selected_text(match startup.as_str() {
@@ -252,7 +716,7 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Default search engine
-        ui.label(egui::RichText::new("Default Search Engine").strong());
+        Self::render_label(ui, "general.default_search_engine");
         let mut search_engine = self.get_string("general.default_search_engine", "DuckDuckGo");
         egui::ComboBox::from_id_salt("search_engine")
             .selected_text(&search_engine)
@@ -272,7 +736,7 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Theme
-        ui.label(egui::RichText::new("Theme").strong());
+        Self::render_label(ui, "ui.theme");
         let mut theme = self.get_string("ui.theme", "light");
         egui::ComboBox::from_id_salt("theme")
             .selected_text(match theme.as_str() {
@@ -292,20 +756,20 @@ impl SettingsUi {
                     self.update_setting("ui.theme".to_string(), SettingValue::String(theme.clone()));
                 }
             });
-        ui.label("Select the color theme for the browser");
+        Self::render_description(ui, "ui.theme");
         ui.add_space(10.0);
 
         // Font size
-        ui.label(egui::RichText::new("Font Size").strong());
+        Self::render_label(ui, "ui.font_size");
         let mut font_size = self.get_int("ui.font_size", 14);
         if ui.add(egui::Slider::new(&mut font_size, 10..=24).suffix(" px")).changed() {
             self.update_setting("ui.font_size".to_string(), SettingValue::Integer(font_size));
         }
-        ui.label("Default font size for browser UI");
+        Self::render_description(ui, "ui.font_size");
         ui.add_space(10.0);
 
         // Toolbar customization
-        ui.label(egui::RichText::new("Toolbar Style").strong());
+        Self::render_label(ui, "appearance.toolbar_customization");
         let mut toolbar = self.get_string("appearance.toolbar_customization", "default");
         egui::ComboBox::from_id_salt("toolbar")
             .selected_text(match toolbar.as_str() {
@@ -325,20 +789,14 @@ impl SettingsUi {
                     self.update_setting("appearance.toolbar_customization".to_string(), SettingValue::String(toolbar.clone()));
                 }
             });
-        ui.label("Customize the toolbar appearance");
+        Self::render_description(ui, "appearance.toolbar_customization");
         ui.add_space(10.0);
 
         // Show bookmarks bar
-        let mut show_bookmarks = self.get_bool("ui.show_bookmarks_bar", true);
-        if ui.checkbox(&mut show_bookmarks, "Show bookmarks bar").changed() {
-            self.update_setting("ui.show_bookmarks_bar".to_string(), SettingValue::Boolean(show_bookmarks));
-        }
+        self.render_checkbox(ui, "ui.show_bookmarks_bar", true);
 
         // Animations
-        let mut animations = self.get_bool("ui.animations_enabled", true);
-        if ui.checkbox(&mut animations, "Enable animations").changed() {
-            self.update_setting("ui.animations_enabled".to_string(), SettingValue::Boolean(animations));
-        }
+        self.render_checkbox(ui, "ui.animations_enabled", true);
     }
 
     /// Render Privacy settings tab
@@ -348,23 +806,15 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Do Not Track
-        let mut dnt = self.get_bool("privacy.do_not_track", true);
-        if ui.checkbox(&mut dnt, "Send \"Do Not Track\" request").changed() {
-            self.update_setting("privacy.do_not_track".to_string(), SettingValue::Boolean(dnt));
-        }
-        ui.label("Request that websites not track your browsing activity");
+        self.render_checkbox(ui, "privacy.do_not_track", true);
         ui.add_space(10.0);
 
         // Tracking protection
-        let mut tracking = self.get_bool("privacy.tracking_protection", true);
-        if ui.checkbox(&mut tracking, "Enable tracking protection").changed() {
-            self.update_setting("privacy.tracking_protection".to_string(), SettingValue::Boolean(tracking));
-        }
-        ui.label("Block known trackers and third-party cookies");
+        self.render_checkbox(ui, "privacy.tracking_protection", true);
         ui.add_space(10.0);
 
         // Cookie policy
-        ui.label(egui::RichText::new("Cookie Policy").strong());
+        Self::render_label(ui, "privacy.cookie_policy");
         let mut cookies = self.get_string("privacy.cookie_policy", "allow_all");
         egui::ComboBox::from_id_salt("cookies")
             .selected_text(match cookies.as_str() {
@@ -387,11 +837,7 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Clear on exit
-        let mut clear_on_exit = self.get_bool("privacy.clear_on_exit", false);
-        if ui.checkbox(&mut clear_on_exit, "Clear browsing data on exit").changed() {
-            self.update_setting("privacy.clear_on_exit".to_string(), SettingValue::Boolean(clear_on_exit));
-        }
-        ui.label("Clear cookies and site data when closing the browser");
+        self.render_checkbox(ui, "privacy.clear_on_exit", false);
         ui.add_space(20.0);
 
         // Clear browsing data button
@@ -409,48 +855,28 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Enable ad blocker
-        let mut ad_blocker_enabled = self.get_bool("privacy.ad_blocker_enabled", true);
-        if ui.checkbox(&mut ad_blocker_enabled, "Enable ad blocker").changed() {
-            self.update_setting("privacy.ad_blocker_enabled".to_string(), SettingValue::Boolean(ad_blocker_enabled));
-        }
-        ui.label("Block ads, trackers, and malicious content");
+        let ad_blocker_enabled = self.render_checkbox(ui, "privacy.ad_blocker_enabled", true);
         ui.add_space(10.0);
 
         if ad_blocker_enabled {
             // Block malware domains
-            let mut block_malware = self.get_bool("privacy.block_malware_domains", true);
-            if ui.checkbox(&mut block_malware, "Block malware domains").changed() {
-                self.update_setting("privacy.block_malware_domains".to_string(), SettingValue::Boolean(block_malware));
-            }
-            ui.label("Protect against known malicious websites");
+            self.render_checkbox(ui, "privacy.block_malware_domains", true);
             ui.add_space(10.0);
 
             // Block tracking scripts
-            let mut block_tracking = self.get_bool("privacy.block_tracking_scripts", true);
-            if ui.checkbox(&mut block_tracking, "Block tracking scripts").changed() {
-                self.update_setting("privacy.block_tracking_scripts".to_string(), SettingValue::Boolean(block_tracking));
-            }
-            ui.label("Prevent websites from tracking your activity");
+            self.render_checkbox(ui, "privacy.block_tracking_scripts", true);
             ui.add_space(10.0);
 
             // Hide elements
-            let mut hide_elements = self.get_bool("privacy.hide_blocked_elements", true);
-            if ui.checkbox(&mut hide_elements, "Hide blocked elements").changed() {
-                self.update_setting("privacy.hide_blocked_elements".to_string(), SettingValue::Boolean(hide_elements));
-            }
-            ui.label("Remove blocked ad elements from pages");
+            self.render_checkbox(ui, "privacy.hide_blocked_elements", true);
             ui.add_space(10.0);
 
             // Aggressive blocking
-            let mut aggressive = self.get_bool("privacy.aggressive_blocking", false);
-            if ui.checkbox(&mut aggressive, "Aggressive blocking mode").changed() {
-                self.update_setting("privacy.aggressive_blocking".to_string(), SettingValue::Boolean(aggressive));
-            }
-            ui.label("Block more aggressively (may break some websites)");
+            self.render_checkbox(ui, "privacy.aggressive_blocking", false);
             ui.add_space(10.0);
 
             // Filter list selection
-            ui.label(egui::RichText::new("Filter Lists").strong());
+            Self::render_label(ui, "privacy.filter_lists");
             let mut filter_lists = self.get_string("privacy.filter_lists", "easylist");
             ui.horizontal(|ui| {
                 let mut use_easylist = filter_lists.contains("easylist");
@@ -483,7 +909,7 @@ impl SettingsUi {
                     self.update_setting("privacy.filter_lists".to_string(), SettingValue::String(filter_lists.clone()));
                 }
             });
-            ui.label("Choose which filter lists to use for blocking");
+            Self::render_description(ui, "privacy.filter_lists");
             ui.add_space(20.0);
 
             // Whitelist management
@@ -502,42 +928,23 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Safe browsing
-        let mut safe_browsing = self.get_bool("security.safe_browsing", true);
-        if ui.checkbox(&mut safe_browsing, "Enable safe browsing").changed() {
-            self.update_setting("security.safe_browsing".to_string(), SettingValue::Boolean(safe_browsing));
-        }
-        ui.label("Protect against dangerous sites and downloads");
+        self.render_checkbox(ui, "security.safe_browsing", true);
         ui.add_space(10.0);
 
         // Sandbox
-        let mut sandbox = self.get_bool("security.enable_sandbox", true);
-        if ui.checkbox(&mut sandbox, "Enable sandboxing").changed() {
-            self.update_setting("security.enable_sandbox".to_string(), SettingValue::Boolean(sandbox));
-        }
-        ui.label("Isolate web content in a security sandbox");
+        self.render_checkbox(ui, "security.enable_sandbox", true);
         ui.add_space(10.0);
 
         // JavaScript
-        let mut javascript = self.get_bool("security.allow_javascript", true);
-        if ui.checkbox(&mut javascript, "Allow JavaScript").changed() {
-            self.update_setting("security.allow_javascript".to_string(), SettingValue::Boolean(javascript));
-        }
-        ui.label("Enable JavaScript on all websites (some sites may not work if disabled)");
+        self.render_checkbox(ui, "security.allow_javascript", true);
         ui.add_space(10.0);
 
         // Third-party cookies
-        let mut block_cookies = self.get_bool("security.block_third_party_cookies", true);
-        if ui.checkbox(&mut block_cookies, "Block third-party cookies").changed() {
-            self.update_setting("security.block_third_party_cookies".to_string(), SettingValue::Boolean(block_cookies));
-        }
+        self.render_checkbox(ui, "security.block_third_party_cookies", true);
         ui.add_space(10.0);
 
         // Password manager
-        let mut password_manager = self.get_bool("security.password_manager_enabled", true);
-        if ui.checkbox(&mut password_manager, "Enable password manager").changed() {
-            self.update_setting("security.password_manager_enabled".to_string(), SettingValue::Boolean(password_manager));
-        }
-        ui.label("Offer to save and auto-fill passwords");
+        let password_manager = self.render_checkbox(ui, "security.password_manager_enabled", true);
         ui.add_space(20.0);
 
         ui.separator();
@@ -555,7 +962,7 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Default download location
-        ui.label(egui::RichText::new("Download Location").strong());
+        Self::render_label(ui, "downloads.default_location");
         let mut download_path = self.get_string("downloads.default_location", "~/Downloads");
         ui.horizontal(|ui| {
             ui.text_edit_singleline(&mut download_path);
@@ -570,10 +977,7 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Ask where to save
-        let mut ask_location = self.get_bool("downloads.ask_where_to_save", false);
-        if ui.checkbox(&mut ask_location, "Ask where to save each file before downloading").changed() {
-            self.update_setting("downloads.ask_where_to_save".to_string(), SettingValue::Boolean(ask_location));
-        }
+        self.render_checkbox(ui, "downloads.ask_where_to_save", false);
         ui.add_space(20.0);
 
         ui.separator();
@@ -589,45 +993,32 @@ impl SettingsUi {
         ui.add_space(10.0);
 
         // Hardware acceleration
-        let mut hw_accel = self.get_bool("advanced.hardware_acceleration", true);
-        if ui.checkbox(&mut hw_accel, "Use hardware acceleration when available").changed() {
-            self.update_setting("advanced.hardware_acceleration".to_string(), SettingValue::Boolean(hw_accel));
-        }
-        ui.label("Improves performance but may cause issues on some systems");
+        self.render_checkbox(ui, "advanced.hardware_acceleration", true);
         ui.add_space(10.0);
 
         // HTTP/2
-        let mut http2 = self.get_bool("network.enable_http2", true);
-        if ui.checkbox(&mut http2, "Enable HTTP/2").changed() {
-            self.update_setting("network.enable_http2".to_string(), SettingValue::Boolean(http2));
-        }
+        self.render_checkbox(ui, "network.enable_http2", true);
         ui.add_space(10.0);
 
         // HTTP/3 / QUIC
-        let mut http3 = self.get_bool("network.enable_http3", true);
-        if ui.checkbox(&mut http3, "Enable HTTP/3 (QUIC)").changed() {
-            self.update_setting("network.enable_http3".to_string(), SettingValue::Boolean(http3));
-        }
+        self.render_checkbox(ui, "network.enable_http3", true);
         ui.add_space(20.0);
 
         // Proxy settings
         ui.separator();
         ui.label(egui::RichText::new("Proxy Settings").strong());
 
-        let mut proxy_enabled = self.get_bool("advanced.proxy_enabled", false);
-        if ui.checkbox(&mut proxy_enabled, "Use proxy server").changed() {
-            self.update_setting("advanced.proxy_enabled".to_string(), SettingValue::Boolean(proxy_enabled));
-        }
+        let proxy_enabled = self.render_checkbox(ui, "advanced.proxy_enabled", false);
 
         if proxy_enabled {
             ui.add_space(5.0);
-            ui.label("Proxy Host:");
+            ui.label(format!("{}:", definition("advanced.proxy_host").label));
             let mut proxy_host = self.get_string("advanced.proxy_host", "");
             if ui.text_edit_singleline(&mut proxy_host).changed() {
                 self.update_setting("advanced.proxy_host".to_string(), SettingValue::String(proxy_host));
             }
 
-            ui.label("Proxy Port:");
+            ui.label(format!("{}:", definition("advanced.proxy_port").label));
             let mut proxy_port = self.get_int("advanced.proxy_port", 8080);
             if ui.add(egui::DragValue::new(&mut proxy_port).range(1..=65535)).changed() {
                 self.update_setting("advanced.proxy_port".to_string(), SettingValue::Integer(proxy_port));
@@ -678,4 +1069,106 @@ mod tests {
         assert_eq!(SettingsTab::General.name(), "General");
         assert_eq!(SettingsTab::Privacy.name(), "Privacy");
     }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut source = SettingsUi::new();
+        source.load_settings(HashMap::from([
+            (
+                "ui.theme".to_string(),
+                SettingValue::String("dark".to_string()),
+            ),
+            ("ui.font_size".to_string(), SettingValue::Integer(16)),
+            (
+                "privacy.do_not_track".to_string(),
+                SettingValue::Boolean(false),
+            ),
+            ("sync.interval".to_string(), SettingValue::Float(1.5)),
+        ]));
+        let exported = source.export_json().unwrap();
+
+        // Another machine with different values for the same keys
+        let mut target = SettingsUi::new();
+        target.load_settings(HashMap::from([
+            (
+                "ui.theme".to_string(),
+                SettingValue::String("light".to_string()),
+            ),
+            ("sync.interval".to_string(), SettingValue::Float(30.0)),
+        ]));
+        target.update_setting("ui.font_size".to_string(), SettingValue::Integer(12));
+        target.mark_saved();
+
+        target.import_json(&exported).unwrap();
+
+        assert_eq!(target.get_all_settings(), source.get_all_settings());
+        assert!(target.has_unsaved_changes);
+        assert!(target.import_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_import_skips_unknown_keys_and_rejects_bad_types() {
+        let mut ui = SettingsUi::new();
+
+        ui.import_json(r#"{"ui.theme": "dark", "legacy.toolbar": true}"#)
+            .unwrap();
+        assert_eq!(ui.get_string("ui.theme", "light"), "dark");
+        assert_eq!(ui.get_setting("legacy.toolbar"), None);
+        assert_eq!(ui.import_warnings().len(), 1);
+        assert!(ui.import_warnings()[0].contains("legacy.toolbar"));
+
+        // A type mismatch fails the whole import
+        ui.mark_saved();
+        let result = ui.import_json(r#"{"ui.theme": "light", "ui.font_size": "large"}"#);
+        assert!(matches!(result, Err(ComponentError::InvalidState(_))));
+        assert_eq!(ui.get_string("ui.theme", "light"), "dark");
+        assert!(!ui.has_unsaved_changes);
+
+        assert!(ui.import_json("[1, 2, 3]").is_err());
+    }
+
+    #[test]
+    fn test_filter_matches_across_tabs() {
+        let ui = SettingsUi::new();
+
+        // "cookie" appears in Privacy labels and a Security label
+        let matches = ui.filter("COOKIE");
+        let found: Vec<_> = matches.iter().map(|m| (m.tab, m.key)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (SettingsTab::Privacy, "privacy.tracking_protection"),
+                (SettingsTab::Privacy, "privacy.cookie_policy"),
+                (SettingsTab::Privacy, "privacy.clear_on_exit"),
+                (SettingsTab::Security, "security.block_third_party_cookies"),
+            ]
+        );
+
+        // Keys match too, and labels come back for display
+        let matches = ui.filter("proxy_port");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].tab, SettingsTab::Advanced);
+        assert_eq!(matches[0].label, "Proxy Port");
+
+        let tabs: Vec<_> = ui.filter("http").iter().map(|m| m.tab).collect();
+        assert!(tabs.iter().all(|tab| *tab == SettingsTab::Advanced));
+        assert_eq!(tabs.len(), 2);
+
+        assert!(ui.filter("no such setting").is_empty());
+        assert_eq!(ui.filter("  ").len(), SETTINGS.len());
+    }
+
+    #[test]
+    fn test_every_tab_renders_from_settings_table() {
+        let mut ui = SettingsUi::new();
+        // Show the settings hidden behind toggles too
+        ui.update_setting("advanced.proxy_enabled".to_string(), SettingValue::Boolean(true));
+
+        // Rendering looks up every label, panicking on keys not in SETTINGS
+        let ctx = Context::default();
+        for tab in SettingsTab::all() {
+            ui.current_tab = tab;
+            let _ = ctx.run(Default::default(), |ctx| ui.show(ctx));
+        }
+    }
 }
@@ -0,0 +1,283 @@
+//! Tab Switcher Overlay
+//!
+//! Quick "search tabs" overlay (Ctrl+Shift+A) for jumping between open tabs.
+//! This module handles:
+//! - Fuzzy/subsequence matching of a query against tab titles and URLs
+//! - Ranking results by match quality, then by recency
+//! - Keyboard selection state (up/down, Enter to activate)
+//! - Plain substring filtering of the tab bar while searching
+
+use shared_types::TabId;
+
+/// Score for a query matching at the start of the text
+const SCORE_PREFIX: u32 = 300;
+
+/// Score for a query matching at the start of a word
+const SCORE_WORD_START: u32 = 250;
+
+/// Score for a query matching as a contiguous substring
+const SCORE_SUBSTRING: u32 = 200;
+
+/// Base score for a subsequence match (reduced by the gaps between characters)
+const SCORE_SUBSEQUENCE: u32 = 100;
+
+/// A ranked tab switcher match
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabSwitchResult {
+    /// The matching tab
+    pub tab_id: TabId,
+    /// Tab title
+    pub title: String,
+    /// Tab URL (empty if unknown)
+    pub url: String,
+    /// Match quality (higher is better)
+    pub score: u32,
+}
+
+/// State for the tab switcher overlay
+#[derive(Debug, Clone, Default)]
+pub struct TabSwitcher {
+    /// Whether the overlay is visible
+    pub visible: bool,
+
+    /// Current search query
+    pub query: String,
+
+    /// Ranked results for the current query
+    pub results: Vec<TabSwitchResult>,
+
+    /// Index of the selected result
+    pub selected: usize,
+}
+
+impl TabSwitcher {
+    /// Create a new, hidden tab switcher
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the overlay with an empty query
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.query.clear();
+        self.results.clear();
+        self.selected = 0;
+    }
+
+    /// Hide the overlay and reset its state
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.query.clear();
+        self.results.clear();
+        self.selected = 0;
+    }
+
+    /// Replace the results, resetting the selection to the best match
+    pub fn set_results(&mut self, results: Vec<TabSwitchResult>) {
+        self.results = results;
+        self.selected = 0;
+    }
+
+    /// Move the selection down (wraps around)
+    pub fn select_next(&mut self) {
+        if !self.results.is_empty() {
+            self.selected = (self.selected + 1) % self.results.len();
+        }
+    }
+
+    /// Move the selection up (wraps around)
+    pub fn select_previous(&mut self) {
+        if !self.results.is_empty() {
+            self.selected = if self.selected == 0 {
+                self.results.len() - 1
+            } else {
+                self.selected - 1
+            };
+        }
+    }
+
+    /// Get the currently selected result
+    pub fn selected_result(&self) -> Option<&TabSwitchResult> {
+        self.results.get(self.selected)
+    }
+}
+
+/// Score how well `query` matches `text`, case-insensitively
+///
+/// Returns `None` if the query characters do not all appear in order.
+/// Contiguous matches score higher than scattered ones, and matches at the
+/// start of the text or of a word score highest.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    if let Some(pos) = text.find(&query) {
+        let at_word_start = text[..pos]
+            .chars()
+            .next_back()
+            .is_some_and(|c| !c.is_alphanumeric());
+        return Some(if pos == 0 {
+            SCORE_PREFIX
+        } else if at_word_start {
+            SCORE_WORD_START
+        } else {
+            SCORE_SUBSTRING
+        });
+    }
+
+    // Subsequence match: penalize the gaps between matched characters
+    let mut query_chars = query.chars().peekable();
+    let mut first = None;
+    let mut last = 0;
+    for (i, c) in text.chars().enumerate() {
+        if query_chars.peek() == Some(&c) {
+            query_chars.next();
+            first.get_or_insert(i);
+            last = i;
+        }
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    let span = last - first.unwrap_or(0) + 1;
+    let gaps = span - query.chars().count();
+    Some(SCORE_SUBSEQUENCE.saturating_sub(gaps as u32).max(1))
+}
+
+/// Rank tabs against a query
+///
+/// `candidates` are `(tab_id, title, url)` tuples ordered from most to least
+/// recently used; ties in match quality keep that order. Tabs that don't
+/// match are omitted. An empty query returns every tab in recency order.
+pub fn rank_tabs<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = (TabId, &'a str, &'a str)>,
+) -> Vec<TabSwitchResult> {
+    let mut results: Vec<TabSwitchResult> = candidates
+        .into_iter()
+        .filter_map(|(tab_id, title, url)| {
+            let score = fuzzy_score(query, title).max(fuzzy_score(query, url))?;
+            Some(TabSwitchResult {
+                tab_id,
+                title: title.to_string(),
+                url: url.to_string(),
+                score,
+            })
+        })
+        .collect();
+
+    // Stable sort preserves recency order among equal scores
+    results.sort_by_key(|r| std::cmp::Reverse(r.score));
+    results
+}
+
+/// Filter tabs whose title or URL contains `query`, case-insensitively
+///
+/// `candidates` are `(tab_id, title, url)` tuples; matches keep their input
+/// order. An empty query matches every tab.
+pub fn filter_tabs<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = (TabId, &'a str, &'a str)>,
+) -> Vec<TabId> {
+    let query = query.to_lowercase();
+    candidates
+        .into_iter()
+        .filter(|(_, title, url)| {
+            title.to_lowercase().contains(&query) || url.to_lowercase().contains(&query)
+        })
+        .map(|(tab_id, _, _)| tab_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_ordering() {
+        let prefix = fuzzy_score("rust", "Rust Programming").unwrap();
+        let word = fuzzy_score("prog", "Rust Programming").unwrap();
+        let substring = fuzzy_score("gram", "Rust Programming").unwrap();
+        let subsequence = fuzzy_score("rsp", "Rust Programming").unwrap();
+
+        assert!(prefix > word);
+        assert!(word > substring);
+        assert!(substring > subsequence);
+    }
+
+    #[test]
+    fn test_fuzzy_score_no_match() {
+        assert_eq!(fuzzy_score("xyz", "Rust Programming"), None);
+        assert_eq!(fuzzy_score("tsur", "rust"), None);
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_score_tighter_subsequence_wins() {
+        let tight = fuzzy_score("gh", "github").unwrap();
+        let loose = fuzzy_score("gb", "github").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn test_rank_tabs_ties_keep_recency() {
+        let recent = TabId::new();
+        let older = TabId::new();
+        let results = rank_tabs(
+            "docs",
+            [
+                (recent, "Docs A", "https://a.example"),
+                (older, "Docs B", "https://b.example"),
+            ],
+        );
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].tab_id, recent);
+        assert_eq!(results[1].tab_id, older);
+    }
+
+    #[test]
+    fn test_tab_switcher_selection_wraps() {
+        let mut switcher = TabSwitcher::new();
+        switcher.set_results(rank_tabs(
+            "",
+            [
+                (TabId::new(), "One", ""),
+                (TabId::new(), "Two", ""),
+                (TabId::new(), "Three", ""),
+            ],
+        ));
+
+        assert_eq!(switcher.selected, 0);
+        switcher.select_previous();
+        assert_eq!(switcher.selected, 2);
+        switcher.select_next();
+        assert_eq!(switcher.selected, 0);
+        switcher.select_next();
+        assert_eq!(switcher.selected_result().unwrap().title, "Two");
+    }
+
+    #[test]
+    fn test_filter_tabs_matches_title_or_url() {
+        let docs = TabId::new();
+        let mail = TabId::new();
+        let news = TabId::new();
+        let candidates = [
+            (docs, "Rust Docs", "https://doc.rust-lang.org"),
+            (mail, "Inbox (3)", "https://mail.example.com"),
+            (news, "Headlines", "https://news.example.com/RUST"),
+        ];
+
+        assert_eq!(filter_tabs("rust", candidates), vec![docs, news]);
+        assert_eq!(filter_tabs("EXAMPLE", candidates), vec![mail, news]);
+        assert_eq!(filter_tabs("inbox", candidates), vec![mail]);
+        assert_eq!(filter_tabs("", candidates), vec![docs, mail, news]);
+        assert!(filter_tabs("weather", candidates).is_empty());
+    }
+}
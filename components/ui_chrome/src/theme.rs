@@ -5,6 +5,7 @@
 
 use egui::Color32;
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
 
 /// Theme mode selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -44,6 +45,19 @@ impl std::str::FromStr for ThemeMode {
     }
 }
 
+/// Colors for a user-defined theme
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CustomPalette {
+    /// Main background color
+    pub background: Color32,
+    /// Main text color
+    pub text: Color32,
+    /// Accent color for highlights, buttons, links
+    pub accent: Color32,
+    /// Border color for windows and separators
+    pub border: Color32,
+}
+
 /// Browser theme configuration
 ///
 /// Contains all customizable visual properties for the browser UI.
@@ -69,6 +83,12 @@ pub struct Theme {
     pub font_size: f32,
     /// Border radius for UI elements
     pub border_radius: f32,
+    /// Name of a user-defined theme (`None` for the built-in themes)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Border color override for user-defined themes
+    #[serde(default)]
+    pub border: Option<Color32>,
 }
 
 impl Default for Theme {
@@ -91,6 +111,8 @@ impl Theme {
             font_family: "system-ui".to_string(),
             font_size: 14.0,
             border_radius: 4.0,
+            name: None,
+            border: None,
         }
     }
 
@@ -107,6 +129,8 @@ impl Theme {
             font_family: "system-ui".to_string(),
             font_size: 14.0,
             border_radius: 4.0,
+            name: None,
+            border: None,
         }
     }
 
@@ -125,6 +149,29 @@ impl Theme {
         }
     }
 
+    /// Create a named, user-defined theme from a palette
+    ///
+    /// The theme is treated as dark when its background is dark, which picks
+    /// the egui base visuals the palette is layered on.
+    pub fn custom(name: String, colors: CustomPalette) -> Self {
+        let [r, g, b, _] = colors.background.to_array();
+        let luminance = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+        let mut theme = if luminance < 128.0 {
+            Self::dark()
+        } else {
+            Self::light()
+        };
+        theme.background = colors.background;
+        theme.foreground = colors.text;
+        theme.accent = colors.accent;
+        theme.toolbar_bg = colors.background;
+        theme.tab_active_bg = colors.background;
+        theme.tab_inactive_bg = colors.background;
+        theme.name = Some(name);
+        theme.border = Some(colors.border);
+        theme
+    }
+
     /// Create a custom theme with the specified accent color
     pub fn with_accent(mut self, accent: Color32) -> Self {
         self.accent = accent;
@@ -148,9 +195,12 @@ impl Theme {
     ///
     /// Updates the egui visuals and style to match this theme's colors and settings.
     pub fn apply(&self, ctx: &egui::Context) {
-        let mut visuals = if matches!(self.mode, ThemeMode::Dark)
-            || (matches!(self.mode, ThemeMode::Auto) && detect_system_dark_mode())
-        {
+        self.apply_with(ctx, self.is_dark());
+    }
+
+    /// Apply this theme using an already-resolved light/dark preference
+    fn apply_with(&self, ctx: &egui::Context, dark: bool) {
+        let mut visuals = if dark {
             egui::Visuals::dark()
         } else {
             egui::Visuals::light()
@@ -172,6 +222,11 @@ impl Theme {
         visuals.widgets.active.bg_fill = self.accent;
         visuals.widgets.hovered.bg_fill = self.accent.linear_multiply(0.8);
 
+        if let Some(border) = self.border {
+            visuals.window_stroke.color = border;
+            visuals.widgets.noninteractive.bg_stroke.color = border;
+        }
+
         // Border radius
         visuals.window_rounding = egui::Rounding::same(self.border_radius);
         visuals.menu_rounding = egui::Rounding::same(self.border_radius);
@@ -222,6 +277,24 @@ impl Theme {
     }
 }
 
+/// Source of the operating system's light/dark preference
+///
+/// Consulted by [`ThemeManager`] to resolve [`ThemeMode::Auto`].
+pub trait SystemThemeDetector: Send + Sync {
+    /// Whether the system currently prefers a dark appearance
+    fn prefers_dark(&self) -> bool;
+}
+
+/// Detector that queries the current platform's settings
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlatformThemeDetector;
+
+impl SystemThemeDetector for PlatformThemeDetector {
+    fn prefers_dark(&self) -> bool {
+        detect_system_dark_mode()
+    }
+}
+
 /// Detect if the system prefers dark mode
 ///
 /// Platform-specific implementation:
@@ -322,16 +395,43 @@ fn detect_dark_mode_windows() -> bool {
     false
 }
 
+/// Callback invoked with the current mode whenever the theme changes
+pub type ThemeChangeCallback = Box<dyn Fn(ThemeMode) + Send>;
+
 /// Theme manager for handling runtime theme changes
 ///
 /// Provides a higher-level interface for managing themes, including
 /// caching and change detection.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ThemeManager {
     /// Current active theme
     current_theme: Theme,
     /// Whether the theme has changed since last apply
     needs_apply: bool,
+    /// Source of the system preference used in Auto mode
+    detector: Arc<dyn SystemThemeDetector>,
+    /// Light or Dark, whichever palette `current_theme` currently holds
+    resolved_mode: ThemeMode,
+    /// Registered user-defined themes
+    custom_themes: Vec<Theme>,
+    /// Incremented on every theme change
+    version: u64,
+    /// Version last pushed to egui by `apply`
+    applied_version: Option<u64>,
+    /// Change listeners (shared between clones)
+    listeners: Arc<Mutex<Vec<ThemeChangeCallback>>>,
+}
+
+impl std::fmt::Debug for ThemeManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThemeManager")
+            .field("current_theme", &self.current_theme)
+            .field("needs_apply", &self.needs_apply)
+            .field("resolved_mode", &self.resolved_mode)
+            .field("custom_themes", &self.custom_themes)
+            .field("version", &self.version)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for ThemeManager {
@@ -343,17 +443,75 @@ impl Default for ThemeManager {
 impl ThemeManager {
     /// Create a new theme manager with the default light theme
     pub fn new() -> Self {
-        Self {
-            current_theme: Theme::default(),
-            needs_apply: true,
-        }
+        Self::with_theme(Theme::default())
     }
 
     /// Create a theme manager with a specific theme
     pub fn with_theme(theme: Theme) -> Self {
+        let resolved_mode = if theme.is_dark() {
+            ThemeMode::Dark
+        } else {
+            ThemeMode::Light
+        };
         Self {
             current_theme: theme,
             needs_apply: true,
+            detector: Arc::new(PlatformThemeDetector),
+            resolved_mode,
+            custom_themes: Vec::new(),
+            version: 0,
+            applied_version: None,
+            listeners: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Get the theme version, incremented on every theme change
+    ///
+    /// Components caching styled resources can compare versions to know
+    /// when to rebuild them.
+    pub fn theme_version(&self) -> u64 {
+        self.version
+    }
+
+    /// Register a callback invoked with the current mode on every theme change
+    pub fn on_change(&mut self, callback: ThemeChangeCallback) {
+        if let Ok(mut listeners) = self.listeners.lock() {
+            listeners.push(callback);
+        }
+    }
+
+    /// Record a theme change: bump the version and notify listeners
+    fn mark_changed(&mut self) {
+        self.needs_apply = true;
+        self.version += 1;
+        if let Ok(listeners) = self.listeners.lock() {
+            for listener in listeners.iter() {
+                listener(self.current_theme.mode);
+            }
+        }
+    }
+
+    /// Replace the source of the system light/dark preference
+    ///
+    /// In Auto mode the palette is refreshed from the new source immediately.
+    pub fn set_system_theme_source(&mut self, detector: Box<dyn SystemThemeDetector>) {
+        self.detector = Arc::from(detector);
+        self.check_system_change();
+    }
+
+    /// Resolve the current mode to Light or Dark
+    ///
+    /// Auto consults the system theme source; other modes are returned as is.
+    pub fn resolve_mode(&self) -> ThemeMode {
+        self.resolve(self.current_theme.mode)
+    }
+
+    /// Resolve a mode to Light or Dark using the system theme source
+    fn resolve(&self, mode: ThemeMode) -> ThemeMode {
+        match mode {
+            ThemeMode::Auto if self.detector.prefers_dark() => ThemeMode::Dark,
+            ThemeMode::Auto => ThemeMode::Light,
+            mode => mode,
         }
     }
 
@@ -368,9 +526,12 @@ impl ThemeManager {
     }
 
     /// Set the theme mode
+    ///
+    /// Switching mode also leaves any active custom theme.
     pub fn set_mode(&mut self, mode: ThemeMode) {
-        if self.current_theme.mode != mode {
-            self.current_theme = Theme::for_mode(mode)
+        if self.current_theme.mode != mode || self.current_theme.name.is_some() {
+            self.resolved_mode = self.resolve(mode);
+            self.current_theme = Theme::for_mode(self.resolved_mode)
                 .with_accent(self.current_theme.accent)
                 .with_font(
                     self.current_theme.font_family.clone(),
@@ -378,15 +539,76 @@ impl ThemeManager {
                 )
                 .with_border_radius(self.current_theme.border_radius);
             self.current_theme.mode = mode;
-            self.needs_apply = true;
+            self.mark_changed();
+        }
+    }
+
+    /// Register a user-defined theme, replacing any with the same name
+    ///
+    /// Returns false (and registers nothing) for themes without a name.
+    pub fn register_custom(&mut self, theme: Theme) -> bool {
+        let Some(name) = &theme.name else {
+            return false;
+        };
+        match self
+            .custom_themes
+            .iter_mut()
+            .find(|t| t.name.as_ref() == Some(name))
+        {
+            Some(existing) => *existing = theme,
+            None => self.custom_themes.push(theme),
         }
+        true
+    }
+
+    /// Switch to a registered custom theme by name
+    ///
+    /// The current font and border radius are kept. Returns false if no
+    /// custom theme has that name.
+    pub fn set_custom(&mut self, name: &str) -> bool {
+        let Some(theme) = self
+            .custom_themes
+            .iter()
+            .find(|t| t.name.as_deref() == Some(name))
+        else {
+            return false;
+        };
+        self.current_theme = theme
+            .clone()
+            .with_font(
+                self.current_theme.font_family.clone(),
+                self.current_theme.font_size,
+            )
+            .with_border_radius(self.current_theme.border_radius);
+        self.resolved_mode = self.current_theme.mode;
+        self.mark_changed();
+        true
+    }
+
+    /// Get the registered custom themes
+    pub fn custom_themes(&self) -> &[Theme] {
+        &self.custom_themes
+    }
+
+    /// Serialize the registered custom themes for persistence
+    pub fn custom_themes_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.custom_themes)
+    }
+
+    /// Register custom themes previously saved with [`Self::custom_themes_json`]
+    pub fn load_custom_themes_json(&mut self, json: &str) -> Result<(), serde_json::Error> {
+        let themes: Vec<Theme> = serde_json::from_str(json)?;
+        for theme in themes {
+            self.register_custom(theme);
+        }
+        Ok(())
     }
 
     /// Set the accent color
     pub fn set_accent(&mut self, accent: Color32) {
         if self.current_theme.accent != accent {
             self.current_theme.accent = accent;
-            self.needs_apply = true;
+            self.mark_changed();
         }
     }
 
@@ -395,7 +617,7 @@ impl ThemeManager {
         if self.current_theme.font_family != family || self.current_theme.font_size != size {
             self.current_theme.font_family = family;
             self.current_theme.font_size = size;
-            self.needs_apply = true;
+            self.mark_changed();
         }
     }
 
@@ -403,7 +625,7 @@ impl ThemeManager {
     pub fn set_border_radius(&mut self, radius: f32) {
         if (self.current_theme.border_radius - radius).abs() > f32::EPSILON {
             self.current_theme.border_radius = radius;
-            self.needs_apply = true;
+            self.mark_changed();
         }
     }
 
@@ -412,18 +634,31 @@ impl ThemeManager {
     /// Returns true if the theme was applied, false if no changes were needed.
     pub fn apply_if_needed(&mut self, ctx: &egui::Context) -> bool {
         if self.needs_apply {
-            self.current_theme.apply(ctx);
-            self.needs_apply = false;
+            self.push_visuals(ctx);
             true
         } else {
             false
         }
     }
 
-    /// Force apply the theme to the egui context
+    /// Apply the theme to the egui context
+    ///
+    /// In Auto mode the system preference is checked first, so the applied
+    /// palette always matches the resolved mode. Visuals are only pushed
+    /// when the theme version changed since the last apply.
     pub fn apply(&mut self, ctx: &egui::Context) {
-        self.current_theme.apply(ctx);
+        self.check_system_change();
+        if self.applied_version != Some(self.version) {
+            self.push_visuals(ctx);
+        }
+    }
+
+    /// Push the current theme's visuals to the egui context
+    fn push_visuals(&mut self, ctx: &egui::Context) {
+        self.current_theme
+            .apply_with(ctx, self.resolved_mode == ThemeMode::Dark);
         self.needs_apply = false;
+        self.applied_version = Some(self.version);
     }
 
     /// Check if the theme needs to be reapplied
@@ -431,21 +666,17 @@ impl ThemeManager {
     /// This is useful for Auto mode where the system preference may have changed.
     pub fn check_system_change(&mut self) {
         if matches!(self.current_theme.mode, ThemeMode::Auto) {
-            let current_is_dark = self.current_theme.is_dark();
-            let system_is_dark = detect_system_dark_mode();
-            if current_is_dark != system_is_dark {
-                self.needs_apply = true;
+            let resolved = self.resolve_mode();
+            if resolved != self.resolved_mode {
+                self.resolved_mode = resolved;
                 // Update the theme colors based on new system preference
-                let base = if system_is_dark {
-                    Theme::dark()
-                } else {
-                    Theme::light()
-                };
+                let base = Theme::for_mode(resolved);
                 self.current_theme.background = base.background;
                 self.current_theme.foreground = base.foreground;
                 self.current_theme.toolbar_bg = base.toolbar_bg;
                 self.current_theme.tab_active_bg = base.tab_active_bg;
                 self.current_theme.tab_inactive_bg = base.tab_inactive_bg;
+                self.mark_changed();
             }
         }
     }
@@ -626,6 +857,184 @@ mod tests {
         assert_eq!(manager.mode(), ThemeMode::Dark);
     }
 
+    /// System theme source with a fixed preference
+    struct MockDetector(bool);
+
+    impl SystemThemeDetector for MockDetector {
+        fn prefers_dark(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_theme_manager_resolve_mode() {
+        let mut manager = ThemeManager::new();
+        manager.set_system_theme_source(Box::new(MockDetector(true)));
+        assert_eq!(manager.resolve_mode(), ThemeMode::Light);
+
+        manager.set_mode(ThemeMode::Auto);
+        assert_eq!(manager.mode(), ThemeMode::Auto);
+        assert_eq!(manager.resolve_mode(), ThemeMode::Dark);
+
+        manager.set_system_theme_source(Box::new(MockDetector(false)));
+        assert_eq!(manager.resolve_mode(), ThemeMode::Light);
+        assert_eq!(manager.theme().background, Theme::light().background);
+    }
+
+    #[test]
+    fn test_theme_manager_auto_applies_dark_palette() {
+        let ctx = egui::Context::default();
+        let mut manager = ThemeManager::new();
+        manager.set_system_theme_source(Box::new(MockDetector(true)));
+        manager.set_mode(ThemeMode::Auto);
+        manager.apply(&ctx);
+
+        let visuals = ctx.style().visuals.clone();
+        assert!(visuals.dark_mode);
+        assert_eq!(visuals.panel_fill, Theme::dark().background);
+        assert_eq!(visuals.override_text_color, Some(Theme::dark().foreground));
+    }
+
+    #[test]
+    fn test_theme_manager_auto_follows_system_change_on_apply() {
+        let ctx = egui::Context::default();
+        let mut manager = ThemeManager::new();
+        manager.set_system_theme_source(Box::new(MockDetector(false)));
+        manager.set_mode(ThemeMode::Auto);
+        manager.apply(&ctx);
+        assert!(!ctx.style().visuals.dark_mode);
+
+        // Swap the source without notifying the manager; apply re-checks it
+        manager.detector = Arc::new(MockDetector(true));
+        manager.apply(&ctx);
+        assert!(ctx.style().visuals.dark_mode);
+        assert_eq!(ctx.style().visuals.panel_fill, Theme::dark().background);
+    }
+
+    fn ocean_palette() -> CustomPalette {
+        CustomPalette {
+            background: Color32::from_rgb(10, 30, 50),
+            text: Color32::from_rgb(220, 240, 255),
+            accent: Color32::from_rgb(0, 200, 180),
+            border: Color32::from_rgb(40, 80, 120),
+        }
+    }
+
+    #[test]
+    fn test_theme_custom() {
+        let theme = Theme::custom("Ocean".to_string(), ocean_palette());
+        assert_eq!(theme.name.as_deref(), Some("Ocean"));
+        assert_eq!(theme.mode, ThemeMode::Dark);
+        assert_eq!(theme.background, Color32::from_rgb(10, 30, 50));
+        assert_eq!(theme.border, Some(Color32::from_rgb(40, 80, 120)));
+
+        let paper = Theme::custom(
+            "Paper".to_string(),
+            CustomPalette {
+                background: Color32::from_rgb(250, 245, 230),
+                ..ocean_palette()
+            },
+        );
+        assert_eq!(paper.mode, ThemeMode::Light);
+    }
+
+    #[test]
+    fn test_theme_manager_custom_theme_applies_palette() {
+        let ctx = egui::Context::default();
+        let mut manager = ThemeManager::new();
+        assert!(manager.register_custom(Theme::custom("Ocean".to_string(), ocean_palette())));
+        assert!(!manager.register_custom(Theme::dark()));
+        assert!(!manager.set_custom("Missing"));
+
+        assert!(manager.set_custom("Ocean"));
+        manager.apply(&ctx);
+
+        let visuals = ctx.style().visuals.clone();
+        assert!(visuals.dark_mode);
+        assert_eq!(visuals.panel_fill, Color32::from_rgb(10, 30, 50));
+        assert_eq!(
+            visuals.override_text_color,
+            Some(Color32::from_rgb(220, 240, 255))
+        );
+        assert_eq!(visuals.hyperlink_color, Color32::from_rgb(0, 200, 180));
+        assert_eq!(visuals.window_stroke.color, Color32::from_rgb(40, 80, 120));
+
+        // Switching back to a built-in mode leaves the custom theme
+        manager.set_mode(ThemeMode::Dark);
+        assert_eq!(manager.theme().name, None);
+        assert_eq!(manager.theme().background, Theme::dark().background);
+    }
+
+    #[test]
+    fn test_theme_manager_custom_themes_persist() {
+        let mut manager = ThemeManager::new();
+        manager.register_custom(Theme::custom("Ocean".to_string(), ocean_palette()));
+        manager.register_custom(Theme::custom("Ocean".to_string(), ocean_palette()));
+        assert_eq!(manager.custom_themes().len(), 1);
+        let json = manager.custom_themes_json().unwrap();
+
+        let mut restored = ThemeManager::new();
+        restored.load_custom_themes_json(&json).unwrap();
+        assert!(restored.set_custom("Ocean"));
+        assert_eq!(restored.theme().accent, Color32::from_rgb(0, 200, 180));
+        assert_eq!(
+            restored.theme().border,
+            Some(Color32::from_rgb(40, 80, 120))
+        );
+    }
+
+    #[test]
+    fn test_theme_manager_version_increments_on_change() {
+        let mut manager = ThemeManager::new();
+        assert_eq!(manager.theme_version(), 0);
+
+        manager.set_mode(ThemeMode::Dark);
+        assert_eq!(manager.theme_version(), 1);
+
+        // Setting the same mode is not a change
+        manager.set_mode(ThemeMode::Dark);
+        assert_eq!(manager.theme_version(), 1);
+
+        manager.register_custom(Theme::custom("Ocean".to_string(), ocean_palette()));
+        assert_eq!(manager.theme_version(), 1);
+        manager.set_custom("Ocean");
+        assert_eq!(manager.theme_version(), 2);
+    }
+
+    #[test]
+    fn test_theme_manager_on_change_callback() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = ThemeManager::new();
+        let sink = Arc::clone(&seen);
+        manager.on_change(Box::new(move |mode| sink.lock().unwrap().push(mode)));
+
+        manager.set_mode(ThemeMode::Dark);
+        manager.set_mode(ThemeMode::Dark);
+        manager.set_mode(ThemeMode::Light);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![ThemeMode::Dark, ThemeMode::Light]
+        );
+    }
+
+    #[test]
+    fn test_theme_manager_apply_skips_unchanged_version() {
+        let ctx = egui::Context::default();
+        let mut manager = ThemeManager::with_theme(Theme::dark());
+        manager.apply(&ctx);
+        assert!(ctx.style().visuals.dark_mode);
+
+        // Nothing changed, so apply must not re-push the theme's visuals
+        ctx.set_visuals(egui::Visuals::light());
+        manager.apply(&ctx);
+        assert!(!ctx.style().visuals.dark_mode);
+
+        manager.set_accent(Color32::GREEN);
+        manager.apply(&ctx);
+        assert!(ctx.style().visuals.dark_mode);
+    }
+
     #[test]
     fn test_theme_resolve_light() {
         let theme = Theme::light();
@@ -3,8 +3,8 @@
 //! Following TDD: Write failing tests first (RED), then implement (GREEN), then refactor
 
 use eframe::App;
-use shared_types::{KeyboardShortcut, TabId};
-use ui_chrome::UiChrome;
+use shared_types::{ComponentError, DownloadId, KeyboardShortcut, TabId};
+use ui_chrome::{DownloadDisplay, DownloadDisplayStatus, NavigationCommand, UiChrome, ZoomConfig};
 
 #[test]
 fn test_ui_chrome_creation() {
@@ -722,6 +722,72 @@ fn test_status_bar_download_count() {
     assert_eq!(chrome.get_download_count(), 3);
 }
 
+fn download(
+    downloaded_bytes: u64,
+    total_bytes: u64,
+    status: DownloadDisplayStatus,
+) -> DownloadDisplay {
+    DownloadDisplay {
+        id: DownloadId::new(),
+        filename: "file.bin".to_string(),
+        downloaded_bytes,
+        total_bytes,
+        bytes_per_second: 0,
+        eta_seconds: 0,
+        status,
+    }
+}
+
+#[test]
+fn test_aggregate_download_progress() {
+    // Given a UiChrome with known-size, unknown-size and finished downloads
+    // When computing the aggregate progress
+    // Then only active downloads of known size are counted
+
+    // Given
+    let mut chrome = UiChrome::new();
+    assert_eq!(chrome.aggregate_download_progress(), None);
+    chrome.set_downloads(vec![
+        download(100, 400, DownloadDisplayStatus::Downloading),
+        download(500, 600, DownloadDisplayStatus::Paused),
+        download(9_000, 0, DownloadDisplayStatus::Downloading),
+        download(1_000, 1_000, DownloadDisplayStatus::Complete),
+        download(
+            10,
+            5_000,
+            DownloadDisplayStatus::Failed("network".to_string()),
+        ),
+    ]);
+
+    // When
+    let progress = chrome.aggregate_download_progress().unwrap();
+
+    // Then
+    assert_eq!(chrome.get_download_count(), 3);
+    assert!((progress - 0.6).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_aggregate_download_progress_unknown_sizes_only() {
+    // Given a UiChrome whose only active download has an unknown size
+    // When computing the aggregate progress
+    // Then it is indeterminate
+
+    // Given
+    let mut chrome = UiChrome::new();
+    chrome.set_downloads(vec![
+        download(2_048, 0, DownloadDisplayStatus::Downloading),
+        download(300, 300, DownloadDisplayStatus::Complete),
+    ]);
+
+    // When
+    let progress = chrome.aggregate_download_progress();
+
+    // Then
+    assert_eq!(chrome.get_download_count(), 1);
+    assert_eq!(progress, None);
+}
+
 // Test 7: Bookmark functionality
 #[test]
 fn test_bookmark_current_page() {
@@ -758,3 +824,896 @@ fn test_bookmark_empty_address_fails() {
     // Then
     assert!(result.is_err());
 }
+
+#[test]
+fn test_tab_switcher_subsequence_ranks_above_weaker_match() {
+    // Given tabs where one title contains the query as a tight subsequence
+    // and another only as a scattered one
+    // When searching tabs
+    // Then the tighter match should rank first
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let weak = chrome.add_tab("Gardening tips & tricks for Hobbyists".to_string());
+    let strong = chrome.add_tab("GitHub - pull requests".to_string());
+    chrome.add_tab("Weather".to_string());
+
+    // When
+    let results = chrome.tab_switcher_results("gthb");
+
+    // Then
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].tab_id, strong);
+    assert_eq!(results[1].tab_id, weak);
+    assert!(results[0].score > results[1].score);
+}
+
+#[test]
+fn test_tab_switcher_ties_rank_by_recency() {
+    // Given two equally matching tabs where the older one was activated last
+    // When searching tabs
+    // Then the most recently active tab should rank first
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.add_tab("Docs one".to_string());
+    let second = chrome.add_tab("Docs two".to_string());
+    chrome.set_active_tab(first).unwrap();
+
+    // When
+    let results = chrome.tab_switcher_results("docs");
+
+    // Then
+    assert_eq!(results[0].tab_id, first);
+    assert_eq!(results[1].tab_id, second);
+}
+
+#[test]
+fn test_tab_switcher_keyboard_selection_activates_tab() {
+    // Given an open tab switcher with a query matching two tabs
+    // When moving the selection down and pressing Enter
+    // Then the second result should become the active tab and the overlay closes
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let rust = chrome.add_tab("Rust Book".to_string());
+    let rustlings = chrome.add_tab("Rustlings".to_string());
+    chrome.add_tab("News".to_string());
+    chrome.toggle_tab_switcher();
+    assert!(chrome.tab_switcher().visible);
+    chrome.set_tab_switcher_query("rust".to_string());
+    assert_eq!(chrome.tab_switcher().results.len(), 2);
+    assert_eq!(chrome.tab_switcher().results[0].tab_id, rustlings);
+
+    // When
+    chrome.tab_switcher_select_next();
+    let activated = chrome.activate_tab_switcher_selection();
+
+    // Then
+    assert_eq!(activated, Some(rust));
+    assert_eq!(chrome.active_tab_id(), Some(rust));
+    assert!(!chrome.tab_switcher().visible);
+}
+
+#[test]
+fn test_toolbar_buttons_queue_navigation_commands() {
+    // Given a UiChrome instance
+    // When the back, forward, reload, and stop handlers fire
+    // Then matching commands should be queued in order and drained once
+
+    // Given
+    let mut chrome = UiChrome::new();
+
+    // When
+    chrome.go_back();
+    chrome.go_forward();
+    chrome.reload();
+    chrome.stop_loading();
+
+    // Then
+    assert_eq!(
+        chrome.take_pending_commands(),
+        vec![
+            NavigationCommand::Back,
+            NavigationCommand::Forward,
+            NavigationCommand::Reload,
+            NavigationCommand::Stop,
+        ]
+    );
+    assert!(chrome.take_pending_commands().is_empty());
+}
+
+#[test]
+fn test_address_bar_enter_queues_navigate() {
+    // Given an address bar containing a URL with surrounding whitespace
+    // When the address bar is submitted
+    // Then a Navigate command with the trimmed URL should be queued
+
+    // Given
+    let mut chrome = UiChrome::new();
+    chrome
+        .handle_address_bar_input("  https://example.com  ".to_string())
+        .unwrap();
+
+    // When
+    chrome.submit_address_bar();
+
+    // Then
+    assert_eq!(
+        chrome.take_pending_commands(),
+        vec![NavigationCommand::Navigate(
+            "https://example.com".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_empty_address_bar_submit_is_ignored() {
+    // Given an empty address bar
+    // When the address bar is submitted
+    // Then no command should be queued
+
+    // Given
+    let mut chrome = UiChrome::new();
+
+    // When
+    chrome.submit_address_bar();
+
+    // Then
+    assert!(chrome.take_pending_commands().is_empty());
+}
+
+#[test]
+fn test_reload_shortcut_queues_reload() {
+    // Given a UiChrome instance
+    // When pressing F5 and Ctrl+R
+    // Then two Reload commands should be queued
+
+    // Given
+    let mut chrome = UiChrome::new();
+
+    // When
+    chrome
+        .handle_keyboard_shortcut(KeyboardShortcut::F5)
+        .unwrap();
+    chrome
+        .handle_keyboard_shortcut(KeyboardShortcut::CtrlR)
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        chrome.take_pending_commands(),
+        vec![NavigationCommand::Reload, NavigationCommand::Reload]
+    );
+}
+
+#[test]
+fn test_close_other_tabs_keeps_only_target() {
+    // Given five tabs with the third one kept
+    // When closing the other tabs
+    // Then only the kept tab should remain, active, with four tabs recorded as closed
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let tabs: Vec<_> = (1..5)
+        .map(|i| chrome.add_tab(format!("Tab {}", i)))
+        .collect();
+    assert_eq!(chrome.tab_count(), 5);
+    let keep = tabs[1];
+
+    // When
+    let result = chrome.close_other_tabs(keep);
+
+    // Then
+    assert!(result.is_ok());
+    assert_eq!(chrome.tab_count(), 1);
+    assert_eq!(chrome.active_tab_id(), Some(keep));
+    assert_eq!(chrome.recently_closed_count(), 4);
+}
+
+#[test]
+fn test_close_other_tabs_nonexistent_tab() {
+    // Given five tabs
+    // When closing all tabs except one that doesn't exist
+    // Then an error should be returned and no tab closed
+
+    // Given
+    let mut chrome = UiChrome::new();
+    for i in 1..5 {
+        chrome.add_tab(format!("Tab {}", i));
+    }
+
+    // When
+    let result = chrome.close_other_tabs(TabId::new());
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(chrome.tab_count(), 5);
+    assert_eq!(chrome.recently_closed_count(), 0);
+}
+
+#[test]
+fn test_close_all_tabs_except_last() {
+    // Given five tabs with the first one active
+    // When closing all tabs
+    // Then the last tab should remain and become active
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let mut last = None;
+    for i in 1..5 {
+        last = Some(chrome.add_tab(format!("Tab {}", i)));
+    }
+    chrome.switch_to_tab_number(1).unwrap();
+
+    // When
+    chrome.close_all_tabs_except_last();
+
+    // Then
+    assert_eq!(chrome.tab_count(), 1);
+    assert_eq!(chrome.active_tab_id(), last);
+    assert_eq!(chrome.recently_closed_count(), 4);
+
+    // Closing all again is a no-op on the single remaining tab
+    chrome.close_all_tabs_except_last();
+    assert_eq!(chrome.tab_count(), 1);
+}
+
+#[test]
+fn test_set_and_get_tab_url() {
+    // Given a UiChrome instance with a tab
+    // When setting the tab's URL
+    // Then it should be returned for that tab, and unknown tabs return None
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let tab_id = chrome.add_tab("Example".to_string());
+    assert_eq!(chrome.get_tab_url(tab_id), Some(""));
+
+    // When
+    let result = chrome.set_tab_url(tab_id, "https://example.com".to_string());
+
+    // Then
+    assert!(result.is_ok());
+    assert_eq!(chrome.get_tab_url(tab_id), Some("https://example.com"));
+    assert_eq!(chrome.get_tab_url(TabId::new()), None);
+    assert!(chrome
+        .set_tab_url(TabId::new(), "https://nowhere".to_string())
+        .is_err());
+}
+
+#[test]
+fn test_close_tab_records_its_own_url() {
+    // Given several tabs with distinct URLs and a different active tab
+    // When closing a non-active tab
+    // Then the recently closed entry should carry that tab's URL
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    chrome
+        .set_tab_url(first, "https://first.example".to_string())
+        .unwrap();
+    let second = chrome.add_tab("Second".to_string());
+    chrome
+        .set_tab_url(second, "https://second.example".to_string())
+        .unwrap();
+    let third = chrome.add_tab("Third".to_string());
+    chrome
+        .handle_address_bar_input("https://third.example".to_string())
+        .unwrap();
+    chrome.submit_address_bar();
+    assert_eq!(chrome.get_tab_url(third), Some("https://third.example"));
+
+    // When
+    chrome.close_tab(second).unwrap();
+
+    // Then
+    let closed = &chrome.recently_closed_tabs()[0];
+    assert_eq!(closed.id, second);
+    assert_eq!(closed.url, "https://second.example");
+}
+
+#[test]
+fn test_tab_audio_state_transitions() {
+    // Given a tab that starts playing audio
+    // When toggling mute twice
+    // Then the tab should be muted, then unmuted, staying audible throughout
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let tab_id = chrome.add_tab("Music".to_string());
+    chrome.set_tab_audible(tab_id, true).unwrap();
+
+    assert_eq!(chrome.is_tab_audible(tab_id), Some(true));
+    assert_eq!(chrome.is_tab_muted(tab_id), Some(false));
+
+    // When
+    let muted = chrome.toggle_tab_mute(tab_id).unwrap();
+
+    // Then
+    assert!(muted);
+    assert_eq!(chrome.is_tab_muted(tab_id), Some(true));
+    assert_eq!(chrome.is_tab_audible(tab_id), Some(true));
+
+    // When
+    let muted = chrome.toggle_tab_mute(tab_id).unwrap();
+
+    // Then
+    assert!(!muted);
+    assert_eq!(chrome.is_tab_muted(tab_id), Some(false));
+}
+
+#[test]
+fn test_toggle_mute_nonexistent_tab() {
+    // Given a UiChrome instance
+    // When toggling mute on a tab that doesn't exist
+    // Then ResourceNotFound should be returned
+
+    // Given
+    let mut chrome = UiChrome::new();
+
+    // When
+    let result = chrome.toggle_tab_mute(TabId::new());
+
+    // Then
+    assert!(matches!(result, Err(ComponentError::ResourceNotFound(_))));
+}
+
+#[test]
+fn test_toggle_pin_moves_tabs_to_front() {
+    // Given four tabs, with the third active
+    // When pinning the third and then the fourth tab
+    // Then pinned tabs should precede unpinned ones and the active tab is unchanged
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    let second = chrome.add_tab("Second".to_string());
+    let third = chrome.add_tab("Third".to_string());
+    let fourth = chrome.add_tab("Fourth".to_string());
+    chrome.set_active_tab(third).unwrap();
+
+    // When
+    assert!(chrome.toggle_pin(third).unwrap());
+    assert!(chrome.toggle_pin(fourth).unwrap());
+
+    // Then
+    let order: Vec<_> = (0..4).map(|i| chrome.get_tab_id(i).unwrap()).collect();
+    assert_eq!(order, vec![third, fourth, first, second]);
+    assert_eq!(chrome.active_tab_id(), Some(third));
+    assert_eq!(chrome.is_tab_pinned(third), Some(true));
+
+    // When - unpinning moves the tab to the start of the unpinned group
+    assert!(!chrome.toggle_pin(third).unwrap());
+
+    // Then
+    let order: Vec<_> = (0..4).map(|i| chrome.get_tab_id(i).unwrap()).collect();
+    assert_eq!(order, vec![fourth, third, first, second]);
+    assert_eq!(chrome.active_tab_id(), Some(third));
+}
+
+#[test]
+fn test_reorder_rejects_cross_pin_boundary() {
+    // Given two pinned tabs followed by two unpinned tabs
+    // When dragging across the pinned/unpinned boundary
+    // Then the move should be rejected and the order left untouched
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    let second = chrome.add_tab("Second".to_string());
+    let third = chrome.add_tab("Third".to_string());
+    let fourth = chrome.add_tab("Fourth".to_string());
+    chrome.toggle_pin(first).unwrap();
+    chrome.toggle_pin(second).unwrap();
+
+    // When - pinned tab dragged into the unpinned group
+    let pinned_result = chrome.reorder_tab(0, 3);
+    // When - unpinned tab dragged into the pinned group
+    let unpinned_result = chrome.reorder_tab(3, 1);
+
+    // Then
+    assert!(matches!(
+        pinned_result,
+        Err(ComponentError::InvalidState(_))
+    ));
+    assert!(matches!(
+        unpinned_result,
+        Err(ComponentError::InvalidState(_))
+    ));
+    let order: Vec<_> = (0..4).map(|i| chrome.get_tab_id(i).unwrap()).collect();
+    assert_eq!(order, vec![first, second, third, fourth]);
+
+    // Moves within a group are still allowed
+    assert!(chrome.reorder_tab(0, 2).is_ok());
+    assert!(chrome.reorder_tab(3, 2).is_ok());
+    let order: Vec<_> = (0..4).map(|i| chrome.get_tab_id(i).unwrap()).collect();
+    assert_eq!(order, vec![second, first, fourth, third]);
+}
+
+#[test]
+fn test_move_active_tab_with_keyboard() {
+    // Given four tabs with the first one active
+    // When moving the active tab right and left
+    // Then it should move one slot at a time, clamped at the ends, with the
+    // active index following it
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    let second = chrome.add_tab("Second".to_string());
+    let third = chrome.add_tab("Third".to_string());
+    let fourth = chrome.add_tab("Fourth".to_string());
+    chrome.switch_to_tab_number(1).unwrap();
+
+    // When - all the way to the right, then past the end
+    for expected in 1..4 {
+        assert!(chrome.move_active_tab_right());
+        assert_eq!(chrome.active_tab_index(), expected);
+    }
+    assert!(!chrome.move_active_tab_right());
+
+    // Then
+    let order: Vec<_> = (0..4).map(|i| chrome.get_tab_id(i).unwrap()).collect();
+    assert_eq!(order, vec![second, third, fourth, first]);
+    assert_eq!(chrome.active_tab_id(), Some(first));
+
+    // When - back two slots to the left
+    assert!(chrome.move_active_tab_left());
+    assert!(chrome.move_active_tab_left());
+
+    // Then
+    assert_eq!(chrome.active_tab_index(), 1);
+    let order: Vec<_> = (0..4).map(|i| chrome.get_tab_id(i).unwrap()).collect();
+    assert_eq!(order, vec![second, first, third, fourth]);
+
+    // Clamped at the start
+    assert!(chrome.move_active_tab_left());
+    assert!(!chrome.move_active_tab_left());
+    assert_eq!(chrome.active_tab_index(), 0);
+    assert_eq!(chrome.active_tab_id(), Some(first));
+}
+
+#[test]
+fn test_move_active_tab_respects_pin_boundary() {
+    // Given two pinned tabs followed by two unpinned tabs
+    // When moving tabs with the keyboard toward the boundary
+    // Then they should stop at the edge of their group
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    let second = chrome.add_tab("Second".to_string());
+    let third = chrome.add_tab("Third".to_string());
+    chrome.add_tab("Fourth".to_string());
+    chrome.toggle_pin(first).unwrap();
+    chrome.toggle_pin(second).unwrap();
+
+    // When - last pinned tab moved right
+    chrome.set_active_tab(second).unwrap();
+    let pinned_moved = chrome.move_active_tab_right();
+    // When - first unpinned tab moved left
+    chrome.set_active_tab(third).unwrap();
+    let unpinned_moved = chrome.move_active_tab_left();
+
+    // Then
+    assert!(!pinned_moved);
+    assert!(!unpinned_moved);
+    assert_eq!(chrome.get_tab_id(1), Some(second));
+    assert_eq!(chrome.get_tab_id(2), Some(third));
+    assert_eq!(chrome.active_tab_index(), 2);
+}
+
+#[test]
+fn test_zoom_in_clamps_at_maximum() {
+    // Given a tab at the default zoom
+    // When zooming in far past the maximum
+    // Then the zoom should stop at 300%
+
+    // Given
+    let mut chrome = UiChrome::new();
+    assert_eq!(chrome.active_tab_zoom(), 100);
+
+    // When
+    for _ in 0..30 {
+        chrome.zoom_in();
+    }
+
+    // Then
+    assert_eq!(chrome.active_tab_zoom(), 300);
+    assert_eq!(chrome.zoom_in(), 300);
+}
+
+#[test]
+fn test_zoom_out_clamps_at_minimum() {
+    // Given a tab at the default zoom
+    // When zooming out far past the minimum
+    // Then the zoom should stop at 25%
+
+    // Given
+    let mut chrome = UiChrome::new();
+
+    // When
+    for _ in 0..30 {
+        chrome.zoom_out();
+    }
+
+    // Then
+    assert_eq!(chrome.active_tab_zoom(), 25);
+    assert_eq!(chrome.zoom_out(), 25);
+}
+
+#[test]
+fn test_zoom_is_per_tab_and_resets() {
+    // Given two tabs where the first is zoomed in
+    // When switching tabs and resetting zoom
+    // Then each tab keeps its own level and reset returns to 100%
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    chrome.zoom_in();
+    chrome.zoom_in();
+    let second = chrome.add_tab("Second".to_string());
+
+    // Then
+    assert_eq!(chrome.active_tab_zoom(), 100);
+    chrome.set_active_tab(first).unwrap();
+    assert_eq!(chrome.active_tab_zoom(), 120);
+
+    // When
+    assert_eq!(chrome.reset_zoom(), 100);
+
+    // Then
+    assert_eq!(chrome.active_tab_zoom(), 100);
+    chrome.set_active_tab(second).unwrap();
+    assert_eq!(chrome.active_tab_zoom(), 100);
+}
+
+#[test]
+fn test_zoom_changes_queue_commands() {
+    // Given a tab at the default zoom
+    // When zooming in, then resetting twice
+    // Then a SetZoom command is queued for each actual change only
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let tab = chrome.active_tab_id().unwrap();
+
+    // When
+    chrome.zoom_in();
+    chrome.reset_zoom();
+    chrome.reset_zoom();
+
+    // Then
+    assert_eq!(
+        chrome.take_pending_commands(),
+        vec![
+            NavigationCommand::SetZoom { tab, percent: 110 },
+            NavigationCommand::SetZoom { tab, percent: 100 },
+        ]
+    );
+}
+
+#[test]
+fn test_filter_tabs_by_title_and_url() {
+    // Given tabs with mixed titles and URLs
+    // When filtering by queries that match titles, URLs, or nothing
+    // Then matching tabs should be returned in tab bar order
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let first = chrome.active_tab_id().unwrap();
+    chrome
+        .set_tab_url(first, "https://github.com/rust-lang".to_string())
+        .unwrap();
+    let news = chrome.add_tab("Hacker News".to_string());
+    chrome
+        .set_tab_url(news, "https://news.ycombinator.com".to_string())
+        .unwrap();
+    let book = chrome.add_tab("The Rust Book".to_string());
+    chrome
+        .set_tab_url(book, "https://doc.rust-lang.org/book".to_string())
+        .unwrap();
+
+    // When / Then
+    assert_eq!(chrome.filter_tabs("RUST"), vec![first, book]);
+    assert_eq!(chrome.filter_tabs("news"), vec![news]);
+    assert_eq!(chrome.filter_tabs(""), vec![first, news, book]);
+    assert!(chrome.filter_tabs("weather").is_empty());
+}
+
+#[test]
+fn test_tab_search_query_follows_search_box() {
+    // Given the tab search opened via Ctrl+Shift+A
+    // When typing a query and then closing the search
+    // Then the query should be exposed while open and cleared on close
+
+    // Given
+    let mut chrome = UiChrome::new();
+    chrome.toggle_tab_switcher();
+    assert_eq!(chrome.tab_search_query(), "");
+
+    // When
+    chrome.set_tab_switcher_query("news".to_string());
+
+    // Then
+    assert_eq!(chrome.tab_search_query(), "news");
+
+    // When
+    chrome.toggle_tab_switcher();
+
+    // Then
+    assert_eq!(chrome.tab_search_query(), "");
+}
+
+#[test]
+fn test_address_bar_cut_then_paste() {
+    // Given an address bar with part of the URL selected
+    // When cutting the selection and pasting it over another range
+    // Then the text should be moved and the cut text returned
+
+    // Given
+    let mut chrome = UiChrome::new();
+    chrome
+        .handle_address_bar_input("https://example.com/docs".to_string())
+        .unwrap();
+    chrome.set_address_bar_selection(19..24);
+
+    // When
+    let cut = chrome.address_bar_cut();
+
+    // Then
+    assert_eq!(cut, "/docs");
+    assert_eq!(chrome.address_bar_text(), "https://example.com");
+    assert_eq!(chrome.address_bar_selection(), 19..19);
+
+    // When
+    chrome.set_address_bar_selection(8..15);
+    chrome.address_bar_paste("rust-lang.org");
+
+    // Then
+    assert_eq!(chrome.address_bar_text(), "https://rust-lang.org.com");
+    assert_eq!(chrome.address_bar_selection(), 21..21);
+}
+
+#[test]
+fn test_address_bar_copy_and_select_all() {
+    // Given an address bar with no selection
+    // When copying, then selecting all
+    // Then the whole URL is copied and the selection covers all of it
+
+    // Given
+    let mut chrome = UiChrome::new();
+    chrome
+        .handle_address_bar_input("https://example.com".to_string())
+        .unwrap();
+
+    // When
+    let copied = chrome.address_bar_copy();
+    chrome.address_bar_select_all();
+
+    // Then
+    assert_eq!(copied, "https://example.com");
+    assert_eq!(chrome.address_bar_selection(), 0..19);
+}
+
+#[test]
+fn test_reopen_last_closed_tab_is_lifo() {
+    // Given two tabs with distinct URLs that are closed in turn
+    // When reopening closed tabs repeatedly
+    // Then they should come back most recent first, then nothing
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let docs = chrome.add_tab("Docs".to_string());
+    chrome
+        .set_tab_url(docs, "https://docs.example".to_string())
+        .unwrap();
+    let mail = chrome.add_tab("Mail".to_string());
+    chrome
+        .set_tab_url(mail, "https://mail.example".to_string())
+        .unwrap();
+    chrome.close_tab(docs).unwrap();
+    chrome.close_tab(mail).unwrap();
+
+    // When / Then
+    assert_eq!(
+        chrome.reopen_last_closed_tab(),
+        Some(("Mail".to_string(), "https://mail.example".to_string()))
+    );
+    assert_eq!(
+        chrome.reopen_last_closed_tab(),
+        Some(("Docs".to_string(), "https://docs.example".to_string()))
+    );
+    assert_eq!(chrome.reopen_last_closed_tab(), None);
+}
+
+#[test]
+fn test_restore_closed_tab_by_id() {
+    // Given two closed tabs
+    // When restoring the older one by ID
+    // Then its title and URL are returned and only it leaves the list
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let docs = chrome.add_tab("Docs".to_string());
+    chrome
+        .set_tab_url(docs, "https://docs.example".to_string())
+        .unwrap();
+    let mail = chrome.add_tab("Mail".to_string());
+    chrome.close_tab(docs).unwrap();
+    chrome.close_tab(mail).unwrap();
+
+    // When
+    let restored = chrome.restore_closed_tab(docs);
+
+    // Then
+    assert_eq!(
+        restored,
+        Some(("Docs".to_string(), "https://docs.example".to_string()))
+    );
+    assert_eq!(chrome.recently_closed_count(), 1);
+    assert_eq!(chrome.restore_closed_tab(docs), None);
+}
+
+#[test]
+fn test_ctrl_shift_t_reopens_tab_and_navigates() {
+    // Given a tab closed with Ctrl+W
+    // When pressing Ctrl+Shift+T
+    // Then a tab with the same title and URL is opened and navigation is queued
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let news = chrome.add_tab("News".to_string());
+    chrome
+        .set_tab_url(news, "https://news.example".to_string())
+        .unwrap();
+    chrome
+        .handle_keyboard_shortcut(KeyboardShortcut::CtrlW)
+        .unwrap();
+    assert_eq!(chrome.tab_count(), 1);
+
+    // When
+    chrome
+        .handle_keyboard_shortcut(KeyboardShortcut::CtrlShiftT)
+        .unwrap();
+
+    // Then
+    assert_eq!(chrome.tab_count(), 2);
+    let reopened = chrome.active_tab_id().unwrap();
+    assert_eq!(chrome.get_tab_title(reopened), Some("News".to_string()));
+    assert_eq!(chrome.get_tab_url(reopened), Some("https://news.example"));
+    assert_eq!(
+        chrome.take_pending_commands(),
+        vec![NavigationCommand::Navigate(
+            "https://news.example".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_toggle_reader_mode_on_http_page() {
+    // Given a tab showing an https page
+    // When toggling reader mode on and off
+    // Then the state flips and a SetReaderMode command is queued each time
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let tab = chrome.active_tab_id().unwrap();
+    chrome
+        .set_tab_url(tab, "https://example.com/article".to_string())
+        .unwrap();
+
+    // When
+    let enabled = chrome.toggle_reader_mode(tab).unwrap();
+    let disabled = chrome.toggle_reader_mode(tab).unwrap();
+
+    // Then
+    assert!(enabled);
+    assert!(!disabled);
+    assert_eq!(chrome.is_reader_mode(tab), Some(false));
+    assert_eq!(
+        chrome.take_pending_commands(),
+        vec![
+            NavigationCommand::SetReaderMode { tab, enabled: true },
+            NavigationCommand::SetReaderMode {
+                tab,
+                enabled: false
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_reader_mode_rejected_on_about_blank() {
+    // Given a tab showing about:blank
+    // When toggling reader mode
+    // Then InvalidState is returned and nothing changes
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let tab = chrome.active_tab_id().unwrap();
+    chrome.set_tab_url(tab, "about:blank".to_string()).unwrap();
+
+    // When
+    let result = chrome.toggle_reader_mode(tab);
+
+    // Then
+    assert!(matches!(result, Err(ComponentError::InvalidState(_))));
+    assert_eq!(chrome.is_reader_mode(tab), Some(false));
+    assert!(chrome.take_pending_commands().is_empty());
+    assert!(matches!(
+        chrome.toggle_reader_mode(TabId::new()),
+        Err(ComponentError::ResourceNotFound(_))
+    ));
+}
+
+#[test]
+fn test_custom_zoom_config_applies_to_tabs() {
+    // Given a chrome with a 50-150% range and 25% steps
+    // When zooming the active tab in and out past the bounds
+    // Then each step moves 25% and the level stops at the bounds
+
+    // Given
+    let mut chrome = UiChrome::new();
+    chrome.set_zoom_config(ZoomConfig {
+        min: 50,
+        max: 150,
+        step: 25,
+    });
+
+    // When / Then
+    assert_eq!(chrome.zoom_in(), 125);
+    assert_eq!(chrome.zoom_in(), 150);
+    assert_eq!(chrome.zoom_in(), 150);
+    chrome.reset_zoom();
+    assert_eq!(chrome.zoom_out(), 75);
+    assert_eq!(chrome.zoom_out(), 50);
+    assert_eq!(chrome.zoom_out(), 50);
+    assert_eq!(chrome.active_tab_zoom(), 50);
+}
+
+#[test]
+fn test_keyboard_zoom_scales_page_not_chrome() {
+    // Given a rendered chrome at the default zoom
+    // When pressing Ctrl+= in a frame
+    // Then the page zoom steps up but egui's UI zoom stays at 1.0
+
+    // Given
+    let mut chrome = UiChrome::new();
+    let ctx = egui::Context::default();
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        chrome.render(ctx).unwrap();
+    });
+
+    // When (on Linux and Windows Ctrl is also the command key)
+    let ctrl = egui::Modifiers {
+        ctrl: true,
+        command: true,
+        ..Default::default()
+    };
+    let input = egui::RawInput {
+        modifiers: ctrl,
+        events: vec![egui::Event::Key {
+            key: egui::Key::Equals,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: ctrl,
+        }],
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        chrome.render(ctx).unwrap();
+    });
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        chrome.render(ctx).unwrap();
+    });
+
+    // Then
+    assert_eq!(chrome.active_tab_zoom(), 110);
+    assert_eq!(ctx.zoom_factor(), 1.0);
+    assert!(!ctx.options(|o| o.zoom_with_keyboard));
+}
@@ -45,6 +45,8 @@ pub enum WebViewError {
     NotInitialized,
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
+    #[error("Navigation blocked: {0}")]
+    Blocked(String),
 }
 
 pub type Result<T> = std::result::Result<T, WebViewError>;
@@ -63,6 +65,8 @@ pub enum NavigationEvent {
 pub enum LoadState {
     Idle,
     Loading,
+    /// The response started arriving; the document isn't parsed yet
+    Committed,
     Interactive,
     Complete,
     Failed,
@@ -79,6 +83,12 @@ pub struct WebViewState {
     pub can_go_forward: bool,
     pub is_loading: bool,
     pub zoom_level: f32,
+    /// Referrer sent with the most recent navigation, if any
+    #[serde(default)]
+    pub referrer: Option<String>,
+    /// Extra headers sent with the most recent navigation
+    #[serde(default)]
+    pub request_headers: HashMap<String, String>,
 }
 
 impl Default for WebViewState {
@@ -92,10 +102,66 @@ impl Default for WebViewState {
             can_go_forward: false,
             is_loading: false,
             zoom_level: 1.0,
+            referrer: None,
+            request_headers: HashMap::new(),
         }
     }
 }
 
+/// Referrer policy governing the `Referer` sent with link navigations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReferrerPolicy {
+    /// Never send a referrer
+    NoReferrer,
+    /// Send only the origin of the referring page
+    Origin,
+    /// Send the full URL for same-origin requests, nothing otherwise
+    SameOrigin,
+    /// Full URL same-origin, origin cross-origin, nothing on https -> http
+    #[default]
+    StrictOriginWhenCrossOrigin,
+    /// Always send the full URL (minus credentials and fragment)
+    UnsafeUrl,
+}
+
+/// Compute the referrer for a navigation from `from` to `to` under `policy`
+///
+/// Returns `None` when no `Referer` header should be sent. Credentials and
+/// fragments are always stripped from the returned URL.
+pub fn compute_referrer(policy: ReferrerPolicy, from: &Url, to: &Url) -> Option<Url> {
+    if !matches!(from.scheme(), "http" | "https") {
+        return None;
+    }
+
+    let mut full = from.clone();
+    full.set_fragment(None);
+    let _ = full.set_username("");
+    let _ = full.set_password(None);
+
+    let mut origin = full.clone();
+    origin.set_path("/");
+    origin.set_query(None);
+
+    let same_origin = from.origin() == to.origin();
+    let downgrade = from.scheme() == "https" && to.scheme() != "https";
+
+    match policy {
+        ReferrerPolicy::NoReferrer => None,
+        ReferrerPolicy::Origin => Some(origin),
+        ReferrerPolicy::SameOrigin => same_origin.then_some(full),
+        ReferrerPolicy::StrictOriginWhenCrossOrigin => {
+            if same_origin {
+                Some(full)
+            } else if downgrade {
+                None
+            } else {
+                Some(origin)
+            }
+        }
+        ReferrerPolicy::UnsafeUrl => Some(full),
+    }
+}
+
 /// Cached resource
 #[derive(Debug, Clone)]
 struct CachedResource {
@@ -105,6 +171,19 @@ struct CachedResource {
     expires_at: Option<DateTime<Utc>>,
 }
 
+/// Extra request headers attached to a view's requests
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewHeaders {
+    /// Header names and values
+    pub headers: HashMap<String, String>,
+    /// Origin the headers are scoped to, e.g. `https://tools.internal`
+    #[serde(default)]
+    pub origin: Option<String>,
+    /// Also send the headers with navigations and subresource requests to
+    /// other origins
+    pub include_cross_origin: bool,
+}
+
 /// JavaScript message from web page
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsMessage {
@@ -136,6 +215,17 @@ pub struct WebViewConfig {
     pub autoplay_enabled: bool,
     /// Cache size in bytes
     pub cache_size: usize,
+    /// Host patterns blocked from navigation (`*` glob or plain substring)
+    #[serde(default)]
+    pub blocked_url_patterns: Vec<String>,
+    /// Referrer policy applied to link navigations
+    #[serde(default)]
+    pub referrer_policy: ReferrerPolicy,
+    /// Leave navigations loading until the host reports their progress
+    /// through `mark_committed`, `mark_interactive` and `mark_complete`,
+    /// instead of completing them immediately
+    #[serde(default)]
+    pub host_driven_loads: bool,
 }
 
 impl Default for WebViewConfig {
@@ -147,8 +237,52 @@ impl Default for WebViewConfig {
             clipboard_enabled: true,
             autoplay_enabled: false,
             cache_size: 100 * 1024 * 1024, // 100MB
+            blocked_url_patterns: Vec::new(),
+            referrer_policy: ReferrerPolicy::default(),
+            host_driven_loads: false,
+        }
+    }
+}
+
+impl WebViewConfig {
+    /// Check whether a host matches any blocked pattern (case-insensitive)
+    pub fn is_host_blocked(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        self.blocked_url_patterns.iter().any(|pattern| {
+            let pattern = pattern.to_ascii_lowercase();
+            if pattern.contains('*') {
+                glob_match(&pattern, &host)
+            } else {
+                !pattern.is_empty() && host.contains(&pattern)
+            }
+        })
+    }
+}
+
+/// Match `text` against a glob `pattern` where `*` matches any run of characters
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
         }
     }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 /// WebView Manager for coordinating web content rendering
@@ -167,6 +301,10 @@ pub struct WebViewManager {
     navigation_events: Arc<RwLock<Vec<NavigationEvent>>>,
     /// Next view ID
     next_id: Arc<RwLock<u64>>,
+    /// Last-used zoom level per host (host -> zoom)
+    host_zoom: Arc<RwLock<HashMap<String, f32>>>,
+    /// Injected request headers per view (id -> headers)
+    view_headers: Arc<RwLock<HashMap<u64, ViewHeaders>>>,
 }
 
 impl WebViewManager {
@@ -180,6 +318,8 @@ impl WebViewManager {
             config: Arc::new(RwLock::new(WebViewConfig::default())),
             navigation_events: Arc::new(RwLock::new(Vec::new())),
             next_id: Arc::new(RwLock::new(1)),
+            host_zoom: Arc::new(RwLock::new(HashMap::new())),
+            view_headers: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -219,6 +359,8 @@ impl WebViewManager {
         let mut positions = self.history_position.write().await;
         positions.remove(&id);
 
+        self.view_headers.write().await.remove(&id);
+
         Ok(())
     }
 
@@ -228,6 +370,25 @@ impl WebViewManager {
         let parsed_url = Url::parse(&url)
             .map_err(|e| WebViewError::InvalidUrl(e.to_string()))?;
 
+        if !self.views.read().await.contains_key(&id) {
+            return Err(WebViewError::NotInitialized);
+        }
+
+        // Reject navigation to blocked hosts
+        if let Some(host) = parsed_url.host_str() {
+            let blocked = self.config.read().await.is_host_blocked(host);
+            if blocked {
+                let mut events = self.navigation_events.write().await;
+                events.push(NavigationEvent::Failed {
+                    url: url.clone(),
+                    error: format!("Blocked host: {}", host),
+                    timestamp: Utc::now(),
+                });
+                return Err(WebViewError::Blocked(host.to_string()));
+            }
+        }
+
+        let request_headers = self.navigation_headers(id, &parsed_url).await?;
         let mut views = self.views.write().await;
         let view = views
             .get_mut(&id)
@@ -244,10 +405,25 @@ impl WebViewManager {
 
         // Update state
         view.current_url = url.clone();
+        view.referrer = None;
+        view.request_headers = request_headers;
         view.load_state = LoadState::Loading;
         view.is_loading = true;
         view.title = parsed_url.host_str().unwrap_or("Loading...").to_string();
 
+        // Apply remembered zoom for this host, so one site's zoom doesn't
+        // carry over to another
+        view.zoom_level = match parsed_url.host_str() {
+            Some(host) => self
+                .host_zoom
+                .read()
+                .await
+                .get(&host.to_ascii_lowercase())
+                .copied()
+                .unwrap_or(1.0),
+            None => 1.0,
+        };
+
         // Update history
         drop(views);
         let mut history = self.history.write().await;
@@ -268,21 +444,116 @@ impl WebViewManager {
         // Update navigation state
         self.update_navigation_state(id).await;
 
-        // Simulate load completion (in real impl, this would be triggered by actual page load)
+        // Simulate load completion unless the host reports it
+        if !self.config.read().await.host_driven_loads {
+            self.mark_complete(id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Report that a view's navigation committed (first bytes received)
+    ///
+    /// Moves the view to `Committed` and records a `Committed` event. Repeat
+    /// reports for the same navigation are ignored.
+    pub async fn mark_committed(&self, id: u64) -> Result<()> {
+        let committed = self
+            .views
+            .read()
+            .await
+            .get(&id)
+            .is_some_and(|view| view.load_state == LoadState::Committed);
+        if committed {
+            return Ok(());
+        }
+
+        let url = self
+            .advance_load(id, &[LoadState::Loading], LoadState::Committed)
+            .await?;
+        self.navigation_events
+            .write()
+            .await
+            .push(NavigationEvent::Committed {
+                url,
+                timestamp: Utc::now(),
+            });
+        Ok(())
+    }
+
+    /// Report that a view's document is parsed and interactive
+    /// (DOMContentLoaded), moving it to `Interactive`
+    pub async fn mark_interactive(&self, id: u64) -> Result<()> {
+        self.advance_load(
+            id,
+            &[LoadState::Loading, LoadState::Committed],
+            LoadState::Interactive,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Report that a view finished loading, moving it to `Complete`
+    ///
+    /// Records a `Completed` event.
+    pub async fn mark_complete(&self, id: u64) -> Result<()> {
+        let url = self
+            .advance_load(
+                id,
+                &[
+                    LoadState::Loading,
+                    LoadState::Committed,
+                    LoadState::Interactive,
+                ],
+                LoadState::Complete,
+            )
+            .await?;
+        self.navigation_events
+            .write()
+            .await
+            .push(NavigationEvent::Completed {
+                url,
+                timestamp: Utc::now(),
+            });
+        Ok(())
+    }
+
+    /// Move a view from one of `from` to `to`, returning its URL
+    async fn advance_load(&self, id: u64, from: &[LoadState], to: LoadState) -> Result<String> {
         let mut views = self.views.write().await;
-        if let Some(view) = views.get_mut(&id) {
-            view.load_state = LoadState::Complete;
-            view.is_loading = false;
+        let view = views.get_mut(&id).ok_or(WebViewError::NotInitialized)?;
+        if !from.contains(&view.load_state) {
+            return Err(WebViewError::NavigationFailed(format!(
+                "Cannot move from {:?} to {:?}",
+                view.load_state, to
+            )));
         }
+        view.load_state = to;
+        view.is_loading = to != LoadState::Complete;
+        Ok(view.current_url.clone())
+    }
 
-        // Record completion event
-        let complete_event = NavigationEvent::Completed {
-            url,
-            timestamp: Utc::now(),
+    /// Navigate to a URL as a link click from the current page
+    ///
+    /// The `Referer` is computed from the current URL using the configured
+    /// referrer policy and recorded on the view state.
+    pub async fn follow_link(&self, id: u64, url: String) -> Result<()> {
+        let target = Url::parse(&url).map_err(|e| WebViewError::InvalidUrl(e.to_string()))?;
+        let from = {
+            let views = self.views.read().await;
+            let view = views.get(&id).ok_or(WebViewError::NotInitialized)?;
+            view.current_url.clone()
         };
-        let mut events = self.navigation_events.write().await;
-        events.push(complete_event);
+        let policy = self.config.read().await.referrer_policy;
+        let referrer = Url::parse(&from)
+            .ok()
+            .and_then(|from| compute_referrer(policy, &from, &target));
 
+        self.navigate(id, url).await?;
+
+        let mut views = self.views.write().await;
+        if let Some(view) = views.get_mut(&id) {
+            view.referrer = referrer.map(|r| r.to_string());
+        }
         Ok(())
     }
 
@@ -444,20 +715,46 @@ impl WebViewManager {
     }
 
     /// Set zoom level
+    ///
+    /// The level is also remembered for the view's current host.
     pub async fn set_zoom(&self, id: u64, level: f32) -> Result<()> {
         let mut views = self.views.write().await;
         let view = views.get_mut(&id).ok_or(WebViewError::NotInitialized)?;
 
-        if level < 0.25 || level > 5.0 {
-            return Err(WebViewError::JsError(
-                "Zoom level must be between 0.25 and 5.0".to_string(),
-            ));
-        }
+        validate_zoom(level)?;
 
         view.zoom_level = level;
+
+        let host = Url::parse(&view.current_url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_ascii_lowercase()));
+        drop(views);
+
+        if let Some(host) = host {
+            self.host_zoom.write().await.insert(host, level);
+        }
         Ok(())
     }
 
+    /// Set the remembered zoom level for a host
+    pub async fn set_host_zoom(&self, host: &str, level: f32) -> Result<()> {
+        validate_zoom(level)?;
+        self.host_zoom
+            .write()
+            .await
+            .insert(host.to_ascii_lowercase(), level);
+        Ok(())
+    }
+
+    /// Get the remembered zoom level for a host
+    pub async fn get_host_zoom(&self, host: &str) -> Option<f32> {
+        self.host_zoom
+            .read()
+            .await
+            .get(&host.to_ascii_lowercase())
+            .copied()
+    }
+
     /// Get zoom level
     pub async fn get_zoom(&self, id: u64) -> Result<f32> {
         let views = self.views.read().await;
@@ -465,6 +762,123 @@ impl WebViewManager {
         Ok(view.zoom_level)
     }
 
+    /// Set extra headers sent with a view's requests, replacing any set before
+    ///
+    /// The headers go with navigations to the origin set with
+    /// [`Self::set_view_headers_origin`], and with subresource requests to
+    /// that origin or the current page's. Use
+    /// [`Self::set_view_headers_cross_origin`] to send them to any origin.
+    pub async fn set_view_headers(&self, id: u64, headers: HashMap<String, String>) -> Result<()> {
+        if !self.views.read().await.contains_key(&id) {
+            return Err(WebViewError::NotInitialized);
+        }
+        self.view_headers
+            .write()
+            .await
+            .entry(id)
+            .or_default()
+            .headers = headers;
+        Ok(())
+    }
+
+    /// Scope a view's headers to the origin of `url`
+    ///
+    /// Navigations only carry the headers when they target this origin.
+    pub async fn set_view_headers_origin(&self, id: u64, url: &Url) -> Result<()> {
+        if !self.views.read().await.contains_key(&id) {
+            return Err(WebViewError::NotInitialized);
+        }
+        self.view_headers
+            .write()
+            .await
+            .entry(id)
+            .or_default()
+            .origin = Some(url.origin().ascii_serialization());
+        Ok(())
+    }
+
+    /// Opt in or out of sending a view's headers to other origins
+    pub async fn set_view_headers_cross_origin(&self, id: u64, enabled: bool) -> Result<()> {
+        if !self.views.read().await.contains_key(&id) {
+            return Err(WebViewError::NotInitialized);
+        }
+        self.view_headers
+            .write()
+            .await
+            .entry(id)
+            .or_default()
+            .include_cross_origin = enabled;
+        Ok(())
+    }
+
+    /// Remove a view's extra headers, origin scope and cross-origin opt-in
+    pub async fn clear_view_headers(&self, id: u64) {
+        self.view_headers.write().await.remove(&id);
+    }
+
+    /// Get a view's extra headers and settings
+    pub async fn get_view_headers(&self, id: u64) -> Option<ViewHeaders> {
+        self.view_headers.read().await.get(&id).cloned()
+    }
+
+    /// Get the extra headers for a top-level navigation in a view
+    ///
+    /// Only the target matters: the headers are sent if it is on the view's
+    /// scoped origin, or if the view opted in to cross-origin headers. The
+    /// page the view is leaving plays no part, so a token meant for one site
+    /// isn't sent to whatever site the view navigates to next.
+    pub async fn navigation_headers(
+        &self,
+        id: u64,
+        target: &Url,
+    ) -> Result<HashMap<String, String>> {
+        self.scoped_view_headers(id, target, None).await
+    }
+
+    /// Get the extra headers for a subresource request from a view's page
+    ///
+    /// Requests to an origin other than the scoped origin and the current
+    /// page's get no headers unless the view opted in.
+    pub async fn subresource_headers(&self, id: u64, url: &str) -> Result<HashMap<String, String>> {
+        let target = Url::parse(url).map_err(|e| WebViewError::InvalidUrl(e.to_string()))?;
+        let page = {
+            let views = self.views.read().await;
+            let view = views.get(&id).ok_or(WebViewError::NotInitialized)?;
+            view.current_url.clone()
+        };
+        self.scoped_view_headers(id, &target, Url::parse(&page).ok())
+            .await
+    }
+
+    /// Get a view's extra headers if they may be sent to `target`
+    ///
+    /// `page`, if given, is another origin the headers may go to.
+    async fn scoped_view_headers(
+        &self,
+        id: u64,
+        target: &Url,
+        page: Option<Url>,
+    ) -> Result<HashMap<String, String>> {
+        if !self.views.read().await.contains_key(&id) {
+            return Err(WebViewError::NotInitialized);
+        }
+        let view_headers = self.view_headers.read().await;
+        let Some(view) = view_headers.get(&id) else {
+            return Ok(HashMap::new());
+        };
+
+        // Opaque origins (e.g. about:blank) never match, so they need the opt-in
+        let origin = target.origin();
+        let in_scope = origin.is_tuple()
+            && (view.origin.as_deref() == Some(origin.ascii_serialization().as_str())
+                || page.is_some_and(|page| page.origin() == origin));
+        if in_scope || view.include_cross_origin {
+            Ok(view.headers.clone())
+        } else {
+            Ok(HashMap::new())
+        }
+    }
+
     /// Add resource to cache
     pub async fn cache_resource(&self, url: String, data: Vec<u8>, mime_type: String) {
         let resource = CachedResource {
@@ -528,6 +942,23 @@ impl WebViewManager {
         *current = config;
     }
 
+    /// Add a blocked host pattern
+    pub async fn add_blocked_pattern(&self, pattern: impl Into<String>) {
+        let pattern = pattern.into();
+        let mut config = self.config.write().await;
+        if !config.blocked_url_patterns.contains(&pattern) {
+            config.blocked_url_patterns.push(pattern);
+        }
+    }
+
+    /// Remove a blocked host pattern, returning whether it was present
+    pub async fn remove_blocked_pattern(&self, pattern: &str) -> bool {
+        let mut config = self.config.write().await;
+        let before = config.blocked_url_patterns.len();
+        config.blocked_url_patterns.retain(|p| p != pattern);
+        config.blocked_url_patterns.len() != before
+    }
+
     /// Get navigation events
     pub async fn get_navigation_events(&self) -> Vec<NavigationEvent> {
         self.navigation_events.read().await.clone()
@@ -552,6 +983,16 @@ impl Default for WebViewManager {
     }
 }
 
+/// Validate a zoom level against the supported 0.25..=5.0 range
+fn validate_zoom(level: f32) -> Result<()> {
+    if !(0.25..=5.0).contains(&level) {
+        return Err(WebViewError::JsError(
+            "Zoom level must be between 0.25 and 5.0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 // ============================================================================
 // WebView Embedding Infrastructure
 // ============================================================================
@@ -579,6 +1020,23 @@ pub struct EmbedConfig {
     pub ipc_handler_name: String,
     /// Custom initialization script
     pub initialization_script: Option<String>,
+    /// How popup (new window) requests are handled
+    #[serde(default)]
+    pub popup_policy: PopupPolicy,
+}
+
+/// Handling of popup (new window) requests from the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PopupPolicy {
+    /// Pass `NewWindowRequested` events on to the host
+    #[default]
+    Allow,
+    /// Drop the request and count it as blocked
+    Block,
+    /// Open the URL in the current view instead
+    SameTab,
+    /// Queue the request for the host to decide later
+    Deferred,
 }
 
 impl Default for EmbedConfig {
@@ -594,6 +1052,7 @@ impl Default for EmbedConfig {
             clipboard_enabled: true,
             ipc_handler_name: "cortenIpc".to_string(),
             initialization_script: None,
+            popup_policy: PopupPolicy::default(),
         }
     }
 }
@@ -622,6 +1081,20 @@ impl Default for WebViewBounds {
     }
 }
 
+/// Identifier for a screenshot requested with [`EmbeddedWebView::capture_thumbnail`]
+pub type CaptureId = u64;
+
+/// Screenshot of the page delivered by a `CaptureReady` event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapturedImage {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// RGBA pixel data, row by row
+    pub rgba: Vec<u8>,
+}
+
 /// Message types for the WebView bridge (egui <-> WebView communication)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BridgeMessage {
@@ -653,6 +1126,12 @@ pub enum BridgeMessage {
     ClearFind,
     /// Custom IPC message from JavaScript
     IpcMessage { method: String, params: serde_json::Value },
+    /// Capture a screenshot scaled to fit within the given size
+    Capture {
+        id: CaptureId,
+        max_width: u32,
+        max_height: u32,
+    },
 }
 
 /// Events emitted by the WebView
@@ -692,6 +1171,22 @@ pub enum WebViewEvent {
     DevToolsClosed,
     /// Find result
     FindResult { active_match: u32, total_matches: u32 },
+    /// Screenshot requested with `BridgeMessage::Capture` is ready
+    CaptureReady {
+        id: CaptureId,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    },
+}
+
+/// Download started by the page, waiting to be handed to the downloads manager
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingDownload {
+    /// URL being downloaded
+    pub url: String,
+    /// File name suggested by the page or server
+    pub suggested_filename: String,
 }
 
 /// WebView bridge for message passing between egui and WebView
@@ -943,6 +1438,16 @@ pub struct EmbeddedWebView {
     native_initialized: bool,
     /// Last known bounds for resize detection
     last_bounds: Option<WebViewBounds>,
+    /// Downloads captured from `DownloadStarted` events
+    downloads: Vec<PendingDownload>,
+    /// Popup URLs queued under [`PopupPolicy::Deferred`]
+    new_window_requests: Vec<String>,
+    /// Popups dropped under [`PopupPolicy::Block`]
+    blocked_popups: u32,
+    /// Screenshots received from `CaptureReady` events
+    captures: HashMap<CaptureId, CapturedImage>,
+    /// ID for the next capture request
+    next_capture_id: CaptureId,
 }
 
 impl EmbeddedWebView {
@@ -961,6 +1466,11 @@ impl EmbeddedWebView {
             js_results: HashMap::new(),
             native_initialized: false,
             last_bounds: None,
+            downloads: Vec::new(),
+            new_window_requests: Vec::new(),
+            blocked_popups: 0,
+            captures: HashMap::new(),
+            next_capture_id: 1,
         }
     }
 
@@ -1196,11 +1706,134 @@ impl EmbeddedWebView {
                 BridgeMessage::SetZoom { level } => {
                     self.state.zoom_level = level;
                 }
+                BridgeMessage::Capture {
+                    id,
+                    max_width,
+                    max_height,
+                } => {
+                    // In placeholder mode, answer with the background color
+                    let image = self.placeholder_capture(max_width, max_height);
+                    self.bridge.push_event(WebViewEvent::CaptureReady {
+                        id,
+                        width: image.width,
+                        height: image.height,
+                        rgba: image.rgba,
+                    });
+                }
                 _ => {
                     // Other messages would be forwarded to native WebView
                 }
             }
         }
+
+        // Capture events handled here; the rest stay queued for the host
+        let mut unhandled = Vec::new();
+        let mut same_tab = Vec::new();
+        for event in self.bridge.take_events() {
+            match event {
+                WebViewEvent::NewWindowRequested { url } => match self.config.popup_policy {
+                    PopupPolicy::Allow => unhandled.push(WebViewEvent::NewWindowRequested { url }),
+                    PopupPolicy::Block => self.blocked_popups += 1,
+                    PopupPolicy::SameTab => same_tab.push(url),
+                    PopupPolicy::Deferred => self.new_window_requests.push(url),
+                },
+                WebViewEvent::DownloadStarted {
+                    url,
+                    suggested_filename,
+                } => self.downloads.push(PendingDownload {
+                    url,
+                    suggested_filename,
+                }),
+                WebViewEvent::CaptureReady {
+                    id,
+                    width,
+                    height,
+                    rgba,
+                } => {
+                    self.captures.insert(
+                        id,
+                        CapturedImage {
+                            width,
+                            height,
+                            rgba,
+                        },
+                    );
+                }
+                event => unhandled.push(event),
+            }
+        }
+        for event in unhandled {
+            self.bridge.push_event(event);
+        }
+        for url in same_tab {
+            self.navigate(url);
+        }
+    }
+
+    /// Take the popup URLs queued under [`PopupPolicy::Deferred`]
+    pub fn take_new_window_requests(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.new_window_requests)
+    }
+
+    /// Get the number of popups dropped under [`PopupPolicy::Block`]
+    pub fn blocked_popup_count(&self) -> u32 {
+        self.blocked_popups
+    }
+
+    /// Get the popup policy
+    pub fn popup_policy(&self) -> PopupPolicy {
+        self.config.popup_policy
+    }
+
+    /// Set the popup policy for future requests
+    pub fn set_popup_policy(&mut self, policy: PopupPolicy) {
+        self.config.popup_policy = policy;
+    }
+
+    /// Request a screenshot of the page scaled to fit within the given size
+    ///
+    /// The image arrives as a `CaptureReady` event; once
+    /// [`Self::process_messages`] has seen it, [`Self::take_capture`]
+    /// returns it.
+    pub fn capture_thumbnail(&mut self, max_width: u32, max_height: u32) -> CaptureId {
+        let id = self.next_capture_id;
+        self.next_capture_id += 1;
+        self.bridge.send(BridgeMessage::Capture {
+            id,
+            max_width,
+            max_height,
+        });
+        id
+    }
+
+    /// Take a screenshot received for a capture request
+    pub fn take_capture(&mut self, id: CaptureId) -> Option<CapturedImage> {
+        self.captures.remove(&id)
+    }
+
+    /// Solid background-color image the size of the view, scaled to fit
+    /// within `max_width` x `max_height`
+    fn placeholder_capture(&self, max_width: u32, max_height: u32) -> CapturedImage {
+        let bounds = self.last_bounds.unwrap_or_default();
+        let (view_width, view_height) = (bounds.width.max(1), bounds.height.max(1));
+        let scale = (max_width as f32 / view_width as f32)
+            .min(max_height as f32 / view_height as f32)
+            .min(1.0);
+        let width = ((view_width as f32 * scale).round() as u32).max(1);
+        let height = ((view_height as f32 * scale).round() as u32).max(1);
+        CapturedImage {
+            width,
+            height,
+            rgba: self
+                .config
+                .background_color
+                .repeat(width as usize * height as usize),
+        }
+    }
+
+    /// Take the downloads captured by [`Self::process_messages`]
+    pub fn take_downloads(&mut self) -> Vec<PendingDownload> {
+        std::mem::take(&mut self.downloads)
     }
 
     /// Update can_go_back and can_go_forward state
@@ -1459,6 +2092,12 @@ impl WebViewBuilder {
         self
     }
 
+    /// Set how popup requests are handled
+    pub fn with_popup_policy(mut self, policy: PopupPolicy) -> Self {
+        self.config.popup_policy = policy;
+        self
+    }
+
     /// Enable/disable JavaScript
     pub fn with_javascript(mut self, enabled: bool) -> Self {
         self.config.javascript_enabled = enabled;
@@ -1689,6 +2328,325 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_zoom_persists_per_host() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+
+        manager
+            .navigate(id, "https://example.com/a".to_string())
+            .await
+            .unwrap();
+        manager.set_zoom(id, 1.5).await.unwrap();
+        assert_eq!(manager.get_host_zoom("example.com").await, Some(1.5));
+
+        // A new view picks up the remembered zoom on navigation
+        manager.destroy_webview(id).await.unwrap();
+        let id2 = manager.create_webview().await;
+        manager
+            .navigate(id2, "https://EXAMPLE.com/b".to_string())
+            .await
+            .unwrap();
+        assert_eq!(manager.get_zoom(id2).await.unwrap(), 1.5);
+
+        manager.set_host_zoom("rust-lang.org", 0.5).await.unwrap();
+        manager
+            .navigate(id2, "https://rust-lang.org".to_string())
+            .await
+            .unwrap();
+        assert_eq!(manager.get_zoom(id2).await.unwrap(), 0.5);
+
+        assert!(manager.set_host_zoom("rust-lang.org", 6.0).await.is_err());
+        assert_eq!(manager.get_host_zoom("rust-lang.org").await, Some(0.5));
+    }
+
+    #[tokio::test]
+    async fn test_zoom_resets_for_host_without_remembered_zoom() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+
+        manager
+            .navigate(id, "https://zoomed.example/".to_string())
+            .await
+            .unwrap();
+        manager.set_zoom(id, 2.0).await.unwrap();
+
+        manager
+            .navigate(id, "https://other.example/".to_string())
+            .await
+            .unwrap();
+        assert_eq!(manager.get_zoom(id).await.unwrap(), 1.0);
+        assert_eq!(manager.get_host_zoom("other.example").await, None);
+
+        manager
+            .navigate(id, "https://zoomed.example/page".to_string())
+            .await
+            .unwrap();
+        assert_eq!(manager.get_zoom(id).await.unwrap(), 2.0);
+    }
+
+    #[tokio::test]
+    async fn test_view_headers_are_per_view() {
+        let manager = WebViewManager::new();
+        let tool = manager.create_webview().await;
+        let other = manager.create_webview().await;
+        let headers: HashMap<String, String> =
+            [("Authorization".to_string(), "Bearer token".to_string())].into();
+
+        let dashboard = Url::parse("https://tools.internal/dashboard").unwrap();
+
+        manager
+            .set_view_headers(tool, headers.clone())
+            .await
+            .unwrap();
+        manager
+            .set_view_headers_origin(tool, &dashboard)
+            .await
+            .unwrap();
+        for id in [tool, other] {
+            manager.navigate(id, dashboard.to_string()).await.unwrap();
+        }
+
+        assert_eq!(
+            manager.get_state(tool).await.unwrap().request_headers,
+            headers
+        );
+        assert!(manager
+            .get_state(other)
+            .await
+            .unwrap()
+            .request_headers
+            .is_empty());
+        assert_eq!(
+            manager.navigation_headers(tool, &dashboard).await.unwrap(),
+            headers
+        );
+        assert_eq!(
+            manager
+                .subresource_headers(tool, "https://tools.internal/api/data")
+                .await
+                .unwrap(),
+            headers
+        );
+        assert!(manager
+            .navigation_headers(other, &dashboard)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(manager
+            .subresource_headers(other, "https://tools.internal/api/data")
+            .await
+            .unwrap()
+            .is_empty());
+
+        manager.clear_view_headers(tool).await;
+        assert!(manager.get_view_headers(tool).await.is_none());
+        assert!(manager
+            .navigation_headers(tool, &dashboard)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(manager.set_view_headers(99, headers).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_view_headers_skip_cross_origin_navigations() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+        let headers: HashMap<String, String> =
+            [("Authorization".to_string(), "Bearer token".to_string())].into();
+        let tools = Url::parse("https://tools.internal/").unwrap();
+        manager.set_view_headers(id, headers.clone()).await.unwrap();
+        manager.set_view_headers_origin(id, &tools).await.unwrap();
+        manager.navigate(id, tools.to_string()).await.unwrap();
+
+        let third_party = Url::parse("https://news.example.com/").unwrap();
+        let nav = manager.navigation_headers(id, &third_party).await.unwrap();
+        assert!(!nav.contains_key("Authorization"));
+        manager.navigate(id, third_party.to_string()).await.unwrap();
+        assert!(manager
+            .get_state(id)
+            .await
+            .unwrap()
+            .request_headers
+            .is_empty());
+
+        manager
+            .set_view_headers_cross_origin(id, true)
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.navigation_headers(id, &third_party).await.unwrap(),
+            headers
+        );
+    }
+
+    #[tokio::test]
+    async fn test_view_headers_sent_with_first_navigation() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+        let headers: HashMap<String, String> =
+            [("Authorization".to_string(), "Bearer token".to_string())].into();
+        let tools = Url::parse("https://tools.internal/").unwrap();
+        manager.set_view_headers(id, headers.clone()).await.unwrap();
+        manager.set_view_headers_origin(id, &tools).await.unwrap();
+
+        // The view is still on about:blank; only the target's origin counts
+        assert_eq!(
+            manager.get_state(id).await.unwrap().current_url,
+            "about:blank"
+        );
+        assert_eq!(
+            manager.navigation_headers(id, &tools).await.unwrap(),
+            headers
+        );
+        manager
+            .navigate(id, "https://tools.internal/home".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.get_state(id).await.unwrap().request_headers,
+            headers
+        );
+    }
+
+    #[tokio::test]
+    async fn test_view_headers_skip_cross_origin_subresources() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+        let headers: HashMap<String, String> =
+            [("X-Api-Key".to_string(), "secret".to_string())].into();
+        manager.set_view_headers(id, headers.clone()).await.unwrap();
+        manager
+            .navigate(id, "https://tools.internal/".to_string())
+            .await
+            .unwrap();
+
+        let cdn = "https://cdn.example.com/app.js";
+        assert!(manager
+            .subresource_headers(id, cdn)
+            .await
+            .unwrap()
+            .is_empty());
+        // Different scheme or port is a different origin too
+        assert!(manager
+            .subresource_headers(id, "http://tools.internal/app.js")
+            .await
+            .unwrap()
+            .is_empty());
+
+        manager
+            .set_view_headers_cross_origin(id, true)
+            .await
+            .unwrap();
+        assert_eq!(manager.subresource_headers(id, cdn).await.unwrap(), headers);
+        // Replacing the headers keeps the opt-in
+        manager.set_view_headers(id, HashMap::new()).await.unwrap();
+        assert!(
+            manager
+                .get_view_headers(id)
+                .await
+                .unwrap()
+                .include_cross_origin
+        );
+    }
+
+    #[tokio::test]
+    async fn test_host_driven_load_transitions() {
+        let manager = WebViewManager::new();
+        manager
+            .set_config(WebViewConfig {
+                host_driven_loads: true,
+                ..Default::default()
+            })
+            .await;
+        let id = manager.create_webview().await;
+        let url = "https://example.com/".to_string();
+
+        manager.navigate(id, url.clone()).await.unwrap();
+        let state = manager.get_state(id).await.unwrap();
+        assert_eq!(state.load_state, LoadState::Loading);
+        assert!(state.is_loading);
+
+        manager.mark_committed(id).await.unwrap();
+        let state = manager.get_state(id).await.unwrap();
+        assert_eq!(state.load_state, LoadState::Committed);
+        assert!(state.is_loading);
+
+        manager.mark_interactive(id).await.unwrap();
+        let state = manager.get_state(id).await.unwrap();
+        assert_eq!(state.load_state, LoadState::Interactive);
+        assert!(state.is_loading);
+
+        manager.mark_complete(id).await.unwrap();
+        let state = manager.get_state(id).await.unwrap();
+        assert_eq!(state.load_state, LoadState::Complete);
+        assert!(!state.is_loading);
+
+        let events = manager.get_navigation_events().await;
+        assert!(matches!(
+            &events[..],
+            [
+                NavigationEvent::Started { url: a, .. },
+                NavigationEvent::Committed { url: b, .. },
+                NavigationEvent::Completed { url: c, .. },
+            ] if *a == url && *b == url && *c == url
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mark_committed_twice_emits_one_event() {
+        let manager = WebViewManager::new();
+        manager
+            .set_config(WebViewConfig {
+                host_driven_loads: true,
+                ..Default::default()
+            })
+            .await;
+        let id = manager.create_webview().await;
+        manager
+            .navigate(id, "https://example.com/".to_string())
+            .await
+            .unwrap();
+
+        manager.mark_committed(id).await.unwrap();
+        manager.mark_committed(id).await.unwrap();
+
+        let commits = manager
+            .get_navigation_events()
+            .await
+            .iter()
+            .filter(|event| matches!(event, NavigationEvent::Committed { .. }))
+            .count();
+        assert_eq!(commits, 1);
+        assert_eq!(
+            manager.get_state(id).await.unwrap().load_state,
+            LoadState::Committed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_transitions_reject_out_of_order() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+
+        // Simulated loads complete immediately
+        manager
+            .navigate(id, "https://example.com/".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.get_state(id).await.unwrap().load_state,
+            LoadState::Complete
+        );
+        assert!(manager.mark_interactive(id).await.is_err());
+        assert!(manager.mark_committed(id).await.is_err());
+        assert!(matches!(
+            manager.mark_complete(99).await,
+            Err(WebViewError::NotInitialized)
+        ));
+    }
+
     #[tokio::test]
     async fn test_cache_resource() {
         let manager = WebViewManager::new();
@@ -1750,6 +2708,138 @@ mod tests {
         assert!(events.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_navigate_blocked_host() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+        manager.add_blocked_pattern("ADS.").await;
+
+        let result = manager
+            .navigate(id, "https://ads.example.com".to_string())
+            .await;
+        assert!(matches!(result, Err(WebViewError::Blocked(_))));
+
+        let events = manager.get_navigation_events().await;
+        assert!(matches!(events.last(), Some(NavigationEvent::Failed { .. })));
+
+        manager
+            .navigate(id, "https://example.com".to_string())
+            .await
+            .unwrap();
+        let state = manager.get_state(id).await.unwrap();
+        assert_eq!(state.current_url, "https://example.com");
+
+        assert!(manager.remove_blocked_pattern("ADS.").await);
+        assert!(!manager.remove_blocked_pattern("ADS.").await);
+    }
+
+    #[tokio::test]
+    async fn test_navigate_unknown_view_before_block_check() {
+        let manager = WebViewManager::new();
+        manager.add_blocked_pattern("ads.").await;
+
+        let result = manager
+            .navigate(99, "https://ads.example.com".to_string())
+            .await;
+        assert!(matches!(result, Err(WebViewError::NotInitialized)));
+        assert!(manager.get_navigation_events().await.is_empty());
+    }
+
+    #[test]
+    fn test_blocked_pattern_glob() {
+        let config = WebViewConfig {
+            blocked_url_patterns: vec!["*.doubleclick.net".to_string()],
+            ..Default::default()
+        };
+        assert!(config.is_host_blocked("ad.DoubleClick.net"));
+        assert!(!config.is_host_blocked("doubleclick.net.example.com"));
+        assert!(!config.is_host_blocked("example.com"));
+    }
+
+    fn referrer(policy: ReferrerPolicy, from: &str, to: &str) -> Option<String> {
+        let from = Url::parse(from).unwrap();
+        let to = Url::parse(to).unwrap();
+        compute_referrer(policy, &from, &to).map(|u| u.to_string())
+    }
+
+    const FROM: &str = "https://user:pw@example.com/page?q=1#frag";
+    const SAME: &str = "https://example.com/other";
+    const CROSS: &str = "https://rust-lang.org/";
+    const DOWNGRADE: &str = "http://rust-lang.org/";
+    const FULL: &str = "https://example.com/page?q=1";
+    const ORIGIN: &str = "https://example.com/";
+
+    #[test]
+    fn test_referrer_no_referrer() {
+        for to in [SAME, CROSS, DOWNGRADE] {
+            assert_eq!(referrer(ReferrerPolicy::NoReferrer, FROM, to), None);
+        }
+    }
+
+    #[test]
+    fn test_referrer_origin() {
+        for to in [SAME, CROSS, DOWNGRADE] {
+            assert_eq!(
+                referrer(ReferrerPolicy::Origin, FROM, to).as_deref(),
+                Some(ORIGIN)
+            );
+        }
+    }
+
+    #[test]
+    fn test_referrer_same_origin() {
+        let policy = ReferrerPolicy::SameOrigin;
+        assert_eq!(referrer(policy, FROM, SAME).as_deref(), Some(FULL));
+        assert_eq!(referrer(policy, FROM, CROSS), None);
+        assert_eq!(referrer(policy, FROM, DOWNGRADE), None);
+    }
+
+    #[test]
+    fn test_referrer_strict_origin_when_cross_origin() {
+        let policy = ReferrerPolicy::StrictOriginWhenCrossOrigin;
+        assert_eq!(referrer(policy, FROM, SAME).as_deref(), Some(FULL));
+        assert_eq!(referrer(policy, FROM, CROSS).as_deref(), Some(ORIGIN));
+        assert_eq!(referrer(policy, FROM, DOWNGRADE), None);
+    }
+
+    #[test]
+    fn test_referrer_unsafe_url() {
+        for to in [SAME, CROSS, DOWNGRADE] {
+            assert_eq!(
+                referrer(ReferrerPolicy::UnsafeUrl, FROM, to).as_deref(),
+                Some(FULL)
+            );
+        }
+        assert_eq!(
+            referrer(ReferrerPolicy::UnsafeUrl, "about:blank", SAME),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_follow_link_sets_referrer() {
+        let manager = WebViewManager::new();
+        let id = manager.create_webview().await;
+
+        manager
+            .navigate(id, "https://example.com/page".to_string())
+            .await
+            .unwrap();
+        manager
+            .follow_link(id, "https://rust-lang.org/".to_string())
+            .await
+            .unwrap();
+        let state = manager.get_state(id).await.unwrap();
+        assert_eq!(state.referrer.as_deref(), Some("https://example.com/"));
+
+        // Typed navigations carry no referrer
+        manager
+            .navigate(id, "https://example.com/".to_string())
+            .await
+            .unwrap();
+        assert!(manager.get_state(id).await.unwrap().referrer.is_none());
+    }
+
     #[tokio::test]
     async fn test_get_active_views() {
         let manager = WebViewManager::new();
@@ -1970,6 +3060,150 @@ mod tests {
         assert!(script.contains("window.corten.version = '0.5.0';"));
     }
 
+    #[test]
+    fn test_embedded_webview_captures_downloads() {
+        let mut webview = EmbeddedWebView::new(EmbedConfig::default());
+        let bridge = webview.bridge().clone();
+
+        bridge.push_event(WebViewEvent::DownloadStarted {
+            url: "https://example.com/report.pdf".to_string(),
+            suggested_filename: "report.pdf".to_string(),
+        });
+        bridge.push_event(WebViewEvent::TitleChanged {
+            title: "Reports".to_string(),
+        });
+        webview.process_messages();
+
+        assert_eq!(
+            webview.take_downloads(),
+            vec![PendingDownload {
+                url: "https://example.com/report.pdf".to_string(),
+                suggested_filename: "report.pdf".to_string(),
+            }]
+        );
+        assert!(webview.take_downloads().is_empty());
+
+        // Other events are left for the host
+        let events: Vec<_> = webview.poll_events().collect();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], WebViewEvent::TitleChanged { .. }));
+    }
+
+    fn request_popup(webview: &mut EmbeddedWebView, url: &str) {
+        webview
+            .bridge()
+            .push_event(WebViewEvent::NewWindowRequested {
+                url: url.to_string(),
+            });
+        webview.process_messages();
+    }
+
+    #[test]
+    fn test_popup_policy_allow_and_block() {
+        let mut webview = EmbeddedWebView::new(EmbedConfig::default());
+        assert_eq!(webview.popup_policy(), PopupPolicy::Allow);
+
+        request_popup(&mut webview, "https://popup.example/");
+        let events: Vec<_> = webview.poll_events().collect();
+        assert!(matches!(
+            &events[..],
+            [WebViewEvent::NewWindowRequested { url }] if url == "https://popup.example/"
+        ));
+
+        webview.set_popup_policy(PopupPolicy::Block);
+        request_popup(&mut webview, "https://popup.example/");
+        request_popup(&mut webview, "https://popup.example/again");
+        assert_eq!(webview.blocked_popup_count(), 2);
+        assert_eq!(webview.poll_events().count(), 0);
+        assert_eq!(webview.state().url, "about:blank");
+    }
+
+    #[test]
+    fn test_popup_policy_same_tab_navigates() {
+        let mut webview = WebViewBuilder::new()
+            .with_popup_policy(PopupPolicy::SameTab)
+            .build()
+            .unwrap();
+
+        request_popup(&mut webview, "https://popup.example/");
+        assert_eq!(webview.state().url, "https://popup.example/");
+        assert!(webview.state().can_go_back);
+        assert_eq!(webview.blocked_popup_count(), 0);
+        assert!(webview.take_new_window_requests().is_empty());
+    }
+
+    #[test]
+    fn test_popup_policy_deferred_queues() {
+        let mut webview = EmbeddedWebView::new(EmbedConfig {
+            popup_policy: PopupPolicy::Deferred,
+            ..Default::default()
+        });
+
+        request_popup(&mut webview, "https://a.example/");
+        request_popup(&mut webview, "https://b.example/");
+        assert_eq!(
+            webview.take_new_window_requests(),
+            vec!["https://a.example/", "https://b.example/"]
+        );
+        assert!(webview.take_new_window_requests().is_empty());
+        assert_eq!(webview.state().url, "about:blank");
+        assert_eq!(webview.poll_events().count(), 0);
+    }
+
+    #[test]
+    fn test_capture_thumbnail_from_host() {
+        let mut webview = EmbeddedWebView::new(EmbedConfig::default());
+        let id = webview.capture_thumbnail(160, 100);
+        assert_ne!(webview.capture_thumbnail(160, 100), id);
+
+        // Act as the native host: answer the first request
+        let messages = webview.bridge().take_outgoing();
+        assert!(matches!(
+            messages[0],
+            BridgeMessage::Capture {
+                id: sent,
+                max_width: 160,
+                max_height: 100,
+            } if sent == id
+        ));
+        let rgba = vec![10, 20, 30, 255, 40, 50, 60, 255];
+        webview.bridge().push_event(WebViewEvent::CaptureReady {
+            id,
+            width: 2,
+            height: 1,
+            rgba: rgba.clone(),
+        });
+        webview.process_messages();
+
+        assert_eq!(
+            webview.take_capture(id),
+            Some(CapturedImage {
+                width: 2,
+                height: 1,
+                rgba,
+            })
+        );
+        assert_eq!(webview.take_capture(id), None);
+        assert_eq!(webview.poll_events().count(), 0);
+    }
+
+    #[test]
+    fn test_capture_thumbnail_placeholder() {
+        let mut webview = EmbeddedWebView::new(EmbedConfig {
+            background_color: [1, 2, 3, 255],
+            ..Default::default()
+        });
+
+        // The default 800x600 view is scaled to fit 200x200
+        let id = webview.capture_thumbnail(200, 200);
+        webview.process_messages();
+
+        let image = webview.take_capture(id).unwrap();
+        assert_eq!((image.width, image.height), (200, 150));
+        assert_eq!(image.rgba.len(), 200 * 150 * 4);
+        assert!(image.rgba.chunks(4).all(|pixel| pixel == [1, 2, 3, 255]));
+    }
+
     #[test]
     fn test_webview_bridge_messaging() {
         let bridge = WebViewBridge::new();